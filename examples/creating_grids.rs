@@ -20,7 +20,7 @@ fn main() {
 fn grid_from_single_character() {
   let grid = Printer::create_grid_from_single_character('a', WIDTH, HEIGHT);
 
-  Printer::print_over_previous_grid(grid, HEIGHT);
+  Printer::print_over_previous_grid(grid, HEIGHT).unwrap();
 }
 
 /// Prints a grid from a-t
@@ -32,7 +32,13 @@ fn grid_from_full_character_list() {
     "p", "q", "r", "s", "t",
   ];
 
-  let grid = Printer::create_grid_from_full_character_list(&character_list, WIDTH, HEIGHT).unwrap();
+  let grid = Printer::create_grid_from_full_character_list(
+    &character_list,
+    WIDTH,
+    HEIGHT,
+    CharacterOrdering::RowMajor,
+  )
+  .unwrap();
 
-  Printer::print_over_previous_grid(grid, HEIGHT);
+  Printer::print_over_previous_grid(grid, HEIGHT).unwrap();
 }
@@ -28,8 +28,13 @@ fn main() {
       update_random_number_array(&mut rng, &mut list_of_numbers);
 
       // Create a grid with the data
-      let grid =
-        Printer::create_grid_from_full_character_list(&list_of_numbers, width, height).unwrap();
+      let grid = Printer::create_grid_from_full_character_list(
+        &list_of_numbers,
+        width,
+        height,
+        CharacterOrdering::RowMajor,
+      )
+      .unwrap();
 
       // Print the grid
       printer
@@ -0,0 +1,69 @@
+mod tests;
+
+/// Prints grids in a way screen readers can follow, without moving the cursor or printing parts of rows.
+///
+/// Every row that changed since the previous grid is printed in its entirety on a line of its own, below everything printed before it,
+/// so screen readers announce whole rows of text rather than scattered characters. Styles and trailing whitespace are left out.
+///
+/// Set with [`replace_accessibility`](crate::printer::Printer::replace_accessibility).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((20, 8));
+/// printer.replace_accessibility(Some(Accessibility::new().with_row_labels(true)));
+///
+/// printer.dynamic_print("Health: 10\nMana:   20".to_string()).unwrap();
+/// printer.dynamic_print("Health:  9\nMana:   20".to_string()).unwrap();
+///
+/// assert_eq!(
+///   printer.get_capture().unwrap().bytes(),
+///   b"row 1: Health: 10\r\nrow 2: Mana:   20\r\nrow 1: Health:  9\r\n"
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Accessibility {
+  /// Whether every printed row starts with its number, such as `row 2: `, starting from 1.
+  pub row_labels: bool,
+}
+
+impl Accessibility {
+  /// Creates an accessibility mode without row labels.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets whether every printed row starts with its number, such as `row 2: `.
+  pub fn with_row_labels(mut self, row_labels: bool) -> Self {
+    self.row_labels = row_labels;
+
+    self
+  }
+
+  /// Returns every row of the grid that's different from the same row of the previous grid, each on a line of its own.
+  ///
+  /// Both grids are expected to have no escape sequences.
+  pub(crate) fn format_changed_rows(&self, grid: &str, previous_grid: &str) -> String {
+    let previous_rows: Vec<&str> = if previous_grid.is_empty() {
+      Vec::new()
+    } else {
+      previous_grid.split('\n').collect()
+    };
+
+    grid
+      .split('\n')
+      .enumerate()
+      .filter(|(index, row)| previous_rows.get(*index) != Some(row))
+      .map(|(index, row)| {
+        let row = row.trim_end();
+
+        if self.row_labels {
+          format!("row {}: {row}\r\n", index + 1)
+        } else {
+          format!("{row}\r\n")
+        }
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod format_changed_rows_tests {
+  use super::*;
+
+  #[test]
+  fn every_row_of_the_first_grid_is_printed() {
+    let accessibility = Accessibility::new();
+
+    assert_eq!(
+      accessibility.format_changed_rows("ab \ncd", ""),
+      "ab\r\ncd\r\n"
+    );
+  }
+
+  #[test]
+  fn only_changed_rows_are_printed() {
+    let accessibility = Accessibility::new().with_row_labels(true);
+
+    assert_eq!(
+      accessibility.format_changed_rows("ab\nxy\nef", "ab\ncd\nef"),
+      "row 2: xy\r\n"
+    );
+  }
+
+  #[test]
+  fn added_rows_are_printed() {
+    let accessibility = Accessibility::new().with_row_labels(true);
+
+    assert_eq!(
+      accessibility.format_changed_rows("ab\ncd", "ab"),
+      "row 2: cd\r\n"
+    );
+  }
+}
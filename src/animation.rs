@@ -0,0 +1,282 @@
+mod tests;
+
+use crate::change_run::ChangeRun;
+use crate::diff_strategy::{DiffSettings, DiffStrategy};
+use crate::dynamic_printer::{DynamicPrinter, DynamicPrinterMethods};
+use crate::errors::PrintingError;
+use crate::printer::Printer;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A named list of grids registered with a printer, along with the difference between each grid and the one before it.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Animation {
+  /// Every frame of the animation, after being prepared by the printer.
+  frames: Vec<String>,
+  /// The change runs from the frame before each frame to that frame, where the first frame follows the last.
+  transitions: Vec<Vec<ChangeRun>>,
+}
+
+impl Animation {
+  /// Creates an animation out of the frames, finding the difference between each frame and the one before it
+  /// with the given strategy and settings.
+  pub(crate) fn new(
+    frames: Vec<String>,
+    diff_strategy: &dyn DiffStrategy,
+    diff_settings: DiffSettings,
+  ) -> Self {
+    let transitions = (0..frames.len())
+      .map(|index| {
+        let previous_frame = &frames[(index + frames.len() - 1) % frames.len()];
+
+        diff_strategy.change_runs(previous_frame, &frames[index], diff_settings)
+      })
+      .collect();
+
+    Self {
+      frames,
+      transitions,
+    }
+  }
+}
+
+/// How many frames were printed and dropped while [`playing frames`](Printer::play_frames).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct PlaybackStats {
+  pub printed_frames: usize,
+  /// The amount of frames skipped because the frame after them was already due.
+  pub dropped_frames: usize,
+}
+
+/// How the printer waits for frames to come due while [`playing frames`](Printer::play_frames) or [`replaying`](Printer::replay) recordings.
+///
+/// Set with [`replace_frame_pacing`](crate::printer::Printer::replace_frame_pacing).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum FramePacing {
+  /// Sleeps for most of the wait, then spins for the rest of it.
+  ///
+  /// Sleeping alone can wake up late by up to the length of the operating system's scheduling tick,
+  /// which is enough for frames to visibly jitter at 60 frames per second.
+  /// Spinning keeps a core busy for the last fraction of a millisecond before every frame.
+  #[default]
+  Precise,
+  /// Sleeps for the entire wait, for saving power such as when running on battery.
+  Sleep,
+}
+
+/// How long before a frame is due that [`precise pacing`](FramePacing::Precise) stops sleeping and spins instead.
+const SPIN_TIME: Duration = Duration::from_micros(500);
+
+impl FramePacing {
+  /// Blocks the thread for the given duration.
+  pub(crate) fn wait(self, wait_time: Duration) {
+    let due_time = Instant::now() + wait_time;
+
+    match self {
+      Self::Sleep => thread::sleep(wait_time),
+      Self::Precise => {
+        if let Some(sleep_time) = wait_time.checked_sub(SPIN_TIME) {
+          thread::sleep(sleep_time);
+        }
+
+        while Instant::now() < due_time {
+          std::hint::spin_loop();
+        }
+      }
+    }
+  }
+}
+
+/// What to do with a frame when it comes up during playback.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum FrameAction {
+  /// The frame isn't due yet, and should be printed after waiting for the duration.
+  Wait(Duration),
+  Print,
+  /// The next frame is already due, so this one is skipped to catch up.
+  Drop,
+}
+
+impl FrameAction {
+  /// Returns what to do with the frame at the given index, with the given time since playback started.
+  ///
+  /// Every frame is due a frame interval after the one before it, starting from the first frame being due immediately.
+  /// The last frame is never dropped, so playback always ends on it.
+  pub(crate) fn schedule(
+    elapsed: Duration,
+    index: usize,
+    frame_interval: Duration,
+    is_last_frame: bool,
+  ) -> Self {
+    let due_time = frame_interval * index as u32;
+
+    if elapsed < due_time {
+      Self::Wait(due_time - elapsed)
+    } else if !is_last_frame && elapsed >= due_time + frame_interval {
+      Self::Drop
+    } else {
+      Self::Print
+    }
+  }
+}
+
+impl Printer {
+  /// Plays the frames in order at the given frames per second, printing each one as it comes due.
+  ///
+  /// Whenever printing falls behind far enough that the next frame is already due, frames are dropped until playback
+  /// catches up, rather than playing every frame late. The last frame is always printed.
+  /// Frames are taken from the iterator as they're played, so they can be created or decoded on the fly.
+  ///
+  /// For playing images, refer to [`play_images`](Printer::play_images) with the `image` feature.
+  ///
+  /// # Errors
+  ///
+  /// - The frame rate is 0.
+  /// - Any error [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) can return while printing a frame.
+  ///
+  /// # Example
+  /// ```rust,no_run
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// let frames = (0..30).map(|frame| format!("{:>3}", frame));
+  ///
+  /// let playback_stats = printer.play_frames(frames, 10).unwrap();
+  ///
+  /// assert_eq!(playback_stats.printed_frames + playback_stats.dropped_frames, 30);
+  /// ```
+  pub fn play_frames<I>(
+    &mut self,
+    frames: I,
+    frames_per_second: u32,
+  ) -> Result<PlaybackStats, PrintingError>
+  where
+    I: IntoIterator,
+    I::Item: Into<String>,
+  {
+    if frames_per_second == 0 {
+      return Err(PrintingError::InvalidFrameRate);
+    }
+
+    let frame_interval = Duration::from_secs(1) / frames_per_second;
+    let mut frames = frames.into_iter().peekable();
+    let mut playback_stats = PlaybackStats::default();
+    let start_time = Instant::now();
+    let mut index = 0;
+
+    while let Some(frame) = frames.next() {
+      let is_last_frame = frames.peek().is_none();
+
+      match FrameAction::schedule(start_time.elapsed(), index, frame_interval, is_last_frame) {
+        FrameAction::Drop => playback_stats.dropped_frames += 1,
+        action => {
+          if let FrameAction::Wait(wait_time) = action {
+            self.get_frame_pacing().wait(wait_time);
+          }
+
+          self.dynamic_print(frame.into())?;
+          playback_stats.printed_frames += 1;
+        }
+      }
+
+      index += 1;
+    }
+
+    Ok(playback_stats)
+  }
+
+  /// Registers the frames as an animation with the given name, replacing any animation already registered with that name.
+  ///
+  /// The difference between each frame and the one before it is found once here, so [`playing`](Printer::play_animation_frame)
+  /// the frames in order doesn't diff them again. The first frame is treated as following the last, for looping animations.
+  ///
+  /// Frames are prepared with the printer's current settings, such as [`EmojiMode`](crate::cells::EmojiMode) and the
+  /// [`Caret`](crate::caret::Caret). The animation has to be registered again for changes to these settings to apply to it.
+  /// Animations without any frames aren't registered.
+  ///
+  /// # Errors
+  ///
+  /// - Any of the frames isn't rectangular.
+  /// - Any of the frames contains control characters while strict character validation is on.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic();
+  /// let frames = vec!["o  ".to_string(), " o ".to_string(), "  o".to_string()];
+  ///
+  /// printer.register_animation("bounce", frames).unwrap();
+  ///
+  /// for tick in 0..1000 {
+  ///   printer.play_animation_frame("bounce", tick).unwrap();
+  /// }
+  /// ```
+  pub fn register_animation<S: Into<String>>(
+    &mut self,
+    name: S,
+    frames: Vec<String>,
+  ) -> Result<(), PrintingError> {
+    let name = name.into();
+
+    if frames.is_empty() {
+      self.animations.remove(&name);
+
+      return Ok(());
+    }
+
+    let frames = frames
+      .into_iter()
+      .map(|frame| {
+        let frame = self.prepare_grid(frame)?;
+        self.measure_rectangular_dimensions(&frame)?;
+
+        Ok(frame)
+      })
+      .collect::<Result<Vec<String>, PrintingError>>()?;
+
+    let animation = Animation::new(frames, self.get_diff_strategy(), self.get_diff_settings());
+    self.animations.insert(name, animation);
+
+    Ok(())
+  }
+
+  /// Prints the frame at the given index of the animation, where indexes past the last frame loop back around to the first.
+  ///
+  /// When the previously printed grid is the frame before this one, the difference found when registering the animation
+  /// is printed without diffing the frames again. Otherwise, the frame is printed the same way as with
+  /// [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  ///
+  /// # Errors
+  ///
+  /// - No animation with the given name is registered.
+  /// - Any error [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) can return while printing.
+  pub fn play_animation_frame(&mut self, name: &str, index: usize) -> Result<(), PrintingError> {
+    let Some(animation) = self.animations.get(name) else {
+      return Err(PrintingError::AnimationNotFound(name.to_string()));
+    };
+
+    let frame_count = animation.frames.len();
+    let index = index % frame_count;
+    let previous_frame = &animation.frames[(index + frame_count - 1) % frame_count];
+
+    let frame = animation.frames[index].clone();
+    let change_runs =
+      (self.previous_grid == *previous_frame).then(|| animation.transitions[index].clone());
+
+    self.print_prepared_grid(frame, change_runs)
+  }
+
+  /// Removes the animation with the given name, returning true if it was registered.
+  pub fn remove_animation(&mut self, name: &str) -> bool {
+    self.animations.remove(name).is_some()
+  }
+
+  /// Returns the amount of frames in the animation with the given name, or None if it isn't registered.
+  pub fn get_animation_frame_count(&self, name: &str) -> Option<usize> {
+    self
+      .animations
+      .get(name)
+      .map(|animation| animation.frames.len())
+  }
+}
@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use super::*;
+use crate::diff_strategy::CellRunDiff;
+
+#[cfg(test)]
+mod animation_tests {
+  use super::*;
+
+  #[test]
+  fn transitions_loop_from_last_frame() {
+    let frames = vec!["ab".to_string(), "xb".to_string(), "xy".to_string()];
+
+    let animation = Animation::new(frames, &CellRunDiff, DiffSettings::default());
+
+    assert_eq!(animation.transitions[0], ChangeRun::from_grids("xy", "ab"));
+    assert_eq!(animation.transitions[2], ChangeRun::from_grids("xb", "xy"));
+  }
+
+  #[test]
+  fn registering_validates_frames() {
+    let mut printer = Printer::new();
+
+    let result = printer.register_animation("invalid", vec!["ab\nc".to_string()]);
+
+    assert_eq!(result, Err(PrintingError::NonRectangularGrid));
+    assert_eq!(printer.get_animation_frame_count("invalid"), None);
+  }
+
+  #[test]
+  fn unknown_animation_is_an_error() {
+    let mut printer = Printer::new();
+    printer.register_animation("empty", Vec::new()).unwrap();
+
+    assert!(matches!(
+      printer.play_animation_frame("empty", 0),
+      Err(PrintingError::AnimationNotFound(name)) if name == "empty"
+    ));
+  }
+
+  #[test]
+  fn animations_can_be_removed() {
+    let mut printer = Printer::new();
+    printer
+      .register_animation("idle", vec!["a".to_string(), "b".to_string()])
+      .unwrap();
+
+    assert_eq!(printer.get_animation_frame_count("idle"), Some(2));
+    assert!(printer.remove_animation("idle"));
+    assert!(!printer.remove_animation("idle"));
+  }
+}
+
+#[cfg(test)]
+mod playback_tests {
+  use super::*;
+
+  const FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+  #[test]
+  fn early_frames_wait_until_due() {
+    let action = FrameAction::schedule(Duration::from_millis(150), 2, FRAME_INTERVAL, false);
+
+    assert_eq!(action, FrameAction::Wait(Duration::from_millis(50)));
+  }
+
+  #[test]
+  fn due_frames_are_printed() {
+    let action = FrameAction::schedule(Duration::from_millis(250), 2, FRAME_INTERVAL, false);
+
+    assert_eq!(action, FrameAction::Print);
+  }
+
+  #[test]
+  fn frames_are_dropped_once_the_next_is_due() {
+    let action = FrameAction::schedule(Duration::from_millis(300), 2, FRAME_INTERVAL, false);
+
+    assert_eq!(action, FrameAction::Drop);
+  }
+
+  #[test]
+  fn last_frame_is_never_dropped() {
+    let action = FrameAction::schedule(Duration::from_secs(10), 2, FRAME_INTERVAL, true);
+
+    assert_eq!(action, FrameAction::Print);
+  }
+
+  #[test]
+  fn zero_frame_rate_is_an_error() {
+    let mut printer = Printer::new();
+
+    let result = printer.play_frames(vec!["a".to_string()], 0);
+
+    assert_eq!(result, Err(PrintingError::InvalidFrameRate));
+  }
+}
+
+#[cfg(test)]
+mod frame_pacing_tests {
+  use super::*;
+
+  #[test]
+  fn pacing_waits_for_at_least_the_duration() {
+    for frame_pacing in [FramePacing::Precise, FramePacing::Sleep] {
+      let start_time = Instant::now();
+
+      frame_pacing.wait(Duration::from_millis(2));
+
+      assert!(start_time.elapsed() >= Duration::from_millis(2));
+    }
+  }
+
+  #[test]
+  fn waits_shorter_than_the_spin_time_only_spin() {
+    let start_time = Instant::now();
+
+    FramePacing::Precise.wait(Duration::from_micros(100));
+
+    assert!(start_time.elapsed() >= Duration::from_micros(100));
+  }
+
+  #[test]
+  fn printer_defaults_to_precise_pacing() {
+    let mut printer = Printer::deterministic();
+
+    assert_eq!(printer.get_frame_pacing(), FramePacing::Precise);
+
+    printer.replace_frame_pacing(FramePacing::Sleep);
+
+    assert_eq!(printer.get_frame_pacing(), FramePacing::Sleep);
+  }
+}
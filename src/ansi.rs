@@ -0,0 +1,249 @@
+mod tests;
+
+use alloc::string::String;
+
+/// The escape character that starts every ANSI escape sequence.
+pub(crate) const ESCAPE: char = '\x1B';
+
+/// The escape sequence that resets all styling back to the terminal's default.
+pub(crate) const STYLE_RESET: &str = "\x1B[0m";
+
+/// Removes every ANSI escape sequence from the text, such as those used for color and cursor movement.
+///
+/// This uses the same rules for finding escape sequences as the printer does when measuring grids,
+/// so the result always has the same [`width`](crate::cells::measure_row_width) as the original text.
+///
+/// # Example
+/// ```
+/// use screen_printer::core::*;
+///
+/// let styled_grid = "\x1B[31mabc\x1B[0m\n\x1B[1m123\x1B[0m";
+///
+/// assert_eq!(strip_ansi(styled_grid), "abc\n123");
+/// ```
+pub fn strip_ansi(text: &str) -> String {
+  let mut stripped_text = String::with_capacity(text.len());
+  let mut remaining_text = text;
+
+  while let Some(escape_index) = remaining_text.find(ESCAPE) {
+    stripped_text.push_str(&remaining_text[..escape_index]);
+
+    remaining_text = &remaining_text[escape_index..];
+    remaining_text = &remaining_text[escape_sequence_length(remaining_text)..];
+  }
+
+  stripped_text.push_str(remaining_text);
+
+  stripped_text
+}
+
+/// The visible character that escape characters are replaced with by [`EscapeSanitization::Escape`](EscapeSanitization::Escape).
+pub const ESCAPE_SYMBOL: char = '␛';
+
+/// How escape sequences in text are treated by [`sanitize_escapes`](sanitize_escapes).
+///
+/// Text from untrusted sources, such as chat messages or filenames, can contain escape sequences that move the cursor
+/// or change the terminal's title. These desync what the printer thinks is displayed from what actually is.
+///
+/// Any other control characters in the C1 range, such as the single character Control Sequence Introducer `\u{9B}`,
+/// are removed by everything other than [`Off`](EscapeSanitization::Off).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EscapeSanitization {
+  /// Text is left as is.
+  #[default]
+  Off,
+  /// Style sequences are kept, while every other escape sequence is removed, such as cursor movements and title changes.
+  AllowStyles,
+  /// Every escape sequence is removed, including style sequences.
+  Strip,
+  /// Every escape character is replaced with the visible [`ESCAPE_SYMBOL`](ESCAPE_SYMBOL), showing escape sequences as plain text.
+  ///
+  /// This makes the text wider by the length of every escape sequence in it.
+  Escape,
+}
+
+/// Returns the text with its escape sequences treated based on the given [`EscapeSanitization`](EscapeSanitization).
+///
+/// # Example
+/// ```
+/// use screen_printer::core::*;
+///
+/// let message = "\x1B[31mhi\x1B[0m\x1B]0;pwned\x07\x1B[2J";
+///
+/// assert_eq!(sanitize_escapes(message, EscapeSanitization::AllowStyles), "\x1B[31mhi\x1B[0m");
+/// assert_eq!(sanitize_escapes(message, EscapeSanitization::Strip), "hi");
+/// assert_eq!(sanitize_escapes("\x1B[2J", EscapeSanitization::Escape), "␛[2J");
+/// ```
+pub fn sanitize_escapes(text: &str, sanitization: EscapeSanitization) -> String {
+  let text: String = match sanitization {
+    EscapeSanitization::Off => return text.into(),
+    _ => text
+      .chars()
+      .filter(|character| !('\u{80}'..='\u{9F}').contains(character))
+      .collect(),
+  };
+
+  if sanitization == EscapeSanitization::Escape {
+    return text.replace(ESCAPE, ESCAPE_SYMBOL.encode_utf8(&mut [0; 4]));
+  }
+
+  let mut sanitized_text = String::with_capacity(text.len());
+  let mut remaining_text = text.as_str();
+
+  while let Some(escape_index) = remaining_text.find(ESCAPE) {
+    sanitized_text.push_str(&remaining_text[..escape_index]);
+
+    remaining_text = &remaining_text[escape_index..];
+    let sequence_length = escape_sequence_length(remaining_text);
+    let sequence = &remaining_text[..sequence_length];
+
+    if sanitization == EscapeSanitization::AllowStyles && is_style_sequence(sequence) {
+      sanitized_text.push_str(sequence);
+    }
+
+    remaining_text = &remaining_text[sequence_length..];
+  }
+
+  sanitized_text.push_str(remaining_text);
+
+  sanitized_text
+}
+
+/// Returns the length in bytes of the escape sequence at the start of the given text.
+///
+/// The text is expected to start with the [`escape`](ESCAPE) character.
+/// Sequences that never end are considered to take up the rest of the text.
+pub(crate) fn escape_sequence_length(text: &str) -> usize {
+  let bytes = text.as_bytes();
+
+  match bytes.get(1) {
+    // Control Sequence Introducer, ended by a byte in the range of `@` to `~`.
+    Some(b'[') => bytes[2..]
+      .iter()
+      .position(|byte| (0x40..=0x7E).contains(byte))
+      .map_or(bytes.len(), |end| end + 3),
+    // Control strings, ended by either the bell character or the string terminator (`ESC \`).
+    Some(b']' | b'P' | b'X' | b'^' | b'_') => {
+      let mut index = 2;
+
+      while index < bytes.len() {
+        match bytes[index] {
+          0x07 => return index + 1,
+          0x1B if bytes.get(index + 1) == Some(&b'\\') => return index + 2,
+          _ => index += 1,
+        }
+      }
+
+      bytes.len()
+    }
+    // Any other escape is made of intermediate bytes, followed by a single final byte.
+    Some(_) => match bytes[1..]
+      .iter()
+      .position(|byte| !(0x20..=0x2F).contains(byte))
+    {
+      Some(end) if bytes[end + 1].is_ascii() => end + 2,
+      // Characters that aren't ASCII can't end an escape sequence.
+      Some(end) => end + 1,
+      None => bytes.len(),
+    },
+    None => bytes.len(),
+  }
+}
+
+/// Returns true if the escape sequence is a Select Graphic Rendition sequence, which changes the style of text.
+pub(crate) fn is_style_sequence(sequence: &str) -> bool {
+  sequence
+    .strip_prefix("\x1B[")
+    .and_then(|sequence| sequence.strip_suffix('m'))
+    .is_some_and(|parameters| {
+      parameters
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || byte == b';' || byte == b':')
+    })
+}
+
+/// Returns true if the style sequence resets the style before applying anything else.
+pub(crate) fn is_style_reset(sequence: &str) -> bool {
+  let parameters = &sequence[2..sequence.len() - 1];
+  let first_parameter = parameters.split([';', ':']).next().unwrap_or_default();
+
+  first_parameter.bytes().all(|byte| byte == b'0')
+}
+
+/// A color set by a style sequence.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum StyleColor {
+  /// The terminal's default color.
+  #[default]
+  Default,
+  /// One of the 16 basic colors, where 8 to 15 are the bright variants.
+  Basic(u8),
+  /// One of the 256 colors of the extended palette.
+  Palette(u8),
+  /// A 24-bit color.
+  Rgb(u8, u8, u8),
+}
+
+/// Everything a style applies to text, independent of how the style sequences were written.
+///
+/// Different sequences that end up with the same style, such as `\x1B[1;31m` and `\x1B[31m\x1B[1m`, have equal attributes.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct StyleAttributes {
+  pub foreground: StyleColor,
+  pub background: StyleColor,
+  /// Each bit is set when the attribute of the same number is enabled, such as bold (1) or underline (4).
+  pub attributes: u16,
+}
+
+/// Returns the attributes the style sequences apply, where the style is any amount of
+/// [`style sequences`](is_style_sequence) one after the other.
+pub(crate) fn parse_style(style: &str) -> StyleAttributes {
+  let mut attributes = StyleAttributes::default();
+  let mut remaining_style = style;
+
+  while remaining_style.starts_with(ESCAPE) {
+    let sequence_length = escape_sequence_length(remaining_style);
+    let sequence = &remaining_style[..sequence_length];
+    remaining_style = &remaining_style[sequence_length..];
+
+    if !is_style_sequence(sequence) {
+      continue;
+    }
+
+    let mut parameters = sequence[2..sequence.len() - 1]
+      .split([';', ':'])
+      .map(|parameter| parameter.parse::<u16>().unwrap_or(0));
+
+    while let Some(parameter) = parameters.next() {
+      match parameter {
+        0 => attributes = StyleAttributes::default(),
+        1..=9 => attributes.attributes |= 1 << parameter,
+        21 | 22 => attributes.attributes &= !(1 << 1 | 1 << 2),
+        23 | 24 | 27 | 28 | 29 => attributes.attributes &= !(1 << (parameter - 20)),
+        25 => attributes.attributes &= !(1 << 5 | 1 << 6),
+        30..=37 => attributes.foreground = StyleColor::Basic((parameter - 30) as u8),
+        38 => attributes.foreground = parse_extended_color(&mut parameters),
+        39 => attributes.foreground = StyleColor::Default,
+        40..=47 => attributes.background = StyleColor::Basic((parameter - 40) as u8),
+        48 => attributes.background = parse_extended_color(&mut parameters),
+        49 => attributes.background = StyleColor::Default,
+        90..=97 => attributes.foreground = StyleColor::Basic((parameter - 82) as u8),
+        100..=107 => attributes.background = StyleColor::Basic((parameter - 92) as u8),
+        _ => (),
+      }
+    }
+  }
+
+  attributes
+}
+
+/// Returns the color for the parameters following a `38` or `48`, which are either `5;index` or `2;red;green;blue`.
+fn parse_extended_color(parameters: &mut impl Iterator<Item = u16>) -> StyleColor {
+  let mut next_channel = || parameters.next().unwrap_or(0).min(255) as u8;
+
+  match next_channel() {
+    5 => StyleColor::Palette(next_channel()),
+    2 => StyleColor::Rgb(next_channel(), next_channel(), next_channel()),
+    _ => StyleColor::Default,
+  }
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use super::*;
+use crate::cells::measure_row_width;
+
+#[cfg(test)]
+mod strip_ansi_tests {
+  use super::*;
+
+  #[test]
+  fn stripping_keeps_the_width() {
+    let text = "\x1B[1;38;5;200mab\x1B[0m\x1B]8;;link\x1B\\cd\x1B(B\u{65E5}";
+
+    let stripped_text = strip_ansi(text);
+
+    assert_eq!(stripped_text, "abcd\u{65E5}");
+    assert_eq!(measure_row_width(text), measure_row_width(&stripped_text));
+  }
+
+  #[test]
+  fn unfinished_escape_sequences_are_removed() {
+    assert_eq!(strip_ansi("ab\x1B[31"), "ab");
+    assert_eq!(strip_ansi("ab\x1B"), "ab");
+  }
+
+  #[test]
+  fn escape_before_non_ascii_character_keeps_the_character() {
+    assert_eq!(strip_ansi("a\x1B\u{e9}b"), "a\u{e9}b");
+  }
+}
+
+#[cfg(test)]
+mod sanitize_escapes_tests {
+  use super::*;
+
+  const UNTRUSTED_TEXT: &str = "\x1B[1;31mab\x1B[0m\x1B]0;title\x07c\x1B[5;5H\u{9B}2Jd";
+
+  #[test]
+  fn off_leaves_the_text() {
+    assert_eq!(
+      sanitize_escapes(UNTRUSTED_TEXT, EscapeSanitization::Off),
+      UNTRUSTED_TEXT
+    );
+  }
+
+  #[test]
+  fn allowing_styles_removes_everything_else() {
+    assert_eq!(
+      sanitize_escapes(UNTRUSTED_TEXT, EscapeSanitization::AllowStyles),
+      "\x1B[1;31mab\x1B[0mc2Jd"
+    );
+  }
+
+  #[test]
+  fn stripping_removes_every_sequence() {
+    assert_eq!(
+      sanitize_escapes(UNTRUSTED_TEXT, EscapeSanitization::Strip),
+      "abc2Jd"
+    );
+  }
+
+  #[test]
+  fn escaping_shows_the_sequences() {
+    assert_eq!(
+      sanitize_escapes("a\x1B[2J\u{9B}b", EscapeSanitization::Escape),
+      "a␛[2Jb"
+    );
+  }
+
+  #[test]
+  fn unfinished_sequences_are_removed() {
+    assert_eq!(
+      sanitize_escapes("ab\x1B]0;ti", EscapeSanitization::AllowStyles),
+      "ab"
+    );
+  }
+}
+
+#[cfg(test)]
+mod parse_style_tests {
+  use super::*;
+
+  #[test]
+  fn differently_written_styles_are_equal() {
+    assert_eq!(parse_style("\x1B[1;31m"), parse_style("\x1B[31m\x1B[1m"));
+    assert_eq!(parse_style("\x1B[4m\x1B[24m"), parse_style(""));
+  }
+
+  #[test]
+  fn extended_colors_are_parsed() {
+    let style = parse_style("\x1B[38;2;10;20;30;48;5;200m");
+
+    assert_eq!(style.foreground, StyleColor::Rgb(10, 20, 30));
+    assert_eq!(style.background, StyleColor::Palette(200));
+  }
+
+  #[test]
+  fn reset_clears_previous_style() {
+    assert_eq!(
+      parse_style("\x1B[1;92m\x1B[0;3m").foreground,
+      StyleColor::Default
+    );
+    assert_eq!(parse_style("\x1B[92m").foreground, StyleColor::Basic(10));
+  }
+}
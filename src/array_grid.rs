@@ -0,0 +1,91 @@
+mod tests;
+
+use crate::printer::*;
+use ndarray::ArrayView2;
+use std::fmt;
+
+impl Printer {
+  /// Creates a grid out of a 2-dimensional array, where each row of the array is a row of the grid.
+  ///
+  /// Unlike [`create_grid_from_full_character_list`](Printer::create_grid_from_full_character_list),
+  /// the dimensions of the grid are taken from the array's shape, so this can't fail.
+  /// Values that display wider than others are kept rectangular by padding every value with spaces after it,
+  /// up to the width of the widest value in its column.
+  /// Arrays of any memory layout are accepted, including transposed views and slices.
+  /// The displayed values are used as they are, use
+  /// [`create_grid_from_array_with_normalization`](Printer::create_grid_from_array_with_normalization) to normalize them.
+  ///
+  /// # Example
+  /// ```
+  /// use ndarray::array;
+  /// use screen_printer::printer::*;
+  ///
+  /// let data = array![[1, 2, 3], [4, 5, 6]];
+  ///
+  /// assert_eq!(Printer::create_grid_from_array(data.view()), "123\n456");
+  /// assert_eq!(Printer::create_grid_from_array(data.t()), "14\n25\n36");
+  ///
+  /// let data = array![[1, 20], [300, 4]];
+  ///
+  /// assert_eq!(Printer::create_grid_from_array(data.view()), "1  20\n3004 ");
+  /// ```
+  pub fn create_grid_from_array<T: fmt::Display>(array: ArrayView2<T>) -> String {
+    Self::create_grid_from_array_with_normalization(array, GridNormalization::default())
+  }
+
+  /// Creates a grid out of a 2-dimensional array the same as [`create_grid_from_array`](Printer::create_grid_from_array),
+  /// with each displayed value normalized based on the given [`GridNormalization`](crate::cells::GridNormalization)
+  /// before the columns are padded.
+  ///
+  /// # Example
+  /// ```
+  /// use ndarray::array;
+  /// use screen_printer::printer::*;
+  ///
+  /// let data = array![["e\u{301}", "\t"], ["x", "y"]];
+  /// let normalization = GridNormalization::new(CharacterNormalization::Composed)
+  ///   .with_control_character_replacement(' ');
+  ///
+  /// assert_eq!(
+  ///   Printer::create_grid_from_array_with_normalization(data.view(), normalization),
+  ///   "\u{e9} \nxy"
+  /// );
+  /// ```
+  pub fn create_grid_from_array_with_normalization<T: fmt::Display>(
+    array: ArrayView2<T>,
+    normalization: GridNormalization,
+  ) -> String {
+    let cells = array.map(|value| normalization.normalize(&value.to_string()));
+    let column_widths: Vec<usize> = cells
+      .columns()
+      .into_iter()
+      .map(|column| {
+        column
+          .iter()
+          .map(|cell| measure_row_width(cell))
+          .max()
+          .unwrap_or(0)
+      })
+      .collect();
+
+    cells
+      .rows()
+      .into_iter()
+      .map(|row| {
+        row
+          .iter()
+          .zip(&column_widths)
+          .fold(String::new(), |mut row, (cell, column_width)| {
+            row.push_str(cell);
+            row.extend(std::iter::repeat_n(
+              ' ',
+              column_width - measure_row_width(cell),
+            ));
+
+            row
+          })
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+use super::*;
+use ndarray::{s, Array2};
+
+#[cfg(test)]
+mod create_grid_from_array_tests {
+  use super::*;
+
+  #[test]
+  fn sliced_array_uses_sliced_shape() {
+    let array = Array2::from_shape_fn((4, 4), |(y, x)| y * 4 + x);
+
+    let grid = Printer::create_grid_from_array(array.slice(s![1..3, ..;2]));
+
+    assert_eq!(grid, "46 \n810");
+    assert!(Printer::is_rectangular(&grid));
+  }
+
+  #[test]
+  fn empty_array_is_empty_grid() {
+    let array: Array2<char> = Array2::from_elem((0, 3), 'a');
+
+    assert_eq!(Printer::create_grid_from_array(array.view()), "");
+  }
+
+  #[test]
+  fn normalized_values_are_measured_after_normalizing() {
+    let array = ndarray::array![["a\tb", "c"], ["\u{7}", "e\u{301}"]];
+    let normalization = GridNormalization::new(CharacterNormalization::Composed)
+      .with_control_character_replacement('_');
+
+    let grid = Printer::create_grid_from_array_with_normalization(array.view(), normalization);
+
+    assert_eq!(grid, "a_bc\n_  \u{e9}");
+    assert!(Printer::is_rectangular(&grid));
+  }
+}
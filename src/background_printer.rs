@@ -0,0 +1,413 @@
+mod tests;
+
+use crate::dynamic_printer::DynamicPrinter;
+use crate::errors::PrintingError;
+use crate::printer::Printer;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How soon a frame sent to a [`BackgroundPrinter`](BackgroundPrinter) is printed.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum FramePriority {
+  /// The frame is printed after every frame sent before it.
+  #[default]
+  Normal,
+  /// The frame is printed before any normal frames, such as for an error popup that has to appear right away.
+  ///
+  /// Normal frames still waiting to be printed when an urgent frame is sent are dropped,
+  /// since printing them afterwards would cover the urgent frame with older content.
+  Urgent,
+}
+
+/// A [`Printer`](Printer) running on its own thread, printing grids sent to it in the order of their [`priority`](FramePriority).
+///
+/// Sending a grid never waits on it being printed. Frames wait in a queue of a fixed capacity,
+/// where sending a frame to a full queue drops the oldest frame of the same priority.
+///
+/// When the terminal can't keep up, such as over a slow connection, frames pile up in the queue and are printed further and further behind.
+/// Setting a [`max latency`](BackgroundPrinter::with_max_latency) skips frames that waited too long whenever a newer one is queued behind them.
+///
+/// The thread stops on the first error it runs into, which is returned by any following [`send`](BackgroundPrinter::send)
+/// and by [`finish`](BackgroundPrinter::finish).
+///
+/// # Example
+/// ```
+/// use screen_printer::assert_screen_eq;
+/// use screen_printer::background_printer::*;
+/// use screen_printer::printer::*;
+///
+/// let printer = Printer::deterministic().with_printing_position(PrintingPosition::new(
+///   XPrintingPosition::Left,
+///   YPrintingPosition::Top,
+/// ));
+/// let background_printer = BackgroundPrinter::new(printer, 4);
+///
+/// background_printer.send("abc\n123".to_string()).unwrap();
+/// background_printer.send_with_priority("ERR\n!!!".to_string(), FramePriority::Urgent).unwrap();
+///
+/// let printer = background_printer.finish().unwrap();
+///
+/// assert_screen_eq!(printer, "ERR\n!!!");
+/// ```
+#[derive(Debug)]
+pub struct BackgroundPrinter {
+  mailbox: Arc<Mailbox>,
+  thread: Option<JoinHandle<Printer>>,
+}
+
+impl BackgroundPrinter {
+  /// Moves the printer to its own thread, queueing up to the given amount of frames of each priority.
+  ///
+  /// A capacity of 0 is treated as 1.
+  pub fn new(printer: Printer, capacity: usize) -> Self {
+    let mailbox = Arc::new(Mailbox::new(capacity));
+    let thread_mailbox = Arc::clone(&mailbox);
+
+    let thread = thread::spawn(move || print_frames(printer, &thread_mailbox));
+
+    Self {
+      mailbox,
+      thread: Some(thread),
+    }
+  }
+
+  /// Queues the grid to be printed as a [`normal`](FramePriority::Normal) frame.
+  ///
+  /// # Errors
+  ///
+  /// - The printer stopped after failing to print an earlier frame.
+  pub fn send(&self, grid: String) -> Result<(), PrintingError> {
+    self.send_with_priority(grid, FramePriority::Normal)
+  }
+
+  /// Queues the grid to be printed with the given priority.
+  ///
+  /// # Errors
+  ///
+  /// - The printer stopped after failing to print an earlier frame.
+  pub fn send_with_priority(
+    &self,
+    grid: String,
+    priority: FramePriority,
+  ) -> Result<(), PrintingError> {
+    let mut state = self.mailbox.lock();
+
+    if let Some(error) = &state.error {
+      return Err(error.clone());
+    }
+
+    state.queue.push(grid, priority);
+    self.mailbox.frame_sent.notify_one();
+
+    Ok(())
+  }
+
+  /// Returns the background printer with the longest a normal frame can wait in the queue before it's skipped,
+  /// as long as a newer normal frame is queued behind it.
+  ///
+  /// The newest frame is always printed, so the terminal catches up to it rather than falling further behind.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::assert_screen_eq;
+  /// use screen_printer::background_printer::*;
+  /// use screen_printer::printer::*;
+  /// use std::time::Duration;
+  ///
+  /// let printer = Printer::deterministic().with_printing_position(PrintingPosition::new(
+  ///   XPrintingPosition::Left,
+  ///   YPrintingPosition::Top,
+  /// ));
+  /// let background_printer =
+  ///   BackgroundPrinter::new(printer, 60).with_max_latency(Duration::from_millis(100));
+  ///
+  /// for frame in 0..600 {
+  ///   background_printer.send(format!("{frame:>3}")).unwrap();
+  /// }
+  ///
+  /// let printer = background_printer.finish().unwrap();
+  ///
+  /// // The newest frame is never skipped.
+  /// assert_screen_eq!(printer, "599");
+  /// ```
+  pub fn with_max_latency(self, max_latency: Duration) -> Self {
+    self.mailbox.lock().max_latency = Some(max_latency);
+
+    self
+  }
+
+  /// Returns the longest a normal frame can wait in the queue before it's skipped, if any.
+  pub fn get_max_latency(&self) -> Option<Duration> {
+    self.mailbox.lock().max_latency
+  }
+
+  /// Checks whether the terminal was resized every time the given interval passes without a frame being sent,
+  /// reprinting the last frame at a recalculated origin when it was, with [`redraw_if_resized`](Printer::redraw_if_resized).
+  ///
+  /// This turns on [`resize redrawing`](Printer::set_resize_redraw) for the printer.
+  pub fn with_resize_polling(self, interval: Duration) -> Self {
+    self.mailbox.lock().resize_polling_interval = Some(interval);
+    self.mailbox.frame_sent.notify_one();
+
+    self
+  }
+
+  /// Returns how often the terminal is checked for being resized while waiting for frames, if it is.
+  pub fn get_resize_polling_interval(&self) -> Option<Duration> {
+    self.mailbox.lock().resize_polling_interval
+  }
+
+  /// Returns how many frames were dropped without being printed, either from a full queue, from being preempted by an urgent frame,
+  /// or from waiting longer than the [`max latency`](BackgroundPrinter::with_max_latency).
+  pub fn dropped_frames(&self) -> usize {
+    self.mailbox.lock().queue.dropped_frames
+  }
+
+  /// Returns how long the most recently printed frame waited in the queue before being printed, if any were printed.
+  pub fn last_frame_latency(&self) -> Option<Duration> {
+    self.mailbox.lock().last_frame_latency
+  }
+
+  /// Waits for every queued frame to be printed, then stops the thread and returns the printer.
+  ///
+  /// # Errors
+  ///
+  /// - Printing any of the frames failed, which is the first error the printer ran into.
+  pub fn finish(mut self) -> Result<Printer, PrintingError> {
+    let printer = self.stop();
+
+    match self.mailbox.lock().error.take() {
+      Some(error) => Err(error),
+      None => Ok(printer.unwrap_or_default()),
+    }
+  }
+
+  /// Stops the thread after it prints every queued frame, returning its printer.
+  ///
+  /// Returns None if the thread was already stopped.
+  fn stop(&mut self) -> Option<Printer> {
+    let thread = self.thread.take()?;
+
+    self.mailbox.lock().closed = true;
+    self.mailbox.frame_sent.notify_one();
+
+    match thread.join() {
+      Ok(printer) => Some(printer),
+      Err(panic) => std::panic::resume_unwind(panic),
+    }
+  }
+}
+
+impl Drop for BackgroundPrinter {
+  fn drop(&mut self) {
+    if !thread::panicking() {
+      self.stop();
+    }
+  }
+}
+
+/// Prints every frame sent to the mailbox until it's closed and empty, or printing fails.
+fn print_frames(mut printer: Printer, mailbox: &Mailbox) -> Printer {
+  loop {
+    let grid = {
+      let mut state = mailbox.lock();
+
+      loop {
+        if state.resize_polling_interval.is_some() {
+          printer.set_resize_redraw(true);
+        }
+
+        if let Some(max_latency) = state.max_latency {
+          state.queue.skip_late_frames(max_latency);
+        }
+
+        if let Some(frame) = state.queue.pop() {
+          state.last_frame_latency = Some(frame.sent_at.elapsed());
+
+          break frame.grid;
+        }
+
+        if state.closed && !printer.has_debounced_grid() {
+          return printer;
+        }
+
+        // Grids held back by the debounce interval are printed once it passes, unless a newer grid is sent first.
+        if printer.has_debounced_grid() {
+          match printer.get_remaining_debounce_time() {
+            Some(remaining_time) if !state.closed => {
+              state = mailbox
+                .frame_sent
+                .wait_timeout(state, remaining_time)
+                .map(|(state, _)| state)
+                .unwrap_or_else(|poisoned| poisoned.into_inner().0);
+            }
+            _ => {
+              drop(state);
+
+              if let Err(error) = printer.print_debounced_grid() {
+                mailbox.lock().error = Some(error);
+
+                return printer;
+              }
+
+              state = mailbox.lock();
+            }
+          }
+
+          continue;
+        }
+
+        let Some(resize_polling_interval) = state.resize_polling_interval else {
+          state = mailbox
+            .frame_sent
+            .wait(state)
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+          continue;
+        };
+
+        let (waited_state, wait_result) = mailbox
+          .frame_sent
+          .wait_timeout(state, resize_polling_interval)
+          .unwrap_or_else(|poisoned| poisoned.into_inner());
+        state = waited_state;
+
+        if wait_result.timed_out() && state.queue.is_empty() {
+          drop(state);
+
+          if let Err(error) = printer.redraw_if_resized() {
+            mailbox.lock().error = Some(error);
+
+            return printer;
+          }
+
+          state = mailbox.lock();
+        }
+      }
+    };
+
+    if let Err(error) = printer.dynamic_print(grid) {
+      mailbox.lock().error = Some(error);
+
+      return printer;
+    }
+  }
+}
+
+/// Where frames wait between being sent and printed.
+#[derive(Debug)]
+struct Mailbox {
+  state: Mutex<MailboxState>,
+  frame_sent: Condvar,
+}
+
+impl Mailbox {
+  fn new(capacity: usize) -> Self {
+    Self {
+      state: Mutex::new(MailboxState {
+        queue: FrameQueue::new(capacity),
+        closed: false,
+        error: None,
+        max_latency: None,
+        last_frame_latency: None,
+        resize_polling_interval: None,
+      }),
+      frame_sent: Condvar::new(),
+    }
+  }
+
+  /// Locks the state of the mailbox, ignoring any panic from the other thread while it was locked.
+  fn lock(&self) -> MutexGuard<'_, MailboxState> {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[derive(Debug)]
+struct MailboxState {
+  queue: FrameQueue,
+  /// Whether the printer is finishing, after which the thread stops once the queue is empty.
+  closed: bool,
+  /// The error that stopped the thread.
+  error: Option<PrintingError>,
+  max_latency: Option<Duration>,
+  /// How long the most recently printed frame waited in the queue.
+  last_frame_latency: Option<Duration>,
+  /// How often the terminal is checked for being resized while waiting for frames.
+  resize_polling_interval: Option<Duration>,
+}
+
+/// A frame waiting in the queue, along with when it was sent.
+#[derive(Debug)]
+struct QueuedFrame {
+  grid: String,
+  sent_at: Instant,
+}
+
+/// The frames waiting to be printed, split by priority.
+#[derive(Debug, Default)]
+struct FrameQueue {
+  urgent: VecDeque<QueuedFrame>,
+  normal: VecDeque<QueuedFrame>,
+  capacity: usize,
+  dropped_frames: usize,
+}
+
+impl FrameQueue {
+  fn new(capacity: usize) -> Self {
+    Self {
+      capacity: capacity.max(1),
+      ..Default::default()
+    }
+  }
+
+  /// Adds the frame to the end of the queue for its priority, dropping the oldest frame of that priority if the queue is full.
+  fn push(&mut self, grid: String, priority: FramePriority) {
+    let queue = match priority {
+      FramePriority::Normal => &mut self.normal,
+      FramePriority::Urgent => {
+        self.dropped_frames += self.normal.len();
+        self.normal.clear();
+
+        &mut self.urgent
+      }
+    };
+
+    if queue.len() >= self.capacity {
+      queue.pop_front();
+      self.dropped_frames += 1;
+    }
+
+    queue.push_back(QueuedFrame {
+      grid,
+      sent_at: Instant::now(),
+    });
+  }
+
+  /// Drops every normal frame that waited longer than the max latency, other than the newest.
+  fn skip_late_frames(&mut self, max_latency: Duration) {
+    while self.normal.len() > 1
+      && self
+        .normal
+        .front()
+        .is_some_and(|frame| frame.sent_at.elapsed() > max_latency)
+    {
+      self.normal.pop_front();
+      self.dropped_frames += 1;
+    }
+  }
+
+  /// Returns true if there are no frames waiting to be printed.
+  fn is_empty(&self) -> bool {
+    self.urgent.is_empty() && self.normal.is_empty()
+  }
+
+  /// Removes the next frame to print, which is the oldest urgent frame if there are any.
+  fn pop(&mut self) -> Option<QueuedFrame> {
+    self.urgent.pop_front().or_else(|| self.normal.pop_front())
+  }
+}
@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod frame_queue_tests {
+  use super::*;
+
+  /// Returns every frame left in the queue, in the order they'd be printed.
+  fn drain(queue: &mut FrameQueue) -> Vec<String> {
+    std::iter::from_fn(|| queue.pop())
+      .map(|frame| frame.grid)
+      .collect()
+  }
+
+  #[test]
+  fn full_queue_drops_oldest_frame() {
+    let mut queue = FrameQueue::new(2);
+
+    for grid in ["a", "b", "c"] {
+      queue.push(grid.to_string(), FramePriority::Normal);
+    }
+
+    assert_eq!(drain(&mut queue), vec!["b", "c"]);
+    assert_eq!(queue.dropped_frames, 1);
+  }
+
+  #[test]
+  fn urgent_frame_preempts_queued_normal_frames() {
+    let mut queue = FrameQueue::new(4);
+
+    queue.push("a".to_string(), FramePriority::Normal);
+    queue.push("b".to_string(), FramePriority::Normal);
+    queue.push("!".to_string(), FramePriority::Urgent);
+    queue.push("c".to_string(), FramePriority::Normal);
+
+    assert_eq!(drain(&mut queue), vec!["!", "c"]);
+    assert_eq!(queue.dropped_frames, 2);
+  }
+
+  #[test]
+  fn zero_capacity_keeps_latest_frame() {
+    let mut queue = FrameQueue::new(0);
+
+    queue.push("a".to_string(), FramePriority::Normal);
+    queue.push("b".to_string(), FramePriority::Normal);
+
+    assert_eq!(drain(&mut queue), vec!["b"]);
+  }
+
+  #[test]
+  fn late_frames_are_skipped_up_to_the_newest() {
+    let mut queue = FrameQueue::new(4);
+
+    for grid in ["a", "b", "c"] {
+      queue.push(grid.to_string(), FramePriority::Normal);
+    }
+
+    queue.skip_late_frames(Duration::from_secs(60));
+
+    assert_eq!(queue.dropped_frames, 0);
+
+    std::thread::sleep(Duration::from_millis(2));
+    queue.push("d".to_string(), FramePriority::Normal);
+    queue.skip_late_frames(Duration::from_millis(1));
+
+    assert_eq!(drain(&mut queue), vec!["d"]);
+    assert_eq!(queue.dropped_frames, 3);
+  }
+
+  #[test]
+  fn the_newest_frame_is_never_skipped() {
+    let mut queue = FrameQueue::new(4);
+
+    queue.push("a".to_string(), FramePriority::Normal);
+    std::thread::sleep(Duration::from_millis(2));
+    queue.skip_late_frames(Duration::from_millis(1));
+
+    assert_eq!(drain(&mut queue), vec!["a"]);
+  }
+}
+
+#[cfg(test)]
+mod background_printer_tests {
+  use super::*;
+
+  #[test]
+  fn error_is_returned_after_printing_fails() {
+    let mut printer = Printer::new();
+    printer.replace_terminal_dimensions(Some((2, 2)));
+    printer.set_cursor_addressing(true);
+
+    let background_printer = BackgroundPrinter::new(printer, 4);
+    background_printer.send("abc".to_string()).unwrap();
+
+    assert!(matches!(
+      background_printer.finish(),
+      Err(PrintingError::GridLargerThanTerminal(_))
+    ));
+  }
+
+  #[test]
+  fn latency_of_printed_frames_is_tracked() {
+    let background_printer = BackgroundPrinter::new(Printer::on_virtual_terminal((3, 1)), 4)
+      .with_max_latency(Duration::from_secs(1));
+
+    assert_eq!(
+      background_printer.get_max_latency(),
+      Some(Duration::from_secs(1))
+    );
+    assert!(background_printer.last_frame_latency().is_none());
+
+    background_printer.send("abc".to_string()).unwrap();
+
+    while background_printer.last_frame_latency().is_none() {
+      std::thread::yield_now();
+    }
+
+    background_printer.finish().unwrap();
+  }
+
+  #[test]
+  fn debounced_grids_are_printed_before_finishing() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+    printer.replace_debounce_interval(Some(std::time::Duration::from_secs(60)));
+
+    let background_printer = BackgroundPrinter::new(printer, 4);
+    background_printer.send("abc".to_string()).unwrap();
+    background_printer.send("def".to_string()).unwrap();
+
+    let printer = background_printer.finish().unwrap();
+
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "def");
+    assert!(!printer.has_debounced_grid());
+  }
+
+  #[test]
+  fn resized_terminals_are_redrawn_while_waiting() {
+    let background_printer = BackgroundPrinter::new(Printer::on_virtual_terminal((3, 1)), 4)
+      .with_resize_polling(Duration::from_millis(1));
+    background_printer.send("abc".to_string()).unwrap();
+    background_printer.send("abc".to_string()).unwrap();
+
+    std::thread::sleep(Duration::from_millis(20));
+    let printer = background_printer.finish().unwrap();
+
+    assert!(printer.get_resize_redraw());
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "abc");
+  }
+}
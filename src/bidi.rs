@@ -0,0 +1,57 @@
+use unicode_bidi::{BidiInfo, Level};
+
+/// How the printer handles right-to-left text, such as Arabic or Hebrew.
+///
+/// The printer places every cell of a grid from left to right in the order they're stored.
+/// Terminals differ in how they display right-to-left text, where some will reorder it and others won't,
+/// so by default right-to-left text may not end up displayed in the cells the printer expects.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BidiMode {
+  /// Grids are printed as they are, leaving any reordering up to the terminal.
+  #[default]
+  Off,
+  /// Every row is reordered from the order it's stored in, to the order it would be displayed in.
+  ///
+  /// This is for terminals that don't reorder text themselves, which is most of them.
+  /// Each row is treated as a left-to-right paragraph.
+  Reorder,
+  /// Everything printed is wrapped in left-to-right isolates, which stops terminals
+  /// that reorder text from doing so.
+  ForceLeftToRight,
+}
+
+/// Unicode's left-to-right isolate character.
+const LEFT_TO_RIGHT_ISOLATE: char = '\u{2066}';
+/// Unicode's pop directional isolate character, which ends an isolate.
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Reorders every row in the grid from the order it's stored in, to the order it would be displayed in.
+pub(crate) fn reorder_grid(grid: &str) -> String {
+  grid
+    .split('\n')
+    .map(reorder_row)
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+/// Reorders the row from the order it's stored in, to the order it would be displayed in,
+/// treating the row as a left-to-right paragraph.
+fn reorder_row(row: &str) -> String {
+  let bidi_info = BidiInfo::new(row, Some(Level::ltr()));
+
+  match bidi_info.paragraphs.first() {
+    Some(paragraph) if bidi_info.has_rtl() => bidi_info
+      .reorder_line(paragraph, paragraph.range.clone())
+      .into_owned(),
+    _ => row.to_string(),
+  }
+}
+
+/// Wraps the text in a left-to-right isolate when the mode is [`ForceLeftToRight`](BidiMode::ForceLeftToRight).
+pub(crate) fn isolate_text(text: &str, bidi_mode: BidiMode) -> String {
+  if bidi_mode == BidiMode::ForceLeftToRight {
+    format!("{LEFT_TO_RIGHT_ISOLATE}{text}{POP_DIRECTIONAL_ISOLATE}")
+  } else {
+    text.to_string()
+  }
+}
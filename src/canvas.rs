@@ -0,0 +1,177 @@
+//! A fixed size grid of cells that can be drawn into one cell at a time, then turned into a grid to be printed.
+
+mod tests;
+
+use crate::coordinates::coordinates_to_index;
+use alloc::{string::String, vec, vec::Vec};
+use unicode_width::UnicodeWidthChar;
+
+/// A grid of cells of a fixed (width, height), where every cell is one column of a row.
+///
+/// Each cell holds the text printed in its column, which is usually a single character, optionally wrapped in style escape sequences.
+/// Wide characters are held by the cell of their first column, followed by an empty cell for the column they cover.
+///
+/// The [`Printer`](crate::printer::Printer) owns a canvas as its [`back buffer`](crate::printer::Printer::back_buffer),
+/// which stays the same between frames so only what changes has to be drawn.
+///
+/// Every cell whose text changes is marked as touched until [`clear_touched`](Canvas::clear_touched) is called,
+/// which lets the printer only compare the rows that were touched when [`modifying`](crate::printer::Printer::modify) its back buffer.
+///
+/// # Example
+/// ```
+/// use screen_printer::canvas::Canvas;
+///
+/// let mut canvas = Canvas::new((5, 2));
+///
+/// canvas.set((0, 0), "#");
+/// canvas.write_text((1, 1), "abcdef");
+///
+/// assert_eq!(canvas.to_grid(), "#    \n abcd");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Canvas {
+  width: usize,
+  height: usize,
+  cells: Vec<String>,
+  touched: Vec<bool>,
+}
+
+impl Canvas {
+  /// Creates a canvas of the given (width, height) filled with spaces, where every cell is touched.
+  pub fn new((width, height): (usize, usize)) -> Self {
+    Self {
+      width,
+      height,
+      cells: vec![String::from(" "); width * height],
+      touched: vec![true; width * height],
+    }
+  }
+
+  /// Returns the (width, height) of the canvas.
+  pub fn dimensions(&self) -> (usize, usize) {
+    (self.width, self.height)
+  }
+
+  /// Returns the text of the cell at the given (x, y) coordinates, or None if they're outside the canvas.
+  pub fn get(&self, (x, y): (usize, usize)) -> Option<&str> {
+    self
+      .contains((x, y))
+      .then(|| self.cells[coordinates_to_index((x, y), self.width)].as_str())
+  }
+
+  /// Replaces the text of the cell at the given (x, y) coordinates, doing nothing if they're outside the canvas.
+  ///
+  /// The cell is only touched if its text changed.
+  pub fn set<S: Into<String>>(&mut self, (x, y): (usize, usize), text: S) {
+    if !self.contains((x, y)) {
+      return;
+    }
+
+    let index = coordinates_to_index((x, y), self.width);
+    let text = text.into();
+
+    if self.cells[index] != text {
+      self.cells[index] = text;
+      self.touched[index] = true;
+    }
+  }
+
+  /// Replaces the text of every cell with the given text.
+  pub fn fill(&mut self, text: &str) {
+    self
+      .cells
+      .iter_mut()
+      .zip(&mut self.touched)
+      .filter(|(cell, _)| cell.as_str() != text)
+      .for_each(|(cell, touched)| {
+        cell.clear();
+        cell.push_str(text);
+        *touched = true;
+      });
+  }
+
+  /// Returns true if the text of the cell at the given (x, y) coordinates changed since the last time touched cells were cleared.
+  ///
+  /// Cells outside the canvas are never touched.
+  pub fn is_touched(&self, (x, y): (usize, usize)) -> bool {
+    self.contains((x, y)) && self.touched[coordinates_to_index((x, y), self.width)]
+  }
+
+  /// Returns a list of whether each row contains a touched cell, from top to bottom.
+  pub fn touched_rows(&self) -> Vec<bool> {
+    self
+      .touched
+      .chunks(self.width.max(1))
+      .map(|row| row.contains(&true))
+      .chain(core::iter::repeat(false))
+      .take(self.height)
+      .collect()
+  }
+
+  /// Marks every cell as untouched.
+  pub fn clear_touched(&mut self) {
+    self.touched.fill(false);
+  }
+
+  /// Writes each character of the text into its own cell along the row, starting from the given (x, y) coordinates.
+  ///
+  /// Wide characters take up two cells, and anything past the right edge of the canvas is cut off.
+  /// Control characters such as newlines are skipped.
+  pub fn write_text(&mut self, (mut x, y): (usize, usize), text: &str) {
+    for character in text.chars() {
+      let Some(character_width) = character.width().filter(|&width| width > 0) else {
+        continue;
+      };
+
+      if x + character_width > self.width {
+        break;
+      }
+
+      self.set((x, y), character);
+      (1..character_width).for_each(|covered_x| self.set((x + covered_x, y), ""));
+
+      x += character_width;
+    }
+  }
+
+  /// Changes the (width, height) of the canvas, keeping every cell still within it and filling new cells with spaces.
+  ///
+  /// Every cell is touched after resizing.
+  pub fn resize(&mut self, (width, height): (usize, usize)) {
+    if (width, height) == self.dimensions() {
+      return;
+    }
+
+    let mut resized_canvas = Self::new((width, height));
+
+    for y in 0..height.min(self.height) {
+      for x in 0..width.min(self.width) {
+        let index = coordinates_to_index((x, y), self.width);
+        resized_canvas.cells[coordinates_to_index((x, y), width)] =
+          core::mem::take(&mut self.cells[index]);
+      }
+    }
+
+    *self = resized_canvas;
+  }
+
+  /// Returns the canvas as a grid, where every row of cells is a line.
+  pub fn to_grid(&self) -> String {
+    let mut grid = String::with_capacity(self.cells.len() + self.height);
+
+    for (y, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+      if y > 0 {
+        grid.push('\n');
+      }
+
+      row.iter().for_each(|cell| grid.push_str(cell));
+    }
+
+    grid
+  }
+
+  /// Returns true if the given (x, y) coordinates are within the canvas.
+  fn contains(&self, (x, y): (usize, usize)) -> bool {
+    x < self.width && y < self.height
+  }
+}
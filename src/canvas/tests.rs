@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod canvas_tests {
+  use super::*;
+
+  #[test]
+  fn new_canvases_are_filled_with_spaces() {
+    let canvas = Canvas::new((3, 2));
+
+    assert_eq!(canvas.dimensions(), (3, 2));
+    assert_eq!(canvas.to_grid(), "   \n   ");
+  }
+
+  #[test]
+  fn cells_outside_the_canvas_are_ignored() {
+    let mut canvas = Canvas::new((2, 2));
+
+    canvas.set((2, 0), "x");
+    canvas.set((1, 1), "x");
+
+    assert_eq!(canvas.get((2, 0)), None);
+    assert_eq!(canvas.get((1, 1)), Some("x"));
+    assert_eq!(canvas.to_grid(), "  \n x");
+  }
+
+  #[test]
+  fn wide_characters_cover_the_next_cell() {
+    let mut canvas = Canvas::new((4, 1));
+
+    canvas.write_text((0, 0), "a字字");
+
+    assert_eq!(canvas.get((2, 0)), Some(""));
+    assert_eq!(canvas.to_grid(), "a字 ");
+  }
+
+  #[test]
+  fn resizing_keeps_cells_within_the_canvas() {
+    let mut canvas = Canvas::new((3, 2));
+    canvas.write_text((0, 0), "abc");
+    canvas.write_text((0, 1), "def");
+
+    canvas.resize((2, 3));
+
+    assert_eq!(canvas.to_grid(), "ab\nde\n  ");
+  }
+
+  #[test]
+  fn filling_replaces_every_cell() {
+    let mut canvas = Canvas::new((2, 2));
+
+    canvas.fill("#");
+
+    assert_eq!(canvas.to_grid(), "##\n##");
+  }
+
+  #[test]
+  fn only_changed_cells_are_touched() {
+    let mut canvas = Canvas::new((3, 3));
+    assert!(canvas.is_touched((0, 0)));
+
+    canvas.clear_touched();
+    canvas.set((1, 1), " ");
+    canvas.write_text((0, 2), "ab");
+
+    assert!(!canvas.is_touched((1, 1)));
+    assert!(canvas.is_touched((1, 2)));
+    assert!(!canvas.is_touched((3, 2)));
+    assert_eq!(canvas.touched_rows(), vec![false, false, true]);
+
+    canvas.clear_touched();
+    canvas.fill(" ");
+
+    assert_eq!(canvas.touched_rows(), vec![false, false, true]);
+    assert!(!canvas.is_touched((2, 2)));
+  }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod back_buffer_tests {
+  use super::*;
+  use crate::printer::{DynamicPrinter, Printer};
+
+  #[test]
+  fn back_buffer_starts_at_the_printing_area() {
+    let mut printer = Printer::on_virtual_terminal((4, 3));
+
+    assert_eq!(printer.back_buffer().dimensions(), (4, 3));
+  }
+
+  #[test]
+  fn presenting_prints_only_the_changes() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+    printer.back_buffer().write_text((0, 0), "abc");
+    printer.present().unwrap();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+
+    printer.back_buffer().set((1, 0), "x");
+    printer.present().unwrap();
+
+    let update = &printer.get_capture().unwrap().bytes()[printed_bytes..];
+
+    assert!(String::from_utf8_lossy(update).ends_with("\x1B[1;2Hx"));
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "axc");
+  }
+
+  #[test]
+  fn modifying_compares_only_touched_rows() {
+    let mut printer = Printer::on_virtual_terminal((3, 3));
+    printer.modify(|grid| grid.fill("a")).unwrap();
+
+    printer.modify(|grid| grid.set((1, 2), "x")).unwrap();
+
+    assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 1);
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "aaa\naaa\naxa");
+    assert!(!printer.back_buffer().is_touched((1, 2)));
+  }
+
+  #[test]
+  fn modifying_after_printing_another_grid_compares_everything() {
+    let mut printer = Printer::on_virtual_terminal((3, 2));
+    printer.modify(|grid| grid.fill("a")).unwrap();
+    printer.dynamic_print("bbb\nbbb".to_string()).unwrap();
+
+    printer.modify(|grid| grid.set((0, 0), "c")).unwrap();
+
+    assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 6);
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "caa\naaa");
+  }
+}
@@ -0,0 +1,69 @@
+mod tests;
+
+use crate::color::ColorDepth;
+use std::env;
+use std::io::{self, IsTerminal};
+
+/// The terminals known to support synchronized updates, matched against `TERM_PROGRAM` or `TERM`.
+const SYNCHRONIZED_UPDATE_TERMINALS: [&str; 8] = [
+  "wezterm",
+  "iterm.app",
+  "kitty",
+  "alacritty",
+  "foot",
+  "contour",
+  "ghostty",
+  "vscode",
+];
+
+/// What the terminal is able to display, for adapting what's printed to it.
+///
+/// Obtained from [`Printer::capabilities`](crate::printer::Printer::capabilities).
+/// Everything is detected from the environment, so terminals that don't set the usual environment variables
+/// may support more than what's detected.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TerminalCapabilities {
+  /// How many colors the terminal can display.
+  pub color_depth: ColorDepth,
+  /// Whether the terminal supports synchronized updates (mode 2026), which stop it from displaying a frame that's only partially printed.
+  pub synchronized_updates: bool,
+  /// Whether the cursor can be moved to any position, which the [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) method relies on.
+  ///
+  /// This is false when the output isn't a terminal, such as when being piped into a file.
+  /// Grids are then printed without any escape sequences, refer to [`set_cursor_addressing`](crate::printer::Printer::set_cursor_addressing).
+  pub cursor_addressing: bool,
+}
+
+impl TerminalCapabilities {
+  /// Detects the capabilities of the terminal from the environment, and whether stdout is a terminal.
+  pub fn detect() -> Self {
+    Self::detect_with_terminal(io::stdout().is_terminal())
+  }
+
+  /// Detects the capabilities of the terminal from the environment, given whether the output is a terminal.
+  pub(crate) fn detect_with_terminal(is_terminal: bool) -> Self {
+    let term = env::var("TERM").unwrap_or_default();
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+
+    Self::from_environment(ColorDepth::detect(), &term, &term_program, is_terminal)
+  }
+
+  /// Returns the capabilities for the given color depth, values of `TERM` and `TERM_PROGRAM`, and whether stdout is a terminal.
+  pub(crate) fn from_environment(
+    color_depth: ColorDepth,
+    term: &str,
+    term_program: &str,
+    is_terminal: bool,
+  ) -> Self {
+    let (term, term_program) = (term.to_lowercase(), term_program.to_lowercase());
+    let synchronized_updates = SYNCHRONIZED_UPDATE_TERMINALS
+      .iter()
+      .any(|terminal| term_program == *terminal || term.contains(terminal));
+
+    Self {
+      color_depth,
+      synchronized_updates: is_terminal && synchronized_updates,
+      cursor_addressing: is_terminal && !term.is_empty() && term != "dumb",
+    }
+  }
+}
@@ -0,0 +1,34 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod detection_tests {
+  use super::*;
+
+  #[test]
+  fn known_terminals_support_synchronized_updates() {
+    let capabilities =
+      TerminalCapabilities::from_environment(ColorDepth::TrueColor, "xterm-kitty", "", true);
+
+    assert!(capabilities.synchronized_updates);
+    assert!(capabilities.cursor_addressing);
+  }
+
+  #[test]
+  fn dumb_terminal_has_no_cursor_addressing() {
+    let capabilities =
+      TerminalCapabilities::from_environment(ColorDepth::Monochrome, "dumb", "", true);
+
+    assert!(!capabilities.cursor_addressing);
+  }
+
+  #[test]
+  fn piped_output_has_no_capabilities() {
+    let capabilities =
+      TerminalCapabilities::from_environment(ColorDepth::Basic16, "xterm", "WezTerm", false);
+
+    assert!(!capabilities.synchronized_updates);
+    assert!(!capabilities.cursor_addressing);
+  }
+}
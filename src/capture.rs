@@ -0,0 +1,131 @@
+mod tests;
+
+use crate::virtual_terminal::VirtualTerminal;
+use std::fmt;
+use std::io::{self, Write};
+
+/// Records everything written to it, and interprets it into what a terminal of a given size would display.
+///
+/// Every flush marks the end of a frame. The [`Printer`](crate::printer::Printer) flushes once after every grid it prints,
+/// so each frame is a single print. Screens are interpreted with a [`VirtualTerminal`](VirtualTerminal), and include the style of every cell.
+///
+/// A printer created with [`Printer::on_virtual_terminal`](crate::printer::Printer::on_virtual_terminal) prints to one of these,
+/// which is obtained with [`Printer::get_capture`](crate::printer::Printer::get_capture).
+///
+/// # Example
+/// ```
+/// use screen_printer::capture::CaptureWriter;
+/// use std::io::Write;
+///
+/// let mut capture = CaptureWriter::new((3, 2));
+///
+/// write!(capture, "abc").unwrap();
+/// capture.flush().unwrap();
+/// write!(capture, "\x1B[2;1H123").unwrap();
+/// capture.flush().unwrap();
+///
+/// assert_eq!(capture.frames(), vec!["abc\n   ", "abc\n123"]);
+/// assert_eq!(capture.final_screen(), "abc\n123");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct CaptureWriter {
+  dimensions: (usize, usize),
+  bytes: Vec<u8>,
+  /// The index in the bytes each frame ends at.
+  frame_ends: Vec<usize>,
+}
+
+impl CaptureWriter {
+  /// Creates an empty capture of a terminal with the given (width, height).
+  pub fn new(dimensions: (usize, usize)) -> Self {
+    Self {
+      dimensions,
+      ..Default::default()
+    }
+  }
+
+  /// Returns the (width, height) of the terminal the capture is interpreted as.
+  pub fn dimensions(&self) -> (usize, usize) {
+    self.dimensions
+  }
+
+  /// Returns every byte written so far.
+  pub fn bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+
+  /// Returns the amount of frames written so far, where anything written since the last flush isn't counted.
+  pub fn frame_count(&self) -> usize {
+    self.frame_ends.len()
+  }
+
+  /// Returns what the terminal displays after everything written so far, including anything written since the last flush.
+  pub fn final_screen(&self) -> String {
+    self.final_terminal().screen()
+  }
+
+  /// Returns the terminal after everything written so far, for inspecting it further.
+  pub fn final_terminal(&self) -> VirtualTerminal {
+    let mut virtual_terminal = self.new_terminal();
+
+    virtual_terminal.write(&String::from_utf8_lossy(&self.bytes));
+
+    virtual_terminal
+  }
+
+  /// Returns what the terminal displayed at the end of every frame, in order.
+  pub fn frames(&self) -> Vec<String> {
+    let mut virtual_terminal = self.new_terminal();
+    let mut frame_start = 0;
+
+    self
+      .frame_ends
+      .iter()
+      .map(|&frame_end| {
+        virtual_terminal.write(&String::from_utf8_lossy(
+          &self.bytes[frame_start..frame_end],
+        ));
+        frame_start = frame_end;
+
+        virtual_terminal.screen()
+      })
+      .collect()
+  }
+
+  /// Removes everything written so far.
+  pub fn clear(&mut self) {
+    self.bytes.clear();
+    self.frame_ends.clear();
+  }
+
+  fn new_terminal(&self) -> VirtualTerminal {
+    VirtualTerminal::new(self.dimensions.0, self.dimensions.1)
+  }
+}
+
+impl Write for CaptureWriter {
+  fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+    self.bytes.extend_from_slice(bytes);
+
+    Ok(bytes.len())
+  }
+
+  /// Marks the end of a frame, unless nothing was written since the last one.
+  fn flush(&mut self) -> io::Result<()> {
+    if self.frame_ends.last().copied().unwrap_or(0) != self.bytes.len() {
+      self.frame_ends.push(self.bytes.len());
+    }
+
+    Ok(())
+  }
+}
+
+/// A writer supplied to a [`deterministic`](crate::printer::Printer::deterministic_with_output) printer,
+/// which is written everything printed to the virtual terminal.
+pub(crate) struct SuppliedOutput(pub(crate) Box<dyn Write + Send>);
+
+impl fmt::Debug for SuppliedOutput {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str("SuppliedOutput")
+  }
+}
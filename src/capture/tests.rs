@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod capture_writer_tests {
+  use super::*;
+
+  #[test]
+  fn flushes_without_output_are_not_frames() {
+    let mut capture = CaptureWriter::new((2, 1));
+
+    capture.flush().unwrap();
+    capture.write_all(b"ab").unwrap();
+    capture.flush().unwrap();
+    capture.flush().unwrap();
+
+    assert_eq!(capture.frame_count(), 1);
+  }
+
+  #[test]
+  fn final_screen_includes_unflushed_output() {
+    let mut capture = CaptureWriter::new((2, 1));
+
+    capture.write_all(b"ab").unwrap();
+    capture.flush().unwrap();
+    capture.write_all(b"\x1B[1;1Hx").unwrap();
+
+    assert_eq!(capture.frames(), vec!["ab"]);
+    assert_eq!(capture.final_screen(), "xb");
+  }
+
+  #[test]
+  fn screens_are_styled() {
+    let mut capture = CaptureWriter::new((2, 1));
+
+    capture.write_all(b"\x1B[31ma\x1B[0mb").unwrap();
+
+    assert_eq!(capture.final_screen(), "\x1B[31ma\x1B[0mb");
+  }
+
+  #[test]
+  fn characters_split_across_writes_are_kept() {
+    let mut capture = CaptureWriter::new((1, 1));
+    let bytes = "é".as_bytes();
+
+    capture.write_all(&bytes[..1]).unwrap();
+    capture.write_all(&bytes[1..]).unwrap();
+
+    assert_eq!(capture.final_screen(), "é");
+  }
+
+  #[test]
+  fn clearing_removes_every_frame() {
+    let mut capture = CaptureWriter::new((1, 1));
+    capture.write_all(b"a").unwrap();
+    capture.flush().unwrap();
+
+    capture.clear();
+
+    assert!(capture.bytes().is_empty());
+    assert_eq!(capture.frame_count(), 0);
+  }
+}
+
+#[cfg(test)]
+mod printer_capture_tests {
+  use crate::printer::*;
+
+  #[test]
+  fn every_print_is_a_frame() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+    printer.dynamic_print("abx".to_string()).unwrap();
+
+    let capture = printer.get_capture().unwrap();
+    assert_eq!(capture.frames(), vec!["abc", "abx"]);
+  }
+}
+
+#[cfg(test)]
+mod deterministic_printer_tests {
+  use crate::printer::*;
+  use std::io::{self, Write};
+  use std::sync::{Arc, Mutex};
+
+  /// A buffer that can still be read after being supplied to a printer.
+  #[derive(Clone, Default)]
+  struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+  impl Write for SharedBuffer {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+      self.0.lock().unwrap().write(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn supplied_output_receives_everything_captured() {
+    let buffer = SharedBuffer::default();
+    let mut printer = Printer::deterministic_with_output(buffer.clone());
+
+    printer.dynamic_print("abc\n123".to_string()).unwrap();
+    printer.dynamic_print("abx\n123".to_string()).unwrap();
+
+    assert_eq!(
+      *buffer.0.lock().unwrap(),
+      printer.get_capture().unwrap().bytes()
+    );
+    assert_eq!(
+      printer.get_current_terminal_dimensions().unwrap(),
+      DETERMINISTIC_DIMENSIONS
+    );
+  }
+
+  #[test]
+  fn environment_is_not_queried() {
+    let printer = Printer::deterministic();
+
+    assert_eq!(printer.get_color_depth(), ColorDepth::TrueColor);
+    assert!(printer.capabilities().cursor_addressing);
+    assert!(!printer.capabilities().synchronized_updates);
+    assert_eq!(
+      printer.get_current_terminal_dimensions().unwrap(),
+      DETERMINISTIC_DIMENSIONS
+    );
+  }
+
+  #[test]
+  fn output_is_identical_between_printers() {
+    let print_frames = || {
+      let mut printer = Printer::deterministic();
+      printer.dynamic_print("abc\n123".to_string()).unwrap();
+      printer.dynamic_print("abx\n123".to_string()).unwrap();
+
+      printer.get_capture().unwrap().bytes().to_vec()
+    };
+
+    assert_eq!(print_frames(), print_frames());
+  }
+}
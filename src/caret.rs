@@ -0,0 +1,89 @@
+mod tests;
+
+use crate::cells::*;
+use crate::style::StyledTextBuilder;
+use std::rc::Rc;
+
+/// The style sequence that swaps the foreground and background colors of the caret's cell.
+const INVERTED_STYLE: &str = "\x1B[7m";
+/// The style sequence that makes the caret's cell blink.
+const BLINKING_STYLE: &str = "\x1B[5m";
+
+/// How the printer shows the [`Caret`](Caret).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum CaretStyle {
+  /// The caret's cell has its foreground and background colors swapped.
+  #[default]
+  Inverted,
+  /// The caret's cell blinks, on terminals that support blinking text.
+  Blinking,
+  /// The terminal's own cursor is shown at the caret's cell after every print, and hidden while printing.
+  TerminalCursor,
+}
+
+/// A cell of the grid marked as where input goes, such as the position of the cursor in a text field.
+///
+/// Set with [`replace_caret`](crate::printer::Printer::replace_caret), after which every printed grid shows the caret.
+/// Since the caret is a part of what's printed, moving it only reprints the cells it moved between.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::deterministic();
+/// printer.replace_caret(Some(Caret::new((3, 0), CaretStyle::TerminalCursor)));
+///
+/// printer.dynamic_print("abc \n123 ".to_string()).unwrap();
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Caret {
+  /// The (x, y) position of the caret in the grid, where x is the column the caret's cell covers.
+  pub position: (usize, usize),
+  /// How the caret is shown.
+  pub style: CaretStyle,
+}
+
+impl Caret {
+  /// Creates a caret at the given (x, y) position in the grid.
+  pub fn new(position: (usize, usize), style: CaretStyle) -> Self {
+    Self { position, style }
+  }
+}
+
+/// Returns the grid with the style of the caret added to the cell covering the caret's position.
+///
+/// The grid is unchanged for carets shown with the terminal's cursor, or outside of the grid.
+pub(crate) fn style_caret_cell(grid: &str, caret: Caret, emoji_mode: EmojiMode) -> String {
+  let caret_style = match caret.style {
+    CaretStyle::Inverted => INVERTED_STYLE,
+    CaretStyle::Blinking => BLINKING_STYLE,
+    CaretStyle::TerminalCursor => return grid.to_string(),
+  };
+  let (caret_x, caret_y) = caret.position;
+  let mut rows = split_grid_into_cells(grid, emoji_mode);
+
+  let Some(caret_cell) = rows.get_mut(caret_y).and_then(|row| {
+    let mut column = 0;
+
+    row.iter_mut().find(|cell| {
+      column += cell.width;
+
+      column > caret_x
+    })
+  }) else {
+    return grid.to_string();
+  };
+
+  caret_cell.style = Rc::from(format!("{}{caret_style}", caret_cell.style));
+
+  rows
+    .iter()
+    .map(|row| {
+      let mut row_text = StyledTextBuilder::default();
+      row.iter().for_each(|cell| row_text.push(cell));
+
+      row_text.finish()
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
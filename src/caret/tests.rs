@@ -0,0 +1,51 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod style_caret_cell_tests {
+  use super::*;
+
+  #[test]
+  fn caret_cell_is_styled() {
+    let caret = Caret::new((1, 1), CaretStyle::Inverted);
+
+    assert_eq!(
+      style_caret_cell("abc\n123", caret, EmojiMode::Off),
+      "abc\n1\x1B[7m2\x1B[0m3"
+    );
+  }
+
+  #[test]
+  fn carried_over_styles_are_kept() {
+    let caret = Caret::new((0, 0), CaretStyle::Blinking);
+
+    assert_eq!(
+      style_caret_cell("\x1B[31mab\ncd\x1B[0m", caret, EmojiMode::Off),
+      "\x1B[31m\x1B[5ma\x1B[0m\x1B[31mb\x1B[0m\n\x1B[31mcd\x1B[0m"
+    );
+  }
+
+  #[test]
+  fn caret_covers_wide_characters() {
+    let caret = Caret::new((2, 0), CaretStyle::Inverted);
+
+    assert_eq!(
+      style_caret_cell("a日b", caret, EmojiMode::Off),
+      "a\x1B[7m日\x1B[0mb"
+    );
+  }
+
+  #[test]
+  fn caret_outside_grid_changes_nothing() {
+    let grid = "ab\ncd";
+
+    for caret in [
+      Caret::new((2, 0), CaretStyle::Inverted),
+      Caret::new((0, 2), CaretStyle::Inverted),
+      Caret::new((0, 0), CaretStyle::TerminalCursor),
+    ] {
+      assert_eq!(style_caret_cell(grid, caret, EmojiMode::Off), grid);
+    }
+  }
+}
@@ -0,0 +1,452 @@
+use crate::ansi::*;
+use crate::style::apply_style_sequence;
+#[cfg(feature = "std")]
+use crate::style::StyledTextBuilder;
+#[cfg(feature = "std")]
+use alloc::{format, string::String, string::ToString};
+use alloc::{rc::Rc, vec::Vec};
+#[cfg(feature = "std")]
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use unicode_width::UnicodeWidthChar;
+
+/// How the printer handles emoji made out of multiple characters, such as `👨‍👩‍👧` or `👍🏽`.
+///
+/// Most terminals display these sequences as a single emoji two columns wide,
+/// while the printer would otherwise measure every emoji in them on its own.
+/// This causes everything after the emoji in a row to be printed in the wrong place.
+///
+/// Emoji made out of a single character are not affected by this.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EmojiMode {
+  /// Every character in an emoji sequence is measured on its own.
+  #[default]
+  Off,
+  /// Every emoji sequence is treated as a single cell that's two columns wide.
+  Cluster,
+  /// Every emoji sequence is replaced with the given character.
+  Placeholder(char),
+}
+
+/// How characters made out of multiple Unicode code points are normalized before being printed.
+///
+/// The same text can be written in multiple ways, such as `é` being either a single character,
+/// or `e` followed by a combining accent (`U+0301`).
+/// Without normalization, these are considered different from each other, causing them to be reprinted.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CharacterNormalization {
+  /// Characters are left as they are.
+  #[default]
+  Off,
+  /// Characters are composed into [`NFC`](https://unicode.org/reports/tr15/) form,
+  /// which combines characters and their combining marks where possible.
+  Composed,
+  /// Characters are composed into NFC form, then any combining marks that couldn't
+  /// be combined into the character before them are removed.
+  ComposedWithoutOrphans,
+}
+
+/// How the characters given to the grid builders, such as
+/// [`create_grid_from_full_character_list_with_normalization`](crate::printer::Printer::create_grid_from_full_character_list_with_normalization),
+/// are normalized so every one of them makes up a single cell.
+///
+/// Nothing is normalized by default.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let normalization = GridNormalization::new(CharacterNormalization::Composed)
+///   .with_control_character_replacement(' ');
+///
+/// assert_eq!(normalization.normalize("e\u{301}"), "\u{e9}");
+/// assert_eq!(normalization.normalize("\t"), " ");
+/// assert_eq!(normalization.normalize("\x1B[31m\x07\x1B[0m"), "\x1B[31m \x1B[0m");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GridNormalization {
+  pub characters: CharacterNormalization,
+  /// The character that tabs and other control characters are replaced with, or None to leave them as they are.
+  ///
+  /// Escape sequences are kept as they are.
+  pub control_character_replacement: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl GridNormalization {
+  /// Creates a normalization with the given [`CharacterNormalization`](CharacterNormalization), leaving control characters as they are.
+  pub fn new(characters: CharacterNormalization) -> Self {
+    Self {
+      characters,
+      ..Default::default()
+    }
+  }
+
+  /// Returns the normalization with tabs and other control characters replaced with the given character.
+  pub fn with_control_character_replacement(mut self, replacement: char) -> Self {
+    self.control_character_replacement = Some(replacement);
+
+    self
+  }
+
+  /// Returns the text with its characters normalized.
+  pub fn normalize(&self, text: &str) -> String {
+    let text = normalize_characters(text, self.characters);
+
+    match self.control_character_replacement {
+      Some(replacement) => replace_control_characters(&text, replacement),
+      None => text,
+    }
+  }
+}
+
+/// A single cell of a grid, which is what the printer compares when finding the difference between grids.
+///
+/// Characters with no width, such as combining marks, are a part of the cell before them.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct Cell<'a> {
+  /// The characters printed for this cell.
+  pub text: &'a str,
+  /// How many columns on the terminal this cell takes up.
+  pub width: usize,
+  /// Every style escape sequence that applies to this cell, or an empty string for unstyled cells.
+  pub style: Rc<str>,
+}
+
+/// Splits every row of the grid into the cells the terminal would display.
+///
+/// Styles carry over from one row to the next, the same way they would when printing the grid.
+pub(crate) fn split_grid_into_cells(grid: &str, emoji_mode: EmojiMode) -> Vec<Vec<Cell<'_>>> {
+  let mut style: Rc<str> = Rc::from("");
+
+  grid
+    .split('\n')
+    .map(|row| split_row_into_cells(row, emoji_mode, &mut style))
+    .collect()
+}
+
+/// Splits the row into the cells the terminal would display, based on the given [`EmojiMode`](EmojiMode).
+///
+/// Escape sequences take up no cells. Any style sequences are applied to the given style,
+/// which is then assigned to every cell after them.
+pub(crate) fn split_row_into_cells<'a>(
+  row: &'a str,
+  emoji_mode: EmojiMode,
+  style: &mut Rc<str>,
+) -> Vec<Cell<'a>> {
+  let mut cells: Vec<Cell<'a>> = Vec::new();
+  let mut last_cell_start = 0;
+  // The start of any characters with no width that don't have a cell before them to be a part of.
+  let mut unattached_start: Option<usize> = None;
+  let mut index = 0;
+
+  while let Some(character) = row[index..].chars().next() {
+    if character == ESCAPE {
+      let sequence_length = escape_sequence_length(&row[index..]);
+      let sequence = &row[index..index + sequence_length];
+
+      if is_style_sequence(sequence) {
+        apply_style_sequence(style, sequence);
+      }
+
+      index += sequence_length;
+
+      continue;
+    }
+
+    let (end_index, width) = measure_cell(row, index, character, emoji_mode);
+
+    if width == 0 {
+      match cells.last_mut() {
+        Some(cell) if unattached_start.is_none() => cell.text = &row[last_cell_start..end_index],
+        _ => {
+          unattached_start.get_or_insert(index);
+        }
+      }
+    } else {
+      last_cell_start = unattached_start.take().unwrap_or(index);
+
+      cells.push(Cell {
+        text: &row[last_cell_start..end_index],
+        width,
+        style: style.clone(),
+      });
+    }
+
+    index = end_index;
+  }
+
+  cells
+}
+
+/// Replaces every emoji sequence in the grid with the given placeholder character.
+#[cfg(feature = "std")]
+pub(crate) fn replace_emoji_sequences(grid: &str, placeholder: char) -> String {
+  let mut replaced_grid = String::with_capacity(grid.len());
+  let mut index = 0;
+
+  while let Some(character) = grid[index..].chars().next() {
+    let end_index = emoji_sequence_end(grid, index, character);
+
+    if end_index == index + character.len_utf8() {
+      replaced_grid.push(character);
+    } else {
+      replaced_grid.push(placeholder);
+    }
+
+    index = end_index;
+  }
+
+  replaced_grid
+}
+
+/// Normalizes the characters in the grid based on the given [`CharacterNormalization`](CharacterNormalization).
+#[cfg(feature = "std")]
+pub(crate) fn normalize_characters(grid: &str, normalization: CharacterNormalization) -> String {
+  match normalization {
+    CharacterNormalization::Off => grid.to_string(),
+    CharacterNormalization::Composed => grid.nfc().collect(),
+    CharacterNormalization::ComposedWithoutOrphans => grid
+      .nfc()
+      .filter(|character| !is_combining_mark(*character))
+      .collect(),
+  }
+}
+
+/// Replaces every control character in the text that isn't part of an escape sequence with the given character.
+#[cfg(feature = "std")]
+fn replace_control_characters(text: &str, replacement: char) -> String {
+  let mut replaced_text = String::with_capacity(text.len());
+  let mut index = 0;
+
+  while let Some(character) = text[index..].chars().next() {
+    if character == ESCAPE {
+      let sequence_length = escape_sequence_length(&text[index..]);
+      replaced_text.push_str(&text[index..index + sequence_length]);
+      index += sequence_length;
+    } else {
+      replaced_text.push(match character.is_control() {
+        true => replacement,
+        false => character,
+      });
+      index += character.len_utf8();
+    }
+  }
+
+  replaced_text
+}
+
+/// Returns the amount of columns the row takes up on the terminal.
+///
+/// This ignores any escape sequences the same way [`strip_ansi`](crate::ansi::strip_ansi) does,
+/// and accounts for characters that take up more or less than a single column, such as `日` or combining marks.
+///
+/// # Example
+/// ```
+/// use screen_printer::core::*;
+///
+/// assert_eq!(measure_row_width("abc"), 3);
+/// assert_eq!(measure_row_width("\x1B[31mabc\x1B[0m"), 3);
+/// assert_eq!(measure_row_width("日本"), 4);
+/// assert_eq!(measure_row_width("e\u{301}"), 1);
+/// ```
+pub fn measure_row_width(row: &str) -> usize {
+  row_width(row, EmojiMode::Off)
+}
+
+/// Returns the width of the widest row in the grid, and the amount of rows in it.
+///
+/// Rows are measured the same way as [`measure_row_width`](measure_row_width).
+/// Unlike [`get_rectangular_dimensions`](crate::printer::Printer::get_rectangular_dimensions),
+/// this doesn't require the grid to be rectangular.
+///
+/// # Example
+/// ```
+/// use screen_printer::core::*;
+///
+/// assert_eq!(measure_grid("abc\n\x1B[1m12\x1B[0m"), (3, 2));
+/// assert_eq!(measure_grid(""), (0, 0));
+/// ```
+pub fn measure_grid(grid: &str) -> (usize, usize) {
+  if grid.is_empty() {
+    return (0, 0);
+  }
+
+  grid.split('\n').fold((0, 0), |(width, height), row| {
+    (width.max(measure_row_width(row)), height + 1)
+  })
+}
+
+/// Returns the amount of columns the row takes up on the terminal, with emoji sequences
+/// measured based on the given [`EmojiMode`](EmojiMode).
+pub(crate) fn row_width(row: &str, emoji_mode: EmojiMode) -> usize {
+  let mut width = 0;
+  let mut index = 0;
+
+  while let Some(character) = row[index..].chars().next() {
+    if character == ESCAPE {
+      index += escape_sequence_length(&row[index..]);
+
+      continue;
+    }
+
+    let (end_index, cell_width) = measure_cell(row, index, character, emoji_mode);
+    width += cell_width;
+    index = end_index;
+  }
+
+  width
+}
+
+/// Returns the index right after the end of the cell that starts with the given character, and how many columns it takes up.
+///
+/// Emoji sequences are a single cell two columns wide when clustering them, based on the given [`EmojiMode`](EmojiMode).
+fn measure_cell(
+  row: &str,
+  start_index: usize,
+  character: char,
+  emoji_mode: EmojiMode,
+) -> (usize, usize) {
+  let end_index = start_index + character.len_utf8();
+
+  if emoji_mode == EmojiMode::Cluster {
+    let sequence_end_index = emoji_sequence_end(row, start_index, character);
+
+    if sequence_end_index != end_index {
+      return (sequence_end_index, 2);
+    }
+  }
+
+  (end_index, character.width().unwrap_or(0))
+}
+
+/// Pads every row of the grid with whitespace to the width of its widest row,
+/// with emoji sequences measured based on the given [`EmojiMode`](EmojiMode).
+#[cfg(feature = "std")]
+pub(crate) fn pad_rows(grid: &str, emoji_mode: EmojiMode) -> String {
+  let row_widths: Vec<usize> = grid
+    .split('\n')
+    .map(|row| row_width(row, emoji_mode))
+    .collect();
+  let widest_row = row_widths.iter().copied().max().unwrap_or(0);
+
+  grid
+    .split('\n')
+    .zip(row_widths)
+    .map(|(row, width)| format!("{row}{}", " ".repeat(widest_row - width)))
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+/// Cuts the row down to the given width, or pads it with whitespace to reach it.
+///
+/// Any styling in the row is kept for the cells that remain, and reset before the padding.
+/// A wide cell that would only partially fit is replaced with padding.
+#[cfg(feature = "std")]
+pub(crate) fn fit_row_to_width(row: &str, width: usize) -> String {
+  let cells = split_row_into_cells(row, EmojiMode::Off, &mut Rc::from(""));
+  let mut fitted_row = StyledTextBuilder::default();
+  let mut fitted_width = 0;
+
+  for cell in cells.iter() {
+    if fitted_width + cell.width > width {
+      break;
+    }
+
+    fitted_width += cell.width;
+    fitted_row.push(cell);
+  }
+
+  let mut fitted_row = fitted_row.finish();
+  fitted_row.push_str(&" ".repeat(width - fitted_width));
+
+  fitted_row
+}
+
+/// Returns the first control character in the grid that isn't a newline or part of an escape sequence,
+/// along with the row it's in and the column it would be printed in.
+#[cfg(feature = "std")]
+pub(crate) fn find_control_character(
+  grid: &str,
+  emoji_mode: EmojiMode,
+) -> Option<(char, usize, usize)> {
+  grid.split('\n').enumerate().find_map(|(y, row)| {
+    let mut index = 0;
+
+    while let Some(character) = row[index..].chars().next() {
+      if character == ESCAPE {
+        index += escape_sequence_length(&row[index..]);
+      } else if character.is_control() {
+        return Some((character, y, row_width(&row[..index], emoji_mode)));
+      } else {
+        index += character.len_utf8();
+      }
+    }
+
+    None
+  })
+}
+
+/// Returns the index right after the end of the emoji sequence that starts with the given character.
+///
+/// If the character doesn't start an emoji sequence, this is the index right after the character.
+fn emoji_sequence_end(text: &str, start_index: usize, character: char) -> usize {
+  let sequence_start_index = start_index + character.len_utf8();
+  let mut end_index = sequence_start_index;
+  let mut characters = text[sequence_start_index..]
+    .char_indices()
+    .map(|(offset, character)| (sequence_start_index + offset, character))
+    .peekable();
+
+  while let Some(&(joiner_index, joiner)) = characters.peek() {
+    if !joins_emoji_sequence(&text[start_index..end_index], joiner) {
+      break;
+    }
+
+    characters.next();
+    end_index = joiner_index + joiner.len_utf8();
+
+    // The zero width joiner joins the character after it into the sequence.
+    if joiner == ZERO_WIDTH_JOINER {
+      match characters.peek() {
+        Some(&(joined_index, joined_character))
+          if joined_character != ESCAPE && joined_character != '\n' =>
+        {
+          characters.next();
+          end_index = joined_index + joined_character.len_utf8();
+        }
+        _ => (),
+      }
+    }
+  }
+
+  end_index
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Returns true if the next character continues the given emoji sequence.
+fn joins_emoji_sequence(sequence: &str, next_character: char) -> bool {
+  match next_character {
+    // Zero width joiner.
+    ZERO_WIDTH_JOINER => true,
+    // Variation selectors.
+    '\u{FE0E}' | '\u{FE0F}' => true,
+    // Skin tone modifiers.
+    '\u{1F3FB}'..='\u{1F3FF}' => true,
+    // Combining enclosing keycap.
+    '\u{20E3}' => true,
+    // Tag characters, used for subdivision flags.
+    '\u{E0020}'..='\u{E007F}' => true,
+    // Two regional indicators make up a flag.
+    '\u{1F1E6}'..='\u{1F1FF}' => {
+      let mut sequence_characters = sequence.chars();
+
+      matches!(
+        (sequence_characters.next(), sequence_characters.next()),
+        (Some('\u{1F1E6}'..='\u{1F1FF}'), None)
+      )
+    }
+    _ => false,
+  }
+}
@@ -0,0 +1,428 @@
+use crate::ansi::{parse_style, StyleAttributes, StyleColor};
+use crate::cells::*;
+use crate::coordinates::grid_to_terminal;
+use crate::escape_emitter::{translate_styles, AnsiEmitter, EscapeEmitter};
+use crate::style::StyledTextBuilder;
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// A ChangeRun is a horizontal run of characters that differ between two grids.
+///
+/// Runs never span multiple rows, so a change that continues past the end of one row
+/// and into the next is split into two runs.
+///
+/// These are what the [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) method prints,
+/// and can be used to build custom ways of emitting the difference between grids.
+///
+/// # Example
+/// ```
+/// use screen_printer::core::*;
+///
+/// let old_grid = "abc\n123\nxyz";
+/// let new_grid = "abc\n1l3\nxyl";
+///
+/// let change_runs = ChangeRun::from_grids(old_grid, new_grid);
+///
+/// assert_eq!(
+///   change_runs,
+///   vec![ChangeRun::new((1, 1), "l"), ChangeRun::new((2, 2), "l")]
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ChangeRun {
+  /// The (x, y) position of the first character in the run.
+  ///
+  /// This is relative to the top left of the grid, starting from (0, 0).
+  pub start: (usize, usize),
+  /// The characters that changed, in the order they appear in the row.
+  ///
+  /// Styled characters are surrounded by the escape sequences needed to style them,
+  /// followed by a reset if any styling was applied.
+  pub text: String,
+}
+
+impl ChangeRun {
+  /// Creates a new [`ChangeRun`](ChangeRun) starting at the given position in the grid.
+  pub fn new<S: Into<String>>(start: (usize, usize), text: S) -> Self {
+    Self {
+      start,
+      text: text.into(),
+    }
+  }
+
+  /// Returns every run of characters that differ between the old and new grid.
+  ///
+  /// The grids are compared row by row.
+  /// Any part of the new grid that doesn't exist in the old one counts as changed.
+  pub fn from_grids(old_grid: &str, new_grid: &str) -> Vec<Self> {
+    Self::from_grids_with_granularity(old_grid, new_grid, DiffGranularity::Cell)
+  }
+
+  /// Returns the difference between the old and new grid, split into runs based on the given [`DiffGranularity`](DiffGranularity).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::core::*;
+  ///
+  /// let old_grid = "abc\n123\nxyz";
+  /// let new_grid = "abc\n1l3\nxyz";
+  ///
+  /// let change_runs = ChangeRun::from_grids_with_granularity(old_grid, new_grid, DiffGranularity::Row);
+  ///
+  /// assert_eq!(change_runs, vec![ChangeRun::new((0, 1), "1l3")]);
+  /// ```
+  pub fn from_grids_with_granularity(
+    old_grid: &str,
+    new_grid: &str,
+    granularity: DiffGranularity,
+  ) -> Vec<Self> {
+    Self::from_grids_with_settings(
+      old_grid,
+      new_grid,
+      granularity,
+      EmojiMode::default(),
+      DiffPolicy::default(),
+    )
+  }
+
+  /// Returns the difference between the old and new grid, where cells are compared based on the given [`DiffPolicy`](DiffPolicy).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::core::*;
+  ///
+  /// let old_grid = "\x1B[31mabc\x1B[0m";
+  /// let new_grid = "\x1B[32mabc\x1B[0m";
+  ///
+  /// assert!(ChangeRun::from_grids_with_policy(old_grid, new_grid, DiffPolicy::IgnoreColors).is_empty());
+  /// ```
+  pub fn from_grids_with_policy(old_grid: &str, new_grid: &str, policy: DiffPolicy) -> Vec<Self> {
+    Self::from_grids_with_settings(
+      old_grid,
+      new_grid,
+      DiffGranularity::default(),
+      EmojiMode::default(),
+      policy,
+    )
+  }
+
+  /// Returns the difference between the old and new grid, where the grids are split into cells based on the given [`EmojiMode`](EmojiMode),
+  /// and compared based on the given [`DiffPolicy`](DiffPolicy).
+  ///
+  /// The x position of each run is the column it starts in, which accounts for any cells wider than one column.
+  /// Grids made of nothing but printable ASCII are compared byte by byte, without being split into cells.
+  pub(crate) fn from_grids_with_settings(
+    old_grid: &str,
+    new_grid: &str,
+    granularity: DiffGranularity,
+    emoji_mode: EmojiMode,
+    policy: DiffPolicy,
+  ) -> Vec<Self> {
+    if is_plain_ascii(old_grid) && is_plain_ascii(new_grid) {
+      return Self::from_ascii_grids(old_grid, new_grid, granularity);
+    }
+
+    let old_rows = split_grid_into_cells(old_grid, emoji_mode);
+    let new_rows = split_grid_into_cells(new_grid, emoji_mode);
+
+    new_rows
+      .iter()
+      .enumerate()
+      .flat_map(|(y, new_cells)| {
+        let old_cells = old_rows.get(y).map(Vec::as_slice).unwrap_or_default();
+
+        Self::from_rows_with_granularity(old_cells, new_cells, y, granularity, policy)
+      })
+      .collect()
+  }
+
+  /// Returns the difference between two grids of [`plain ASCII`](is_plain_ascii), where every byte is a cell one column wide.
+  fn from_ascii_grids(old_grid: &str, new_grid: &str, granularity: DiffGranularity) -> Vec<Self> {
+    let mut old_rows = old_grid.split('\n');
+    let mut change_runs = Vec::new();
+
+    for (y, new_row) in new_grid.split('\n').enumerate() {
+      let old_row = old_rows.next().unwrap_or_default().as_bytes();
+      let is_changed = |x: usize| old_row.get(x) != Some(&new_row.as_bytes()[x]);
+
+      if granularity == DiffGranularity::Row {
+        if (0..new_row.len()).any(is_changed) {
+          change_runs.push(Self::new((0, y), new_row));
+        }
+
+        continue;
+      }
+
+      let mut run_start = None;
+
+      for x in 0..new_row.len() {
+        match (is_changed(x), run_start) {
+          (true, None) => run_start = Some(x),
+          (false, Some(start_x)) => {
+            change_runs.push(Self::new((start_x, y), &new_row[start_x..x]));
+            run_start = None;
+          }
+          _ => (),
+        }
+      }
+
+      if let Some(start_x) = run_start {
+        change_runs.push(Self::new((start_x, y), &new_row[start_x..]));
+      }
+    }
+
+    change_runs
+  }
+
+  /// Returns the difference between the old and new row at the given y position, split into runs based on the given [`DiffGranularity`](DiffGranularity).
+  pub(crate) fn from_rows_with_granularity(
+    old_cells: &[Cell],
+    new_cells: &[Cell],
+    y: usize,
+    granularity: DiffGranularity,
+    policy: DiffPolicy,
+  ) -> Vec<Self> {
+    let change_runs = Self::from_rows(old_cells, new_cells, y, policy);
+
+    match granularity {
+      DiffGranularity::Cell => change_runs,
+      DiffGranularity::Row if !change_runs.is_empty() => {
+        let mut row_text = StyledTextBuilder::default();
+        new_cells.iter().for_each(|cell| row_text.push(cell));
+
+        vec![Self::new((0, y), row_text.finish())]
+      }
+      DiffGranularity::Row => vec![],
+    }
+  }
+
+  /// Returns every run of cells that differ between the old and new row.
+  ///
+  /// Cells are compared by the column they start in, so a cell is only unchanged when
+  /// the old row had a matching cell starting in the same column.
+  fn from_rows(old_cells: &[Cell], new_cells: &[Cell], y: usize, policy: DiffPolicy) -> Vec<Self> {
+    let old_columns = cells_by_column(old_cells);
+    let mut change_runs: Vec<Self> = Vec::new();
+    let mut open_run: Option<((usize, usize), StyledTextBuilder)> = None;
+    let mut x = 0;
+
+    for new_cell in new_cells {
+      let cell_x = x;
+      x += new_cell.width;
+
+      if old_columns
+        .get(cell_x)
+        .copied()
+        .flatten()
+        .is_some_and(|old_cell| policy.cells_match(old_cell, new_cell))
+      {
+        if let Some((start, run_text)) = open_run.take() {
+          change_runs.push(Self::new(start, run_text.finish()));
+        }
+
+        continue;
+      }
+
+      open_run
+        .get_or_insert_with(|| ((cell_x, y), StyledTextBuilder::default()))
+        .1
+        .push(new_cell);
+    }
+
+    if let Some((start, run_text)) = open_run {
+      change_runs.push(Self::new(start, run_text.finish()));
+    }
+
+    change_runs
+  }
+
+  /// Returns the escape code that moves the cursor to the start of this run, followed by the run's text.
+  ///
+  /// The origin is the position of the top left of the grid on the terminal.
+  pub fn to_printable_difference(&self, origin: (usize, usize)) -> String {
+    self.to_printable_difference_with_emitter(origin, &AnsiEmitter)
+  }
+
+  /// Returns the escape code that moves the cursor to the start of this run, followed by the run's text,
+  /// where the escape codes are produced by the given [`EscapeEmitter`](crate::escape_emitter::EscapeEmitter).
+  ///
+  /// The origin is the position of the top left of the grid on the terminal.
+  pub fn to_printable_difference_with_emitter(
+    &self,
+    origin: (usize, usize),
+    emitter: &dyn EscapeEmitter,
+  ) -> String {
+    let (x, y) = grid_to_terminal(self.start, origin);
+
+    format!(
+      "{}{}",
+      emitter.move_cursor(x, y),
+      translate_styles(&self.text, emitter)
+    )
+  }
+}
+
+/// How the difference between two grids is split into [`ChangeRuns`](ChangeRun).
+///
+/// Some terminals render many short cursor movements slower than a few longer writes,
+/// in which case printing entire rows can end up being faster.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DiffGranularity {
+  /// Every run of changed characters in a row is printed on its own.
+  #[default]
+  Cell,
+  /// Any row containing a changed character is reprinted in its entirety.
+  Row,
+}
+
+/// How the styles of cells are compared when finding the difference between grids.
+///
+/// Cells with different characters are always considered different.
+/// Policies other than [`Exact`](DiffPolicy::Exact) leave the old style on the terminal when a cell's style changes
+/// without being considered different, until the cell's characters change or the grid is fully reprinted.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DiffPolicy {
+  /// Cells are different when anything about their style is different.
+  ///
+  /// Styles that end up the same, despite being written with different escape sequences, are considered equal.
+  #[default]
+  Exact,
+  /// Changes to the foreground and background colors are ignored, while other attributes such as bold are compared.
+  ///
+  /// This is for terminals that are displaying in monochrome.
+  IgnoreColors,
+  /// Only the characters of cells are compared.
+  IgnoreStyles,
+  /// 24-bit colors are considered equal when none of their red, green, or blue values differ by more than the given amount.
+  ///
+  /// This stops slight changes to colors, such as a theme's colors fading, from reprinting every cell.
+  /// Colors that aren't 24-bit still have to match exactly.
+  ColorTolerance(u8),
+}
+
+impl DiffPolicy {
+  /// Returns true if the new cell is the same as the old one under this policy.
+  pub(crate) fn cells_match(&self, old_cell: &Cell, new_cell: &Cell) -> bool {
+    if old_cell.text != new_cell.text || old_cell.width != new_cell.width {
+      return false;
+    }
+
+    if old_cell.style == new_cell.style || *self == Self::IgnoreStyles {
+      return true;
+    }
+
+    let old_style = parse_style(&old_cell.style);
+    let new_style = parse_style(&new_cell.style);
+
+    match self {
+      Self::Exact => old_style == new_style,
+      Self::IgnoreColors => old_style.attributes == new_style.attributes,
+      Self::IgnoreStyles => true,
+      Self::ColorTolerance(tolerance) => styles_within_tolerance(old_style, new_style, *tolerance),
+    }
+  }
+}
+
+/// Returns true if both styles have the same attributes, and colors within the tolerance of each other.
+fn styles_within_tolerance(
+  old_style: StyleAttributes,
+  new_style: StyleAttributes,
+  tolerance: u8,
+) -> bool {
+  let colors_within_tolerance =
+    |old_color: StyleColor, new_color: StyleColor| match (old_color, new_color) {
+      (
+        StyleColor::Rgb(old_red, old_green, old_blue),
+        StyleColor::Rgb(new_red, new_green, new_blue),
+      ) => {
+        old_red.abs_diff(new_red) <= tolerance
+          && old_green.abs_diff(new_green) <= tolerance
+          && old_blue.abs_diff(new_blue) <= tolerance
+      }
+      _ => old_color == new_color,
+    };
+
+  old_style.attributes == new_style.attributes
+    && colors_within_tolerance(old_style.foreground, new_style.foreground)
+    && colors_within_tolerance(old_style.background, new_style.background)
+}
+
+/// Returns the grid that would be displayed after printing only the difference between the old and new grid.
+///
+/// Cells that match under the [`DiffPolicy`](DiffPolicy) keep the old cell, since they aren't reprinted.
+/// This is what later grids need to be compared against, so that small changes can't build up without being printed.
+#[cfg(feature = "std")]
+pub(crate) fn displayed_grid(
+  old_grid: &str,
+  new_grid: &str,
+  granularity: DiffGranularity,
+  emoji_mode: EmojiMode,
+  policy: DiffPolicy,
+) -> String {
+  // Unstyled cells are only ever considered unchanged when they're identical.
+  if is_plain_ascii(old_grid) && is_plain_ascii(new_grid) {
+    return new_grid.to_string();
+  }
+
+  let old_rows = split_grid_into_cells(old_grid, emoji_mode);
+  let new_rows = split_grid_into_cells(new_grid, emoji_mode);
+
+  new_rows
+    .iter()
+    .enumerate()
+    .map(|(y, new_cells)| {
+      let old_cells = old_rows.get(y).map(Vec::as_slice).unwrap_or_default();
+      let old_columns = cells_by_column(old_cells);
+      let mut displayed_cells: Vec<&Cell> = Vec::with_capacity(new_cells.len());
+      let mut x = 0;
+
+      for new_cell in new_cells {
+        let matching_old_cell = old_columns
+          .get(x)
+          .copied()
+          .flatten()
+          .filter(|old_cell| policy.cells_match(old_cell, new_cell));
+
+        displayed_cells.push(matching_old_cell.unwrap_or(new_cell));
+        x += new_cell.width;
+      }
+
+      let row_changed = displayed_cells
+        .iter()
+        .zip(new_cells)
+        .any(|(displayed_cell, new_cell)| core::ptr::eq(*displayed_cell, new_cell));
+
+      // Rows are reprinted in their entirety with row granularity.
+      if granularity == DiffGranularity::Row && row_changed {
+        displayed_cells = new_cells.iter().collect();
+      }
+
+      let mut row_text = StyledTextBuilder::default();
+      displayed_cells
+        .into_iter()
+        .for_each(|cell| row_text.push(cell));
+
+      row_text.finish()
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+/// Returns true if the grid contains nothing but printable ASCII characters and newlines,
+/// which means every byte of a row is a cell of its own, one column wide and without any style.
+pub(crate) fn is_plain_ascii(grid: &str) -> bool {
+  grid
+    .bytes()
+    .all(|byte| byte == b'\n' || (b' '..=b'~').contains(&byte))
+}
+
+/// Returns a list of every column in the row, containing the cell that starts in that column.
+///
+/// Columns covered by the rest of a wide cell are None.
+fn cells_by_column<'a>(cells: &'a [Cell]) -> Vec<Option<&'a Cell<'a>>> {
+  cells
+    .iter()
+    .flat_map(|cell| {
+      core::iter::once(Some(cell)).chain(core::iter::repeat_n(None, cell.width.saturating_sub(1)))
+    })
+    .collect()
+}
@@ -0,0 +1,466 @@
+mod tests;
+
+use crate::ansi::{escape_sequence_length, is_style_sequence, ESCAPE};
+use std::env;
+
+/// A 24-bit color used for styling text printed to the terminal.
+///
+/// Styling with these requires a terminal with 24-bit color support.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Color {
+  pub red: u8,
+  pub green: u8,
+  pub blue: u8,
+}
+
+impl Color {
+  /// Creates a new color out of the given red, green, and blue values.
+  pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+    Self { red, green, blue }
+  }
+
+  /// Returns the color that's the given fraction of the way from this color to the other one.
+  ///
+  /// The fraction is clamped between 0.0 and 1.0.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::color::Color;
+  ///
+  /// let black = Color::new(0, 0, 0);
+  /// let white = Color::new(255, 255, 255);
+  ///
+  /// assert_eq!(black.blend(white, 0.5), Color::new(128, 128, 128));
+  /// ```
+  pub fn blend(self, other: Self, fraction: f64) -> Self {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let blend_channel = |from: u8, to: u8| -> u8 {
+      (from as f64 + (to as f64 - from as f64) * fraction).round() as u8
+    };
+
+    Self {
+      red: blend_channel(self.red, other.red),
+      green: blend_channel(self.green, other.green),
+      blue: blend_channel(self.blue, other.blue),
+    }
+  }
+}
+
+/// Returns the text styled with the given foreground and background colors.
+///
+/// The style sequence starts by resetting any previous style, so styles don't stack on top of each other
+/// when many of these are placed one after the other.
+/// No reset is added after the text.
+pub(crate) fn colored_text(
+  text: &str,
+  foreground: Option<Color>,
+  background: Option<Color>,
+) -> String {
+  let mut parameters = vec!["0".to_string()];
+
+  if let Some(Color { red, green, blue }) = foreground {
+    parameters.push(format!("38;2;{red};{green};{blue}"));
+  }
+
+  if let Some(Color { red, green, blue }) = background {
+    parameters.push(format!("48;2;{red};{green};{blue}"));
+  }
+
+  format!("\x1B[{}m{text}", parameters.join(";"))
+}
+
+/// How many colors the terminal can display.
+///
+/// Colors in style sequences are converted down to the closest color the terminal supports,
+/// since terminals that don't support a color format can display the rest of the sequence incorrectly.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum ColorDepth {
+  /// No colors, only attributes such as bold and underline.
+  Monochrome,
+  /// The 16 basic colors, which every color terminal supports.
+  Basic16,
+  /// The 256 colors of the extended palette.
+  Palette256,
+  /// Any 24-bit color.
+  #[default]
+  TrueColor,
+}
+
+impl ColorDepth {
+  /// Detects the color depth of the terminal from the environment.
+  ///
+  /// - `NO_COLOR` being set means [`Monochrome`](ColorDepth::Monochrome).
+  /// - `COLORTERM` being `truecolor` or `24bit` means [`TrueColor`](ColorDepth::TrueColor).
+  /// - `TERM` containing `256color` means [`Palette256`](ColorDepth::Palette256).
+  /// - `TERM` being `dumb` means [`Monochrome`](ColorDepth::Monochrome).
+  /// - Anything else means [`Basic16`](ColorDepth::Basic16).
+  pub fn detect() -> Self {
+    let color_term = env::var("COLORTERM").unwrap_or_default();
+    let term = env::var("TERM").unwrap_or_default();
+
+    Self::from_environment(
+      env::var_os("NO_COLOR").is_some_and(|no_color| !no_color.is_empty()),
+      &color_term,
+      &term,
+    )
+  }
+
+  /// Returns the color depth for the given values of `NO_COLOR` being set, `COLORTERM`, and `TERM`.
+  pub(crate) fn from_environment(no_color: bool, color_term: &str, term: &str) -> Self {
+    if no_color || term == "dumb" {
+      Self::Monochrome
+    } else if matches!(color_term, "truecolor" | "24bit") {
+      Self::TrueColor
+    } else if term.contains("256color") {
+      Self::Palette256
+    } else {
+      Self::Basic16
+    }
+  }
+}
+
+/// The colors of the 16 basic colors, as displayed by xterm by default.
+const BASIC_COLORS: [Color; 16] = [
+  Color::new(0, 0, 0),
+  Color::new(205, 0, 0),
+  Color::new(0, 205, 0),
+  Color::new(205, 205, 0),
+  Color::new(0, 0, 238),
+  Color::new(205, 0, 205),
+  Color::new(0, 205, 205),
+  Color::new(229, 229, 229),
+  Color::new(127, 127, 127),
+  Color::new(255, 0, 0),
+  Color::new(0, 255, 0),
+  Color::new(255, 255, 0),
+  Color::new(92, 92, 255),
+  Color::new(255, 0, 255),
+  Color::new(0, 255, 255),
+  Color::new(255, 255, 255),
+];
+
+/// The values each channel of the 6x6x6 color cube in the extended palette can have.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+  /// Returns the color of the given index in the 256 color palette.
+  pub fn from_palette_index(index: u8) -> Self {
+    match index {
+      0..=15 => BASIC_COLORS[index as usize],
+      16..=231 => {
+        let index = index - 16;
+
+        Self::new(
+          CUBE_LEVELS[(index / 36) as usize],
+          CUBE_LEVELS[(index / 6 % 6) as usize],
+          CUBE_LEVELS[(index % 6) as usize],
+        )
+      }
+      232..=255 => {
+        let level = 8 + (index - 232) * 10;
+
+        Self::new(level, level, level)
+      }
+    }
+  }
+
+  /// Returns the index of the closest color in the 256 color palette, ignoring the 16 basic colors
+  /// since terminals commonly change what those look like.
+  pub fn to_palette_index(self) -> u8 {
+    let closest_level = |channel: u8| {
+      (0..CUBE_LEVELS.len())
+        .min_by_key(|&level| CUBE_LEVELS[level].abs_diff(channel))
+        .unwrap_or(0) as u8
+    };
+    let cube_index =
+      16 + 36 * closest_level(self.red) + 6 * closest_level(self.green) + closest_level(self.blue);
+
+    let average = ((self.red as u16 + self.green as u16 + self.blue as u16) / 3) as u8;
+    let gray_index = 232 + (average.saturating_sub(3) / 10).min(23);
+
+    [cube_index, gray_index]
+      .into_iter()
+      .min_by_key(|&index| self.distance_to(Self::from_palette_index(index)))
+      .unwrap_or(cube_index)
+  }
+
+  /// Returns the index of the closest of the 16 basic colors.
+  pub fn to_basic_index(self) -> u8 {
+    (0..BASIC_COLORS.len())
+      .min_by_key(|&index| self.distance_to(BASIC_COLORS[index]))
+      .unwrap_or(0) as u8
+  }
+
+  /// Returns the closest color the color depth can display, which is the color itself for
+  /// [`TrueColor`](ColorDepth::TrueColor) and [`Monochrome`](ColorDepth::Monochrome).
+  pub(crate) fn to_nearest_displayable(self, color_depth: ColorDepth) -> Self {
+    match color_depth {
+      ColorDepth::Monochrome | ColorDepth::TrueColor => self,
+      ColorDepth::Basic16 => BASIC_COLORS[self.to_basic_index() as usize],
+      ColorDepth::Palette256 => Self::from_palette_index(self.to_palette_index()),
+    }
+  }
+
+  /// Returns the squared distance between the two colors.
+  pub(crate) fn distance_to(self, other: Self) -> u32 {
+    let channel_distance = |first: u8, second: u8| (first.abs_diff(second) as u32).pow(2);
+
+    channel_distance(self.red, other.red)
+      + channel_distance(self.green, other.green)
+      + channel_distance(self.blue, other.blue)
+  }
+}
+
+/// How colors are picked from the palette of a [`ColorDepth`](ColorDepth) when quantizing them.
+///
+/// Used for [`quantize_colors`](quantize_colors).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Quantization {
+  /// Every color becomes the closest color in the palette, the same as when [`converting down`](downconvert_colors) style sequences.
+  #[default]
+  Nearest,
+  /// The colors are grouped into as many groups as the palette has colors by repeatedly splitting the group with the widest range
+  /// of a channel in half, then every color becomes the closest color in the palette to the average of its group.
+  ///
+  /// Similar colors are kept together, which avoids neighboring shades landing on noticeably different palette colors.
+  MedianCut,
+}
+
+/// Returns every color quantized to one displayable by the color depth, in the same order.
+///
+/// Colors are unchanged for [`TrueColor`](ColorDepth::TrueColor), and for [`Monochrome`](ColorDepth::Monochrome) where no color can be displayed.
+///
+/// # Example
+/// ```
+/// use screen_printer::color::*;
+///
+/// let colors = [Color::new(250, 10, 10), Color::new(10, 10, 240)];
+///
+/// assert_eq!(
+///   quantize_colors(&colors, ColorDepth::Basic16, Quantization::Nearest),
+///   [Color::new(255, 0, 0), Color::new(0, 0, 238)]
+/// );
+/// ```
+pub fn quantize_colors(
+  colors: &[Color],
+  color_depth: ColorDepth,
+  quantization: Quantization,
+) -> Vec<Color> {
+  let palette_size = match color_depth {
+    ColorDepth::Monochrome | ColorDepth::TrueColor => return colors.to_vec(),
+    ColorDepth::Basic16 => BASIC_COLORS.len(),
+    ColorDepth::Palette256 => 256,
+  };
+
+  match quantization {
+    Quantization::Nearest => colors
+      .iter()
+      .map(|color| color.to_nearest_displayable(color_depth))
+      .collect(),
+    Quantization::MedianCut => {
+      let groups = median_cut(colors, palette_size);
+      let mut quantized_colors = colors.to_vec();
+
+      for group in groups {
+        let group_color = average_color(group.iter().map(|&index| colors[index]))
+          .to_nearest_displayable(color_depth);
+
+        for index in group {
+          quantized_colors[index] = group_color;
+        }
+      }
+
+      quantized_colors
+    }
+  }
+}
+
+/// Splits the indices of the colors into at most the given amount of groups,
+/// repeatedly splitting the group with the widest range of a channel at the median of that channel.
+fn median_cut(colors: &[Color], group_count: usize) -> Vec<Vec<usize>> {
+  let mut groups = vec![(0..colors.len()).collect::<Vec<usize>>()];
+
+  while groups.len() < group_count {
+    let widest_group = groups
+      .iter()
+      .enumerate()
+      .map(|(group_index, group)| (group_index, widest_channel(colors, group)))
+      .filter(|(_, (_, range))| *range > 0)
+      .max_by_key(|(_, (_, range))| *range);
+
+    let Some((group_index, (channel, _))) = widest_group else {
+      break;
+    };
+
+    let mut group = groups.swap_remove(group_index);
+    group.sort_by_key(|&index| channel(colors[index]));
+    let upper_half = group.split_off(group.len() / 2);
+
+    groups.push(group);
+    groups.push(upper_half);
+  }
+
+  groups
+}
+
+/// Returns the channel with the widest range of values among the colors at the given indices, along with that range.
+fn widest_channel(colors: &[Color], group: &[usize]) -> (fn(Color) -> u8, u8) {
+  let channels: [fn(Color) -> u8; 3] = [|color| color.red, |color| color.green, |color| color.blue];
+
+  channels
+    .into_iter()
+    .map(|channel| {
+      let values = group.iter().map(|&index| channel(colors[index]));
+      let range = values.clone().max().unwrap_or(0) - values.min().unwrap_or(0);
+
+      (channel, range)
+    })
+    .max_by_key(|(_, range)| *range)
+    .unwrap_or((channels[0], 0))
+}
+
+/// Returns the average of the colors, or black if there are none.
+fn average_color(colors: impl Iterator<Item = Color>) -> Color {
+  let (count, red, green, blue) = colors.fold((0, 0, 0, 0), |(count, red, green, blue), color| {
+    (
+      count + 1,
+      red + color.red as usize,
+      green + color.green as usize,
+      blue + color.blue as usize,
+    )
+  });
+
+  if count == 0 {
+    return Color::default();
+  }
+
+  Color::new(
+    (red / count) as u8,
+    (green / count) as u8,
+    (blue / count) as u8,
+  )
+}
+
+/// Converts every color in the grid's style sequences down to the closest color supported by the color depth.
+///
+/// Style sequences that only contained colors are removed entirely when converting to [`Monochrome`](ColorDepth::Monochrome).
+///
+/// # Example
+/// ```
+/// use screen_printer::color::*;
+///
+/// let grid = "\x1B[1;38;2;255;0;0mab\x1B[0m";
+///
+/// assert_eq!(downconvert_colors(grid, ColorDepth::Palette256), "\x1B[1;38;5;196mab\x1B[0m");
+/// assert_eq!(downconvert_colors(grid, ColorDepth::Basic16), "\x1B[1;91mab\x1B[0m");
+/// assert_eq!(downconvert_colors(grid, ColorDepth::Monochrome), "\x1B[1mab\x1B[0m");
+/// ```
+pub fn downconvert_colors(grid: &str, color_depth: ColorDepth) -> String {
+  if color_depth == ColorDepth::TrueColor || !grid.contains(ESCAPE) {
+    return grid.to_string();
+  }
+
+  let mut converted_grid = String::with_capacity(grid.len());
+  let mut remaining_grid = grid;
+
+  while let Some(escape_index) = remaining_grid.find(ESCAPE) {
+    converted_grid.push_str(&remaining_grid[..escape_index]);
+    remaining_grid = &remaining_grid[escape_index..];
+
+    let sequence_length = escape_sequence_length(remaining_grid);
+    let sequence = &remaining_grid[..sequence_length];
+    remaining_grid = &remaining_grid[sequence_length..];
+
+    if is_style_sequence(sequence) {
+      let parameters = downconvert_parameters(&sequence[2..sequence.len() - 1], color_depth);
+
+      if !parameters.is_empty() || sequence.len() == 3 {
+        converted_grid.push_str(&format!("\x1B[{}m", parameters.join(";")));
+      }
+    } else {
+      converted_grid.push_str(sequence);
+    }
+  }
+
+  converted_grid.push_str(remaining_grid);
+
+  converted_grid
+}
+
+/// Returns the parameters of a style sequence with every color converted down to the color depth.
+fn downconvert_parameters(parameters: &str, color_depth: ColorDepth) -> Vec<String> {
+  let mut parameters = parameters.split([';', ':']);
+  let mut converted_parameters = Vec::new();
+
+  while let Some(parameter) = parameters.next() {
+    let code = parameter.parse::<u16>().unwrap_or(0);
+    // Foreground colors start at 30, and background colors at 40.
+    let color_offset = if code == 48 { 10 } else { 0 };
+
+    match code {
+      38 | 48 => {
+        let mut next_channel = || parameters.next().and_then(|value| value.parse::<u8>().ok());
+
+        let color = match next_channel() {
+          Some(5) => next_channel().map(PaletteOrColor::Palette),
+          Some(2) => match (next_channel(), next_channel(), next_channel()) {
+            (Some(red), Some(green), Some(blue)) => {
+              Some(PaletteOrColor::Color(Color::new(red, green, blue)))
+            }
+            _ => None,
+          },
+          _ => None,
+        };
+
+        let Some(color) = color else {
+          continue;
+        };
+
+        match (color_depth, color) {
+          (ColorDepth::Monochrome, _) => (),
+          (ColorDepth::Basic16, color) => {
+            let index = color.to_color().to_basic_index() as u16;
+
+            converted_parameters.push(basic_color_code(index, color_offset).to_string());
+          }
+          (_, PaletteOrColor::Palette(index)) => {
+            converted_parameters.push(format!("{code};5;{index}"));
+          }
+          (_, PaletteOrColor::Color(color)) => {
+            converted_parameters.push(format!("{code};5;{}", color.to_palette_index()));
+          }
+        }
+      }
+      30..=37 | 39 | 40..=47 | 49 | 90..=97 | 100..=107
+        if color_depth == ColorDepth::Monochrome => {}
+      _ => converted_parameters.push(parameter.to_string()),
+    }
+  }
+
+  converted_parameters
+}
+
+/// A color from a style sequence, which is either an index into the palette or a 24-bit color.
+#[derive(Debug, Clone, Copy)]
+enum PaletteOrColor {
+  Palette(u8),
+  Color(Color),
+}
+
+impl PaletteOrColor {
+  fn to_color(self) -> Color {
+    match self {
+      Self::Palette(index) => Color::from_palette_index(index),
+      Self::Color(color) => color,
+    }
+  }
+}
+
+/// Returns the style parameter for the basic color, where the offset is 0 for foreground colors, and 10 for background colors.
+fn basic_color_code(index: u16, offset: u16) -> u16 {
+  if index < 8 {
+    30 + offset + index
+  } else {
+    90 + offset + index - 8
+  }
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod detection_tests {
+  use super::*;
+
+  #[test]
+  fn environment_decides_color_depth() {
+    assert_eq!(
+      ColorDepth::from_environment(false, "truecolor", "xterm-256color"),
+      ColorDepth::TrueColor
+    );
+    assert_eq!(
+      ColorDepth::from_environment(false, "", "xterm-256color"),
+      ColorDepth::Palette256
+    );
+    assert_eq!(
+      ColorDepth::from_environment(false, "", "xterm"),
+      ColorDepth::Basic16
+    );
+    assert_eq!(
+      ColorDepth::from_environment(true, "truecolor", "xterm"),
+      ColorDepth::Monochrome
+    );
+  }
+}
+
+#[cfg(test)]
+mod conversion_tests {
+  use super::*;
+
+  #[test]
+  fn palette_index_round_trips() {
+    for index in 16..=255 {
+      assert_eq!(Color::from_palette_index(index).to_palette_index(), index);
+    }
+  }
+
+  #[test]
+  fn grays_use_gray_ramp() {
+    assert_eq!(Color::new(128, 128, 128).to_palette_index(), 244);
+  }
+
+  #[test]
+  fn palette_colors_convert_to_basic_colors() {
+    let grid = "\x1B[48;5;21;38;5;9ma";
+
+    assert_eq!(
+      downconvert_colors(grid, ColorDepth::Basic16),
+      "\x1B[44;91ma"
+    );
+  }
+
+  #[test]
+  fn color_only_sequences_are_removed_for_monochrome() {
+    let grid = "\x1B[31ma\x1B[0m\x1B[m";
+
+    assert_eq!(
+      downconvert_colors(grid, ColorDepth::Monochrome),
+      "a\x1B[0m\x1B[m"
+    );
+  }
+
+  #[test]
+  fn other_escape_sequences_are_kept() {
+    let grid = "\x1B]0;title\x07\x1B[2Ja";
+
+    assert_eq!(downconvert_colors(grid, ColorDepth::Basic16), grid);
+  }
+}
+
+#[cfg(test)]
+mod quantization_tests {
+  use super::*;
+
+  #[test]
+  fn nearest_uses_closest_palette_color() {
+    let colors = [Color::new(0, 0, 0), Color::new(100, 140, 170)];
+
+    assert_eq!(
+      quantize_colors(&colors, ColorDepth::Palette256, Quantization::Nearest),
+      [Color::new(0, 0, 0), Color::new(95, 135, 175)]
+    );
+  }
+
+  #[test]
+  fn true_color_is_unchanged() {
+    let colors = [Color::new(1, 2, 3)];
+
+    assert_eq!(
+      quantize_colors(&colors, ColorDepth::TrueColor, Quantization::MedianCut),
+      colors
+    );
+  }
+
+  #[test]
+  fn median_cut_splits_the_widest_channel() {
+    let colors: Vec<Color> = (0..4)
+      .map(|shade| Color::new(100 + shade, 0, 0))
+      .chain((0..4).map(|shade| Color::new(0, 0, 200 + shade)))
+      .collect();
+
+    let mut groups = median_cut(&colors, 2);
+    groups.sort();
+
+    assert_eq!(groups, [vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+  }
+
+  #[test]
+  fn median_cut_colors_are_displayable() {
+    let colors: Vec<Color> = (0..=255)
+      .map(|shade| Color::new(shade, 255 - shade, 128))
+      .collect();
+
+    let quantized_colors = quantize_colors(&colors, ColorDepth::Basic16, Quantization::MedianCut);
+
+    assert!(quantized_colors
+      .iter()
+      .all(|color| BASIC_COLORS.contains(color)));
+  }
+
+  #[test]
+  fn median_cut_stops_when_colors_can_not_be_split() {
+    let colors = [Color::new(5, 5, 5); 3];
+
+    assert_eq!(median_cut(&colors, 16).len(), 1);
+  }
+}
@@ -0,0 +1,60 @@
+//! Conversions between the positions used throughout the crate.
+//!
+//! Grid coordinates are the (x, y) cell within a grid, starting from (0, 0) at its top left,
+//! where x is the column within the grid.
+//! Terminal positions are the (x, y) position on the terminal, starting from (1, 1) at its top left,
+//! the same as cursor movements and mouse events.
+//! The origin of a grid is the terminal position of its top left cell.
+//!
+//! # Example
+//! ```
+//! use screen_printer::coordinates::*;
+//!
+//! // A grid 4 cells wide, printed with its top left at the top left of the terminal.
+//! let origin = (1, 1);
+//! let grid_coordinates = index_to_coordinates(5, 4);
+//!
+//! assert_eq!(grid_coordinates, (1, 1));
+//! assert_eq!(grid_to_terminal(grid_coordinates, origin), (2, 2));
+//! assert_eq!(terminal_to_grid((2, 2), origin), Some((1, 1)));
+//! ```
+
+mod tests;
+
+/// Returns the (x, y) grid coordinates of the given index into a grid of the given width, where the grid is read row by row.
+///
+/// # Panics
+///
+/// - The grid width is 0.
+pub fn index_to_coordinates(index: usize, grid_width: usize) -> (usize, usize) {
+  (index % grid_width, index / grid_width)
+}
+
+/// Returns the index into a grid of the given width of the given (x, y) grid coordinates, where the grid is read row by row.
+///
+/// This is the inverse of [`index_to_coordinates`](index_to_coordinates) for coordinates within the grid.
+pub fn coordinates_to_index((x, y): (usize, usize), grid_width: usize) -> usize {
+  y * grid_width + x
+}
+
+/// Returns the terminal position of the given (x, y) grid coordinates, for a grid with its top left at the given origin.
+pub fn grid_to_terminal(
+  (x, y): (usize, usize),
+  (origin_x, origin_y): (usize, usize),
+) -> (usize, usize) {
+  (origin_x + x, origin_y + y)
+}
+
+/// Returns the (x, y) grid coordinates at the given terminal position, for a grid with its top left at the given origin.
+///
+/// None is returned when the position is left of or above the grid.
+/// Whether it's right of or below the grid isn't checked, as that depends on the grid's dimensions.
+pub fn terminal_to_grid(
+  (terminal_x, terminal_y): (usize, usize),
+  (origin_x, origin_y): (usize, usize),
+) -> Option<(usize, usize)> {
+  Some((
+    terminal_x.checked_sub(origin_x)?,
+    terminal_y.checked_sub(origin_y)?,
+  ))
+}
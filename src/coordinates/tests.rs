@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod index_conversion_tests {
+  use super::*;
+
+  #[test]
+  fn indexes_wrap_onto_the_next_row() {
+    assert_eq!(index_to_coordinates(0, 3), (0, 0));
+    assert_eq!(index_to_coordinates(2, 3), (2, 0));
+    assert_eq!(index_to_coordinates(3, 3), (0, 1));
+  }
+
+  #[test]
+  fn coordinates_convert_back_to_their_index() {
+    for index in 0..12 {
+      assert_eq!(
+        coordinates_to_index(index_to_coordinates(index, 4), 4),
+        index
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod terminal_conversion_tests {
+  use super::*;
+
+  #[test]
+  fn top_left_cell_is_at_the_origin() {
+    assert_eq!(grid_to_terminal((0, 0), (1, 1)), (1, 1));
+    assert_eq!(grid_to_terminal((2, 1), (5, 3)), (7, 4));
+  }
+
+  #[test]
+  fn terminal_positions_convert_back_to_their_coordinates() {
+    assert_eq!(terminal_to_grid((7, 4), (5, 3)), Some((2, 1)));
+    assert_eq!(terminal_to_grid((5, 3), (5, 3)), Some((0, 0)));
+  }
+
+  #[test]
+  fn positions_before_the_origin_are_outside_the_grid() {
+    assert_eq!(terminal_to_grid((4, 3), (5, 3)), None);
+    assert_eq!(terminal_to_grid((5, 2), (5, 3)), None);
+  }
+}
@@ -0,0 +1,34 @@
+//! The grid measuring and diffing logic of the crate, without any printing or terminal probing.
+//!
+//! Everything here compiles under `no_std` with `alloc`, by building the crate without its default features:
+//!
+//! ```toml
+//! screen_printer = { version = "*", default-features = false }
+//! ```
+//!
+//! This makes it possible to find the difference between grids on devices without an operating system,
+//! such as a microcontroller driving a serial terminal, and print the [`ChangeRuns`](ChangeRun) with an
+//! [`EscapeEmitter`](EscapeEmitter) suited to that terminal.
+//! The diff follows the exact same rules as the [`Printer`](crate::printer::Printer) does with the `std` feature.
+//!
+//! # Example
+//! ```
+//! use screen_printer::core::*;
+//!
+//! let change_runs = ChangeRun::from_grids("abc\n123", "abc\n1x3");
+//! let printable_difference: String = change_runs
+//!   .iter()
+//!   .map(|change_run| change_run.to_printable_difference_with_emitter((1, 1), &AnsiEmitter))
+//!   .collect();
+//!
+//! assert_eq!(printable_difference, "\x1B[2;2Hx");
+//! ```
+
+pub use crate::ansi::{sanitize_escapes, strip_ansi, EscapeSanitization, ESCAPE_SYMBOL};
+pub use crate::canvas::Canvas;
+pub use crate::cells::{measure_grid, measure_row_width, CharacterNormalization, EmojiMode};
+pub use crate::change_run::{ChangeRun, DiffGranularity, DiffPolicy};
+pub use crate::coordinates::*;
+pub use crate::escape_emitter::{AnsiEmitter, EscapeEmitter};
+pub use crate::layout::{Constraint, Direction, Layout};
+pub use crate::printing_position::*;
@@ -0,0 +1,278 @@
+//! The algorithms used to find the difference between the previously printed grid and the next one.
+
+mod tests;
+
+use crate::ansi::{escape_sequence_length, is_style_sequence, ESCAPE};
+use crate::cells::{split_grid_into_cells, split_row_into_cells, EmojiMode};
+use crate::change_run::{displayed_grid, ChangeRun, DiffGranularity, DiffPolicy};
+use crate::style::apply_style_sequence;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+/// The settings of a printer that a [`DiffStrategy`](DiffStrategy) finds the difference between grids with.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct DiffSettings {
+  pub granularity: DiffGranularity,
+  pub emoji_mode: EmojiMode,
+  pub policy: DiffPolicy,
+}
+
+/// How the difference between the previously printed grid and the next one is found when
+/// [`dynamic printing`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+///
+/// Different workloads want different trade-offs. A dashboard where a few numbers change is best served by finding every changed cell,
+/// while an animation where most of the screen changes every frame spends less time by reprinting everything.
+///
+/// Set with [`replace_diff_strategy`](crate::printer::Printer::replace_diff_strategy), where [`CellRunDiff`](CellRunDiff) is the default.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// /// Reprints every row where the first character changed.
+/// #[derive(Debug)]
+/// struct FirstColumnDiff;
+///
+/// impl DiffStrategy for FirstColumnDiff {
+///   fn change_runs(&self, old_grid: &str, new_grid: &str, _settings: DiffSettings) -> Vec<ChangeRun> {
+///     let old_rows: Vec<&str> = old_grid.split('\n').collect();
+///
+///     new_grid
+///       .split('\n')
+///       .enumerate()
+///       .filter(|(y, row)| old_rows.get(*y).map(|old_row| old_row.chars().next()) != Some(row.chars().next()))
+///       .map(|(y, row)| ChangeRun::new((0, y), row))
+///       .collect()
+///   }
+///
+///   fn displayed_grid(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> String {
+///     let old_rows: Vec<&str> = old_grid.split('\n').collect();
+///     let changed_rows = self.change_runs(old_grid, new_grid, settings);
+///
+///     new_grid
+///       .split('\n')
+///       .enumerate()
+///       .map(|(y, row)| match changed_rows.iter().any(|change_run| change_run.start.1 == y) {
+///         true => row,
+///         false => old_rows[y],
+///       })
+///       .collect::<Vec<&str>>()
+///       .join("\n")
+///   }
+/// }
+///
+/// let mut printer = Printer::deterministic();
+/// printer.replace_diff_strategy(FirstColumnDiff);
+///
+/// printer.dynamic_print("abc\n123".to_string()).unwrap();
+/// printer.dynamic_print("xbc\n1x3".to_string()).unwrap();
+/// ```
+pub trait DiffStrategy: Debug + Send {
+  /// Returns the runs of text to print over the old grid for the terminal to display the new grid.
+  ///
+  /// Both grids are rectangular and have the same dimensions.
+  fn change_runs(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> Vec<ChangeRun>;
+
+  /// Returns the grid displayed on the terminal after printing the [`change runs`](DiffStrategy::change_runs) over the old grid,
+  /// which the next grid is compared against.
+  ///
+  /// This only differs from the new grid when the [`DiffPolicy`](crate::change_run::DiffPolicy) leaves cells that were considered
+  /// unchanged displaying their old style. Defaults to finding which cells match under the policy.
+  fn displayed_grid(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> String {
+    displayed_grid(
+      old_grid,
+      new_grid,
+      settings.granularity,
+      settings.emoji_mode,
+      settings.policy,
+    )
+  }
+}
+
+/// The default [`DiffStrategy`](DiffStrategy), which compares every cell and prints each run of changed cells,
+/// or every changed row with [`row granularity`](crate::change_run::DiffGranularity::Row).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct CellRunDiff;
+
+impl DiffStrategy for CellRunDiff {
+  fn change_runs(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> Vec<ChangeRun> {
+    ChangeRun::from_grids_with_settings(
+      old_grid,
+      new_grid,
+      settings.granularity,
+      settings.emoji_mode,
+      settings.policy,
+    )
+  }
+}
+
+/// A [`DiffStrategy`](DiffStrategy) that reprints every row containing a change in its entirety,
+/// regardless of the [`DiffGranularity`](crate::change_run::DiffGranularity).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct RowDiff;
+
+impl DiffStrategy for RowDiff {
+  fn change_runs(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> Vec<ChangeRun> {
+    CellRunDiff.change_runs(old_grid, new_grid, row_settings(settings))
+  }
+
+  fn displayed_grid(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> String {
+    CellRunDiff.displayed_grid(old_grid, new_grid, row_settings(settings))
+  }
+}
+
+/// A [`DiffStrategy`](DiffStrategy) that compares a hash of each row before comparing any of its cells,
+/// skipping rows that hash the same as the row they replace.
+///
+/// This avoids measuring the cells of unchanged rows, which is most of the work for grids where few rows change at a time.
+/// Rows whose text and starting style hash to the same value are assumed to be unchanged,
+/// so a hash collision, which is vanishingly unlikely, leaves the row unprinted until it changes again.
+///
+/// Since rows are compared by their exact text, any change to a row's styling makes it compared cell by cell,
+/// under the [`DiffPolicy`](crate::change_run::DiffPolicy).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct HashedRowDiff;
+
+impl DiffStrategy for HashedRowDiff {
+  fn change_runs(&self, old_grid: &str, new_grid: &str, settings: DiffSettings) -> Vec<ChangeRun> {
+    let mut old_rows = old_grid.split('\n');
+    let mut old_style: Rc<str> = Rc::from("");
+    let mut new_style: Rc<str> = Rc::from("");
+    let mut change_runs = Vec::new();
+
+    for (y, new_row) in new_grid.split('\n').enumerate() {
+      let old_row = old_rows.next().unwrap_or_default();
+
+      if row_hash(old_row, &old_style) == row_hash(new_row, &new_style) {
+        apply_row_styles(old_row, &mut old_style);
+        apply_row_styles(new_row, &mut new_style);
+
+        continue;
+      }
+
+      let old_cells = split_row_into_cells(old_row, settings.emoji_mode, &mut old_style);
+      let new_cells = split_row_into_cells(new_row, settings.emoji_mode, &mut new_style);
+
+      change_runs.extend(ChangeRun::from_rows_with_granularity(
+        &old_cells,
+        &new_cells,
+        y,
+        settings.granularity,
+        settings.policy,
+      ));
+    }
+
+    change_runs
+  }
+}
+
+/// A [`DiffStrategy`](DiffStrategy) that reprints every row of the grid without comparing anything,
+/// for grids where nearly everything changes every frame.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct FullDiff;
+
+impl DiffStrategy for FullDiff {
+  fn change_runs(&self, _old_grid: &str, new_grid: &str, settings: DiffSettings) -> Vec<ChangeRun> {
+    split_grid_into_cells(new_grid, settings.emoji_mode)
+      .iter()
+      .enumerate()
+      .flat_map(|(y, new_cells)| {
+        ChangeRun::from_rows_with_granularity(
+          &[],
+          new_cells,
+          y,
+          DiffGranularity::Row,
+          settings.policy,
+        )
+      })
+      .collect()
+  }
+
+  fn displayed_grid(&self, _old_grid: &str, new_grid: &str, _settings: DiffSettings) -> String {
+    new_grid.to_string()
+  }
+}
+
+/// Returns the change runs between the grids found by comparing only the touched rows cell by cell,
+/// or None if the grids don't have the same amount of rows, or any untouched row differs between them.
+pub(crate) fn touched_row_change_runs(
+  old_grid: &str,
+  new_grid: &str,
+  touched_rows: &[bool],
+  settings: DiffSettings,
+) -> Option<Vec<ChangeRun>> {
+  if old_grid.split('\n').count() != touched_rows.len()
+    || new_grid.split('\n').count() != touched_rows.len()
+  {
+    return None;
+  }
+
+  let mut old_style: Rc<str> = Rc::from("");
+  let mut new_style: Rc<str> = Rc::from("");
+  let mut change_runs = Vec::new();
+
+  for (y, ((old_row, new_row), &touched)) in old_grid
+    .split('\n')
+    .zip(new_grid.split('\n'))
+    .zip(touched_rows)
+    .enumerate()
+  {
+    if !touched {
+      if old_row != new_row {
+        return None;
+      }
+
+      apply_row_styles(old_row, &mut old_style);
+      apply_row_styles(new_row, &mut new_style);
+
+      continue;
+    }
+
+    let old_cells = split_row_into_cells(old_row, settings.emoji_mode, &mut old_style);
+    let new_cells = split_row_into_cells(new_row, settings.emoji_mode, &mut new_style);
+
+    change_runs.extend(ChangeRun::from_rows_with_granularity(
+      &old_cells,
+      &new_cells,
+      y,
+      settings.granularity,
+      settings.policy,
+    ));
+  }
+
+  Some(change_runs)
+}
+
+/// Returns the settings with [`row granularity`](crate::change_run::DiffGranularity::Row).
+fn row_settings(settings: DiffSettings) -> DiffSettings {
+  DiffSettings {
+    granularity: DiffGranularity::Row,
+    ..settings
+  }
+}
+
+/// Returns the hash of the row's text along with the style it starts with.
+fn row_hash(row: &str, style: &str) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  (row, style).hash(&mut hasher);
+
+  hasher.finish()
+}
+
+/// Applies every style sequence in the row to the given style, in the order they appear.
+fn apply_row_styles(row: &str, style: &mut Rc<str>) {
+  let mut index = 0;
+
+  while let Some(escape_index) = row[index..].find(ESCAPE) {
+    let start = index + escape_index;
+    let sequence = &row[start..start + escape_sequence_length(&row[start..])];
+
+    if is_style_sequence(sequence) {
+      apply_style_sequence(style, sequence);
+    }
+
+    index = start + sequence.len().max(1);
+  }
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod diff_strategy_tests {
+  use super::*;
+  use crate::dynamic_printer::DynamicPrinter;
+  use crate::printer::Printer;
+
+  const OLD_GRID: &str = "abcd\n\x1B[31mefgh\n1234\x1B[0m";
+  const NEW_GRID: &str = "abxd\n\x1B[31mefgh\n12x4\x1B[0m";
+
+  #[test]
+  fn cell_runs_are_the_default_diff() {
+    assert_eq!(
+      CellRunDiff.change_runs(OLD_GRID, NEW_GRID, DiffSettings::default()),
+      ChangeRun::from_grids(OLD_GRID, NEW_GRID)
+    );
+  }
+
+  #[test]
+  fn row_diffs_reprint_changed_rows() {
+    assert_eq!(
+      RowDiff.change_runs(OLD_GRID, NEW_GRID, DiffSettings::default()),
+      ChangeRun::from_grids_with_granularity(OLD_GRID, NEW_GRID, DiffGranularity::Row)
+    );
+  }
+
+  #[test]
+  fn hashed_row_diffs_match_cell_runs() {
+    let restyled_grid = "abcd\n\x1B[32mefgh\n1234\x1B[0m";
+
+    for (old_grid, new_grid) in [
+      (OLD_GRID, NEW_GRID),
+      (OLD_GRID, restyled_grid),
+      (OLD_GRID, OLD_GRID),
+    ] {
+      assert_eq!(
+        HashedRowDiff.change_runs(old_grid, new_grid, DiffSettings::default()),
+        ChangeRun::from_grids(old_grid, new_grid)
+      );
+    }
+  }
+
+  #[test]
+  fn full_diffs_reprint_every_row() {
+    let change_runs = FullDiff.change_runs(OLD_GRID, OLD_GRID, DiffSettings::default());
+
+    assert_eq!(change_runs.len(), 3);
+    assert_eq!(
+      FullDiff.displayed_grid(OLD_GRID, NEW_GRID, DiffSettings::default()),
+      NEW_GRID
+    );
+  }
+
+  #[test]
+  fn printers_use_their_diff_strategy() {
+    let mut printer = Printer::on_virtual_terminal((4, 3));
+    printer.replace_diff_strategy(RowDiff);
+
+    printer.dynamic_print(OLD_GRID.to_string()).unwrap();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+    printer.dynamic_print(NEW_GRID.to_string()).unwrap();
+
+    let update =
+      String::from_utf8_lossy(&printer.get_capture().unwrap().bytes()[printed_bytes..]).to_string();
+
+    assert!(update.contains("abxd"), "{update:?}");
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "abxd\nefgh\n12x4"
+    );
+  }
+
+  #[test]
+  fn streaming_diffs_use_cell_runs() {
+    let mut printer = Printer::on_virtual_terminal((4, 3));
+    printer.replace_diff_strategy(RowDiff);
+    printer.set_streaming_diff(true);
+
+    printer.dynamic_print(OLD_GRID.to_string()).unwrap();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+    printer.dynamic_print(NEW_GRID.to_string()).unwrap();
+
+    let update =
+      String::from_utf8_lossy(&printer.get_capture().unwrap().bytes()[printed_bytes..]).to_string();
+
+    assert!(!update.contains("abxd"), "{update:?}");
+    assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 2);
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "abxd\nefgh\n12x4"
+    );
+  }
+}
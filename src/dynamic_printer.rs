@@ -1,5 +1,21 @@
+use crate::ansi::ESCAPE;
+use crate::bidi::{isolate_text, reorder_grid};
+use crate::caret::style_caret_cell;
+use crate::cells::{
+  pad_rows, replace_emoji_sequences, row_width, split_grid_into_cells, split_row_into_cells, Cell,
+};
+use crate::escape_emitter::{translate_styles, EscapeEmitter};
+use crate::frame_stats::{next_random_number, Phase, PhaseTimer};
 use crate::printer::*;
-use std::{io, io::Write};
+use crate::scaling::{fit_dimensions, scale_grid};
+use crate::scrollback::anchored_grid;
+use crate::style::StyledTextBuilder;
+use std::cell::OnceCell;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// The widest gap of unchanged cells between two changes on a row that's reprinted rather than moved over.
+const MAX_OVERWRITTEN_GAP: usize = 3;
 
 mod tests;
 
@@ -8,37 +24,48 @@ pub trait DynamicPrinter {
   ///
   /// When printing a new grid to the screen, it'll compare every character from the previous one, and only print the characters that have changed.
   ///
+  /// If [`line ending normalization`](crate::printer::Printer::set_line_ending_normalization) is enabled,
+  /// the grid is [`normalized`](crate::printer::Printer::normalize_line_endings) before anything else.
+  /// Following that, the characters in the grid are [`normalized`](crate::printer::Printer::normalize_grid)
+  /// based on the printer's [`CharacterNormalization`](crate::cells::CharacterNormalization).
+  /// Colors are then [`converted down`](crate::color::downconvert_colors) to the printer's [`color depth`](crate::printer::Printer::get_color_depth).
+  /// If [`strict character validation`](crate::printer::Printer::set_strict_character_validation) is enabled,
+  /// the grid is then [`checked`](crate::printer::Printer::validate_characters) for control characters.
+  ///
   /// # Errors
   ///
   /// - The given grid wasn't rectangular in shape.
+  /// - The given grid contains a control character while strict character validation is enabled.
   /// - The string for the grid is empty.
   /// - The given grid is larger than the current dimensions of the terminal.
+  /// - Writing to the terminal failed.
   ///
   /// # Example
-  /// ```rust,no_run
+  /// ```rust
   /// use screen_printer::printer::*;
   ///
-  /// const WIDTH: usize = 3;
-  /// const HEIGHT: usize = 3;
-  ///
   /// fn main() {
-  ///   print!("\u{1b}[2J"); // Clear all text on the terminal
+  ///   // A deterministic printer prints to an 80x24 virtual terminal rather than stdout, so this runs the same everywhere.
+  ///   // Use `Printer::new()` to print to the terminal instead.
   ///   // The default printing position is the bottom left of the terminal
-  ///   let mut printer = Printer::new();
+  ///   let mut printer = Printer::deterministic();
   ///
   ///   // Create the first grid to be printed.
   ///   let grid_1 = "abc\n123\nxyz".to_string();
   ///   // print the first grid.
   ///   printer.dynamic_print(grid_1).unwrap();
   ///
-  ///   // Wait before printing the second grid.
-  ///   std::thread::sleep(std::time::Duration::from_millis(500));
-  ///
   ///   // Create the second grid to be printed.
   ///   let grid_2 = "abc\n789\nxyz".to_string();
   ///   // Print the second grid.
-  ///   // This will only end up printing the difference between the two grids/
+  ///   // This will only end up printing the difference between the two grids.
   ///   printer.dynamic_print(grid_2).unwrap();
+  ///
+  ///   let screen = printer.virtual_screen().unwrap().plain_screen();
+  ///   let bottom_rows: Vec<&str> = screen.lines().skip(21).map(str::trim_end).collect();
+  ///
+  ///   assert_eq!(bottom_rows, vec!["abc", "789", "xyz"]);
+  ///   assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 3);
   /// }
   /// ```
   ///
@@ -67,62 +94,62 @@ pub trait DynamicPrinter {
   ///
   /// - Grid dimensions weren't defined.
   /// - Origin wasn't defined.
+  /// - Writing to the terminal failed.
   fn clear_grid(&mut self) -> Result<(), PrintingError>;
+
+  /// Returns every [`ChangeRun`](crate::change_run::ChangeRun) between the previously printed grid and the given one.
+  ///
+  /// This is the difference the [`dynamic_print`](DynamicPrinter::dynamic_print) method would print
+  /// if the grid were to be printed in the same position as the previous one.
+  fn get_change_runs(&self, new_grid: &str) -> Vec<ChangeRun>;
 }
 
 impl DynamicPrinter for Printer {
   fn dynamic_print(&mut self, new_grid: String) -> Result<(), PrintingError> {
-    let terminal_dimensions = Printer::get_terminal_dimensions()?;
-    let new_grid_dimensions = Self::get_rectangular_dimensions(&new_grid)?;
+    if self.get_remaining_debounce_time().is_some() {
+      self.debounced_grid = Some(new_grid);
 
-    if new_grid_dimensions.0 > terminal_dimensions.0
-      || new_grid_dimensions.1 > terminal_dimensions.1
-    {
-      return Err(PrintingError::GridLargerThanTerminal);
+      return Ok(());
     }
 
-    // Check if the dimensions of the grid have changed
-    if let Ok((old_grid_width, old_grid_height)) = self.get_grid_dimensions() {
-      if old_grid_width != new_grid_dimensions.0 || old_grid_height != new_grid_dimensions.1 {
-        self.printing_position_changed_since_last_print = true;
-      }
-    }
+    self.debounced_grid = None;
+    let mut submitted_grid = None;
 
-    // Check if the dimensions of the terminal have changed
-    if let Ok((old_terminal_width, old_terminal_height)) =
-      self.get_terminal_dimensions_from_previous_print()
-    {
-      if old_terminal_width != terminal_dimensions.0 || old_terminal_height != terminal_dimensions.1
-      {
-        self.printing_position_changed_since_last_print = true;
+    if self.get_idle_suppression() {
+      let terminal_dimensions = self.get_printing_area(self.get_current_terminal_dimensions()?);
+
+      if self.should_suppress_idle_grid(&new_grid, terminal_dimensions) {
+        self.idle = true;
+        let (grid_width, grid_height) = self.get_grid_dimensions()?;
+        self.update_frame_stats(FrameStats {
+          changed_cells: 0,
+          total_cells: grid_width * grid_height,
+          full_reprint: false,
+          budget_degradation: None,
+          resize_behavior: None,
+          #[cfg(feature = "profiling")]
+          phase_timings: PhaseTimings::default(),
+        });
+
+        return self.run_watchdog();
       }
-    }
 
-    if !self.previous_grid.is_empty() && !self.printing_position_changed_since_last_print {
-      let new_origin = self.get_new_origin(new_grid_dimensions, terminal_dimensions);
-      self.update_origin(new_origin);
+      submitted_grid = Some(new_grid.clone());
+    }
 
-      let printable_difference = self.get_printable_difference(&new_grid)?;
+    let timer = PhaseTimer::start();
+    let new_grid = self.prepare_grid(new_grid)?;
+    self.record_phase(Phase::Validation, timer);
 
-      print!("{}", printable_difference);
-    } else if self.printing_position_changed_since_last_print {
-      self.replace_currently_printed_grid(
-        &new_grid,
-        Some(new_grid_dimensions),
-        terminal_dimensions,
-      )?;
+    if self.uses_cursor_addressing() {
+      self.print_prepared_grid(new_grid, None)?;
     } else {
-      let new_origin = self.get_new_origin(new_grid_dimensions, terminal_dimensions);
-      self.update_origin(new_origin);
-
-      print_grid_freestanding(&new_grid, new_origin)?;
+      self.print_without_cursor_addressing(new_grid)?;
     }
 
-    let _ = io::stdout().flush();
-    self.previous_grid = new_grid;
-    self.update_dimensions(new_grid_dimensions);
-    self.update_terminal_dimensions_from_previous_print(terminal_dimensions);
-    self.printing_position_changed_since_last_print = false;
+    if let Some(submitted_grid) = submitted_grid {
+      self.last_submitted_grid = submitted_grid;
+    }
 
     Ok(())
   }
@@ -130,34 +157,117 @@ impl DynamicPrinter for Printer {
   fn clear_grid(&mut self) -> Result<(), PrintingError> {
     let (grid_width, grid_height) = self.get_grid_dimensions()?;
 
-    Self::clear_space_on_terminal((grid_width, grid_height), self.get_origin_position()?)?;
+    self.clear_space_on_terminal((grid_width, grid_height), self.get_origin_position()?)?;
+    self.flush_output()?;
 
     self.previous_grid = Self::create_grid_from_single_character(' ', grid_width, grid_height);
+    self.last_submitted_grid.clear();
+    self.debounced_grid = None;
+    self.redraw_grid.clear();
 
     Ok(())
   }
+
+  fn get_change_runs(&self, new_grid: &str) -> Vec<ChangeRun> {
+    self
+      .get_diff_strategy()
+      .change_runs(&self.previous_grid, new_grid, self.get_diff_settings())
+  }
 }
 
-trait DynamicPrinterMethods {
+pub(crate) trait DynamicPrinterMethods {
+  /// Applies every setting of the printer that changes the contents of grids, such as normalization and the caret.
+  ///
+  /// # Errors
+  ///
+  /// - The grid contains control characters while strict character validation is on.
+  fn prepare_grid(&self, grid: String) -> Result<String, PrintingError>;
+
+  /// Prints the grid after it was [`prepared`](DynamicPrinterMethods::prepare_grid) in its entirety, without any escape sequences,
+  /// for terminals that can't move the cursor.
+  ///
+  /// Grids of a single row are printed over the previous one with a carriage return,
+  /// while taller grids are printed below the previous one, followed by a newline.
+  /// While [`logging frames`](crate::frame_log::FrameLog), every grid is printed below the previous one in the log's format instead,
+  /// and in [`accessibility mode`](crate::accessibility::Accessibility) only the rows that changed are printed below the previous ones.
+  /// Grids the same as the previous one aren't printed again.
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular.
+  /// - The grid is larger than the terminal or [`BoundingBox`](crate::printing_position::BoundingBox),
+  ///   when the terminal's dimensions can be obtained and frames aren't being logged.
+  /// - Writing to the terminal failed.
+  fn print_without_cursor_addressing(&mut self, new_grid: String) -> Result<(), PrintingError>;
+
+  /// Returns an error if a grid of the given dimensions doesn't fit within the terminal or the [`BoundingBox`](crate::printing_position::BoundingBox).
+  ///
+  /// # Errors
+  ///
+  /// - The grid was larger than the terminal.
+  /// - The grid was larger than the bounding box.
+  fn validate_grid_dimensions(
+    &self,
+    grid_dimensions: (usize, usize),
+    terminal_dimensions: (usize, usize),
+  ) -> Result<(), PrintingError>;
+
+  /// Prints the grid after it was [`prepared`](DynamicPrinterMethods::prepare_grid), reprinting only what changed where possible.
+  ///
+  /// The change runs from the previous grid to this one are found unless they're given.
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular, or was larger than the terminal.
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  fn print_prepared_grid(
+    &mut self,
+    new_grid: String,
+    precomputed_change_runs: Option<Vec<ChangeRun>>,
+  ) -> Result<(), PrintingError>;
+
   /// Gets a list of escape codes for cursor movement followed by
-  /// the difference in pixels between the old and new grids.
+  /// the text of each change run between the old and new grids.
+  ///
+  /// The displayed grid is what's on the terminal before the runs are printed,
+  /// which unchanged cells between runs are reprinted from.
   ///
   /// # Errors
   ///
   /// - When origin hasn't been set before calling this method.
-  /// - When the old grid's dimensions haven't been set before calling this method.
-  fn get_printable_difference(&self, grid: &str) -> Result<String, PrintingError>;
+  fn get_printable_change_runs(
+    &self,
+    change_runs: Vec<ChangeRun>,
+    displayed_grid: &str,
+  ) -> Result<String, PrintingError>;
 
-  /// Moves the cursor to the assigned origin.
+  /// Finds the difference between the old and new grid one row at a time, writing the changes of each row to the output
+  /// before moving on to the next, and returns the amount of cells that changed.
+  ///
+  /// This is the same as printing the [`change runs`](DynamicPrinterMethods::get_printable_change_runs)
+  /// found by the [`CellRunDiff`](crate::diff_strategy::CellRunDiff), without holding onto more than a row of either grid at a time.
   ///
   /// # Errors
   ///
-  /// - When origin isn't set.
-  fn move_to_origin(&self) -> Result<(), PrintingError>;
+  /// - When origin hasn't been set before calling this method.
+  /// - Writing to the output failed.
+  fn stream_change_runs(&mut self, old_grid: &str, new_grid: &str) -> Result<usize, PrintingError>;
+
+  /// Returns the sequence that scrolls the previous grid up to line up with the new grid, along with the grid displayed after scrolling,
+  /// if the new grid is the previous grid shifted up by some amount of rows.
+  ///
+  /// Grids are only scrolled when they span the width of the terminal, since scrolling moves entire rows of the terminal.
+  fn get_scrolled_grid(
+    &self,
+    new_grid: &str,
+    new_grid_dimensions: (usize, usize),
+    terminal_dimensions: (usize, usize),
+  ) -> Option<(String, String)>;
 
   /// Returns a new origin based on a few parameters:
   /// The dimensions of the new grid,
-  /// The dimensions of the terminal and;
+  /// The dimensions of the [`printing area`](crate::printer::Printer::get_printing_area) and;
   /// The current printing settings, or where the terminal cursor is if there are none.
   fn get_new_origin(
     &self,
@@ -182,62 +292,578 @@ trait DynamicPrinterMethods {
     terminal_dimensions: (usize, usize),
   ) -> Result<(), PrintingError>;
 
+  /// Returns the grid printed in its entirety at the given origin, based on the [`UpdateStrategy`](crate::scrollback::UpdateStrategy).
+  ///
+  /// When preserving scrollback, the grid is printed from the cursor or the previous grid's top left, and the origin is unused.
+  fn get_printable_full_grid(&mut self, grid: &str, origin: (usize, usize)) -> String;
+
+  /// Prints the fill character over the entire [`BoundingBox`](crate::printing_position::BoundingBox), if it's filled.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
+  fn fill_bounding_box(&mut self) -> Result<(), PrintingError>;
+
+  /// Prints whitespace over the given area of the terminal.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
   fn clear_space_on_terminal(
+    &mut self,
     clearing_dimensions: (usize, usize),
     top_left_position: (usize, usize),
   ) -> Result<(), PrintingError>;
+
+  /// Returns the cheapest way to print the grid at the given origin within the byte budget, when printing its difference doesn't fit,
+  /// along with the text to print and the amount of cells it prints.
+  ///
+  /// # Errors
+  ///
+  /// - Origin wasn't set.
+  fn degrade_to_byte_budget(
+    &self,
+    new_grid: &str,
+    origin: (usize, usize),
+    byte_budget: usize,
+  ) -> Result<(BudgetDegradation, String, usize), PrintingError>;
+
+  /// Reprints a random row of the previously printed grid, or the entire grid, if the [`Watchdog`](crate::frame_stats::Watchdog) is due to.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
+  fn run_watchdog(&mut self) -> Result<(), PrintingError>;
 }
 
 impl DynamicPrinterMethods for Printer {
-  fn get_printable_difference(&self, grid: &str) -> Result<String, PrintingError> {
-    let old_grid = self.previous_grid.replace('\n', "");
-    let new_grid = grid.replace('\n', "");
-    let grid_size = new_grid.chars().count();
-
-    let (origin_x, origin_y) = self.get_origin_position()?;
-    let (grid_width, _) = self.get_grid_dimensions()?;
-
-    let mut last_appended_pixel_index = 1000000;
-    let mut latest_pixel_index = 1000000;
-    let mut printable_difference = String::new();
-
-    old_grid.chars().zip(new_grid.chars()).enumerate().for_each(
-      |(pixel_index, (old_pixel, new_pixel))| {
-        if new_pixel == old_pixel {
-          return;
+  fn prepare_grid(&self, mut new_grid: String) -> Result<String, PrintingError> {
+    let escape_sanitization = self.get_escape_sanitization();
+
+    if escape_sanitization != EscapeSanitization::Off {
+      new_grid = sanitize_escapes(&new_grid, escape_sanitization);
+    }
+
+    if self.get_line_ending_normalization() {
+      Self::normalize_line_endings(&mut new_grid);
+    }
+
+    Self::normalize_grid(&mut new_grid, self.get_character_normalization());
+
+    let color_depth = self.get_color_depth();
+
+    if color_depth != ColorDepth::TrueColor {
+      new_grid = downconvert_colors(&new_grid, color_depth);
+    }
+
+    if self.get_strict_character_validation() {
+      validate_characters_with_emoji_mode(&new_grid, self.get_emoji_mode())?;
+    }
+
+    if let EmojiMode::Placeholder(placeholder) = self.get_emoji_mode() {
+      new_grid = replace_emoji_sequences(&new_grid, placeholder);
+    }
+
+    if self.get_bidi_mode() == BidiMode::Reorder {
+      new_grid = reorder_grid(&new_grid);
+    }
+
+    if self.get_row_padding() {
+      new_grid = pad_rows(&new_grid, self.get_emoji_mode());
+    }
+
+    if let Some(caret) = self.get_caret() {
+      new_grid = style_caret_cell(&new_grid, caret, self.get_emoji_mode());
+    }
+
+    Ok(new_grid)
+  }
+
+  fn print_without_cursor_addressing(&mut self, new_grid: String) -> Result<(), PrintingError> {
+    let new_grid = strip_ansi(&new_grid);
+    let new_grid_dimensions = self.measure_rectangular_dimensions(&new_grid)?;
+
+    // Output that isn't a terminal, such as a pipe, has no dimensions to fit within.
+    if self.get_frame_log().is_none() {
+      if let Ok(terminal_dimensions) = self.get_current_terminal_dimensions() {
+        self.validate_grid_dimensions(new_grid_dimensions, terminal_dimensions)?;
+      }
+    }
+
+    let total_cells = new_grid_dimensions.0 * new_grid_dimensions.1;
+    let unchanged = new_grid == self.previous_grid;
+
+    if !unchanged {
+      let printable_frame = match (self.log_frame(&new_grid), self.get_accessibility()) {
+        (Some(logged_frame), _) => logged_frame,
+        (None, Some(accessibility)) => {
+          accessibility.format_changed_rows(&new_grid, &self.previous_grid)
+        }
+        (None, None) => unaddressed_frame(&new_grid, &self.previous_grid, self.get_emoji_mode()),
+      };
+
+      self.write_output(&printable_frame)?;
+      self.flush_output()?;
+    }
+
+    if let Some(recording) = &mut self.recording {
+      recording.push_frame(&new_grid);
+    }
+
+    self.update_frame_stats(FrameStats {
+      changed_cells: if unchanged { 0 } else { total_cells },
+      total_cells,
+      full_reprint: !unchanged,
+      budget_degradation: None,
+      resize_behavior: None,
+      #[cfg(feature = "profiling")]
+      phase_timings: PhaseTimings::default(),
+    });
+
+    let replaced_grid = std::mem::replace(&mut self.previous_grid, new_grid);
+    self.recycle_grid(replaced_grid);
+    self.update_last_print_time();
+    self.idle = false;
+    self.update_dimensions(new_grid_dimensions);
+
+    Ok(())
+  }
+
+  fn validate_grid_dimensions(
+    &self,
+    grid_dimensions: (usize, usize),
+    terminal_dimensions: (usize, usize),
+  ) -> Result<(), PrintingError> {
+    if grid_dimensions.0 > terminal_dimensions.0 || grid_dimensions.1 > terminal_dimensions.1 {
+      return Err(PrintingError::GridLargerThanTerminal(
+        DimensionsErrorData::new(grid_dimensions, terminal_dimensions),
+      ));
+    }
+
+    if let Some(BoundingBox { dimensions, .. }) = self.get_bounding_box() {
+      if grid_dimensions.0 > dimensions.0 || grid_dimensions.1 > dimensions.1 {
+        return Err(PrintingError::GridLargerThanBoundingBox(
+          BoundingBoxErrorData::new(grid_dimensions, dimensions),
+        ));
+      }
+    }
+
+    Ok(())
+  }
+
+  fn print_prepared_grid(
+    &mut self,
+    mut new_grid: String,
+    mut precomputed_change_runs: Option<Vec<ChangeRun>>,
+  ) -> Result<(), PrintingError> {
+    let terminal_dimensions = self.get_printing_area(self.get_current_terminal_dimensions()?);
+
+    if self.get_resize_redraw() {
+      self.redraw_grid.clone_from(&new_grid);
+    }
+
+    let timer = PhaseTimer::start();
+    let mut new_grid_dimensions = self.measure_rectangular_dimensions(&new_grid)?;
+    self.record_phase(Phase::Validation, timer);
+    let (_, area_dimensions) = self.get_placement_area(terminal_dimensions);
+
+    if self.get_aspect_ratio_fit() {
+      let fitted_dimensions = fit_dimensions(new_grid_dimensions, area_dimensions);
+
+      if fitted_dimensions != new_grid_dimensions {
+        new_grid = scale_grid(&new_grid, fitted_dimensions, self.get_emoji_mode());
+        new_grid_dimensions = fitted_dimensions;
+        // Any precomputed runs were for the unscaled grid.
+        precomputed_change_runs = None;
+      }
+    }
+
+    let overflow_policy = self.get_overflow_policy();
+
+    if let OverflowPolicy::Paginate { .. } = overflow_policy {
+      self.page_count = overflow_policy.page_count(new_grid_dimensions, area_dimensions);
+      self.page = self.page.min(self.page_count - 1);
+      self.paged_grid = new_grid.clone();
+    }
+
+    if let Some((fitted_grid, fitted_dimensions)) = overflow_policy.fit_grid(
+      &new_grid,
+      new_grid_dimensions,
+      area_dimensions,
+      self.page,
+      self.get_emoji_mode(),
+    ) {
+      new_grid = fitted_grid;
+      new_grid_dimensions = fitted_dimensions;
+      precomputed_change_runs = None;
+    }
+
+    if let Some(overlay) = self.get_overlay() {
+      let sanitized_overlay;
+      let overlay = match self.get_escape_sanitization() {
+        EscapeSanitization::Off => overlay,
+        escape_sanitization => {
+          sanitized_overlay = Overlay {
+            text: sanitize_escapes(&overlay.text, escape_sanitization),
+            ..overlay.clone()
+          };
+
+          &sanitized_overlay
         }
+      };
+
+      new_grid = overlay.composite(
+        &new_grid,
+        new_grid_dimensions,
+        (self.get_x_center_rounding(), self.get_y_center_rounding()),
+        self.get_color_depth(),
+        self.get_emoji_mode(),
+      );
+      // Any precomputed runs were for the grid without the overlay.
+      precomputed_change_runs = None;
+    }
+
+    self.validate_grid_dimensions(new_grid_dimensions, terminal_dimensions)?;
+
+    // Check if the dimensions of the grid have changed
+    if let Ok((old_grid_width, old_grid_height)) = self.get_grid_dimensions() {
+      if old_grid_width != new_grid_dimensions.0 || old_grid_height != new_grid_dimensions.1 {
+        self.printing_position_changed_since_last_print = true;
+      }
+    }
+
+    // Check if the dimensions of the terminal have changed
+    let mut resize_behavior = None;
 
-        if pixel_index != 0
-          && (last_appended_pixel_index == pixel_index - 1 || latest_pixel_index == pixel_index - 1)
-          && (pixel_index % grid_width != 0 || pixel_index == grid_size - 1)
-        {
-          printable_difference.push(new_pixel);
+    if let Ok((old_terminal_width, old_terminal_height)) =
+      self.get_terminal_dimensions_from_previous_print()
+    {
+      if old_terminal_width != terminal_dimensions.0 || old_terminal_height != terminal_dimensions.1
+      {
+        let keeps_origin = self.get_resize_behavior() == ResizeBehavior::KeepOrigin
+          && !self.printing_position_changed_since_last_print
+          && self
+            .get_origin_position()
+            .is_ok_and(|(origin_x, origin_y)| {
+              origin_x + new_grid_dimensions.0 <= terminal_dimensions.0 + 1
+                && origin_y + new_grid_dimensions.1
+                  <= self.get_printing_area_top_row() + terminal_dimensions.1
+            });
 
-          last_appended_pixel_index = pixel_index;
+        if keeps_origin {
+          self.kept_origin = self.get_origin_position().ok();
+          resize_behavior = Some(ResizeBehavior::KeepOrigin);
         } else {
-          let mut index_as_coords = pixel_index.index_as_coordinates(&grid_width);
-          index_as_coords.0 += origin_x;
-          index_as_coords.1 += origin_y;
+          self.printing_position_changed_since_last_print = true;
+          resize_behavior = Some(ResizeBehavior::Recenter);
+        }
+      }
+    }
+
+    if self.printing_position_changed_since_last_print {
+      self.kept_origin = None;
+    }
+
+    let terminal_cursor_caret = self
+      .get_caret()
+      .filter(|caret| caret.style == CaretStyle::TerminalCursor);
+
+    if terminal_cursor_caret.is_some() {
+      let hide_cursor = self.get_escape_emitter().hide_cursor();
+      self.write_output(&hide_cursor)?;
+    }
 
-          latest_pixel_index = pixel_index;
+    let total_cells = new_grid_dimensions.0 * new_grid_dimensions.1;
+    let mut changed_cells = total_cells;
+    let can_diff =
+      !self.previous_grid.is_empty() && !self.printing_position_changed_since_last_print;
+    let full_refresh_due = can_diff && self.is_full_refresh_due();
+    let mut full_reprint = !can_diff || full_refresh_due;
+    let mut budget_degradation = None;
 
-          printable_difference.push_str(&format!(
-            "\x1B[{};{}H{}",
-            index_as_coords.1, index_as_coords.0, new_pixel
-          ));
+    if full_refresh_due {
+      let new_origin = self.get_new_origin(new_grid_dimensions, terminal_dimensions);
+      self.update_origin(new_origin);
+
+      // The grid is the same size in the same place, so it's printed over itself without clearing to avoid flickering.
+      let timer = PhaseTimer::start();
+      let emitter = self.get_escape_emitter();
+      let synchronized_updates = self.capabilities().synchronized_updates;
+      let mut printable_grid =
+        get_printable_grid(&new_grid, new_origin, self.get_bidi_mode(), emitter);
+
+      if synchronized_updates {
+        printable_grid = format!(
+          "{}{printable_grid}{}",
+          emitter.begin_synchronized_update(),
+          emitter.end_synchronized_update()
+        );
+      }
+
+      self.record_phase(Phase::EscapeGeneration, timer);
+      self.write_output(&printable_grid)?;
+    } else if !full_reprint && self.get_streaming_diff() {
+      let new_origin = self.get_new_origin(new_grid_dimensions, terminal_dimensions);
+      self.update_origin(new_origin);
+
+      let timer = PhaseTimer::start();
+      let previous_grid = std::mem::take(&mut self.previous_grid);
+      let streamed_cells = self.stream_change_runs(&previous_grid, &new_grid);
+      self.previous_grid = previous_grid;
+      changed_cells = streamed_cells?;
+      self.record_phase(Phase::Diffing, timer);
+
+      // Cells that weren't reprinted due to the diff policy still display their old style.
+      if self.get_diff_policy() != DiffPolicy::Exact {
+        new_grid =
+          CellRunDiff.displayed_grid(&self.previous_grid, &new_grid, self.get_diff_settings());
+      }
+    } else if !full_reprint {
+      let new_origin = self.get_new_origin(new_grid_dimensions, terminal_dimensions);
+      self.update_origin(new_origin);
+
+      let timer = PhaseTimer::start();
+      let mut change_runs =
+        precomputed_change_runs.unwrap_or_else(|| self.get_change_runs(&new_grid));
+      self.record_phase(Phase::Diffing, timer);
+
+      let timer = PhaseTimer::start();
+      let mut printable_difference =
+        self.get_printable_change_runs(change_runs.clone(), &self.previous_grid)?;
+      self.record_phase(Phase::EscapeGeneration, timer);
+
+      // The grid displayed before printing the difference, which differs from the previous grid when it's scrolled.
+      let mut scrolled_grid = None;
+
+      if let Some((scroll_sequence, displayed_grid)) =
+        self.get_scrolled_grid(&new_grid, new_grid_dimensions, terminal_dimensions)
+      {
+        let timer = PhaseTimer::start();
+        let scrolled_change_runs = self.get_diff_strategy().change_runs(
+          &displayed_grid,
+          &new_grid,
+          self.get_diff_settings(),
+        );
+        self.record_phase(Phase::Diffing, timer);
+
+        let timer = PhaseTimer::start();
+        let scrolled_difference = scroll_sequence
+          + &self.get_printable_change_runs(scrolled_change_runs.clone(), &displayed_grid)?;
+        self.record_phase(Phase::EscapeGeneration, timer);
+
+        if scrolled_difference.len() < printable_difference.len() {
+          change_runs = scrolled_change_runs;
+          printable_difference = scrolled_difference;
+          scrolled_grid = Some(displayed_grid);
         }
-      },
-    );
+      }
+
+      changed_cells = change_runs
+        .iter()
+        .map(|change_run| row_width(&change_run.text, self.get_emoji_mode()))
+        .sum();
+      let mut degraded_to_rows = false;
+
+      if let Some(byte_budget) = self
+        .get_frame_byte_budget()
+        .filter(|&byte_budget| printable_difference.len() > byte_budget)
+      {
+        let timer = PhaseTimer::start();
+        let (degradation, printable_text, printed_cells) =
+          self.degrade_to_byte_budget(&new_grid, new_origin, byte_budget)?;
+        self.record_phase(Phase::EscapeGeneration, timer);
+        budget_degradation = Some(degradation);
+        // Degrading diffs against the previous grid without scrolling it.
+        scrolled_grid = None;
+        printable_difference = printable_text;
+        changed_cells = printed_cells;
+
+        match degradation {
+          BudgetDegradation::RowGranularity => degraded_to_rows = true,
+          BudgetDegradation::FullReprint => full_reprint = true,
+          BudgetDegradation::SkippedFrame => {
+            if terminal_cursor_caret.is_some() {
+              let show_cursor = self.get_escape_emitter().show_cursor();
+              self.write_output(&show_cursor)?;
+              self.flush_output()?;
+            }
+
+            self.update_frame_stats(FrameStats {
+              changed_cells: 0,
+              total_cells,
+              full_reprint: false,
+              budget_degradation,
+              resize_behavior,
+              #[cfg(feature = "profiling")]
+              phase_timings: PhaseTimings::default(),
+            });
+
+            return Ok(());
+          }
+        }
+      }
+
+      self.write_output(&printable_difference)?;
+
+      // Cells that weren't reprinted due to the diff policy still display their old style.
+      if self.get_diff_policy() != DiffPolicy::Exact && !full_reprint {
+        let diff_strategy = match degraded_to_rows {
+          true => &RowDiff,
+          false => self.get_diff_strategy(),
+        };
+
+        new_grid = diff_strategy.displayed_grid(
+          scrolled_grid.as_deref().unwrap_or(&self.previous_grid),
+          &new_grid,
+          self.get_diff_settings(),
+        );
+      }
+    } else if self.printing_position_changed_since_last_print {
+      self.replace_currently_printed_grid(
+        &new_grid,
+        Some(new_grid_dimensions),
+        terminal_dimensions,
+      )?;
+    } else {
+      self.fill_bounding_box()?;
+
+      let new_origin = self.get_new_origin(new_grid_dimensions, terminal_dimensions);
+      self.update_origin(new_origin);
+
+      let timer = PhaseTimer::start();
+      let printable_grid = self.get_printable_full_grid(&new_grid, new_origin);
+      self.record_phase(Phase::EscapeGeneration, timer);
+
+      self.write_output(&printable_grid)?;
+    }
+
+    let letterbox = self
+      .get_letterbox()
+      .filter(|_| self.get_update_strategy() == UpdateStrategy::Absolute);
+
+    if let Some(letterbox) = letterbox {
+      let grid_area = (self.get_origin_position()?, new_grid_dimensions);
+      let printable_fill = letterbox.printable_fill(
+        self.get_placement_area(terminal_dimensions),
+        grid_area,
+        self.get_color_depth(),
+        self.get_escape_emitter(),
+      );
+
+      self.write_output(&printable_fill)?;
+    }
+
+    if let Some(Caret {
+      position: (caret_x, caret_y),
+      ..
+    }) = terminal_cursor_caret
+    {
+      let (origin_x, origin_y) = self.get_origin_position()?;
+      let emitter = self.get_escape_emitter();
+      let caret_cursor = format!(
+        "{}{}",
+        emitter.move_cursor(origin_x + caret_x, origin_y + caret_y),
+        emitter.show_cursor()
+      );
+
+      self.write_output(&caret_cursor)?;
+    }
+
+    self.flush_output()?;
+
+    if let Some(recording) = &mut self.recording {
+      recording.push_frame(&new_grid);
+    }
+
+    self.update_full_refresh_count(full_reprint);
+    self.update_frame_stats(FrameStats {
+      changed_cells,
+      total_cells,
+      full_reprint,
+      budget_degradation,
+      resize_behavior,
+      #[cfg(feature = "profiling")]
+      phase_timings: PhaseTimings::default(),
+    });
+
+    let replaced_grid = std::mem::replace(&mut self.previous_grid, new_grid);
+    self.recycle_grid(replaced_grid);
+    self.last_submitted_grid.clear();
+    self.update_last_print_time();
+    self.idle = false;
+    self.update_dimensions(new_grid_dimensions);
+    self.update_terminal_dimensions_from_previous_print(terminal_dimensions);
+    self.printing_position_changed_since_last_print = false;
 
-    Ok(printable_difference)
+    self.run_watchdog()
   }
 
-  fn move_to_origin(&self) -> Result<(), PrintingError> {
-    let (x, y) = self.get_origin_position()?;
+  fn get_printable_change_runs(
+    &self,
+    change_runs: Vec<ChangeRun>,
+    displayed_grid: &str,
+  ) -> Result<String, PrintingError> {
+    let mut change_run_printer = ChangeRunPrinter::new(self)?;
+    let displayed_rows = OnceCell::new();
+    let mut printable_change_runs = String::new();
 
-    print!("\x1B[{};{}H", y, x);
+    for change_run in change_runs {
+      change_run_printer.push(
+        &mut printable_change_runs,
+        &change_run,
+        self.get_escape_emitter(),
+        || {
+          displayed_rows
+            .get_or_init(|| split_grid_into_cells(displayed_grid, self.get_emoji_mode()))
+            .get(change_run.start.1)
+            .map(Vec::as_slice)
+        },
+      );
+    }
 
-    Ok(())
+    Ok(printable_change_runs)
+  }
+
+  fn stream_change_runs(&mut self, old_grid: &str, new_grid: &str) -> Result<usize, PrintingError> {
+    let DiffSettings {
+      granularity,
+      emoji_mode,
+      policy,
+    } = self.get_diff_settings();
+    let mut change_run_printer = ChangeRunPrinter::new(self)?;
+    let mut old_rows = old_grid.split('\n');
+    let mut old_style: Rc<str> = Rc::from("");
+    let mut new_style: Rc<str> = Rc::from("");
+    let mut printable_row = String::new();
+    let mut changed_cells = 0;
+
+    for (y, new_row) in new_grid.split('\n').enumerate() {
+      let old_cells = split_row_into_cells(
+        old_rows.next().unwrap_or_default(),
+        emoji_mode,
+        &mut old_style,
+      );
+      let new_cells = split_row_into_cells(new_row, emoji_mode, &mut new_style);
+
+      for change_run in
+        ChangeRun::from_rows_with_granularity(&old_cells, &new_cells, y, granularity, policy)
+      {
+        changed_cells += row_width(&change_run.text, emoji_mode);
+        change_run_printer.push(
+          &mut printable_row,
+          &change_run,
+          self.get_escape_emitter(),
+          || Some(&old_cells),
+        );
+      }
+
+      if !printable_row.is_empty() {
+        self.write_output(&printable_row)?;
+        printable_row.clear();
+      }
+    }
+
+    Ok(changed_cells)
   }
 
   fn get_new_origin(
@@ -245,11 +871,28 @@ impl DynamicPrinterMethods for Printer {
     (grid_width, grid_height): (usize, usize),
     (terminal_width, terminal_height): (usize, usize),
   ) -> (usize, usize) {
+    if let Some(kept_origin) = self.kept_origin {
+      return kept_origin;
+    }
+
+    if self.get_update_strategy() == UpdateStrategy::ScrollbackPreserving {
+      return (1, 1);
+    }
+
+    if let Some(bounding_box) = self.get_bounding_box() {
+      return bounding_box.align(
+        (grid_width, grid_height),
+        (self.get_x_center_rounding(), self.get_y_center_rounding()),
+      );
+    }
+
     let printing_position = self.get_current_printing_position();
 
     let x: usize = match printing_position.x_printing_position {
       XPrintingPosition::Left => 1,
-      XPrintingPosition::Middle => calculate_grid_center_placement(grid_width, terminal_width),
+      XPrintingPosition::Middle => self
+        .get_x_center_rounding()
+        .center(grid_width, terminal_width),
       XPrintingPosition::Right => {
         calculate_grid_positive_border_placement(grid_width, terminal_width)
       }
@@ -260,7 +903,9 @@ impl DynamicPrinterMethods for Printer {
 
     let y: usize = match printing_position.y_printing_position {
       YPrintingPosition::Top => 1,
-      YPrintingPosition::Middle => calculate_grid_center_placement(grid_height, terminal_height),
+      YPrintingPosition::Middle => self
+        .get_y_center_rounding()
+        .center(grid_height, terminal_height),
       YPrintingPosition::Bottom => {
         calculate_grid_positive_border_placement(grid_height, terminal_height)
       }
@@ -269,7 +914,7 @@ impl DynamicPrinterMethods for Printer {
       }
     };
 
-    (x, y)
+    (x, y + self.get_printing_area_top_row() - 1)
   }
 
   fn replace_currently_printed_grid(
@@ -281,62 +926,434 @@ impl DynamicPrinterMethods for Printer {
     let (new_grid_width, new_grid_height) = if let Some(new_grid_dimensions) = new_grid_dimensions {
       new_grid_dimensions
     } else {
-      Self::get_rectangular_dimensions(new_grid)?
+      self.measure_rectangular_dimensions(new_grid)?
     };
 
-    // Can return an error if the PrintingPosition was changed before a first print.
-    let _ = self.clear_grid();
+    // Before the first print there's no grid to clear, so its dimensions and origin aren't set.
+    match self.clear_grid() {
+      Ok(()) | Err(PrintingError::GridDimensionsNotDefined | PrintingError::OriginNotDefined) => (),
+      Err(error) => return Err(error),
+    }
+
+    self.fill_bounding_box()?;
 
     let new_origin = self.get_new_origin((new_grid_width, new_grid_height), terminal_dimensions);
 
     self.update_dimensions((new_grid_width, new_grid_height));
     self.update_origin(new_origin);
 
-    print_grid_freestanding(new_grid, new_origin)?;
+    let timer = PhaseTimer::start();
+    let printable_grid = self.get_printable_full_grid(new_grid, new_origin);
+    self.record_phase(Phase::EscapeGeneration, timer);
 
-    Ok(())
+    self.write_output(&printable_grid)
+  }
+
+  fn get_printable_full_grid(&mut self, grid: &str, origin: (usize, usize)) -> String {
+    match self.get_update_strategy() {
+      UpdateStrategy::Absolute => get_printable_grid(
+        grid,
+        origin,
+        self.get_bidi_mode(),
+        self.get_escape_emitter(),
+      ),
+      UpdateStrategy::ScrollbackPreserving => {
+        let printable_grid = anchored_grid(grid, self.scrollback_anchored, self.get_bidi_mode());
+        self.scrollback_anchored = true;
+
+        printable_grid
+      }
+    }
+  }
+
+  fn fill_bounding_box(&mut self) -> Result<(), PrintingError> {
+    if self.get_update_strategy() == UpdateStrategy::ScrollbackPreserving {
+      return Ok(());
+    }
+
+    let Some(BoundingBox {
+      position,
+      dimensions: (box_width, box_height),
+      fill: Some(fill),
+      ..
+    }) = self.get_bounding_box()
+    else {
+      return Ok(());
+    };
+
+    let filled_grid = Self::create_grid_from_single_character(fill, box_width, box_height);
+
+    self.write_output(&get_printable_grid(
+      &filled_grid,
+      position,
+      BidiMode::Off,
+      self.get_escape_emitter(),
+    ))
   }
 
   fn clear_space_on_terminal(
+    &mut self,
     clearing_dimensions: (usize, usize),
     top_left_position: (usize, usize),
   ) -> Result<(), PrintingError> {
     let empty_grid =
       Self::create_grid_from_single_character(' ', clearing_dimensions.0, clearing_dimensions.1);
 
-    print_grid_freestanding(&empty_grid, top_left_position)
+    self.write_output(&get_printable_grid(
+      &empty_grid,
+      top_left_position,
+      BidiMode::Off,
+      self.get_escape_emitter(),
+    ))
+  }
+
+  fn get_scrolled_grid(
+    &self,
+    new_grid: &str,
+    (grid_width, grid_height): (usize, usize),
+    (terminal_width, _): (usize, usize),
+  ) -> Option<(String, String)> {
+    let (origin_x, origin_y) = self.get_origin_position().ok()?;
+
+    if origin_x != 1 || grid_width != terminal_width || grid_height < 2 {
+      return None;
+    }
+
+    let emoji_mode = self.get_emoji_mode();
+    let previous_rows = split_grid_into_cells(&self.previous_grid, emoji_mode);
+    let new_rows = split_grid_into_cells(new_grid, emoji_mode);
+
+    if previous_rows.len() != grid_height || new_rows.len() != grid_height {
+      return None;
+    }
+
+    let scrolled_rows = (1..grid_height).find(|&scrolled_rows| {
+      previous_rows[scrolled_rows..] == new_rows[..grid_height - scrolled_rows]
+    })?;
+    let scroll_sequence = self.get_escape_emitter().scroll_region_up(
+      origin_y,
+      origin_y + grid_height - 1,
+      scrolled_rows,
+    );
+
+    if scroll_sequence.is_empty() {
+      return None;
+    }
+
+    let displayed_grid = previous_rows[scrolled_rows..]
+      .iter()
+      .map(|row| {
+        let mut row_text = StyledTextBuilder::default();
+        row.iter().for_each(|cell| row_text.push(cell));
+
+        row_text.finish()
+      })
+      .chain(std::iter::repeat_n(" ".repeat(grid_width), scrolled_rows))
+      .collect::<Vec<String>>()
+      .join("\n");
+
+    Some((scroll_sequence, displayed_grid))
+  }
+
+  fn degrade_to_byte_budget(
+    &self,
+    new_grid: &str,
+    origin: (usize, usize),
+    byte_budget: usize,
+  ) -> Result<(BudgetDegradation, String, usize), PrintingError> {
+    let emoji_mode = self.get_emoji_mode();
+
+    if self.get_diff_granularity() == DiffGranularity::Cell {
+      let change_runs = ChangeRun::from_grids_with_settings(
+        &self.previous_grid,
+        new_grid,
+        DiffGranularity::Row,
+        emoji_mode,
+        self.get_diff_policy(),
+      );
+      let printed_cells = change_runs
+        .iter()
+        .map(|change_run| row_width(&change_run.text, emoji_mode))
+        .sum();
+      let printable_rows = self.get_printable_change_runs(change_runs, &self.previous_grid)?;
+
+      if printable_rows.len() <= byte_budget {
+        return Ok((
+          BudgetDegradation::RowGranularity,
+          printable_rows,
+          printed_cells,
+        ));
+      }
+    }
+
+    let printable_grid = get_printable_grid(
+      new_grid,
+      origin,
+      self.get_bidi_mode(),
+      self.get_escape_emitter(),
+    );
+
+    if printable_grid.len() <= byte_budget {
+      let (grid_width, grid_height) = self.measure_rectangular_dimensions(new_grid)?;
+
+      return Ok((
+        BudgetDegradation::FullReprint,
+        printable_grid,
+        grid_width * grid_height,
+      ));
+    }
+
+    Ok((BudgetDegradation::SkippedFrame, String::new(), 0))
+  }
+
+  fn run_watchdog(&mut self) -> Result<(), PrintingError> {
+    let Some(watchdog) = self.get_watchdog() else {
+      return Ok(());
+    };
+    let Ok((origin_x, origin_y)) = self.get_origin_position() else {
+      return Ok(());
+    };
+
+    if self.previous_grid.is_empty() {
+      return Ok(());
+    }
+
+    let now = Instant::now();
+    let bidi_mode = self.get_bidi_mode();
+    let mut printable_repair = None;
+
+    if let Some(frame_interval) = watchdog.frame_interval {
+      let next_frame_time =
+        self.schedule_watchdog_repair(self.next_watchdog_frame_time, frame_interval);
+
+      if now >= next_frame_time {
+        let emitter = self.get_escape_emitter();
+        let printable_grid = get_printable_grid(
+          &self.previous_grid,
+          (origin_x, origin_y),
+          bidi_mode,
+          emitter,
+        );
+
+        // The grid is printed over itself the same way as a full refresh, so it doesn't flicker.
+        printable_repair = Some(if self.capabilities().synchronized_updates {
+          format!(
+            "{}{printable_grid}{}",
+            emitter.begin_synchronized_update(),
+            emitter.end_synchronized_update()
+          )
+        } else {
+          printable_grid
+        });
+        self.next_watchdog_frame_time = Some(self.schedule_watchdog_repair(None, frame_interval));
+      } else {
+        self.next_watchdog_frame_time = Some(next_frame_time);
+      }
+    }
+
+    let next_row_time =
+      self.schedule_watchdog_repair(self.next_watchdog_row_time, watchdog.row_interval);
+
+    if printable_repair.is_none() && now >= next_row_time {
+      let rows = split_grid_into_cells(&self.previous_grid, self.get_emoji_mode());
+      let row_index =
+        (next_random_number(&mut self.watchdog_random_state) % rows.len() as u64) as usize;
+      let mut row_text = StyledTextBuilder::default();
+      rows[row_index].iter().for_each(|cell| row_text.push(cell));
+
+      printable_repair = Some(get_printable_grid(
+        &row_text.finish(),
+        (origin_x, origin_y + row_index),
+        bidi_mode,
+        self.get_escape_emitter(),
+      ));
+      self.next_watchdog_row_time =
+        Some(self.schedule_watchdog_repair(None, watchdog.row_interval));
+    } else {
+      self.next_watchdog_row_time = Some(next_row_time);
+    }
+
+    let Some(mut printable_repair) = printable_repair else {
+      return Ok(());
+    };
+
+    if let Some(Caret {
+      position: (caret_x, caret_y),
+      style: CaretStyle::TerminalCursor,
+    }) = self.get_caret()
+    {
+      // Puts the terminal's cursor back where the caret is.
+      printable_repair.push_str(
+        &self
+          .get_escape_emitter()
+          .move_cursor(origin_x + caret_x, origin_y + caret_y),
+      );
+    }
+
+    self.write_output(&printable_repair)?;
+    self.flush_output()
   }
 }
 
-/// Splits the grid into rows and moves the cursor down to print each row at the given position, starting from the top left.
-/// Does not check if the printed grid will overflow off the right or bottom of the terminal.
+/// Turns change runs into printable text one at a time, keeping track of where each one leaves the cursor.
+struct ChangeRunPrinter {
+  origin: (usize, usize),
+  bidi_mode: BidiMode,
+  emoji_mode: EmojiMode,
+  gap_overwriting: bool,
+  terminal_width: Option<usize>,
+  cursor_position: Option<(usize, usize)>,
+}
+
+impl ChangeRunPrinter {
+  /// Creates a change run printer with the printer's settings, where the cursor's position is unknown.
+  ///
+  /// # Errors
+  ///
+  /// - When origin hasn't been set before calling this method.
+  fn new(printer: &Printer) -> Result<Self, PrintingError> {
+    Ok(Self {
+      origin: printer.get_origin_position()?,
+      bidi_mode: printer.get_bidi_mode(),
+      emoji_mode: printer.get_emoji_mode(),
+      gap_overwriting: printer.get_gap_overwriting(),
+      terminal_width: printer
+        .get_terminal_dimensions_from_previous_print()
+        .ok()
+        .map(|(terminal_width, _)| terminal_width),
+      cursor_position: None,
+    })
+  }
+
+  /// Adds the movement to the start of the change run followed by its text to the printable text.
+  ///
+  /// The displayed row is the row of cells on the terminal that the run is printed over,
+  /// which is only needed when reprinting the unchanged cells before the run.
+  fn push<'a>(
+    &mut self,
+    printable_text: &mut String,
+    change_run: &ChangeRun,
+    emitter: &dyn EscapeEmitter,
+    displayed_row: impl FnOnce() -> Option<&'a [Cell<'a>]>,
+  ) {
+    let (x, y) = grid_to_terminal(change_run.start, self.origin);
+    let cursor_movement = match self.cursor_position {
+      Some(cursor_position) => emitter.move_cursor_from(cursor_position, x, y),
+      None => emitter.move_cursor(x, y),
+    };
+    // Reprinting the unchanged cells between two runs on the same row can be shorter than moving over them.
+    let unchanged_cells = self
+      .cursor_position
+      .filter(|&(cursor_x, cursor_y)| {
+        self.gap_overwriting
+          && cursor_y == y
+          && cursor_x < x
+          && x - cursor_x <= MAX_OVERWRITTEN_GAP
+          && self.bidi_mode == BidiMode::Off
+      })
+      .and_then(|(cursor_x, _)| {
+        unstyled_cells(
+          displayed_row()?,
+          (cursor_x - self.origin.0, change_run.start.0),
+        )
+      })
+      .filter(|unchanged_cells| unchanged_cells.len() < cursor_movement.len());
+
+    printable_text.push_str(&unchanged_cells.unwrap_or(cursor_movement));
+
+    let text = isolate_text(&change_run.text, self.bidi_mode);
+    printable_text.push_str(&translate_styles(&text, emitter));
+
+    // The cursor's position is unknown once it reaches the end of the terminal, where it waits to wrap onto the next row.
+    let end_x = x + row_width(&text, self.emoji_mode);
+    self.cursor_position = self
+      .terminal_width
+      .filter(|&terminal_width| end_x <= terminal_width)
+      .map(|_| (end_x, y));
+  }
+}
+
+/// Returns the text of the cells in the row covering exactly the given range of columns, if none of them are styled.
+fn unstyled_cells(row: &[Cell], (start_x, end_x): (usize, usize)) -> Option<String> {
+  let mut text = String::new();
+  let mut x = 0;
+
+  for cell in row {
+    if x >= end_x {
+      break;
+    }
+
+    if x >= start_x {
+      if !cell.style.is_empty() {
+        return None;
+      }
+
+      text.push_str(cell.text);
+    } else if x + cell.width > start_x {
+      // A wide cell is partway into the range.
+      return None;
+    }
+
+    x += cell.width;
+  }
+
+  (x == end_x).then_some(text)
+}
+
+/// Returns the grid printed in its entirety without any escape sequences, given the previous grid printed the same way.
 ///
-/// # Errors
+/// A single row is printed over a previous single row with a carriage return, padded to cover the rest of the previous row.
+/// Anything taller is printed on the rows below, ending with a newline so the next grid starts on a row of its own.
+pub(crate) fn unaddressed_frame(grid: &str, previous_grid: &str, emoji_mode: EmojiMode) -> String {
+  let previous_row_width = (!previous_grid.is_empty() && !previous_grid.contains('\n'))
+    .then(|| row_width(previous_grid, emoji_mode));
+
+  match (grid.contains('\n'), previous_row_width) {
+    (false, Some(previous_row_width)) => {
+      let padding = previous_row_width.saturating_sub(row_width(grid, emoji_mode));
+
+      format!("\r{grid}{}", " ".repeat(padding))
+    }
+    (false, None) => format!("\r{grid}"),
+    (true, previous_row_width) => {
+      // Moves off of the previous row, which didn't end with a newline.
+      let separator = if previous_row_width.is_some() {
+        "\r\n"
+      } else {
+        ""
+      };
+
+      format!("{separator}{}\r\n", grid.replace('\n', "\r\n"))
+    }
+  }
+}
+
+/// Splits the grid into rows, adding the cursor movements needed to print each row at the given position, starting from the top left.
+/// Does not check if the printed grid is rectangular, or if it will overflow off the right or bottom of the terminal.
 ///
-/// - The passed in grid isn't rectangular.
-fn print_grid_freestanding(
+/// Each row is wrapped in a left-to-right isolate if the [`BidiMode`](crate::bidi::BidiMode) calls for it.
+pub(crate) fn get_printable_grid(
   grid: &str,
-  printing_position: (usize, usize),
-) -> Result<(), PrintingError> {
-  Printer::get_rectangular_dimensions(grid)?;
-  let mut grid_with_cursor_movements = String::new();
-  let cursor_movement = format!("\x1B[1B\x1B[{}G", printing_position.0);
-
-  for grid_row in grid.split('\n') {
-    grid_with_cursor_movements.push_str(grid_row);
-    grid_with_cursor_movements.push_str(&cursor_movement);
-  }
+  (x, y): (usize, usize),
+  bidi_mode: BidiMode,
+  emitter: &dyn EscapeEmitter,
+) -> String {
+  let mut grid_with_cursor_movements = emitter.move_cursor(x, y);
 
-  print!("\x1B[{};{}H", printing_position.1, printing_position.0);
-  print!("{}", grid_with_cursor_movements);
+  for (row_index, grid_row) in grid.split('\n').enumerate() {
+    grid_with_cursor_movements.push_str(&translate_styles(
+      &isolate_text(grid_row, bidi_mode),
+      emitter,
+    ));
+    grid_with_cursor_movements.push_str(&emitter.next_row(x, y + row_index + 1));
+  }
 
-  Ok(())
-}
+  // Stops any styling left on by the grid from applying to anything printed after it.
+  if grid.contains(ESCAPE) {
+    grid_with_cursor_movements.push_str(&emitter.reset_style());
+  }
 
-/// Determines the position of where to place a grid in the center of the screen based on the length
-/// of the grid and terinal.
-fn calculate_grid_center_placement(grid_length: usize, terminal_length: usize) -> usize {
-  ((terminal_length as f32 / 2.0).floor() - (grid_length as f32 / 2.0).floor()) as usize
+  grid_with_cursor_movements
 }
 
 /// Determines the position of where to place a grid on the positive border of the screen(bottom and right)
@@ -356,41 +1373,3 @@ fn calculate_custom_grid_position(
   grid_placement
     - ((grid_placement + grid_length) as isize - terminal_length as isize).max(0) as usize
 }
-
-trait VecMethods<T> {
-  /// Gets a mutable reference to the top most item in a vector
-  ///
-  /// # Example
-  /// ```ignore
-  /// let mut data = vec![0, 2, 4, 5];
-  ///
-  /// let mut top_item = data.get_mut_top();
-  /// *top_item += 1;
-  ///
-  /// assert_eq!(data.get(3), Some(6));
-  /// ```
-  fn get_mut_top(&mut self) -> Option<&mut T>;
-}
-
-impl<T> VecMethods<T> for std::vec::Vec<T> {
-  fn get_mut_top(&mut self) -> Option<&mut T> {
-    let item_count = self.len();
-
-    if !self.is_empty() {
-      self.get_mut(item_count - 1)
-    } else {
-      None
-    }
-  }
-}
-
-trait UsizeMethods {
-  /// Converts an index into coordinates for the given grid's width.
-  fn index_as_coordinates(&self, grid_width: &Self) -> (usize, usize);
-}
-
-impl UsizeMethods for usize {
-  fn index_as_coordinates(&self, grid_width: &Self) -> (usize, usize) {
-    (self % grid_width, self / grid_width)
-  }
-}
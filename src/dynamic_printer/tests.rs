@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::*;
+use std::time::Duration;
 
 #[cfg(test)]
 mod get_printable_difference_logic {
@@ -21,6 +22,28 @@ mod get_printable_difference_logic {
       })
   }
 
+  /// Asserts that the change runs between the [`BASE_GRID`](BASE_GRID) and the given grid are the ones expected,
+  /// and that the printable difference is made of those runs.
+  fn assert_difference(
+    printer: &Printer,
+    different_grid: &str,
+    expected_change_runs: Vec<ChangeRun>,
+  ) {
+    let origin = printer.get_origin_position().unwrap();
+    let expected_printable_difference: String = expected_change_runs
+      .iter()
+      .map(|change_run| change_run.to_printable_difference(origin))
+      .collect();
+
+    let change_runs = printer.get_change_runs(different_grid);
+    let printable_difference = printer
+      .get_printable_change_runs(change_runs.clone(), &printer.previous_grid)
+      .unwrap();
+
+    assert_eq!(expected_change_runs, change_runs);
+    assert_eq!(expected_printable_difference, printable_difference);
+  }
+
   #[test]
   fn one_pixel_difference() {
     // lbcde
@@ -28,17 +51,10 @@ mod get_printable_difference_logic {
     // vwxyz
     let printer = get_preassigned_printer();
     let different_grid = get_modified_base_grid(vec![0]);
-    let origin = printer.get_origin_position().unwrap();
-
-    let expected_different_pixels = PixelDifference {
-      pixels: String::from("l"),
-      index: 0,
-    }
-    .into_printable_difference(origin, GRID_SIZES.0);
 
-    let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+    let expected_change_runs = vec![ChangeRun::new((0, 0), "l")];
 
-    assert_eq!(expected_different_pixels, different_pixels);
+    assert_difference(&printer, &different_grid, expected_change_runs);
   }
 
   #[cfg(test)]
@@ -52,17 +68,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![0, 1]);
-      let origin = printer.get_origin_position().unwrap();
 
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("ll"),
-        index: 0,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((0, 0), "ll")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -72,17 +81,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![4, 6]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("l\nl"),
-        index: 4,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
 
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((4, 0), "l"), ChangeRun::new((0, 1), "l")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -92,17 +94,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![3, 4]);
-      let origin = printer.get_origin_position().unwrap();
 
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("ll"),
-        index: 3,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((3, 0), "ll")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -112,17 +107,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![7, 8]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("ll"),
-        index: 6,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
 
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((1, 1), "ll")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -132,25 +120,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![1, 8]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = [
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 1,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 7,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-      ]
-      .join("");
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
-
-      assert_eq!(expected_different_pixels, different_pixels);
+
+      let expected_change_runs = vec![ChangeRun::new((1, 0), "l"), ChangeRun::new((2, 1), "l")];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -160,17 +133,10 @@ mod get_printable_difference_logic {
       // vwxll
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![15, 16]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("ll"),
-        index: 13,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
 
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((3, 2), "ll")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
   }
 
@@ -185,17 +151,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![0, 1, 2]);
-      let origin = printer.get_origin_position().unwrap();
 
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("lll"),
-        index: 0,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((0, 0), "lll")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -205,25 +164,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![0, 1, 8]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = [
-        PixelDifference {
-          pixels: String::from("ll"),
-          index: 0,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 7,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-      ]
-      .join("");
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
-
-      assert_eq!(expected_different_pixels, different_pixels);
+
+      let expected_change_runs = vec![ChangeRun::new((0, 0), "ll"), ChangeRun::new((2, 1), "l")];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -233,25 +177,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![1, 7, 8]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = [
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 1,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("ll"),
-          index: 6,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-      ]
-      .join("");
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
-
-      assert_eq!(expected_different_pixels, different_pixels);
+
+      let expected_change_runs = vec![ChangeRun::new((1, 0), "l"), ChangeRun::new((1, 1), "ll")];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -261,25 +190,14 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![1, 4, 6]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels = [
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 1,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("l\nl"),
-          index: 4,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-      ]
-      .join("");
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
-
-      assert_eq!(expected_different_pixels, different_pixels);
+
+      let expected_change_runs = vec![
+        ChangeRun::new((1, 0), "l"),
+        ChangeRun::new((4, 0), "l"),
+        ChangeRun::new((0, 1), "l"),
+      ];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
   }
 
@@ -294,17 +212,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![0, 1, 2, 3]);
-      let origin = printer.get_origin_position().unwrap();
 
-      let expected_different_pixels = PixelDifference {
-        pixels: String::from("llll"),
-        index: 0,
-      }
-      .into_printable_difference(origin, GRID_SIZES.0);
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+      let expected_change_runs = vec![ChangeRun::new((0, 0), "llll")];
 
-      assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -314,25 +225,10 @@ mod get_printable_difference_logic {
       // vwxyz
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![0, 1, 6, 7]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels: String = [
-        PixelDifference {
-          pixels: String::from("ll"),
-          index: 0,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("ll"),
-          index: 5,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-      ]
-      .join("");
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
-
-      assert_eq!(expected_different_pixels, different_pixels);
+
+      let expected_change_runs = vec![ChangeRun::new((0, 0), "ll"), ChangeRun::new((0, 1), "ll")];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
 
     #[test]
@@ -342,35 +238,15 @@ mod get_printable_difference_logic {
       // lwxyl
       let printer = get_preassigned_printer();
       let different_grid = get_modified_base_grid(vec![0, 4, 12, 16]);
-      let origin = printer.get_origin_position().unwrap();
-
-      let expected_different_pixels: String = [
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 0,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 4,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 10,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-        PixelDifference {
-          pixels: String::from("l"),
-          index: 14,
-        }
-        .into_printable_difference(origin, GRID_SIZES.0),
-      ]
-      .join("");
-
-      let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
-
-      assert_eq!(expected_different_pixels, different_pixels);
+
+      let expected_change_runs = vec![
+        ChangeRun::new((0, 0), "l"),
+        ChangeRun::new((4, 0), "l"),
+        ChangeRun::new((0, 2), "l"),
+        ChangeRun::new((4, 2), "l"),
+      ];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
   }
 
@@ -382,209 +258,1420 @@ mod get_printable_difference_logic {
     let printer = get_preassigned_printer();
     let different_grid =
       get_modified_base_grid(vec![0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 12, 13, 14, 15, 16]);
-    let origin = printer.get_origin_position().unwrap();
 
-    let expected_different_pixels = PixelDifference {
-      pixels: String::from("lllll\nlllll\nlllll"),
-      index: 0,
+    let expected_change_runs = vec![
+      ChangeRun::new((0, 0), "lllll"),
+      ChangeRun::new((0, 1), "lllll"),
+      ChangeRun::new((0, 2), "lllll"),
+    ];
+
+    assert_difference(&printer, &different_grid, expected_change_runs);
+  }
+
+  #[cfg(test)]
+  mod row_granularity {
+    use super::*;
+
+    #[test]
+    fn changed_rows_are_printed_whole() {
+      // alcde
+      // 12345
+      // vwxyl
+      let mut printer = get_preassigned_printer();
+      printer.replace_diff_granularity(DiffGranularity::Row);
+      let different_grid = get_modified_base_grid(vec![1, 16]);
+
+      let expected_change_runs = vec![
+        ChangeRun::new((0, 0), "alcde"),
+        ChangeRun::new((0, 2), "vwxyl"),
+      ];
+
+      assert_difference(&printer, &different_grid, expected_change_runs);
     }
-    .into_printable_difference(origin, GRID_SIZES.0);
 
-    let different_pixels = printer.get_printable_difference(&different_grid).unwrap();
+    #[test]
+    fn unchanged_grid_has_no_difference() {
+      let mut printer = get_preassigned_printer();
+      printer.replace_diff_granularity(DiffGranularity::Row);
 
-    assert_eq!(expected_different_pixels, different_pixels);
+      assert_difference(&printer, BASE_GRID, vec![]);
+    }
   }
 }
 
 #[cfg(test)]
-mod get_origin_from_printing_potision_tests {
+mod cell_measurement_tests {
   use super::*;
 
   #[test]
-  fn x_left_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    printer
-      .replace_x_printing_position(XPrintingPosition::Left)
-      .unwrap();
+  fn wide_characters_shift_following_changes() {
+    let change_runs = ChangeRun::from_grids("\u{65E5}\u{672C}a", "\u{65E5}\u{672C}b");
 
-    let expected_x_position = 1;
+    assert_eq!(change_runs, vec![ChangeRun::new((4, 0), "b")]);
+  }
 
-    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+  #[test]
+  fn combining_marks_are_part_of_the_previous_cell() {
+    let change_runs = ChangeRun::from_grids("abc", "ae\u{301}c");
 
-    assert_eq!(origin_x, expected_x_position);
+    assert_eq!(change_runs, vec![ChangeRun::new((1, 0), "e\u{301}")]);
   }
 
   #[test]
-  fn x_middle_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    printer
-      .replace_x_printing_position(XPrintingPosition::Middle)
-      .unwrap();
+  fn styled_changes_are_printed_with_their_style() {
+    let change_runs = ChangeRun::from_grids("\x1B[31mab\x1B[0mc", "\x1B[31mal\x1B[0mc");
 
-    let expected_x_position = ((terminal_width as f32 / 2.0).floor()
-      - (grid_dimensions.0 as f32 / 2.0).floor())
-    .floor() as usize;
+    assert_eq!(
+      change_runs,
+      vec![ChangeRun::new((1, 0), "\x1B[31ml\x1B[0m")]
+    );
+  }
 
-    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+  #[test]
+  fn style_changes_are_differences() {
+    let change_runs = ChangeRun::from_grids("abc", "a\x1B[1mb\x1B[0mc");
 
-    assert_eq!(origin_x, expected_x_position);
+    assert_eq!(change_runs, vec![ChangeRun::new((1, 0), "\x1B[1mb\x1B[0m")]);
   }
 
   #[test]
-  fn x_right_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    printer
-      .replace_x_printing_position(XPrintingPosition::Right)
-      .unwrap();
+  fn styles_carry_over_between_rows() {
+    let change_runs = ChangeRun::from_grids("\x1B[31mab\ncd", "\x1B[31mab\ncx");
 
-    let expected_x_position = (terminal_width - grid_dimensions.0) + 1;
+    assert_eq!(
+      change_runs,
+      vec![ChangeRun::new((1, 1), "\x1B[31mx\x1B[0m")]
+    );
+  }
 
-    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+  #[test]
+  fn escape_sequences_have_no_width() {
+    let grid = "\x1B[1m\x1B[38;2;10;20;30mab\x1B[0m\n\x1B]0;title\x07cd";
 
-    assert_eq!(origin_x, expected_x_position);
+    let dimensions = Printer::get_rectangular_dimensions(grid).unwrap();
+
+    assert_eq!(dimensions, (2, 2));
   }
+}
+
+#[cfg(test)]
+mod emoji_mode_tests {
+  use super::*;
+
+  // A family emoji made out of 3 emoji joined by zero width joiners.
+  const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
 
   #[test]
-  fn y_top_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    printer
-      .replace_y_printing_position(YPrintingPosition::Top)
-      .unwrap();
+  fn emoji_sequences_are_two_columns_wide() {
+    let mut printer = Printer::new();
+    printer.replace_emoji_mode(EmojiMode::Cluster);
+    let grid = format!("a{FAMILY}b\nxxxx");
 
-    let expected_y_position = 1;
+    let dimensions = printer.measure_rectangular_dimensions(&grid).unwrap();
 
-    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+    assert_eq!(dimensions, (4, 2));
+  }
 
-    assert_eq!(origin_y, expected_y_position);
+  #[test]
+  fn changes_after_emoji_sequences_are_positioned_by_column() {
+    let old_grid = format!("a{FAMILY}b");
+    let new_grid = format!("a{FAMILY}c");
+
+    let change_runs = ChangeRun::from_grids_with_settings(
+      &old_grid,
+      &new_grid,
+      DiffGranularity::Cell,
+      EmojiMode::Cluster,
+      DiffPolicy::Exact,
+    );
+
+    assert_eq!(change_runs, vec![ChangeRun::new((3, 0), "c")]);
   }
 
   #[test]
-  fn y_middle_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    printer
-      .replace_y_printing_position(YPrintingPosition::Middle)
-      .unwrap();
+  fn replaced_emoji_sequence_is_a_single_change() {
+    let old_grid = "abcd";
+    let new_grid = format!("a{FAMILY}d");
+
+    let change_runs = ChangeRun::from_grids_with_settings(
+      old_grid,
+      &new_grid,
+      DiffGranularity::Cell,
+      EmojiMode::Cluster,
+      DiffPolicy::Exact,
+    );
+
+    assert_eq!(change_runs, vec![ChangeRun::new((1, 0), FAMILY)]);
+  }
 
-    let expected_y_position = ((terminal_height as f32 / 2.0).floor()
-      - (grid_dimensions.1 as f32 / 2.0).floor())
-    .floor() as usize;
+  #[test]
+  fn printer_diffs_with_its_emoji_mode() {
+    let mut printer = Printer::new();
+    printer.replace_emoji_mode(EmojiMode::Cluster);
+    printer.previous_grid = format!("a{FAMILY}b");
 
-    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+    let change_runs = printer.get_change_runs(&format!("a{FAMILY}c"));
 
-    assert_eq!(origin_y, expected_y_position);
+    assert_eq!(change_runs, vec![ChangeRun::new((3, 0), "c")]);
   }
 
   #[test]
-  fn y_bottom_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    printer
-      .replace_y_printing_position(YPrintingPosition::Bottom)
-      .unwrap();
+  fn placeholder_replaces_emoji_sequences() {
+    let grid = format!("a{FAMILY}b\n\u{1F1EF}\u{1F1F5}cd");
 
-    let expected_y_position = (terminal_height - grid_dimensions.1) + 1;
+    let replaced_grid = crate::cells::replace_emoji_sequences(&grid, '?');
 
-    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+    assert_eq!(replaced_grid, "a?b\n?cd");
+  }
+}
 
-    assert_eq!(origin_y, expected_y_position);
+#[cfg(test)]
+mod diff_policy_tests {
+  use super::*;
+
+  #[test]
+  fn exact_policy_ignores_how_styles_are_written() {
+    let old_grid = "\x1B[1;31mab\x1B[0m";
+    let new_grid = "\x1B[31m\x1B[1mab\x1B[0m";
+
+    assert!(ChangeRun::from_grids_with_policy(old_grid, new_grid, DiffPolicy::Exact).is_empty());
   }
 
   #[test]
-  fn y_custom_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    let printing_position = (terminal_height / 10).max(1);
-    printer
-      .replace_y_printing_position(YPrintingPosition::Custom(printing_position))
-      .unwrap();
+  fn ignoring_colors_still_compares_attributes() {
+    let old_grid = "\x1B[31mab\x1B[0m";
+    let new_grid = "\x1B[1;32mab\x1B[0m";
 
-    let expected_y_position = printing_position
-      - ((printing_position + grid_dimensions.1) as isize - terminal_height as isize).max(0)
-        as usize;
+    let change_runs =
+      ChangeRun::from_grids_with_policy(old_grid, new_grid, DiffPolicy::IgnoreColors);
 
-    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+    assert_eq!(change_runs, vec![ChangeRun::new((0, 0), new_grid)]);
+  }
 
-    assert_eq!(origin_y, expected_y_position);
+  #[test]
+  fn colors_within_tolerance_are_unchanged() {
+    let old_grid = "\x1B[38;2;100;100;100ma\x1B[0m\x1B[38;2;100;100;100mb\x1B[0m";
+    let new_grid = "\x1B[38;2;104;96;100ma\x1B[0m\x1B[38;2;110;100;100mb\x1B[0m";
+
+    let change_runs =
+      ChangeRun::from_grids_with_policy(old_grid, new_grid, DiffPolicy::ColorTolerance(5));
+
+    assert_eq!(
+      change_runs,
+      vec![ChangeRun::new((1, 0), "\x1B[38;2;110;100;100mb\x1B[0m")]
+    );
   }
 
   #[test]
-  fn x_custom_position() {
-    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
-    let mut printer = get_preassigned_printer();
-    let grid_dimensions = GRID_SIZES;
-    let printing_position = (terminal_width / 10).max(1);
-    printer
-      .replace_x_printing_position(XPrintingPosition::Custom(printing_position))
-      .unwrap();
+  fn small_changes_can_not_build_up() {
+    let policy = DiffPolicy::ColorTolerance(5);
+    let first_grid = "\x1B[38;2;100;0;0ma\x1B[0m";
+    let second_grid = "\x1B[38;2;104;0;0ma\x1B[0m";
+    let third_grid = "\x1B[38;2;108;0;0ma\x1B[0m";
+
+    let displayed_grid = crate::change_run::displayed_grid(
+      first_grid,
+      second_grid,
+      DiffGranularity::Cell,
+      EmojiMode::Off,
+      policy,
+    );
+
+    assert_eq!(displayed_grid, first_grid);
+    assert_eq!(
+      ChangeRun::from_grids_with_policy(&displayed_grid, third_grid, policy),
+      vec![ChangeRun::new((0, 0), third_grid)]
+    );
+  }
+}
 
-    let expected_x_position = printing_position
-      - ((printing_position + grid_dimensions.1) as isize - terminal_width as isize).max(0)
-        as usize;
+#[cfg(test)]
+mod bidi_mode_tests {
+  use super::*;
 
-    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+  #[test]
+  fn right_to_left_text_is_reordered() {
+    let grid = "ab \u{5D0}\u{5D1}\u{5D2}\nxyz123";
 
-    assert_eq!(origin_x, expected_x_position);
+    let reordered_grid = reorder_grid(grid);
+
+    assert_eq!(reordered_grid, "ab \u{5D2}\u{5D1}\u{5D0}\nxyz123");
+  }
+
+  #[test]
+  fn isolates_are_only_added_when_forced() {
+    assert_eq!(isolate_text("abc", BidiMode::Off), "abc");
+    assert_eq!(
+      isolate_text("abc", BidiMode::ForceLeftToRight),
+      "\u{2066}abc\u{2069}"
+    );
   }
 }
 
-// Base grid will be
-// abcde
-// 12345
-// vwxyz
-static BASE_GRID: &str = "abcde\n12345\nvwxyz";
-static GRID_SIZES: (usize, usize) = (5, 3);
+#[cfg(test)]
+mod io_error_tests {
+  use super::*;
+  use std::io::{self, Write};
+  use std::sync::atomic::{AtomicBool, Ordering};
+  use std::sync::Arc;
+
+  /// A pipe that fails every write and flush once its reader exits.
+  #[derive(Clone, Default)]
+  struct Pipe {
+    closed: Arc<AtomicBool>,
+  }
 
-fn get_preassigned_printer() -> Printer {
-  let terminal_dimensions = Printer::get_terminal_dimensions().unwrap();
-  let (grid_width, grid_height) = Printer::get_rectangular_dimensions(BASE_GRID).unwrap();
+  impl Pipe {
+    fn closed() -> Self {
+      let pipe = Self::default();
+      pipe.close();
 
-  let mut printer = Printer::new_with_printing_position(
-    PrintingPosition::with_y_printing_position(YPrintingPosition::Top),
-  );
+      pipe
+    }
 
-  printer.previous_grid = BASE_GRID.to_string();
-  printer.update_dimensions((grid_width, grid_height));
-  printer.update_origin(printer.get_new_origin((grid_width, grid_height), terminal_dimensions));
+    fn close(&self) {
+      self.closed.store(true, Ordering::SeqCst);
+    }
 
-  printer
+    fn check_closed(&self) -> io::Result<()> {
+      match self.closed.load(Ordering::SeqCst) {
+        true => Err(io::ErrorKind::BrokenPipe.into()),
+        false => Ok(()),
+      }
+    }
+  }
+
+  impl Write for Pipe {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+      self.check_closed()?;
+
+      Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      self.check_closed()
+    }
+  }
+
+  fn assert_broken_pipe(result: Result<(), PrintingError>) {
+    assert!(
+      matches!(&result, Err(PrintingError::Io(io_error)) if io_error.kind() == io::ErrorKind::BrokenPipe),
+      "Expected a broken pipe, got {result:?}"
+    );
+  }
+
+  #[test]
+  fn failed_writes_are_returned() {
+    let mut printer = Printer::deterministic_with_output(Pipe::closed());
+
+    assert_broken_pipe(printer.dynamic_print("abc".to_string()));
+    assert_broken_pipe(printer.dynamic_print("xyz".to_string()));
+  }
+
+  #[test]
+  fn failed_clears_are_returned() {
+    let pipe = Pipe::default();
+    let mut printer = Printer::deterministic_with_output(pipe.clone());
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    pipe.close();
+
+    assert_broken_pipe(printer.clear_grid());
+    assert_broken_pipe(printer.dynamic_print("ab\ncd".to_string()));
+  }
+}
+
+#[cfg(test)]
+mod character_normalization_tests {
+  use super::*;
+
+  #[test]
+  fn composed_characters_have_no_difference() {
+    let mut old_grid = "e\u{301}bc".to_string();
+    let mut new_grid = "\u{e9}bc".to_string();
+
+    Printer::normalize_grid(&mut old_grid, CharacterNormalization::Composed);
+    Printer::normalize_grid(&mut new_grid, CharacterNormalization::Composed);
+
+    assert!(ChangeRun::from_grids(&old_grid, &new_grid).is_empty());
+  }
+
+  #[test]
+  fn orphan_combining_marks_are_removed() {
+    let mut grid = "\u{301}ab\nq\u{301}c".to_string();
+
+    Printer::normalize_grid(&mut grid, CharacterNormalization::ComposedWithoutOrphans);
+
+    assert_eq!(grid, "ab\nqc");
+  }
+
+  #[test]
+  fn built_grids_are_left_for_normalize_grid() {
+    let characters = ["e\u{301}", "x", "x", "x"];
+    let mut grid =
+      Printer::create_grid_from_full_character_list(&characters, 2, 2, CharacterOrdering::RowMajor)
+        .unwrap();
+
+    assert_eq!(grid, "e\u{301}x\nxx");
+
+    Printer::normalize_grid(&mut grid, CharacterNormalization::Composed);
+
+    assert_eq!(grid, "\u{e9}x\nxx");
+  }
+
+  #[test]
+  fn built_grids_can_be_normalized() {
+    let characters = ["e\u{301}", "\t", "\x1B[31m\x07\x1B[0m", "x"];
+    let normalization = GridNormalization::new(CharacterNormalization::Composed)
+      .with_control_character_replacement(' ');
+
+    let grid = Printer::create_grid_from_full_character_list_with_normalization(
+      &characters,
+      2,
+      2,
+      CharacterOrdering::RowMajor,
+      normalization,
+    )
+    .unwrap();
+
+    assert_eq!(grid, "\u{e9} \n\x1B[31m \x1B[0mx");
+    assert!(Printer::is_rectangular(&grid));
+  }
+
+  #[test]
+  fn default_grid_normalization_changes_nothing() {
+    let characters = ["e\u{301}", "\t", "x", "x"];
+
+    let grid = Printer::create_grid_from_full_character_list_with_normalization(
+      &characters,
+      2,
+      2,
+      CharacterOrdering::RowMajor,
+      GridNormalization::default(),
+    )
+    .unwrap();
+
+    assert_eq!(grid, "e\u{301}\t\nxx");
+  }
 }
 
-// Was used before, now here just to make rewriting tests easier.
-#[derive(Debug, PartialEq)]
-struct PixelDifference {
-  pixels: String,
-  index: usize,
+#[cfg(test)]
+mod escape_sanitization_tests {
+  use super::*;
+
+  #[test]
+  fn grids_are_sanitized_before_validation() {
+    let mut printer = Printer::deterministic();
+    printer.replace_escape_sanitization(EscapeSanitization::Strip);
+    printer.set_strict_character_validation(true);
+
+    let grid = printer.prepare_grid("a\x1B[2Jb\n\x1B]0;title\x07cd".to_string());
+
+    assert_eq!(grid.unwrap(), "ab\ncd");
+  }
+
+  #[test]
+  fn overlay_text_is_sanitized() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+    printer.replace_escape_sanitization(EscapeSanitization::Strip);
+    printer.replace_overlay(Some(Overlay::new("\x1B[Hx", Alignment::TopLeft)));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "xbc");
+  }
 }
 
-impl PixelDifference {
-  fn into_printable_difference(self, origin: (usize, usize), grid_width: usize) -> String {
-    let (mut x, mut y) = self.index.index_as_coordinates(&grid_width);
-    x = (x + origin.1).max(1);
-    y = (y + origin.1).max(1);
+#[cfg(test)]
+mod print_at_tests {
+  use super::*;
+
+  #[test]
+  fn every_row_starts_at_the_position() {
+    let printable_grid = Printer::get_printable_grid_at("ab\ncd", (3, 2)).unwrap();
 
-    let mut printable_difference = String::new();
+    assert_eq!(printable_grid, "\x1B[2;3Hab\x1B[1B\x1B[3Gcd\x1B[1B\x1B[3G");
+  }
 
-    for pixels in self.pixels.split('\n') {
-      println!("x: {x}, y: {y}");
-      //"\x1B[{y};{x}H{pixels}"
-      printable_difference.push_str(&format!("\x1B[{y};{x}H{}", pixels));
+  #[test]
+  fn non_rectangular_grids_are_rejected() {
+    let result = Printer::get_printable_grid_at("ab\nc", (1, 1));
 
-      y += 1;
-      x = (x as isize - (grid_width as isize - 1)).max(1) as usize;
-    }
+    assert_eq!(result, Err(PrintingError::NonRectangularGrid));
+  }
+}
+
+#[cfg(test)]
+mod print_over_previous_grid_tests {
+  use super::*;
+
+  #[test]
+  fn cursor_moves_up_to_the_top_of_the_previous_grid() {
+    let printable_grid = Printer::get_printable_grid_over_previous("ab\ncd\nef", 3).unwrap();
+
+    assert_eq!(printable_grid, "\x1B[2A\rab\ncd\nef");
+  }
 
-    printable_difference
+  #[test]
+  fn small_heights_dont_move_the_cursor() {
+    for height in [0, 1] {
+      let printable_grid = Printer::get_printable_grid_over_previous("ab", height).unwrap();
+
+      assert_eq!(printable_grid, "\rab");
+    }
+  }
+
+  #[test]
+  fn non_rectangular_grids_are_rejected() {
+    let result = Printer::get_printable_grid_over_previous("ab\nc", 2);
+
+    assert_eq!(result, Err(PrintingError::NonRectangularGrid));
+  }
+
+  #[test]
+  fn printer_output_is_used() {
+    let mut printer = Printer::on_virtual_terminal((2, 2));
+
+    printer
+      .print_over_previous_grid_to_output("ab\ncd", 1)
+      .unwrap();
+
+    assert_eq!(printer.get_capture().unwrap().frame_count(), 1);
+  }
+}
+
+#[cfg(test)]
+mod hit_testing_tests {
+  use super::*;
+
+  fn get_printer_with_printed_grid() -> Printer {
+    let mut printer = Printer::on_virtual_terminal((6, 4));
+    printer.replace_printing_position(PrintingPosition::new(
+      XPrintingPosition::Right,
+      YPrintingPosition::Bottom,
+    ));
+
+    printer.dynamic_print("abc\n123".to_string()).unwrap();
+
+    printer
+  }
+
+  #[test]
+  fn terminal_positions_map_to_cells() {
+    let printer = get_printer_with_printed_grid();
+
+    assert_eq!(printer.cell_at(4, 3), Some((0, 0)));
+    assert_eq!(printer.cell_at(6, 4), Some((2, 1)));
+  }
+
+  #[test]
+  fn positions_outside_the_grid_have_no_cell() {
+    let printer = get_printer_with_printed_grid();
+
+    assert_eq!(printer.cell_at(3, 3), None);
+    assert_eq!(printer.cell_at(4, 2), None);
+    assert_eq!(printer.cell_at(7, 4), None);
+    assert_eq!(printer.terminal_position_of(3, 0), None);
+  }
+
+  #[test]
+  fn cells_map_back_to_their_terminal_position() {
+    let printer = get_printer_with_printed_grid();
+
+    for (grid_x, grid_y) in [(0, 0), (1, 1), (2, 0)] {
+      let terminal_position = printer.terminal_position_of(grid_x, grid_y).unwrap();
+
+      assert_eq!(
+        printer.cell_at(terminal_position.0, terminal_position.1),
+        Some((grid_x, grid_y))
+      );
+    }
+  }
+
+  #[test]
+  fn nothing_printed_has_no_cells() {
+    let printer = Printer::on_virtual_terminal((6, 4));
+
+    assert_eq!(printer.cell_at(1, 1), None);
+  }
+}
+
+#[cfg(test)]
+mod will_fit_tests {
+  use super::*;
+
+  #[test]
+  fn grids_larger_than_the_printing_area_dont_fit() {
+    let mut printer = Printer::on_virtual_terminal((4, 3));
+    printer.replace_reserved_top_rows(1);
+
+    assert!(printer.will_fit("abcd\nefgh").is_ok());
+    assert_eq!(
+      printer.will_fit("abcd\nefgh\nijkl"),
+      Err(PrintingError::GridLargerThanTerminal(
+        DimensionsErrorData::new((4, 3), (4, 2))
+      ))
+    );
+  }
+
+  #[test]
+  fn overflow_policy_is_applied_before_checking() {
+    let mut printer = Printer::on_virtual_terminal((2, 2));
+    printer.replace_overflow_policy(OverflowPolicy::Crop);
+
+    assert!(printer.will_fit("abcd\nefgh\nijkl").is_ok());
+  }
+
+  #[test]
+  fn non_rectangular_grids_dont_fit() {
+    let printer = Printer::on_virtual_terminal((4, 4));
+
+    assert_eq!(
+      printer.will_fit("ab\nc"),
+      Err(PrintingError::NonRectangularGrid)
+    );
+  }
+
+  #[test]
+  fn fitting_at_a_position_uses_the_space_left_from_it() {
+    let printer = Printer::on_virtual_terminal((4, 3));
+
+    assert!(printer.fits_at("ab\ncd", (3, 2)).is_ok());
+    assert!(printer.fits_at("ab\ncd", (4, 2)).is_err());
+    assert!(printer.fits_at("ab\ncd", (3, 3)).is_err());
+  }
+
+  #[test]
+  fn checking_doesnt_print() {
+    let printer = Printer::on_virtual_terminal((4, 3));
+
+    printer.will_fit("ab").unwrap();
+
+    assert_eq!(printer.get_capture().unwrap().frame_count(), 0);
+  }
+}
+
+#[cfg(test)]
+mod cursor_addressing_fallback_tests {
+  use super::*;
+
+  #[test]
+  fn single_rows_are_printed_over_each_other() {
+    assert_eq!(unaddressed_frame("abc", "", EmojiMode::Off), "\rabc");
+    assert_eq!(unaddressed_frame("ab", "abcd", EmojiMode::Off), "\rab  ");
+  }
+
+  #[test]
+  fn taller_grids_are_printed_below() {
+    assert_eq!(
+      unaddressed_frame("ab\ncd", "ab\ncd", EmojiMode::Off),
+      "ab\r\ncd\r\n"
+    );
+    assert_eq!(
+      unaddressed_frame("ab\ncd", "xy", EmojiMode::Off),
+      "\r\nab\r\ncd\r\n"
+    );
+  }
+
+  #[test]
+  fn no_escape_sequences_are_printed() {
+    let mut printer = Printer::on_virtual_terminal((10, 4));
+    printer.set_cursor_addressing(false);
+    printer.replace_caret(Some(Caret::new((0, 0), CaretStyle::TerminalCursor)));
+
+    printer.dynamic_print("\x1B[31mab\ncd".to_string()).unwrap();
+    printer.dynamic_print("ab\ncd".to_string()).unwrap();
+
+    let bytes = printer.get_capture().unwrap().bytes();
+
+    assert!(!bytes.contains(&b'\x1B'));
+    assert_eq!(bytes, b"ab\r\ncd\r\n");
+  }
+
+  #[test]
+  fn grids_larger_than_the_terminal_are_still_errors() {
+    let mut printer = Printer::on_virtual_terminal((2, 2));
+    printer.set_cursor_addressing(false);
+
+    let result = printer.dynamic_print("abc".to_string());
+
+    assert_eq!(
+      result,
+      Err(PrintingError::GridLargerThanTerminal(
+        DimensionsErrorData::new((3, 1), (2, 2))
+      ))
+    );
+    assert!(printer.get_capture().unwrap().bytes().is_empty());
+  }
+
+  #[test]
+  fn supplied_dimensions_keep_cursor_addressing() {
+    let mut printer = Printer::new();
+
+    printer.replace_terminal_dimensions(Some((2, 2)));
+
+    assert!(printer.uses_cursor_addressing());
+    assert!(Printer::on_virtual_terminal((2, 2)).uses_cursor_addressing());
+  }
+}
+
+#[cfg(test)]
+mod character_validation_tests {
+  use super::*;
+
+  /// Returns the character, row, and column of the InvalidCharacter error returned when validating the grid.
+  fn get_invalid_character(printer: &mut Printer, grid: &str) -> (char, usize, usize) {
+    match printer.dynamic_print(grid.to_string()) {
+      Err(PrintingError::InvalidCharacter { ch, row, column }) => (ch, row, column),
+      result => panic!("Expected an InvalidCharacter error, got {result:?}"),
+    }
+  }
+
+  #[test]
+  fn styled_grid_is_valid() {
+    let grid = "\x1B[31mabc\x1B[0m\n\x1B]0;title\x07123";
+
+    assert!(Printer::validate_characters(grid).is_ok());
+  }
+
+  #[test]
+  fn control_character_position_is_found() {
+    let mut printer = Printer::new();
+    printer.set_strict_character_validation(true);
+
+    assert_eq!(
+      get_invalid_character(&mut printer, "abc\nab\r"),
+      ('\r', 1, 2)
+    );
+  }
+
+  #[test]
+  fn column_accounts_for_wide_characters_and_styles() {
+    let mut printer = Printer::new();
+    printer.set_strict_character_validation(true);
+
+    let grid = "abcd\n\x1B[1m日\x1B[0m\x07\x1B[0m";
+
+    assert_eq!(get_invalid_character(&mut printer, grid), ('\x07', 1, 2));
+  }
+}
+
+#[cfg(test)]
+mod get_origin_from_printing_potision_tests {
+  use super::*;
+
+  #[test]
+  fn x_left_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    printer.replace_x_printing_position(XPrintingPosition::Left);
+
+    let expected_x_position = 1;
+
+    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_x, expected_x_position);
+  }
+
+  #[test]
+  fn x_middle_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    printer.replace_x_printing_position(XPrintingPosition::Middle);
+
+    let expected_x_position = (terminal_width - grid_dimensions.0) / 2 + 1;
+
+    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_x, expected_x_position);
+  }
+
+  #[test]
+  fn x_right_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    printer.replace_x_printing_position(XPrintingPosition::Right);
+
+    let expected_x_position = (terminal_width - grid_dimensions.0) + 1;
+
+    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_x, expected_x_position);
+  }
+
+  #[test]
+  fn y_top_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    printer.replace_y_printing_position(YPrintingPosition::Top);
+
+    let expected_y_position = 1;
+
+    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_y, expected_y_position);
+  }
+
+  #[test]
+  fn y_middle_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    printer.replace_y_printing_position(YPrintingPosition::Middle);
+
+    let expected_y_position = (terminal_height - grid_dimensions.1) / 2 + 1;
+
+    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_y, expected_y_position);
+  }
+
+  #[test]
+  fn y_bottom_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    printer.replace_y_printing_position(YPrintingPosition::Bottom);
+
+    let expected_y_position = (terminal_height - grid_dimensions.1) + 1;
+
+    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_y, expected_y_position);
+  }
+
+  #[test]
+  fn y_custom_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    let printing_position = (terminal_height / 10).max(1);
+    printer.replace_y_printing_position(YPrintingPosition::Custom(printing_position));
+
+    let expected_y_position = printing_position
+      - ((printing_position + grid_dimensions.1) as isize - terminal_height as isize).max(0)
+        as usize;
+
+    let (_, origin_y) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_y, expected_y_position);
+  }
+
+  #[test]
+  fn x_custom_position() {
+    let (terminal_width, terminal_height) = Printer::get_terminal_dimensions().unwrap();
+    let mut printer = get_preassigned_printer();
+    let grid_dimensions = GRID_SIZES;
+    let printing_position = (terminal_width / 10).max(1);
+    printer.replace_x_printing_position(XPrintingPosition::Custom(printing_position));
+
+    let expected_x_position = printing_position
+      - ((printing_position + grid_dimensions.1) as isize - terminal_width as isize).max(0)
+        as usize;
+
+    let (origin_x, _) = printer.get_new_origin(grid_dimensions, (terminal_width, terminal_height));
+
+    assert_eq!(origin_x, expected_x_position);
+  }
+}
+
+#[cfg(test)]
+mod bounding_box_tests {
+  use super::*;
+
+  #[test]
+  fn bounding_box_replaces_printing_position() {
+    let mut printer = get_preassigned_printer();
+    printer.replace_printing_position(PrintingPosition::new(
+      XPrintingPosition::Right,
+      YPrintingPosition::Bottom,
+    ));
+    printer.replace_bounding_box(Some(
+      BoundingBox::new((4, 2), (11, 7)).with_alignment(Alignment::Center),
+    ));
+
+    assert_eq!(printer.get_new_origin(GRID_SIZES, (80, 24)), (7, 4));
+  }
+
+  #[test]
+  fn grid_larger_than_the_box_is_an_error() {
+    let mut printer = Printer::new();
+    printer.replace_terminal_dimensions(Some((80, 24)));
+    printer.replace_bounding_box(Some(BoundingBox::new((1, 1), (4, 4))));
+
+    let result = printer.print_prepared_grid(BASE_GRID.to_string(), None);
+
+    assert!(matches!(
+      result,
+      Err(PrintingError::GridLargerThanBoundingBox(error_data))
+        if error_data == BoundingBoxErrorData::new(GRID_SIZES, (4, 4))
+    ));
+  }
+}
+
+#[cfg(test)]
+mod controlling_terminal_tests {
+  use super::*;
+
+  #[test]
+  fn dimensions_are_obtained_from_the_controlling_terminal() {
+    let printer = Printer::on_controlling_terminal().unwrap();
+
+    assert!(printer.is_on_controlling_terminal());
+    assert_eq!(
+      printer.get_current_terminal_dimensions().unwrap(),
+      Printer::get_terminal_dimensions().unwrap()
+    );
+  }
+}
+
+#[cfg(test)]
+mod reserved_rows_tests {
+  use super::*;
+
+  #[test]
+  fn bottom_position_is_above_reserved_rows() {
+    let mut printer = get_preassigned_printer();
+    printer.replace_y_printing_position(YPrintingPosition::Bottom);
+    printer.replace_reserved_bottom_rows(2);
+
+    let printing_area = printer.get_printing_area((80, 24));
+    let (_, origin_y) = printer.get_new_origin(GRID_SIZES, printing_area);
+
+    // The grid's 3 rows take up rows 20 to 22, leaving rows 23 and 24 free.
+    assert_eq!(origin_y, 20);
+  }
+
+  #[test]
+  fn positions_are_below_reserved_top_rows() {
+    let mut printer = get_preassigned_printer();
+    printer.replace_y_printing_position(YPrintingPosition::Top);
+    printer.replace_reserved_top_rows(2);
+    printer.replace_reserved_bottom_rows(1);
+
+    let printing_area = printer.get_printing_area((80, 24));
+    let (_, origin_y) = printer.get_new_origin(GRID_SIZES, printing_area);
+
+    assert_eq!(printing_area, (80, 21));
+    assert_eq!(origin_y, 3);
+  }
+
+  #[test]
+  fn middle_position_is_centered_between_reserved_rows() {
+    let mut printer = get_preassigned_printer();
+    printer.replace_y_printing_position(YPrintingPosition::Middle);
+    printer.replace_reserved_top_rows(4);
+    printer.replace_reserved_bottom_rows(2);
+
+    let printing_area = printer.get_printing_area((80, 24));
+    let (_, origin_y) = printer.get_new_origin(GRID_SIZES, printing_area);
+
+    // The 3 rows are centered within rows 5 to 22.
+    assert_eq!(origin_y, 12);
+  }
+
+  #[test]
+  fn reserving_every_row_leaves_no_printing_area() {
+    let mut printer = Printer::new();
+    printer.replace_reserved_bottom_rows(30);
+
+    assert_eq!(printer.get_printing_area((80, 24)), (80, 0));
+  }
+}
+
+#[cfg(test)]
+mod idle_suppression_tests {
+  use super::*;
+
+  /// Returns a printer that was idle suppressing, and last printed the base grid to an 80x24 terminal.
+  fn get_idle_printer() -> Printer {
+    let mut printer = get_preassigned_printer();
+    printer.set_idle_suppression(true);
+    printer.last_submitted_grid = BASE_GRID.to_string();
+    printer.update_terminal_dimensions_from_previous_print((80, 24));
+    printer.update_last_print_time();
+
+    printer
+  }
+
+  #[test]
+  fn identical_grid_is_suppressed() {
+    let mut printer = get_idle_printer();
+
+    assert!(printer.should_suppress_idle_grid(BASE_GRID, (80, 24)));
+    assert!(!printer.should_suppress_idle_grid("abcde\n12345\nvwxy ", (80, 24)));
+  }
+
+  #[test]
+  fn resized_terminal_is_not_suppressed() {
+    let mut printer = get_idle_printer();
+
+    assert!(!printer.should_suppress_idle_grid(BASE_GRID, (100, 24)));
+  }
+
+  #[test]
+  fn keep_alive_marks_full_reprint() {
+    let mut printer = get_idle_printer();
+    printer.replace_keep_alive_interval(Some(Duration::ZERO));
+
+    assert!(!printer.should_suppress_idle_grid(BASE_GRID, (80, 24)));
+    assert!(printer.state().full_reprint_pending);
+  }
+}
+
+// Base grid will be
+// abcde
+// 12345
+// vwxyz
+static BASE_GRID: &str = "abcde\n12345\nvwxyz";
+static GRID_SIZES: (usize, usize) = (5, 3);
+
+fn get_preassigned_printer() -> Printer {
+  let terminal_dimensions = Printer::get_terminal_dimensions().unwrap();
+  let (grid_width, grid_height) = Printer::get_rectangular_dimensions(BASE_GRID).unwrap();
+
+  let mut printer = Printer::new_with_printing_position(
+    PrintingPosition::with_y_printing_position(YPrintingPosition::Top),
+  );
+
+  printer.previous_grid = BASE_GRID.to_string();
+  printer.update_dimensions((grid_width, grid_height));
+  printer.update_origin(printer.get_new_origin((grid_width, grid_height), terminal_dimensions));
+
+  printer
+}
+
+#[cfg(test)]
+mod byte_budget_tests {
+  use super::*;
+
+  fn get_printer_with_previous_grid() -> Printer {
+    let mut printer = Printer::new();
+    printer.previous_grid = "abcd\nefgh".to_string();
+    printer.update_origin((1, 1));
+
+    printer
+  }
+
+  #[test]
+  fn changed_rows_are_printed_whole_first() {
+    let printer = get_printer_with_previous_grid();
+
+    let (degradation, printable_text, printed_cells) = printer
+      .degrade_to_byte_budget("xbcx\nefgh", (1, 1), 10)
+      .unwrap();
+
+    assert_eq!(degradation, BudgetDegradation::RowGranularity);
+    assert_eq!(printable_text, "\x1B[1;1Hxbcx");
+    assert_eq!(printed_cells, 4);
+  }
+
+  #[test]
+  fn frames_are_skipped_when_nothing_fits() {
+    let printer = get_printer_with_previous_grid();
+
+    let (degradation, printable_text, _) = printer
+      .degrade_to_byte_budget("xbcx\nefgh", (1, 1), 9)
+      .unwrap();
+
+    assert_eq!(degradation, BudgetDegradation::SkippedFrame);
+    assert!(printable_text.is_empty());
+  }
+}
+
+#[cfg(test)]
+mod cursor_movement_tests {
+  use super::*;
+
+  fn printed_update(old_grid: &str, new_grid: &str) -> String {
+    printed_update_with(Printer::on_virtual_terminal((12, 1)), old_grid, new_grid)
+  }
+
+  fn printed_update_with(mut printer: Printer, old_grid: &str, new_grid: &str) -> String {
+    printer.dynamic_print(old_grid.to_string()).unwrap();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+    printer.dynamic_print(new_grid.to_string()).unwrap();
+
+    String::from_utf8_lossy(&printer.get_capture().unwrap().bytes()[printed_bytes..]).to_string()
+  }
+
+  #[test]
+  fn runs_on_the_same_row_move_relatively() {
+    let update = printed_update("abcdefghij", "xbcdefghix");
+
+    assert!(update.contains("x\x1B[8Cx"), "{update:?}");
+  }
+
+  #[test]
+  fn short_gaps_are_overwritten_with_unchanged_cells() {
+    let update = printed_update("abcdefghij", "xbxdefghij");
+
+    assert!(update.contains("xbx"), "{update:?}");
+  }
+
+  #[test]
+  fn gaps_wider_than_the_limit_are_moved_over() {
+    let update = printed_update("abcdefghij", "xbcdexghij");
+
+    assert!(update.contains("x\x1B[6Gx"), "{update:?}");
+  }
+
+  #[test]
+  fn gap_overwriting_can_be_turned_off() {
+    let mut printer = Printer::on_virtual_terminal((12, 1));
+    printer.set_gap_overwriting(false);
+
+    let update = printed_update_with(printer, "abcdefghij", "xbxdefghij");
+
+    assert!(update.contains("x\x1B[Cx"), "{update:?}");
+  }
+}
+
+#[cfg(test)]
+mod scroll_shift_tests {
+  use super::*;
+
+  const LOG: [&str; 5] = ["apples", "grapes", "melons", "quince", "olives"];
+
+  fn printer_with_log_tail() -> Printer {
+    let mut printer = Printer::on_virtual_terminal((6, 4));
+    printer.dynamic_print(LOG[..3].join("\n")).unwrap();
+
+    printer
+  }
+
+  #[test]
+  fn shifted_grids_are_scrolled() {
+    let mut printer = printer_with_log_tail();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+
+    printer.dynamic_print(LOG[1..4].join("\n")).unwrap();
+
+    let update =
+      String::from_utf8_lossy(&printer.get_capture().unwrap().bytes()[printed_bytes..]).to_string();
+
+    assert!(update.starts_with("\x1B[2;4r\x1B[1S\x1B[r"), "{update:?}");
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "      \ngrapes\nmelons\nquince"
+    );
+  }
+
+  #[test]
+  fn grids_narrower_than_the_terminal_are_diffed() {
+    let mut printer = Printer::on_virtual_terminal((8, 4));
+    printer.dynamic_print(LOG[..3].join("\n")).unwrap();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+
+    printer.dynamic_print(LOG[2..5].join("\n")).unwrap();
+
+    let update =
+      String::from_utf8_lossy(&printer.get_capture().unwrap().bytes()[printed_bytes..]).to_string();
+
+    assert!(!update.contains('r'), "{update:?}");
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "        \nmelons  \nquince  \nolives  "
+    );
+  }
+
+  #[test]
+  fn rows_shifted_by_more_than_one_are_scrolled() {
+    let mut printer = printer_with_log_tail();
+
+    printer.dynamic_print(LOG[2..5].join("\n")).unwrap();
+
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "      \nmelons\nquince\nolives"
+    );
+  }
+}
+
+#[cfg(test)]
+mod debounce_tests {
+  use super::*;
+  use std::time::Duration;
+
+  fn get_debounced_printer(debounce_interval: Duration) -> Printer {
+    let mut printer = Printer::on_virtual_terminal((5, 1));
+    printer.replace_debounce_interval(Some(debounce_interval));
+
+    printer
+  }
+
+  #[test]
+  fn bursts_are_coalesced_into_the_latest_grid() {
+    let mut printer = get_debounced_printer(Duration::from_secs(60));
+
+    for count in 1..=50 {
+      printer.dynamic_print(format!("{count:>5}")).unwrap();
+    }
+
+    assert_eq!(printer.get_capture().unwrap().frame_count(), 1);
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "    1");
+    assert!(printer.has_debounced_grid());
+
+    printer.print_debounced_grid().unwrap();
+
+    assert_eq!(printer.get_capture().unwrap().frame_count(), 2);
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "   50");
+    assert!(!printer.has_debounced_grid());
+  }
+
+  #[test]
+  fn grids_are_printed_once_the_interval_passes() {
+    let mut printer = get_debounced_printer(Duration::from_millis(1));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+    printer.dynamic_print("def".to_string()).unwrap();
+    std::thread::sleep(Duration::from_millis(2));
+    printer.dynamic_print("ghi".to_string()).unwrap();
+
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "ghi");
+    assert!(!printer.has_debounced_grid());
+  }
+
+  #[test]
+  fn clearing_drops_the_held_grid() {
+    let mut printer = get_debounced_printer(Duration::from_secs(60));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+    printer.dynamic_print("def".to_string()).unwrap();
+    printer.clear_grid().unwrap();
+
+    assert!(!printer.has_debounced_grid());
+  }
+}
+
+#[cfg(test)]
+mod ascii_diff_tests {
+  use super::*;
+  use crate::cells::split_grid_into_cells;
+  use crate::change_run::is_plain_ascii;
+
+  /// Returns the difference between the grids found by comparing their cells, without the ASCII fast path.
+  fn cell_diff(old_grid: &str, new_grid: &str, granularity: DiffGranularity) -> Vec<ChangeRun> {
+    let old_rows = split_grid_into_cells(old_grid, EmojiMode::Off);
+
+    split_grid_into_cells(new_grid, EmojiMode::Off)
+      .iter()
+      .enumerate()
+      .flat_map(|(y, new_cells)| {
+        let old_cells = old_rows.get(y).map(Vec::as_slice).unwrap_or_default();
+
+        ChangeRun::from_rows_with_granularity(
+          old_cells,
+          new_cells,
+          y,
+          granularity,
+          DiffPolicy::Exact,
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn ascii_grids_are_diffed_the_same_as_cells() {
+    let grid_pairs = [
+      ("abcde\n12345", "xbcxx\n12345"),
+      ("abc\n123", "abc\n123"),
+      ("abc", "abc\n123"),
+      ("ab", "abcd"),
+    ];
+
+    for (old_grid, new_grid) in grid_pairs {
+      for granularity in [DiffGranularity::Cell, DiffGranularity::Row] {
+        assert_eq!(
+          ChangeRun::from_grids_with_granularity(old_grid, new_grid, granularity),
+          cell_diff(old_grid, new_grid, granularity)
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn unicode_and_styles_fall_back_to_cells() {
+    assert!(is_plain_ascii("abc\n123"));
+    assert!(!is_plain_ascii("ab\x1B[31mc"));
+    assert!(!is_plain_ascii("a\tc"));
+    assert!(!is_plain_ascii("aé"));
+
+    assert_eq!(
+      ChangeRun::from_grids("a字b", "a字c"),
+      vec![ChangeRun::new((3, 0), "c")]
+    );
+  }
+}
+
+#[cfg(test)]
+mod streaming_diff_tests {
+  use super::*;
+
+  const OLD_GRID: &str = "abcdef\n\x1B[31mghijkl\nmnopqr\x1B[0m\nstuvwx";
+  const NEW_GRID: &str = "abXdeY\n\x1B[31mghijkl\nmZopqr\x1B[0m\nstuvwx";
+
+  /// Returns what's printed to update the old grid to the new one, and the amount of cells that changed.
+  fn printed_update(streaming_diff: bool) -> (Vec<u8>, usize) {
+    let mut printer = Printer::on_virtual_terminal((6, 4));
+    printer.set_streaming_diff(streaming_diff);
+    printer.dynamic_print(OLD_GRID.to_string()).unwrap();
+    let printed_bytes = printer.get_capture().unwrap().bytes().len();
+
+    printer.dynamic_print(NEW_GRID.to_string()).unwrap();
+
+    (
+      printer.get_capture().unwrap().bytes()[printed_bytes..].to_vec(),
+      printer.get_last_frame_stats().unwrap().changed_cells,
+    )
+  }
+
+  #[test]
+  fn streaming_prints_the_same_difference() {
+    assert_eq!(printed_update(true), printed_update(false));
+  }
+
+  #[test]
+  fn streamed_grids_are_displayed() {
+    let mut printer = Printer::on_virtual_terminal((6, 4));
+    printer.set_streaming_diff(true);
+
+    printer.dynamic_print(OLD_GRID.to_string()).unwrap();
+    printer.dynamic_print(NEW_GRID.to_string()).unwrap();
+
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "abXdeY\nghijkl\nmZopqr\nstuvwx"
+    );
+    assert_eq!(printer.previous_grid, NEW_GRID);
+  }
+}
+
+#[cfg(test)]
+mod resize_redraw_tests {
+  use super::*;
+
+  #[test]
+  fn resized_terminals_redraw_the_last_grid() {
+    let mut printer = Printer::on_virtual_terminal((7, 1));
+    printer.replace_printing_position(PrintingPosition::new(
+      XPrintingPosition::Right,
+      YPrintingPosition::Top,
+    ));
+    printer.set_resize_redraw(true);
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "    abc");
+
+    printer.replace_terminal_dimensions(Some((5, 1)));
+
+    assert!(printer.redraw_if_resized().unwrap());
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "  abc  ");
+    assert!(!printer.redraw_if_resized().unwrap());
+  }
+
+  #[test]
+  fn nothing_is_redrawn_unless_enabled() {
+    let mut printer = Printer::on_virtual_terminal((7, 1));
+    printer.dynamic_print("abc".to_string()).unwrap();
+    printer.replace_terminal_dimensions(Some((5, 1)));
+
+    assert!(!printer.redraw_if_resized().unwrap());
+  }
+
+  #[test]
+  fn cleared_grids_are_not_redrawn() {
+    let mut printer = Printer::on_virtual_terminal((7, 1));
+    printer.set_resize_redraw(true);
+    printer.dynamic_print("abc".to_string()).unwrap();
+    printer.clear_grid().unwrap();
+    printer.replace_terminal_dimensions(Some((5, 1)));
+
+    assert!(!printer.redraw_if_resized().unwrap());
+  }
+}
+
+#[cfg(test)]
+mod resize_behavior_tests {
+  use super::*;
+
+  /// Returns a printer with "abc" printed in the middle of the top row of a terminal 9 columns wide.
+  fn get_printer_with_printed_grid(resize_behavior: ResizeBehavior) -> Printer {
+    let mut printer = Printer::on_virtual_terminal((9, 1));
+    printer.replace_printing_position(PrintingPosition::new(
+      XPrintingPosition::Middle,
+      YPrintingPosition::Top,
+    ));
+    printer.replace_resize_behavior(resize_behavior);
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    printer
+  }
+
+  #[test]
+  fn resizing_recenters_by_default() {
+    let mut printer = get_printer_with_printed_grid(ResizeBehavior::default());
+    printer.replace_terminal_dimensions(Some((5, 1)));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+    let frame_stats = printer.get_last_frame_stats().unwrap();
+
+    assert_eq!(frame_stats.resize_behavior, Some(ResizeBehavior::Recenter));
+    assert!(frame_stats.full_reprint);
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      " abc     "
+    );
+  }
+
+  #[test]
+  fn kept_origins_stay_until_the_grid_moves() {
+    let mut printer = get_printer_with_printed_grid(ResizeBehavior::KeepOrigin);
+    printer.replace_terminal_dimensions(Some((7, 1)));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    assert_eq!(
+      printer.get_last_frame_stats().unwrap().resize_behavior,
+      Some(ResizeBehavior::KeepOrigin)
+    );
+
+    printer.dynamic_print("abd".to_string()).unwrap();
+    let frame_stats = printer.get_last_frame_stats().unwrap();
+
+    assert_eq!(frame_stats.resize_behavior, None);
+    assert_eq!(frame_stats.changed_cells, 1);
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "   abd   "
+    );
+
+    printer.dynamic_print("ab".to_string()).unwrap();
+
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "  ab     "
+    );
+  }
+
+  #[test]
+  fn grids_that_no_longer_fit_are_recentered() {
+    let mut printer = get_printer_with_printed_grid(ResizeBehavior::KeepOrigin);
+    printer.replace_terminal_dimensions(Some((5, 1)));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    assert_eq!(
+      printer.get_last_frame_stats().unwrap().resize_behavior,
+      Some(ResizeBehavior::Recenter)
+    );
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      " abc     "
+    );
   }
 }
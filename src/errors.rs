@@ -1,9 +1,13 @@
+use std::{io, sync::Arc};
 use thiserror::Error;
 
 /// These are the possible ways the program can fail.
 ///
 /// Each error will contain 'ErrorData' which holds the
 /// expected and outcome results in the event of the error.
+///
+/// Failing to write to the terminal returns an [`Io`](PrintingError::Io) error containing the original
+/// [`io::Error`](std::io::Error), which can be used to detect things such as broken pipes.
 #[derive(Error, Debug, Clone)]
 pub enum PrintingError {
   #[error("Failed to create a grid as there were too many characters. Expected {}, got {}", .0.expected_character_count, .0.actual_character_count)]
@@ -13,17 +17,41 @@ pub enum PrintingError {
 
   #[error("Failed to obtain the dimensions of the terminal. Reason: {}", .0)]
   FailedToGetTerminalDimensions(String),
-  #[error("A grid larger than the terminal itself was passed in.")]
-  GridLargerThanTerminal,
+  #[error("Failed to obtain the position of the cursor. Reason: {}", .0)]
+  FailedToGetCursorPosition(String),
+  #[error("A grid larger than the terminal itself was passed in. The grid was {}x{}, while the terminal is {}x{}", .0.grid_dimensions.0, .0.grid_dimensions.1, .0.terminal_dimensions.0, .0.terminal_dimensions.1)]
+  GridLargerThanTerminal(DimensionsErrorData),
+  #[error("A grid larger than the bounding box it's printed in was passed in. The grid was {}x{}, while the box is {}x{}", .0.grid_dimensions.0, .0.grid_dimensions.1, .0.box_dimensions.0, .0.box_dimensions.1)]
+  GridLargerThanBoundingBox(BoundingBoxErrorData),
 
   #[error("A non rectangular grid was passed in.")]
   NonRectangularGrid,
+  #[error("The grid contains the control character {:?} in row {}, column {}.", .ch, .row, .column)]
+  InvalidCharacter { ch: char, row: usize, column: usize },
   #[error("Failed to obtain the stored dimensions of the grid.")]
   GridDimensionsNotDefined,
   #[error("Failed to obtain the stored terminal dimensions.")]
   TerminalDimensionsNotDefined,
   #[error("Failed to obtain the stored origin position.")]
   OriginNotDefined,
+
+  #[error("The sprite sheet is invalid at line {}. Reason: {}", .line, .reason)]
+  InvalidSpriteSheet { line: usize, reason: String },
+  #[error("No animation named {:?} is registered.", .0)]
+  AnimationNotFound(String),
+  #[error("No region named {:?} is registered.", .0)]
+  RegionNotFound(String),
+  #[error("Frames can't be played at a frame rate of 0.")]
+  InvalidFrameRate,
+
+  #[error("Failed to write to the terminal. Reason: {}", .0)]
+  Io(#[source] Arc<io::Error>),
+}
+
+impl From<io::Error> for PrintingError {
+  fn from(io_error: io::Error) -> Self {
+    Self::Io(Arc::new(io_error))
+  }
 }
 
 impl PartialEq for PrintingError {
@@ -51,3 +79,41 @@ impl LengthErrorData {
     }
   }
 }
+
+/// When printing a grid, the grid's dimensions were larger than the terminal's.
+///
+/// Both dimensions are stored as (width, height).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct DimensionsErrorData {
+  pub grid_dimensions: (usize, usize),
+  pub terminal_dimensions: (usize, usize),
+}
+
+impl DimensionsErrorData {
+  /// Creates a new DimensionsErrorData for the dimensions of the grid and terminal
+  pub(crate) fn new(grid_dimensions: (usize, usize), terminal_dimensions: (usize, usize)) -> Self {
+    Self {
+      grid_dimensions,
+      terminal_dimensions,
+    }
+  }
+}
+
+/// When printing a grid, the grid's dimensions were larger than the [`BoundingBox`](crate::printing_position::BoundingBox) it's printed in.
+///
+/// Both dimensions are stored as (width, height).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BoundingBoxErrorData {
+  pub grid_dimensions: (usize, usize),
+  pub box_dimensions: (usize, usize),
+}
+
+impl BoundingBoxErrorData {
+  /// Creates a new BoundingBoxErrorData for the dimensions of the grid and bounding box
+  pub(crate) fn new(grid_dimensions: (usize, usize), box_dimensions: (usize, usize)) -> Self {
+    Self {
+      grid_dimensions,
+      box_dimensions,
+    }
+  }
+}
@@ -0,0 +1,248 @@
+mod tests;
+
+use crate::ansi::{escape_sequence_length, is_style_sequence, ESCAPE, STYLE_RESET};
+use alloc::{format, string::String, string::ToString};
+use core::cmp::Ordering;
+use core::fmt::Debug;
+
+/// Produces the escape sequences the [`Printer`](crate::printer::Printer) uses to control the terminal.
+///
+/// The printer uses [`AnsiEmitter`](AnsiEmitter) unless another emitter is set with
+/// [`replace_escape_emitter`](crate::printer::Printer::replace_escape_emitter),
+/// which allows printing to terminals that don't understand ANSI escape sequences.
+///
+/// Positions start from (1, 1) at the top left of the terminal.
+///
+/// # Example
+/// ```
+/// use screen_printer::escape_emitter::EscapeEmitter;
+///
+/// /// Emits the cursor addressing of a VT52, which has no styling.
+/// #[derive(Debug)]
+/// struct Vt52Emitter;
+///
+/// impl EscapeEmitter for Vt52Emitter {
+///   fn move_cursor(&self, x: usize, y: usize) -> String {
+///     let row = char::from(31 + y as u8);
+///     let column = char::from(31 + x as u8);
+///
+///     format!("\x1BY{row}{column}")
+///   }
+///
+///   fn erase_screen(&self) -> String {
+///     "\x1BH\x1BJ".to_string()
+///   }
+///
+///   fn erase_line(&self) -> String {
+///     "\x1BK".to_string()
+///   }
+///
+///   fn reset_style(&self) -> String {
+///     String::new()
+///   }
+///
+///   fn style(&self, _sequence: &str) -> String {
+///     String::new()
+///   }
+/// }
+///
+/// assert_eq!(Vt52Emitter.move_cursor(1, 1), "\x1BY  ");
+/// assert_eq!(Vt52Emitter.next_row(3, 2), "\x1BY!\"");
+/// ```
+pub trait EscapeEmitter: Debug + Send {
+  /// Returns the sequence that moves the cursor to the given column and row.
+  fn move_cursor(&self, x: usize, y: usize) -> String;
+
+  /// Returns the sequence that moves the cursor from the given (x, y) position to the given column and row,
+  /// for when the cursor's position is known, such as after printing part of a row.
+  ///
+  /// Defaults to [`move_cursor`](EscapeEmitter::move_cursor).
+  fn move_cursor_from(&self, from: (usize, usize), x: usize, y: usize) -> String {
+    let _ = from;
+
+    self.move_cursor(x, y)
+  }
+
+  /// Returns the sequence that moves the cursor from the end of a printed row to the given column of the row below it, which is row y.
+  ///
+  /// Defaults to [`move_cursor`](EscapeEmitter::move_cursor).
+  fn next_row(&self, x: usize, y: usize) -> String {
+    self.move_cursor(x, y)
+  }
+
+  /// Returns the sequence that erases the entire screen.
+  fn erase_screen(&self) -> String;
+
+  /// Returns the sequence that erases from the cursor to the end of its row.
+  fn erase_line(&self) -> String;
+
+  /// Returns the sequence that resets all styling back to the terminal's default.
+  fn reset_style(&self) -> String;
+
+  /// Returns the sequence that scrolls everything on the terminal up by the given amount of rows, adding empty rows at the bottom.
+  ///
+  /// The cursor is on the last row of the terminal when this is printed.
+  /// Defaults to that many newlines, which scroll the terminal from the last row.
+  fn scroll_up(&self, rows: usize) -> String {
+    "\n".repeat(rows)
+  }
+
+  /// Returns the sequence that scrolls the rows from top to bottom up by the given amount of rows, adding empty rows at the bottom of them,
+  /// without affecting the rest of the terminal.
+  ///
+  /// The cursor is left at an unknown position.
+  /// Defaults to nothing, for terminals that can't scroll part of the screen, which has grids diffed as normal when their rows are shifted.
+  fn scroll_region_up(&self, top: usize, bottom: usize, rows: usize) -> String {
+    let _ = (top, bottom, rows);
+
+    String::new()
+  }
+
+  /// Returns the sequence that hides the terminal's cursor.
+  ///
+  /// Defaults to nothing, for terminals that can't hide their cursor.
+  fn hide_cursor(&self) -> String {
+    String::new()
+  }
+
+  /// Returns the sequence that shows the terminal's cursor.
+  ///
+  /// Defaults to nothing, for terminals that can't hide their cursor.
+  fn show_cursor(&self) -> String {
+    String::new()
+  }
+
+  /// Returns the sequence that stops the terminal from displaying anything printed until the update is ended.
+  ///
+  /// Defaults to nothing, for terminals without synchronized updates.
+  fn begin_synchronized_update(&self) -> String {
+    String::new()
+  }
+
+  /// Returns the sequence that displays everything printed since the update began.
+  ///
+  /// Defaults to nothing, for terminals without synchronized updates.
+  fn end_synchronized_update(&self) -> String {
+    String::new()
+  }
+
+  /// Returns the sequence to print in place of an ANSI style sequence found in a grid, such as `\x1B[31m`.
+  ///
+  /// Defaults to the sequence itself.
+  fn style(&self, sequence: &str) -> String {
+    sequence.to_string()
+  }
+}
+
+/// The default [`EscapeEmitter`](EscapeEmitter), which produces ANSI escape sequences.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct AnsiEmitter;
+
+impl EscapeEmitter for AnsiEmitter {
+  fn move_cursor(&self, x: usize, y: usize) -> String {
+    format!("\x1B[{y};{x}H")
+  }
+
+  /// Picks the shortest of moving to the absolute position, moving to the column within the same row,
+  /// or moving forward or back by the distance between the columns.
+  fn move_cursor_from(&self, (from_x, from_y): (usize, usize), x: usize, y: usize) -> String {
+    let absolute_movement = self.move_cursor(x, y);
+
+    if from_y != y {
+      return absolute_movement;
+    }
+
+    let relative_movement = match x.cmp(&from_x) {
+      Ordering::Equal => return String::new(),
+      Ordering::Greater => relative_cursor_movement(x - from_x, 'C'),
+      Ordering::Less => relative_cursor_movement(from_x - x, 'D'),
+    };
+
+    [absolute_movement, format!("\x1B[{x}G"), relative_movement]
+      .into_iter()
+      .min_by_key(String::len)
+      .unwrap_or_default()
+  }
+
+  fn next_row(&self, x: usize, _y: usize) -> String {
+    format!("\x1B[1B\x1B[{x}G")
+  }
+
+  fn erase_screen(&self) -> String {
+    "\x1B[2J".to_string()
+  }
+
+  fn erase_line(&self) -> String {
+    "\x1B[K".to_string()
+  }
+
+  fn reset_style(&self) -> String {
+    STYLE_RESET.to_string()
+  }
+
+  fn scroll_up(&self, rows: usize) -> String {
+    if rows == 0 {
+      return String::new();
+    }
+
+    format!("\x1B[{rows}S")
+  }
+
+  fn scroll_region_up(&self, top: usize, bottom: usize, rows: usize) -> String {
+    format!("\x1B[{top};{bottom}r\x1B[{rows}S\x1B[r")
+  }
+
+  fn hide_cursor(&self) -> String {
+    "\x1B[?25l".to_string()
+  }
+
+  fn show_cursor(&self) -> String {
+    "\x1B[?25h".to_string()
+  }
+
+  fn begin_synchronized_update(&self) -> String {
+    "\x1B[?2026h".to_string()
+  }
+
+  fn end_synchronized_update(&self) -> String {
+    "\x1B[?2026l".to_string()
+  }
+}
+
+/// Returns the sequence that moves the cursor by the given distance in the direction of the given final character,
+/// leaving out the distance when it's 1.
+fn relative_cursor_movement(distance: usize, direction: char) -> String {
+  if distance == 1 {
+    format!("\x1B[{direction}")
+  } else {
+    format!("\x1B[{distance}{direction}")
+  }
+}
+
+/// Replaces every style sequence in the text with what the emitter prints in its place.
+///
+/// Escape sequences that aren't style sequences are left as they are.
+pub(crate) fn translate_styles(text: &str, emitter: &dyn EscapeEmitter) -> String {
+  let mut translated_text = String::with_capacity(text.len());
+  let mut remaining_text = text;
+
+  while let Some(escape_index) = remaining_text.find(ESCAPE) {
+    translated_text.push_str(&remaining_text[..escape_index]);
+
+    remaining_text = &remaining_text[escape_index..];
+    let sequence_length = escape_sequence_length(remaining_text);
+    let sequence = &remaining_text[..sequence_length];
+
+    if is_style_sequence(sequence) {
+      translated_text.push_str(&emitter.style(sequence));
+    } else {
+      translated_text.push_str(sequence);
+    }
+
+    remaining_text = &remaining_text[sequence_length..];
+  }
+
+  translated_text.push_str(remaining_text);
+
+  translated_text
+}
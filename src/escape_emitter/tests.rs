@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::*;
+use crate::change_run::ChangeRun;
+#[cfg(feature = "std")]
+use crate::printer::Printer;
+
+/// Emits readable placeholders in place of escape sequences.
+#[derive(Debug)]
+struct PlaceholderEmitter;
+
+impl EscapeEmitter for PlaceholderEmitter {
+  fn move_cursor(&self, x: usize, y: usize) -> String {
+    format!("<{x},{y}>")
+  }
+
+  fn erase_screen(&self) -> String {
+    "<erase screen>".to_string()
+  }
+
+  fn erase_line(&self) -> String {
+    "<erase line>".to_string()
+  }
+
+  fn reset_style(&self) -> String {
+    "<reset>".to_string()
+  }
+
+  fn style(&self, sequence: &str) -> String {
+    format!("<style {}>", &sequence[2..sequence.len() - 1])
+  }
+}
+
+#[cfg(test)]
+mod emitter_tests {
+  use super::*;
+
+  #[test]
+  fn only_style_sequences_are_translated() {
+    let text = "a\x1B[31mb\x1B[2Kc\x1B[0m";
+
+    assert_eq!(
+      translate_styles(text, &PlaceholderEmitter),
+      "a<style 31>b\x1B[2Kc<style 0>"
+    );
+    assert_eq!(translate_styles(text, &AnsiEmitter), text);
+  }
+
+  #[test]
+  fn change_runs_use_the_emitter() {
+    let change_runs = ChangeRun::from_grids("abc\n123", "axc\n123");
+
+    assert_eq!(change_runs[0].to_printable_difference((3, 4)), "\x1B[4;4Hx");
+    assert_eq!(
+      change_runs[0].to_printable_difference_with_emitter((3, 4), &PlaceholderEmitter),
+      "<4,4>x"
+    );
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn printer_defaults_to_ansi() {
+    let mut printer = Printer::new();
+
+    assert_eq!(printer.get_escape_emitter().move_cursor(2, 3), "\x1B[3;2H");
+
+    printer.replace_escape_emitter(PlaceholderEmitter);
+
+    assert_eq!(printer.get_escape_emitter().move_cursor(2, 3), "<2,3>");
+    assert!(printer.state().full_reprint_pending);
+  }
+
+  #[test]
+  fn shortest_cursor_movement_is_picked() {
+    assert_eq!(AnsiEmitter.move_cursor_from((3, 2), 3, 2), "");
+    assert_eq!(AnsiEmitter.move_cursor_from((3, 2), 4, 2), "\x1B[C");
+    assert_eq!(AnsiEmitter.move_cursor_from((13, 2), 12, 2), "\x1B[D");
+    assert_eq!(AnsiEmitter.move_cursor_from((3, 2), 15, 2), "\x1B[15G");
+    assert_eq!(AnsiEmitter.move_cursor_from((3, 2), 5, 4), "\x1B[4;5H");
+  }
+
+  #[test]
+  fn movements_from_a_position_default_to_moving_the_cursor() {
+    assert_eq!(PlaceholderEmitter.move_cursor_from((1, 1), 2, 1), "<2,1>");
+  }
+
+  #[test]
+  fn scrolling_defaults_to_newlines() {
+    assert_eq!(PlaceholderEmitter.scroll_up(2), "\n\n");
+    assert_eq!(AnsiEmitter.scroll_up(2), "\x1B[2S");
+  }
+}
@@ -0,0 +1,145 @@
+//! Keeping track of which [`Region`](crate::region::Region) has focus, and highlighting it when printed.
+
+mod tests;
+
+use crate::color::Color;
+
+/// The style sequence that swaps the foreground and background colors.
+const INVERTED_STYLE: &str = "\x1B[7m";
+
+/// How the focused [`Region`](crate::region::Region) is highlighted when [`printing regions`](crate::printer::Printer::print_regions).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum FocusHighlight {
+  /// Every cell of the region has its foreground and background colors swapped.
+  #[default]
+  Inverted,
+  /// Every cell of the region has its background replaced with the given color.
+  Background(Color),
+  /// A border is drawn over the outermost cells of the region, in the given color if any.
+  ///
+  /// Since the border covers the edges of the region, regions highlighted this way should leave room around their grid,
+  /// such as by aligning a smaller grid to the center of the region.
+  Border(Option<Color>),
+}
+
+impl FocusHighlight {
+  /// Returns the style sequence applied to every cell of the highlighted region, which is empty for borders.
+  pub(crate) fn cell_style(&self) -> String {
+    match self {
+      Self::Inverted => INVERTED_STYLE.to_string(),
+      Self::Background(Color { red, green, blue }) => format!("\x1B[48;2;{red};{green};{blue}m"),
+      Self::Border(_) => String::new(),
+    }
+  }
+}
+
+/// Tracks which of a list of [`Regions`](crate::region::Region) has focus, such as for moving between panels with the keyboard.
+///
+/// Set with [`Printer::replace_focus_manager`](crate::printer::Printer::replace_focus_manager),
+/// after which the focused region is highlighted with the [`FocusHighlight`](FocusHighlight) whenever regions are printed.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((6, 1));
+/// let layout = Layout::horizontal(vec![Constraint::Remainder, Constraint::Remainder]);
+/// printer.layout_regions(&layout, &["left", "right"]).unwrap();
+/// printer.replace_focus_manager(Some(FocusManager::new(vec!["left", "right"])));
+///
+/// // Tab was pressed.
+/// printer.focus_manager().unwrap().focus_next();
+/// printer.print_regions().unwrap();
+///
+/// assert_eq!(printer.focus_manager().unwrap().get_focused(), Some("right"));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct FocusManager {
+  order: Vec<String>,
+  focused: Option<usize>,
+  highlight: FocusHighlight,
+}
+
+impl FocusManager {
+  /// Creates a focus manager that moves between the regions with the given names in order, focusing the first.
+  pub fn new<S: Into<String>>(order: Vec<S>) -> Self {
+    let order: Vec<String> = order.into_iter().map(Into::into).collect();
+
+    Self {
+      focused: (!order.is_empty()).then_some(0),
+      order,
+      highlight: FocusHighlight::default(),
+    }
+  }
+
+  /// Replaces how the focused region is highlighted.
+  pub fn with_highlight(mut self, highlight: FocusHighlight) -> Self {
+    self.highlight = highlight;
+
+    self
+  }
+
+  /// Returns how the focused region is highlighted.
+  pub fn get_highlight(&self) -> FocusHighlight {
+    self.highlight
+  }
+
+  /// Focuses the region with the given name, returning false if it isn't one of the regions moved between.
+  pub fn focus(&mut self, name: &str) -> bool {
+    match self
+      .order
+      .iter()
+      .position(|region_name| region_name == name)
+    {
+      Some(index) => {
+        self.focused = Some(index);
+
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Removes focus from every region, so none are highlighted.
+  pub fn clear_focus(&mut self) {
+    self.focused = None;
+  }
+
+  /// Focuses the region after the focused one, wrapping around to the first.
+  pub fn focus_next(&mut self) {
+    if self.order.is_empty() {
+      return;
+    }
+
+    self.focused = Some(
+      self
+        .focused
+        .map_or(0, |focused| (focused + 1) % self.order.len()),
+    );
+  }
+
+  /// Focuses the region before the focused one, wrapping around to the last.
+  pub fn focus_previous(&mut self) {
+    if self.order.is_empty() {
+      return;
+    }
+
+    let last = self.order.len() - 1;
+
+    self.focused = Some(
+      self
+        .focused
+        .map_or(last, |focused| focused.checked_sub(1).unwrap_or(last)),
+    );
+  }
+
+  /// Returns the name of the focused region, if any.
+  pub fn get_focused(&self) -> Option<&str> {
+    self.focused.map(|focused| self.order[focused].as_str())
+  }
+
+  /// Returns true if the region with the given name is focused.
+  pub fn is_focused(&self, name: &str) -> bool {
+    self.get_focused() == Some(name)
+  }
+}
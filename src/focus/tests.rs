@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod focus_manager_tests {
+  use super::*;
+
+  #[test]
+  fn focus_wraps_around_in_both_directions() {
+    let mut focus_manager = FocusManager::new(vec!["a", "b", "c"]);
+
+    focus_manager.focus_previous();
+    assert_eq!(focus_manager.get_focused(), Some("c"));
+
+    focus_manager.focus_next();
+    assert_eq!(focus_manager.get_focused(), Some("a"));
+  }
+
+  #[test]
+  fn regions_are_focused_by_name() {
+    let mut focus_manager = FocusManager::new(vec!["a", "b"]);
+
+    assert!(focus_manager.focus("b"));
+    assert!(!focus_manager.focus("missing"));
+    assert!(focus_manager.is_focused("b"));
+
+    focus_manager.clear_focus();
+    assert_eq!(focus_manager.get_focused(), None);
+
+    focus_manager.focus_next();
+    assert_eq!(focus_manager.get_focused(), Some("a"));
+  }
+
+  #[test]
+  fn empty_managers_never_focus() {
+    let mut focus_manager = FocusManager::new(Vec::<String>::new());
+    focus_manager.focus_next();
+    focus_manager.focus_previous();
+
+    assert_eq!(focus_manager.get_focused(), None);
+  }
+}
+
+#[cfg(test)]
+mod focus_highlight_tests {
+  use crate::printer::*;
+
+  fn printer_with_focus(highlight: FocusHighlight) -> Printer {
+    let mut printer = Printer::on_virtual_terminal((4, 1));
+    printer
+      .region("left", BoundingBox::new((1, 1), (2, 1)))
+      .replace_grid("ab".to_string());
+    printer
+      .region("right", BoundingBox::new((3, 1), (2, 1)))
+      .replace_grid("cd".to_string());
+    printer.replace_focus_manager(Some(
+      FocusManager::new(vec!["left", "right"]).with_highlight(highlight),
+    ));
+
+    printer
+  }
+
+  #[test]
+  fn only_the_focused_region_is_highlighted() {
+    let mut printer = printer_with_focus(FocusHighlight::Inverted);
+    printer.focus_manager().unwrap().focus_next();
+
+    printer.print_regions().unwrap();
+    let screen = printer.virtual_screen().unwrap();
+
+    assert_eq!(screen.plain_screen(), "abcd");
+    assert!(!screen.screen().contains("\x1B[7ma"));
+    assert!(screen.screen().contains("\x1B[7mc"));
+  }
+
+  #[test]
+  fn background_highlights_use_the_given_color() {
+    let mut printer = printer_with_focus(FocusHighlight::Background(Color::new(1, 2, 3)));
+
+    printer.print_regions().unwrap();
+
+    assert!(printer
+      .virtual_screen()
+      .unwrap()
+      .screen()
+      .contains("\x1B[48;2;1;2;3ma"));
+  }
+
+  #[test]
+  fn borders_are_drawn_over_the_edges_of_the_region() {
+    let mut printer = Printer::on_virtual_terminal((3, 3));
+    printer
+      .region(
+        "box",
+        BoundingBox::new((1, 1), (3, 3)).with_alignment(Alignment::Center),
+      )
+      .replace_grid("x".to_string());
+    printer.replace_focus_manager(Some(
+      FocusManager::new(vec!["box"]).with_highlight(FocusHighlight::Border(None)),
+    ));
+
+    printer.print_regions().unwrap();
+
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "┌─┐\n│x│\n└─┘"
+    );
+  }
+
+  #[test]
+  fn nothing_is_highlighted_without_focus() {
+    let mut printer = printer_with_focus(FocusHighlight::Inverted);
+    printer.focus_manager().unwrap().clear_focus();
+
+    printer.print_regions().unwrap();
+
+    assert!(!printer
+      .virtual_screen()
+      .unwrap()
+      .screen()
+      .contains("\x1B[7m"));
+  }
+}
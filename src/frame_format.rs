@@ -0,0 +1,383 @@
+//! The compact binary format for the difference between two frames, for sending grids between processes.
+//!
+//! Each frame is stored as the [`ChangeRuns`](crate::change_run::ChangeRun) from the frame before it,
+//! where repeated cells within a run are stored once along with how many times they repeat.
+//! This is what [`Recordings`](crate::recording::Recording) store their frames as, and can be used to send
+//! frames over any other transport, such as a socket to a process printing them somewhere else.
+//!
+//! All numbers are little endian, and all text is UTF-8 prefixed by its length in bytes as a `u32`.
+//!
+//! ```text
+//! header:
+//!   magic        4 bytes, "SPFD"
+//!   version      u8, currently 1
+//! body:
+//!   width        u32, the width of the new frame
+//!   height       u32, the height of the new frame
+//!   run count    u32
+//!   runs:
+//!     x          u32, the column the run starts in
+//!     y          u32, the row the run is in
+//!     cell runs  u32, the amount of cell runs that follow
+//!     cell runs:
+//!       count    u32, how many times the cell repeats
+//!       style    text, every style escape sequence that applies to the cell, empty for unstyled cells
+//!       text     text, the characters of the cell
+//! ```
+//!
+//! The new frame starts as the frame before it, cut down or padded with spaces to the width and height,
+//! after which the cells of every run are placed over it.
+//! Frames with more than [`MAX_FRAME_CELLS`](MAX_FRAME_CELLS) cells, or runs with more cells than the frame, are rejected when decoding.
+//! Frames are expected to be rectangular, the same as any grid printed with
+//! [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+
+mod tests;
+
+use crate::cells::{measure_grid, measure_row_width, split_grid_into_cells, Cell, EmojiMode};
+use crate::change_run::ChangeRun;
+use crate::errors::PrintingError;
+use crate::style::StyledTextBuilder;
+use std::io::{self, Read, Write};
+use std::rc::Rc;
+
+const MAGIC: &[u8; 4] = b"SPFD";
+const VERSION: u8 = 1;
+/// The most cells a decoded frame can have, which is far larger than any terminal,
+/// so corrupted dimensions can't make [`apply`](FrameDiff::apply) allocate an enormous grid.
+pub const MAX_FRAME_CELLS: usize = 1 << 22;
+
+/// The difference between two frames, as the [`ChangeRuns`](crate::change_run::ChangeRun) that turn the old frame into the new one.
+///
+/// Frame diffs are written in the format described in the [`frame_format`](crate::frame_format) module with [`encode`](FrameDiff::encode),
+/// and turned back into the new frame on the other end with [`decode`](FrameDiff::decode) and [`apply`](FrameDiff::apply).
+///
+/// # Example
+/// ```
+/// use screen_printer::frame_format::FrameDiff;
+///
+/// let old_grid = "abc\n123";
+/// let new_grid = "abc\n1xx";
+///
+/// let bytes = FrameDiff::from_grids(old_grid, new_grid).encode().unwrap();
+///
+/// // On the other end of the transport.
+/// let frame_diff = FrameDiff::decode(&bytes).unwrap();
+///
+/// assert_eq!(frame_diff.apply(old_grid), new_grid);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct FrameDiff {
+  /// The (width, height) of the new frame.
+  pub dimensions: (usize, usize),
+  /// Every run of cells that differs between the old and new frame.
+  pub change_runs: Vec<ChangeRun>,
+}
+
+impl FrameDiff {
+  /// Creates a frame diff that places the change runs over a frame to make one of the given (width, height).
+  pub fn new(dimensions: (usize, usize), change_runs: Vec<ChangeRun>) -> Self {
+    Self {
+      dimensions,
+      change_runs,
+    }
+  }
+
+  /// Returns the difference between the old and new frame.
+  ///
+  /// The difference from an empty grid contains the entirety of the new frame, which is what the first frame sent should be.
+  pub fn from_grids(old_grid: &str, new_grid: &str) -> Self {
+    Self::new(
+      measure_grid(new_grid),
+      ChangeRun::from_grids(old_grid, new_grid),
+    )
+  }
+
+  /// Returns the frame diff in the format described in the [`frame_format`](crate::frame_format) module.
+  ///
+  /// # Errors
+  ///
+  /// - A position or length is too large to be written as a `u32`.
+  pub fn encode(&self) -> Result<Vec<u8>, PrintingError> {
+    let mut bytes = Vec::new();
+    self.write_to(&mut bytes)?;
+
+    Ok(bytes)
+  }
+
+  /// Returns the frame diff from bytes created with [`encode`](FrameDiff::encode).
+  ///
+  /// # Errors
+  ///
+  /// - The data isn't a frame diff, or is of a newer version.
+  /// - The frame has more than [`MAX_FRAME_CELLS`](MAX_FRAME_CELLS) cells.
+  /// - A run is outside of the frame.
+  pub fn decode(bytes: &[u8]) -> Result<Self, PrintingError> {
+    Self::read_from(bytes)
+  }
+
+  /// Writes the frame diff in the format described in the [`frame_format`](crate::frame_format) module.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the writer failed.
+  /// - A position or length is too large to be written as a `u32`.
+  pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), PrintingError> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION])?;
+    self.write_body(&mut writer)?;
+
+    Ok(())
+  }
+
+  /// Reads a frame diff written with [`write_to`](FrameDiff::write_to).
+  ///
+  /// # Errors
+  ///
+  /// - Reading from the reader failed.
+  /// - The data isn't a frame diff, or is of a newer version.
+  /// - The frame has more than [`MAX_FRAME_CELLS`](MAX_FRAME_CELLS) cells.
+  /// - A run is outside of the frame.
+  pub fn read_from<R: Read>(mut reader: R) -> Result<Self, PrintingError> {
+    let mut header = [0; 5];
+    reader.read_exact(&mut header)?;
+
+    if &header[..4] != MAGIC {
+      return Err(invalid_data("The data isn't a frame diff.").into());
+    }
+
+    if header[4] != VERSION {
+      return Err(invalid_data("The frame diff is of an unsupported version.").into());
+    }
+
+    Ok(Self::read_body(&mut reader)?)
+  }
+
+  /// Writes the dimensions and every run of the frame diff, without a header.
+  pub(crate) fn write_body<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+    write_u32(writer, self.dimensions.0)?;
+    write_u32(writer, self.dimensions.1)?;
+    write_u32(writer, self.change_runs.len())?;
+
+    for change_run in &self.change_runs {
+      let cell_runs = run_length_encode(&change_run.text);
+
+      write_u32(writer, change_run.start.0)?;
+      write_u32(writer, change_run.start.1)?;
+      write_u32(writer, cell_runs.len())?;
+
+      for (count, cell) in cell_runs {
+        write_u32(writer, count)?;
+        write_text(writer, &cell.style)?;
+        write_text(writer, cell.text)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Reads the dimensions and every run of a frame diff, without a header.
+  pub(crate) fn read_body<R: Read>(reader: &mut R) -> io::Result<Self> {
+    let dimensions = (read_u32(reader)?, read_u32(reader)?);
+    let frame_cells = dimensions
+      .0
+      .checked_mul(dimensions.1)
+      .filter(|&frame_cells| frame_cells <= MAX_FRAME_CELLS)
+      .ok_or_else(|| invalid_data("The frame is too large."))?;
+    let mut change_runs = Vec::new();
+    let mut total_cell_count: usize = 0;
+
+    for _ in 0..read_u32(reader)? {
+      let start = (read_u32(reader)?, read_u32(reader)?);
+      let mut cell_runs = Vec::new();
+      let mut cell_count: usize = 0;
+
+      for _ in 0..read_u32(reader)? {
+        let count = read_u32(reader)?;
+        let style: Rc<str> = read_text(reader)?.into();
+        let text = read_text(reader)?;
+        cell_count = cell_count.saturating_add(count);
+        total_cell_count = total_cell_count.saturating_add(count);
+
+        // Checked before the cells are repeated, so corrupted counts can't build enormous runs,
+        // whether it's a single run or many runs placed over each other.
+        if start.0.saturating_add(cell_count) > dimensions.0 || start.1 >= dimensions.1 {
+          return Err(invalid_data("A change run is outside of the frame."));
+        }
+
+        if total_cell_count > frame_cells {
+          return Err(invalid_data(
+            "The change runs have more cells than the frame.",
+          ));
+        }
+
+        cell_runs.push((count, style, text));
+      }
+
+      let cells: Vec<Cell> = cell_runs
+        .iter()
+        .flat_map(|(count, style, text)| {
+          let cell = Cell {
+            text,
+            width: measure_row_width(text),
+            style: style.clone(),
+          };
+
+          std::iter::repeat_n(cell, *count)
+        })
+        .collect();
+      let mut run_text = StyledTextBuilder::default();
+      cells.iter().for_each(|cell| run_text.push(cell));
+
+      change_runs.push(ChangeRun::new(start, run_text.finish()));
+    }
+
+    Ok(Self::new(dimensions, change_runs))
+  }
+
+  /// Returns the new frame, by placing every run over the old frame.
+  ///
+  /// The old frame is cut down or padded with spaces to the dimensions of the new frame beforehand,
+  /// and any wide character partially covered by a run is replaced with spaces.
+  ///
+  /// This allocates the entire new frame, which decoding keeps within [`MAX_FRAME_CELLS`](MAX_FRAME_CELLS) cells.
+  pub fn apply(&self, old_grid: &str) -> String {
+    let (width, height) = self.dimensions;
+    let blank_cell = Cell {
+      text: " ",
+      width: 1,
+      style: "".into(),
+    };
+    let mut rows: Vec<Vec<Option<Cell>>> = split_grid_into_cells(old_grid, EmojiMode::default())
+      .into_iter()
+      .map(|row| {
+        let mut columns = cells_by_column(row);
+        columns.resize(width.max(columns.len()), Some(blank_cell.clone()));
+
+        columns
+      })
+      .collect();
+    rows.resize(height, vec![Some(blank_cell.clone()); width]);
+
+    for change_run in &self.change_runs {
+      let Some(row) = rows.get_mut(change_run.start.1) else {
+        continue;
+      };
+      let run_cells = split_grid_into_cells(&change_run.text, EmojiMode::default());
+      let mut x = change_run.start.0;
+
+      for cell in run_cells.into_iter().flatten() {
+        if x + cell.width > row.len() {
+          row.resize(x + cell.width, Some(blank_cell.clone()));
+        }
+
+        (x..x + cell.width).for_each(|column| blank_partial_cell(row, column, &blank_cell));
+        let cell_width = cell.width;
+        row[x] = Some(cell);
+        (x + 1..x + cell_width).for_each(|column| row[column] = None);
+        x += cell_width;
+      }
+    }
+
+    rows
+      .iter_mut()
+      .map(|row| {
+        row.truncate(width);
+        (0..width).for_each(|column| {
+          // Wide characters that would be cut off at the edge of the frame are replaced with spaces.
+          if row[column]
+            .as_ref()
+            .is_some_and(|cell| column + cell.width > width)
+          {
+            blank_partial_cell(row, column, &blank_cell);
+          }
+        });
+
+        let mut row_text = StyledTextBuilder::default();
+        row.iter().flatten().for_each(|cell| row_text.push(cell));
+
+        row_text.finish()
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
+
+/// Returns every cell of the text, grouped with how many times in a row it repeats.
+fn run_length_encode(text: &str) -> Vec<(usize, Cell<'_>)> {
+  let mut cell_runs: Vec<(usize, Cell)> = Vec::new();
+
+  for cell in split_grid_into_cells(text, EmojiMode::default())
+    .into_iter()
+    .flatten()
+  {
+    match cell_runs.last_mut() {
+      Some((count, last_cell)) if *last_cell == cell => *count += 1,
+      _ => cell_runs.push((1, cell)),
+    }
+  }
+
+  cell_runs
+}
+
+/// Returns a list of every column in the row, containing the cell that starts in that column.
+///
+/// Columns covered by the rest of a wide cell are None.
+fn cells_by_column(cells: Vec<Cell>) -> Vec<Option<Cell>> {
+  cells
+    .into_iter()
+    .flat_map(|cell| {
+      let covered_columns = cell.width.saturating_sub(1);
+
+      std::iter::once(Some(cell)).chain(std::iter::repeat_n(None, covered_columns))
+    })
+    .collect()
+}
+
+/// Replaces the wide cell covering the given column with spaces, so that part of it can be printed over.
+fn blank_partial_cell<'a>(row: &mut [Option<Cell<'a>>], column: usize, blank_cell: &Cell<'a>) {
+  let Some(start) = (0..=column).rev().find(|&start| row[start].is_some()) else {
+    return;
+  };
+  let end = start + row[start].as_ref().map_or(1, |cell| cell.width);
+
+  if end - start > 1 {
+    let end = end.min(row.len());
+    row[start..end].fill(Some(blank_cell.clone()));
+  }
+}
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+pub(crate) fn write_u32<W: Write>(writer: &mut W, value: usize) -> io::Result<()> {
+  let value =
+    u32::try_from(value).map_err(|_| invalid_data("A value is too large to be written."))?;
+
+  writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn write_text<W: Write>(writer: &mut W, text: &str) -> io::Result<()> {
+  write_u32(writer, text.len())?;
+
+  writer.write_all(text.as_bytes())
+}
+
+pub(crate) fn read_u32<R: Read>(reader: &mut R) -> io::Result<usize> {
+  let mut bytes = [0; 4];
+  reader.read_exact(&mut bytes)?;
+
+  Ok(u32::from_le_bytes(bytes) as usize)
+}
+
+pub(crate) fn read_text<R: Read>(reader: &mut R) -> io::Result<String> {
+  let length = read_u32(reader)?;
+  let mut bytes = Vec::new();
+  reader.take(length as u64).read_to_end(&mut bytes)?;
+
+  if bytes.len() != length {
+    return Err(io::ErrorKind::UnexpectedEof.into());
+  }
+
+  String::from_utf8(bytes).map_err(|_| invalid_data("Text in the data isn't valid UTF-8."))
+}
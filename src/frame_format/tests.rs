@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod frame_diff_tests {
+  use super::*;
+
+  /// Returns the frame after sending the difference between the frames through the format.
+  fn send(old_grid: &str, new_grid: &str) -> String {
+    let bytes = FrameDiff::from_grids(old_grid, new_grid).encode().unwrap();
+
+    FrameDiff::decode(&bytes).unwrap().apply(old_grid)
+  }
+
+  #[test]
+  fn frames_round_trip() {
+    for (old_grid, new_grid) in [
+      ("", "abc\n123"),
+      ("abc\n123", "abc\n1x3"),
+      ("abc\n123", "ab"),
+      ("ab", "abc\n123\nxyz"),
+      ("abc", "\x1B[31mab\x1B[0mc"),
+      ("a字b", "字字"),
+    ] {
+      assert_eq!(send(old_grid, new_grid), new_grid);
+    }
+  }
+
+  #[test]
+  fn repeated_cells_are_stored_once() {
+    let plain_bytes = FrameDiff::from_grids("", &"a".repeat(1000))
+      .encode()
+      .unwrap();
+    let styled_grid = format!("\x1B[31m{}\x1B[0m", "#".repeat(1000));
+    let styled_bytes = FrameDiff::from_grids("", &styled_grid).encode().unwrap();
+
+    assert!(plain_bytes.len() < 50);
+    assert!(styled_bytes.len() < 50);
+    assert_eq!(
+      FrameDiff::decode(&styled_bytes).unwrap().apply(""),
+      styled_grid
+    );
+  }
+
+  #[test]
+  fn wide_characters_partially_covered_are_replaced_with_spaces() {
+    let frame_diff = FrameDiff::new((4, 1), vec![ChangeRun::new((1, 0), "x")]);
+
+    assert_eq!(frame_diff.apply("字ab"), " xab");
+  }
+
+  #[test]
+  fn runs_outside_of_the_frame_are_rejected() {
+    let bytes = FrameDiff::new((2, 1), vec![ChangeRun::new((1, 0), "abc")])
+      .encode()
+      .unwrap();
+
+    assert!(matches!(
+      FrameDiff::decode(&bytes),
+      Err(PrintingError::Io(_))
+    ));
+  }
+
+  #[test]
+  fn oversized_frames_are_rejected() {
+    let mut bytes = b"SPFD\x01".to_vec();
+    bytes.extend([100_000u32, 100_000, 0].map(u32::to_le_bytes).concat());
+
+    assert!(matches!(
+      FrameDiff::decode(&bytes),
+      Err(PrintingError::Io(error)) if error.kind() == io::ErrorKind::InvalidData
+    ));
+  }
+
+  #[test]
+  fn runs_with_more_cells_than_the_frame_are_rejected() {
+    let mut bytes = b"SPFD\x01".to_vec();
+    bytes.extend([4u32, 1, 3].map(u32::to_le_bytes).concat());
+
+    // Three runs covering the entire row, which is more cells than the frame holds.
+    for _ in 0..3 {
+      bytes.extend([0u32, 0, 1, 4, 0, 1].map(u32::to_le_bytes).concat());
+      bytes.extend(b"a");
+    }
+
+    assert!(matches!(
+      FrameDiff::decode(&bytes),
+      Err(PrintingError::Io(error)) if error.kind() == io::ErrorKind::InvalidData
+    ));
+  }
+
+  #[test]
+  fn other_data_is_rejected() {
+    assert!(matches!(
+      FrameDiff::decode(b"not a frame"),
+      Err(PrintingError::Io(_))
+    ));
+  }
+}
@@ -0,0 +1,78 @@
+mod tests;
+
+use std::time::Duration;
+
+/// Prints every frame in its entirety as plain text, for readable logs of what would've been displayed, such as in CI.
+///
+/// Each frame is printed once below the previous one, with a header line holding its number and optionally the time since the first frame,
+/// followed by the rows of the grid and a delimiter line. Nothing that controls the terminal is printed, including any styles,
+/// and frames the same as the previous one aren't printed again.
+///
+/// Set with [`replace_frame_log`](crate::printer::Printer::replace_frame_log).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((10, 8));
+/// printer.replace_frame_log(Some(FrameLog::new().with_timestamps(false)));
+///
+/// printer.dynamic_print("ab\ncd".to_string()).unwrap();
+/// printer.dynamic_print("ab\ncd".to_string()).unwrap();
+/// printer.dynamic_print("ab\nxy".to_string()).unwrap();
+///
+/// assert_eq!(
+///   printer.get_capture().unwrap().bytes(),
+///   b"Frame 1\nab\ncd\n---\nFrame 2\nab\nxy\n---\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FrameLog {
+  /// The line printed after every frame.
+  pub delimiter: String,
+  /// Whether the header of each frame has the time since the first frame was logged.
+  pub timestamps: bool,
+}
+
+impl Default for FrameLog {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl FrameLog {
+  /// Creates a frame log with timestamps, delimiting frames with `---`.
+  pub fn new() -> Self {
+    Self {
+      delimiter: "---".to_string(),
+      timestamps: true,
+    }
+  }
+
+  /// Replaces the line printed after every frame.
+  pub fn with_delimiter<S: Into<String>>(mut self, delimiter: S) -> Self {
+    self.delimiter = delimiter.into();
+
+    self
+  }
+
+  /// Sets whether the header of each frame has the time since the first frame was logged.
+  pub fn with_timestamps(mut self, timestamps: bool) -> Self {
+    self.timestamps = timestamps;
+
+    self
+  }
+
+  /// Returns the frame with its header and delimiter, given its number starting from 1 and the time since the first frame.
+  ///
+  /// The grid is expected to have no escape sequences.
+  pub(crate) fn format_frame(&self, grid: &str, frame_number: usize, elapsed: Duration) -> String {
+    let header = if self.timestamps {
+      format!("Frame {frame_number} (+{:.3}s)", elapsed.as_secs_f64())
+    } else {
+      format!("Frame {frame_number}")
+    };
+
+    format!("{header}\n{grid}\n{}\n", self.delimiter)
+  }
+}
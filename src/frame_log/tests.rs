@@ -0,0 +1,30 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod format_frame_tests {
+  use super::*;
+
+  #[test]
+  fn header_has_the_number_and_time() {
+    let frame_log = FrameLog::new();
+
+    assert_eq!(
+      frame_log.format_frame("ab\ncd", 3, Duration::from_millis(1250)),
+      "Frame 3 (+1.250s)\nab\ncd\n---\n"
+    );
+  }
+
+  #[test]
+  fn delimiter_is_replaced() {
+    let frame_log = FrameLog::new()
+      .with_timestamps(false)
+      .with_delimiter("=====");
+
+    assert_eq!(
+      frame_log.format_frame("ab", 1, Duration::ZERO),
+      "Frame 1\nab\n=====\n"
+    );
+  }
+}
@@ -0,0 +1,328 @@
+mod tests;
+
+use crate::printing_position::ResizeBehavior;
+#[cfg(feature = "profiling")]
+use std::time::Instant;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The fraction of cells a frame can change while still being considered barely changed, unless replaced.
+const DEFAULT_CHANGE_THRESHOLD: f32 = 0.01;
+
+/// How much of the grid changed in a single print.
+///
+/// Obtained from [`Printer::get_last_frame_stats`](crate::printer::Printer::get_last_frame_stats).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::deterministic();
+///
+/// printer.dynamic_print("abcd\n1234".to_string()).unwrap();
+/// printer.dynamic_print("abcd\n12x4".to_string()).unwrap();
+///
+/// let frame_stats = printer.get_last_frame_stats().unwrap();
+///
+/// assert_eq!(frame_stats.changed_cells, 1);
+/// assert_eq!(frame_stats.change_fraction(), 0.125);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct FrameStats {
+  /// The amount of columns of the grid that were printed.
+  pub changed_cells: usize,
+  /// The amount of columns in the entire grid.
+  pub total_cells: usize,
+  /// Whether the entire grid was printed rather than only what changed.
+  pub full_reprint: bool,
+  /// How the frame was printed differently to stay within the [`byte budget`](crate::printer::Printer::replace_byte_budget), if it was.
+  pub budget_degradation: Option<BudgetDegradation>,
+  /// How the grid was placed when the terminal was resized since the previous frame, if it was.
+  pub resize_behavior: Option<ResizeBehavior>,
+  /// The time spent on each part of printing the frame.
+  #[cfg(feature = "profiling")]
+  pub phase_timings: PhaseTimings,
+}
+
+/// The time spent on each part of printing a frame, with the `profiling` feature.
+///
+/// Obtained from the [`FrameStats`](FrameStats) of a frame.
+///
+/// # Example
+/// ```rust,no_run
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::new();
+///
+/// printer.dynamic_print("abcd\n1234".to_string()).unwrap();
+///
+/// let phase_timings = printer.get_last_frame_stats().unwrap().phase_timings;
+///
+/// if phase_timings.writing > phase_timings.diffing {
+///   println!("Printing is limited by the terminal.");
+/// }
+/// ```
+#[cfg(feature = "profiling")]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct PhaseTimings {
+  /// The time spent preparing the grid and checking that it's valid, which includes normalization.
+  pub validation: Duration,
+  /// The time spent finding the difference from the previous grid.
+  pub diffing: Duration,
+  /// The time spent creating the cursor movements and escape sequences for what's printed.
+  pub escape_generation: Duration,
+  /// The time spent writing to and flushing the terminal.
+  pub writing: Duration,
+}
+
+/// The parts of printing a frame that are timed with the `profiling` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Phase {
+  Validation,
+  Diffing,
+  EscapeGeneration,
+  Writing,
+}
+
+/// Times a [`Phase`](Phase) of printing a frame, which does nothing without the `profiling` feature.
+///
+/// Stopped with [`Printer::record_phase`](crate::printer::Printer::record_phase).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PhaseTimer {
+  #[cfg(feature = "profiling")]
+  start_time: Instant,
+}
+
+impl PhaseTimer {
+  pub(crate) fn start() -> Self {
+    Self {
+      #[cfg(feature = "profiling")]
+      start_time: Instant::now(),
+    }
+  }
+}
+
+#[cfg(feature = "profiling")]
+impl PhaseTimings {
+  /// Adds the time since the timer started to the given phase.
+  pub(crate) fn add(&mut self, phase: Phase, timer: PhaseTimer) {
+    let elapsed = timer.start_time.elapsed();
+    let phase_time = match phase {
+      Phase::Validation => &mut self.validation,
+      Phase::Diffing => &mut self.diffing,
+      Phase::EscapeGeneration => &mut self.escape_generation,
+      Phase::Writing => &mut self.writing,
+    };
+
+    *phase_time += elapsed;
+  }
+}
+
+/// The ways a frame is printed when printing its difference would go over the [`byte budget`](crate::printer::Printer::replace_byte_budget).
+///
+/// Each is tried in order, until one fits within the budget.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BudgetDegradation {
+  /// Every changed row was printed in its entirety, which takes less cursor movement than printing each change on its own.
+  ///
+  /// This is skipped when the [`DiffGranularity`](crate::change_run::DiffGranularity) is already by row.
+  RowGranularity,
+  /// The entire grid was printed over itself.
+  FullReprint,
+  /// Nothing was printed, leaving the previous grid displayed.
+  ///
+  /// The next frame is printed based on what's still displayed, so nothing skipped is lost.
+  SkippedFrame,
+}
+
+impl FrameStats {
+  /// Returns the fraction of the grid that was printed, from 0.0 to 1.0.
+  ///
+  /// Empty grids are considered to have changed nothing.
+  pub fn change_fraction(&self) -> f32 {
+    if self.total_cells == 0 {
+      return 0.0;
+    }
+
+    self.changed_cells as f32 / self.total_cells as f32
+  }
+}
+
+/// Settings for lowering how often frames are printed while they barely change.
+///
+/// Every frame that changes no more than the change threshold doubles the [`refresh interval`](crate::printer::Printer::get_refresh_interval),
+/// up to the maximum interval. Any frame changing more than that goes straight back to the minimum interval.
+///
+/// Set with [`Printer::replace_adaptive_refresh`](crate::printer::Printer::replace_adaptive_refresh).
+///
+/// # Example
+/// ```rust,no_run
+/// use screen_printer::printer::*;
+/// use std::time::Duration;
+///
+/// let mut printer = Printer::new();
+/// printer.replace_adaptive_refresh(Some(AdaptiveRefresh::new(
+///   Duration::from_millis(16),
+///   Duration::from_secs(1),
+/// )));
+///
+/// loop {
+///   printer.dynamic_print("abc\n123".to_string()).unwrap();
+///
+///   std::thread::sleep(printer.get_refresh_interval().unwrap());
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AdaptiveRefresh {
+  /// The interval between frames while they keep changing.
+  pub min_interval: Duration,
+  /// The longest the interval between frames can grow to while they barely change.
+  pub max_interval: Duration,
+  /// The largest [`fraction`](FrameStats::change_fraction) of the grid a frame can change while still being considered barely changed.
+  pub change_threshold: f32,
+}
+
+impl AdaptiveRefresh {
+  /// Creates adaptive refresh settings between the given intervals, where frames changing no more than 1% of the grid are considered barely changed.
+  pub fn new(min_interval: Duration, max_interval: Duration) -> Self {
+    Self {
+      min_interval,
+      max_interval,
+      change_threshold: DEFAULT_CHANGE_THRESHOLD,
+    }
+  }
+
+  /// Replaces the largest fraction of the grid a frame can change while still being considered barely changed.
+  pub fn with_change_threshold(mut self, change_threshold: f32) -> Self {
+    self.change_threshold = change_threshold;
+
+    self
+  }
+
+  /// Returns the interval to wait before the next frame, after a frame with the given stats was printed
+  /// following the given interval.
+  pub(crate) fn next_interval(&self, interval: Duration, frame_stats: &FrameStats) -> Duration {
+    if frame_stats.change_fraction() > self.change_threshold {
+      return self.min_interval;
+    }
+
+    (interval * 2).clamp(self.min_interval, self.max_interval.max(self.min_interval))
+  }
+}
+
+/// How often the entire grid is reprinted, even when little changed, to repair anything else printed over it.
+///
+/// Things like other processes writing to the terminal, or a glitching connection, can corrupt what's displayed
+/// without the printer knowing. Reprinting everything on occasion fixes any such corruption.
+///
+/// The grid is reprinted in place without clearing it first, inside a synchronized update on terminals
+/// that [`support them`](crate::capabilities::TerminalCapabilities::synchronized_updates), so it doesn't flicker.
+///
+/// Set with [`Printer::replace_full_refresh_interval`](crate::printer::Printer::replace_full_refresh_interval).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FullRefreshInterval {
+  /// The grid is reprinted after the given amount of frames were printed without reprinting it.
+  Frames(usize),
+  /// The grid is reprinted on the first frame after the given amount of time passed since it was last reprinted.
+  Time(Duration),
+}
+
+impl FullRefreshInterval {
+  /// Returns true if the grid is due to be reprinted, given the amount of frames and time since it was last reprinted.
+  pub(crate) fn is_due(&self, frames_since_refresh: usize, time_since_refresh: Duration) -> bool {
+    match self {
+      Self::Frames(frames) => frames_since_refresh >= *frames,
+      Self::Time(time) => time_since_refresh >= *time,
+    }
+  }
+}
+
+/// Settings for repairing corruption of the printed grid that the printer can't detect, by reprinting parts of it on occasion.
+///
+/// Every row interval, a random row of the grid is reprinted as it should be displayed. When a frame interval is set,
+/// the entire grid is also reprinted in place after that long, which is meant to happen much less often.
+/// Every interval is randomly lengthened or shortened by up to the jitter fraction of itself, so repairs don't happen
+/// in a steady rhythm that could be noticed.
+///
+/// The watchdog only runs while grids are being printed, including grids skipped by [`idle suppression`](crate::printer::Printer::set_idle_suppression),
+/// so programs that stop printing for long periods should keep printing their last grid.
+///
+/// Set with [`Printer::replace_watchdog`](crate::printer::Printer::replace_watchdog).
+///
+/// # Example
+/// ```rust,no_run
+/// use screen_printer::printer::*;
+/// use std::time::Duration;
+///
+/// let mut printer = Printer::new();
+/// printer.set_idle_suppression(true);
+/// printer.replace_watchdog(Some(
+///   Watchdog::new(Duration::from_secs(5))
+///     .with_frame_interval(Duration::from_secs(600))
+///     .with_jitter(0.5),
+/// ));
+///
+/// loop {
+///   printer.dynamic_print("abc\n123".to_string()).unwrap();
+///
+///   std::thread::sleep(Duration::from_millis(100));
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Watchdog {
+  /// The time between reprinting a random row of the grid.
+  pub row_interval: Duration,
+  /// The time between reprinting the entire grid, if it ever is.
+  pub frame_interval: Option<Duration>,
+  /// The largest fraction of an interval it can be randomly lengthened or shortened by, from 0.0 to 1.0.
+  pub jitter: f32,
+}
+
+impl Watchdog {
+  /// Creates a watchdog that reprints a random row every row interval, without jitter or reprinting the entire grid.
+  pub fn new(row_interval: Duration) -> Self {
+    Self {
+      row_interval,
+      frame_interval: None,
+      jitter: 0.0,
+    }
+  }
+
+  /// Returns the watchdog, also reprinting the entire grid every frame interval.
+  pub fn with_frame_interval(mut self, frame_interval: Duration) -> Self {
+    self.frame_interval = Some(frame_interval);
+
+    self
+  }
+
+  /// Returns the watchdog with the given jitter, which is clamped between 0.0 and 1.0.
+  pub fn with_jitter(mut self, jitter: f32) -> Self {
+    self.jitter = jitter.clamp(0.0, 1.0);
+
+    self
+  }
+
+  /// Returns the interval randomly lengthened or shortened by up to the jitter fraction of itself, based on the random number.
+  pub(crate) fn jittered_interval(&self, interval: Duration, random_number: u64) -> Duration {
+    // A fraction from -1.0 to 1.0 out of the top 53 bits of the number.
+    let fraction = (random_number >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0;
+
+    interval.mul_f64((1.0 + fraction * self.jitter.clamp(0.0, 1.0) as f64).max(0.0))
+  }
+}
+
+/// Returns the next number from the xorshift generator with the given state, seeding the state from the current time first if it's 0.
+pub(crate) fn next_random_number(state: &mut u64) -> u64 {
+  if *state == 0 {
+    let nanoseconds = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |time| time.as_nanos() as u64);
+
+    *state = nanoseconds | 1;
+  }
+
+  *state ^= *state << 13;
+  *state ^= *state >> 7;
+  *state ^= *state << 17;
+
+  *state
+}
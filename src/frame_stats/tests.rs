@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod adaptive_refresh_tests {
+  use super::*;
+
+  fn stats(changed_cells: usize) -> FrameStats {
+    FrameStats {
+      changed_cells,
+      total_cells: 100,
+      full_reprint: false,
+      budget_degradation: None,
+      resize_behavior: None,
+      #[cfg(feature = "profiling")]
+      phase_timings: PhaseTimings::default(),
+    }
+  }
+
+  #[test]
+  fn barely_changed_frames_double_interval() {
+    let adaptive_refresh =
+      AdaptiveRefresh::new(Duration::from_millis(10), Duration::from_millis(30));
+
+    let mut interval = adaptive_refresh.min_interval;
+    let intervals: Vec<u128> = (0..3)
+      .map(|_| {
+        interval = adaptive_refresh.next_interval(interval, &stats(1));
+
+        interval.as_millis()
+      })
+      .collect();
+
+    assert_eq!(intervals, vec![20, 30, 30]);
+  }
+
+  #[test]
+  fn changed_frame_resets_interval() {
+    let adaptive_refresh = AdaptiveRefresh::new(Duration::from_millis(10), Duration::from_secs(1))
+      .with_change_threshold(0.1);
+
+    let interval = adaptive_refresh.next_interval(Duration::from_millis(500), &stats(11));
+
+    assert_eq!(interval, Duration::from_millis(10));
+  }
+
+  #[test]
+  fn empty_grid_changes_nothing() {
+    assert_eq!(FrameStats::default().change_fraction(), 0.0);
+  }
+}
+
+#[cfg(test)]
+mod full_refresh_interval_tests {
+  use super::*;
+
+  #[test]
+  fn refresh_is_due_after_interval() {
+    let frames = FullRefreshInterval::Frames(3);
+    let time = FullRefreshInterval::Time(Duration::from_secs(5));
+
+    assert!(!frames.is_due(2, Duration::from_secs(60)));
+    assert!(frames.is_due(3, Duration::ZERO));
+    assert!(!time.is_due(100, Duration::from_secs(4)));
+    assert!(time.is_due(0, Duration::from_secs(5)));
+  }
+}
+
+#[cfg(test)]
+mod watchdog_tests {
+  use super::*;
+
+  #[test]
+  fn intervals_without_jitter_are_unchanged() {
+    let watchdog = Watchdog::new(Duration::from_secs(1));
+
+    assert_eq!(
+      watchdog.jittered_interval(Duration::from_secs(1), u64::MAX),
+      Duration::from_secs(1)
+    );
+  }
+
+  #[test]
+  fn jitter_stays_within_its_fraction() {
+    let watchdog = Watchdog::new(Duration::from_secs(1)).with_jitter(0.5);
+    let mut random_state = 1;
+
+    for _ in 0..100 {
+      let interval = watchdog.jittered_interval(
+        Duration::from_secs(1),
+        next_random_number(&mut random_state),
+      );
+
+      assert!(interval >= Duration::from_millis(500));
+      assert!(interval <= Duration::from_millis(1500));
+    }
+  }
+
+  #[test]
+  fn jitter_is_clamped() {
+    assert_eq!(Watchdog::new(Duration::ZERO).with_jitter(3.0).jitter, 1.0);
+  }
+
+  #[test]
+  fn random_state_is_seeded() {
+    let mut random_state = 0;
+
+    next_random_number(&mut random_state);
+
+    assert_ne!(random_state, 0);
+  }
+}
+
+#[cfg(all(test, feature = "profiling"))]
+mod profiling_tests {
+  use super::*;
+  use crate::printer::Printer;
+
+  #[test]
+  fn phases_are_added_up() {
+    let mut printer = Printer::new();
+
+    for _ in 0..2 {
+      let timer = PhaseTimer::start();
+      std::thread::sleep(Duration::from_millis(1));
+      printer.record_phase(Phase::Writing, timer);
+    }
+
+    assert!(printer.phase_timings.writing >= Duration::from_millis(2));
+    assert_eq!(printer.phase_timings.diffing, Duration::ZERO);
+  }
+
+  #[test]
+  fn timings_are_moved_into_the_frame_stats() {
+    let mut printer = Printer::new();
+    printer.phase_timings.validation = Duration::from_millis(5);
+
+    printer.update_frame_stats(FrameStats::default());
+
+    let frame_stats = printer.get_last_frame_stats().unwrap();
+    assert_eq!(
+      frame_stats.phase_timings.validation,
+      Duration::from_millis(5)
+    );
+    assert_eq!(printer.phase_timings, PhaseTimings::default());
+  }
+}
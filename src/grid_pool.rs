@@ -0,0 +1,132 @@
+mod tests;
+
+use crate::errors::PrintingError;
+use crate::printer::{CharacterOrdering, Printer};
+use std::fmt;
+
+/// Recycles the strings that grids are built in, so building a grid every frame doesn't allocate a new string every frame.
+///
+/// Strings are [`taken`](GridPool::take) from the pool to build a grid in, and given back with [`recycle`](GridPool::recycle) once they're no longer needed.
+/// A [`Printer`](crate::printer::Printer) with a pool [`set`](crate::printer::Printer::replace_grid_pool) recycles every grid it's done with on its own,
+/// which is the grid printed before the one it just printed.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::deterministic();
+/// printer.replace_grid_pool(Some(GridPool::new(2)));
+///
+/// for frame in 0..10 {
+///   let grid_pool = printer.grid_pool().unwrap();
+///   let grid = grid_pool.fill(if frame % 2 == 0 { '#' } else { '.' }, 10, 10);
+///
+///   printer.dynamic_print(grid).unwrap();
+/// }
+///
+/// assert_eq!(printer.grid_pool().unwrap().len(), 1);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct GridPool {
+  strings: Vec<String>,
+  max_strings: usize,
+}
+
+impl GridPool {
+  /// Creates an empty pool that holds onto at most the given amount of strings, dropping any recycled past that.
+  pub fn new(max_strings: usize) -> Self {
+    Self {
+      strings: Vec::with_capacity(max_strings),
+      max_strings,
+    }
+  }
+
+  /// Returns an empty string with room for a grid of the given (width, height) made of single byte characters.
+  ///
+  /// The string with the least room that's large enough is taken from the pool,
+  /// or the largest one is grown when none of them are, or a new string is created when the pool is empty.
+  pub fn take(&mut self, width: usize, height: usize) -> String {
+    let grid_length = (width + 1) * height;
+    let index = self
+      .strings
+      .iter()
+      .enumerate()
+      .filter(|(_, string)| string.capacity() >= grid_length)
+      .min_by_key(|(_, string)| string.capacity())
+      .or_else(|| {
+        self
+          .strings
+          .iter()
+          .enumerate()
+          .max_by_key(|(_, string)| string.capacity())
+      })
+      .map(|(index, _)| index);
+
+    let mut string = match index {
+      Some(index) => self.strings.swap_remove(index),
+      None => String::new(),
+    };
+    string.reserve(grid_length);
+
+    string
+  }
+
+  /// Gives the string back to the pool to be taken again, unless the pool is full.
+  pub fn recycle(&mut self, mut string: String) {
+    if self.strings.len() < self.max_strings && string.capacity() > 0 {
+      string.clear();
+      self.strings.push(string);
+    }
+  }
+
+  /// Returns a grid of the given size with the given character, built in a string from the pool.
+  ///
+  /// This is the same as [`create_grid_from_single_character`](crate::printer::Printer::create_grid_from_single_character).
+  pub fn fill(&mut self, character: char, width: usize, height: usize) -> String {
+    let mut grid = self.take(width * character.len_utf8(), height);
+    Printer::write_grid_from_single_character(&mut grid, character, width, height);
+
+    grid
+  }
+
+  /// Returns a grid of the given size with the given list of characters, built in a string from the pool.
+  ///
+  /// This is the same as [`create_grid_from_full_character_list`](crate::printer::Printer::create_grid_from_full_character_list).
+  ///
+  /// # Errors
+  ///
+  /// - When the amount of characters passed in doesn't fit the expected grid dimensions.
+  pub fn create_grid_from_full_character_list<T>(
+    &mut self,
+    characters: &[T],
+    width: usize,
+    height: usize,
+    ordering: CharacterOrdering,
+  ) -> Result<String, PrintingError>
+  where
+    T: fmt::Display,
+  {
+    let mut grid = self.take(width, height);
+
+    match Printer::write_grid_from_full_character_list(
+      &mut grid, characters, width, height, ordering,
+    ) {
+      Ok(()) => Ok(grid),
+      Err(error) => {
+        self.recycle(grid);
+
+        Err(error)
+      }
+    }
+  }
+
+  /// Returns the amount of strings waiting in the pool.
+  pub fn len(&self) -> usize {
+    self.strings.len()
+  }
+
+  /// Returns true if there are no strings waiting in the pool.
+  pub fn is_empty(&self) -> bool {
+    self.strings.is_empty()
+  }
+}
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod grid_pool_tests {
+  use super::*;
+  use crate::dynamic_printer::DynamicPrinter;
+
+  #[test]
+  fn taken_strings_have_room_for_the_grid() {
+    let mut grid_pool = GridPool::new(2);
+
+    let grid = grid_pool.take(4, 3);
+
+    assert!(grid.is_empty());
+    assert!(grid.capacity() >= 15);
+  }
+
+  #[test]
+  fn recycled_strings_are_taken_again() {
+    let mut grid_pool = GridPool::new(2);
+    let grid = grid_pool.fill('a', 4, 3);
+    let pointer = grid.as_ptr();
+
+    grid_pool.recycle(grid);
+    let grid = grid_pool.take(4, 3);
+
+    assert_eq!(grid.as_ptr(), pointer);
+    assert!(grid_pool.is_empty());
+  }
+
+  #[test]
+  fn the_smallest_string_large_enough_is_taken() {
+    let mut grid_pool = GridPool::new(3);
+    grid_pool.recycle(String::with_capacity(100));
+    grid_pool.recycle(String::with_capacity(20));
+    grid_pool.recycle(String::with_capacity(5));
+
+    assert_eq!(grid_pool.take(4, 3).capacity(), 20);
+  }
+
+  #[test]
+  fn full_pools_drop_recycled_strings() {
+    let mut grid_pool = GridPool::new(1);
+
+    grid_pool.recycle(String::from("abc"));
+    grid_pool.recycle(String::from("def"));
+
+    assert_eq!(grid_pool.len(), 1);
+  }
+
+  #[test]
+  fn builders_match_the_printer() {
+    let mut grid_pool = GridPool::new(1);
+    let characters = [1, 2, 3, 4, 5, 6];
+
+    assert_eq!(
+      grid_pool.fill('字', 2, 2),
+      Printer::create_grid_from_single_character('字', 2, 2)
+    );
+
+    for ordering in [CharacterOrdering::RowMajor, CharacterOrdering::ColumnMajor] {
+      assert_eq!(
+        grid_pool
+          .create_grid_from_full_character_list(&characters, 3, 2, ordering)
+          .unwrap(),
+        Printer::create_grid_from_full_character_list(&characters, 3, 2, ordering).unwrap()
+      );
+    }
+
+    assert!(grid_pool
+      .create_grid_from_full_character_list(&characters, 2, 2, CharacterOrdering::RowMajor)
+      .is_err());
+  }
+
+  #[test]
+  fn printers_recycle_replaced_grids() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+    printer.replace_grid_pool(Some(GridPool::new(4)));
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+    printer.dynamic_print("def".to_string()).unwrap();
+
+    assert_eq!(printer.grid_pool().unwrap().len(), 1);
+  }
+}
@@ -0,0 +1,140 @@
+mod tests;
+
+use crate::cells::{find_control_character, split_grid_into_cells, EmojiMode};
+use crate::errors::PrintingError;
+use std::fmt;
+
+/// A description of a grid, for finding out why it can't be printed.
+///
+/// Obtained from [`Printer::validate_grid`](crate::printer::Printer::validate_grid).
+/// The [`Display`](std::fmt::Display) implementation writes a line for each problem found with the grid.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let printer = Printer::on_virtual_terminal((10, 5));
+/// let report = printer.validate_grid("abc\nde\tf\ngh");
+///
+/// assert_eq!(report.dimensions, None);
+/// assert_eq!(report.row_widths, vec![3, 3, 2]);
+/// assert_eq!(report.mismatched_rows(), vec![2]);
+/// assert!(report.has_tabs);
+/// assert!(!report.is_printable());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridReport {
+  /// The (width, height) of the grid, if it's rectangular.
+  pub dimensions: Option<(usize, usize)>,
+  /// The width of every row, measured the same way the printer measures them.
+  ///
+  /// Tabs and other control characters are measured as taking up no columns, even though terminals print them differently.
+  pub row_widths: Vec<usize>,
+  /// Whether the grid contains any tabs.
+  pub has_tabs: bool,
+  /// The first control character in the grid other than a newline, along with the row it's in and the column it would be printed in.
+  ///
+  /// Tabs are control characters as well.
+  pub first_control_character: Option<(char, usize, usize)>,
+  /// Whether the grid contains any characters that take up more than one column, such as CJK characters.
+  pub has_wide_characters: bool,
+  /// Why the grid wouldn't be printed with the printer's settings at the time it was validated, if it wouldn't be.
+  ///
+  /// Refer to [`will_fit`](crate::printer::Printer::will_fit) for what's checked.
+  pub printable: Result<(), PrintingError>,
+}
+
+impl GridReport {
+  /// Creates a report of the given grid, measured with the given [`EmojiMode`](crate::cells::EmojiMode).
+  pub(crate) fn new(
+    grid: &str,
+    emoji_mode: EmojiMode,
+    printable: Result<(), PrintingError>,
+  ) -> Self {
+    let cells = split_grid_into_cells(grid, emoji_mode);
+    let row_widths: Vec<usize> = cells
+      .iter()
+      .map(|row| row.iter().map(|cell| cell.width).sum())
+      .collect();
+    let is_rectangular = row_widths.windows(2).all(|pair| pair[0] == pair[1]);
+
+    Self {
+      dimensions: is_rectangular.then(|| (row_widths[0], row_widths.len())),
+      has_tabs: grid.contains('\t'),
+      first_control_character: find_control_character(grid, emoji_mode),
+      has_wide_characters: cells.iter().flatten().any(|cell| cell.width > 1),
+      row_widths,
+      printable,
+    }
+  }
+
+  /// Returns true if every row of the grid is the same width.
+  pub fn is_rectangular(&self) -> bool {
+    self.dimensions.is_some()
+  }
+
+  /// Returns true if the grid would be printed with the printer's settings at the time it was validated.
+  pub fn is_printable(&self) -> bool {
+    self.printable.is_ok()
+  }
+
+  /// Returns the index of every row that isn't the same width as the first row.
+  pub fn mismatched_rows(&self) -> Vec<usize> {
+    let Some(&first_width) = self.row_widths.first() else {
+      return Vec::new();
+    };
+
+    self
+      .row_widths
+      .iter()
+      .enumerate()
+      .filter(|(_, &width)| width != first_width)
+      .map(|(index, _)| index)
+      .collect()
+  }
+}
+
+impl fmt::Display for GridReport {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    match self.dimensions {
+      Some((width, height)) => writeln!(formatter, "The grid is {width}x{height}.")?,
+      None => {
+        let first_width = self.row_widths[0];
+
+        for row in self.mismatched_rows() {
+          writeln!(
+            formatter,
+            "Row {row} is {} columns wide, while row 0 is {first_width} columns wide.",
+            self.row_widths[row]
+          )?;
+        }
+      }
+    }
+
+    if self.has_tabs {
+      writeln!(
+        formatter,
+        "The grid contains tabs, which terminals print at varying widths."
+      )?;
+    }
+
+    if let Some((character, row, column)) = self.first_control_character {
+      writeln!(
+        formatter,
+        "The grid contains the control character {character:?} in row {row}, column {column}."
+      )?;
+    }
+
+    if self.has_wide_characters {
+      writeln!(
+        formatter,
+        "The grid contains characters that take up more than one column."
+      )?;
+    }
+
+    match &self.printable {
+      Ok(()) => write!(formatter, "The grid can be printed."),
+      Err(error) => write!(formatter, "The grid can't be printed: {error}"),
+    }
+  }
+}
@@ -0,0 +1,59 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod grid_report_tests {
+  use super::*;
+
+  #[test]
+  fn rectangular_grids_have_dimensions() {
+    let report = GridReport::new("ab\ncd\nef", EmojiMode::Off, Ok(()));
+
+    assert_eq!(report.dimensions, Some((2, 3)));
+    assert!(report.mismatched_rows().is_empty());
+    assert!(!report.has_tabs);
+    assert_eq!(report.first_control_character, None);
+  }
+
+  #[test]
+  fn rows_of_a_different_width_are_listed() {
+    let report = GridReport::new("abc\nab\nabc\nabcd", EmojiMode::Off, Ok(()));
+
+    assert_eq!(report.dimensions, None);
+    assert_eq!(report.row_widths, vec![3, 2, 3, 4]);
+    assert_eq!(report.mismatched_rows(), vec![1, 3]);
+  }
+
+  #[test]
+  fn wide_characters_are_measured_by_their_columns() {
+    let report = GridReport::new("\u{65E5}\nab", EmojiMode::Off, Ok(()));
+
+    assert!(report.has_wide_characters);
+    assert_eq!(report.dimensions, Some((2, 2)));
+  }
+
+  #[test]
+  fn control_characters_are_located() {
+    let report = GridReport::new("ab\na\x07b", EmojiMode::Off, Ok(()));
+
+    assert_eq!(report.first_control_character, Some(('\x07', 1, 1)));
+  }
+
+  #[test]
+  fn every_problem_is_displayed() {
+    let report = GridReport::new(
+      "ab\na\t",
+      EmojiMode::Off,
+      Err(PrintingError::NonRectangularGrid),
+    );
+
+    assert_eq!(
+      report.to_string(),
+      "Row 1 is 1 columns wide, while row 0 is 2 columns wide.\n\
+       The grid contains tabs, which terminals print at varying widths.\n\
+       The grid contains the control character '\\t' in row 1, column 1.\n\
+       The grid can't be printed: A non rectangular grid was passed in."
+    );
+  }
+}
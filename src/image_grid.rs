@@ -0,0 +1,427 @@
+mod tests;
+
+use crate::ansi::STYLE_RESET;
+use crate::color::{colored_text, quantize_colors, Color, ColorDepth, Quantization};
+use crate::printer::*;
+use image::{imageops::FilterType, DynamicImage, GenericImageView, Rgb, RgbImage};
+
+/// The characters used for [`ImageConversion::Luminance`](ImageConversion::Luminance), from darkest to brightest.
+pub const LUMINANCE_RAMP: &str = " .:-=+*#%@";
+
+/// The character used for [`ImageConversion::ColoredBlocks`](ImageConversion::ColoredBlocks), which fills the top half of a cell.
+const UPPER_HALF_BLOCK: char = '▀';
+
+/// How the pixels of an image are turned into the characters of a grid.
+///
+/// Used for [`create_grid_from_image`](Printer::create_grid_from_image).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ImageConversion {
+  /// Every cell is a character from the [`LUMINANCE_RAMP`](LUMINANCE_RAMP), based on how bright that part of the image is.
+  ///
+  /// This works on any terminal, but loses all color.
+  #[default]
+  Luminance,
+  /// Every cell is a half block colored with two pixels of the image, one on top of the other.
+  ///
+  /// This requires a terminal with 24-bit color support.
+  ColoredBlocks,
+}
+
+/// The 4x4 Bayer matrix used for [`Dithering::Ordered`](Dithering::Ordered), with thresholds from 0 to 15.
+const BAYER_MATRIX: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// How the error from limiting an image to the characters of the [`LUMINANCE_RAMP`](LUMINANCE_RAMP), or the colors of a palette,
+/// is spread across the grid.
+///
+/// Dithering trades the banding of smooth gradients for a pattern of the closest levels,
+/// which looks like the original shade from a distance.
+///
+/// For [`ColoredBlocks`](ImageConversion::ColoredBlocks), dithering only applies when the colors are quantized to a
+/// [`ColorDepth`](ColorDepth) with a palette.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Dithering {
+  /// Every cell is the closest level on its own.
+  #[default]
+  None,
+  /// Every cell is offset by a repeating 4x4 threshold pattern before picking the closest level.
+  ///
+  /// The pattern stays in place from frame to frame, making this the better choice for animations.
+  Ordered,
+  /// The difference between every cell and its closest level is carried over to the cells to the right and below it.
+  FloydSteinberg,
+}
+
+/// The settings for turning an image into a grid.
+///
+/// Used for [`create_grid_from_image_with_options`](Printer::create_grid_from_image_with_options).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let options = ImageOptions::new(ImageConversion::ColoredBlocks)
+///   .with_color_depth(ColorDepth::Palette256)
+///   .with_quantization(Quantization::MedianCut)
+///   .with_dithering(Dithering::FloydSteinberg);
+/// ```
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ImageOptions {
+  pub conversion: ImageConversion,
+  /// The color depth the colors of the image are [`quantized`](crate::color::quantize_colors) to.
+  ///
+  /// This is [`TrueColor`](ColorDepth::TrueColor) by default, which keeps every color as is.
+  pub color_depth: ColorDepth,
+  /// How the colors of the image are quantized to the color depth.
+  pub quantization: Quantization,
+  pub dithering: Dithering,
+}
+
+impl ImageOptions {
+  /// Creates options for the given conversion, keeping every color as is.
+  pub fn new(conversion: ImageConversion) -> Self {
+    Self {
+      conversion,
+      ..Default::default()
+    }
+  }
+
+  /// Returns the options with colors quantized to the given color depth.
+  pub fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+    self.color_depth = color_depth;
+
+    self
+  }
+
+  /// Returns the options with colors quantized with the given [`Quantization`](Quantization).
+  pub fn with_quantization(mut self, quantization: Quantization) -> Self {
+    self.quantization = quantization;
+
+    self
+  }
+
+  /// Returns the options with the given [`Dithering`](Dithering).
+  pub fn with_dithering(mut self, dithering: Dithering) -> Self {
+    self.dithering = dithering;
+
+    self
+  }
+}
+
+impl Printer {
+  /// Creates a grid out of the image that fits within the given (width, height), keeping the image's aspect ratio.
+  ///
+  /// Terminal cells are about twice as tall as they are wide, so every cell covers two rows of pixels
+  /// to keep the image from being stretched.
+  /// The image is scaled up or down to be as large as possible within the given dimensions,
+  /// so the grid is only as large as both dimensions when the image's aspect ratio matches.
+  ///
+  /// Refer to [`ImageConversion`](ImageConversion) for the ways pixels can be turned into characters.
+  ///
+  /// # Example
+  /// ```
+  /// use image::{DynamicImage, RgbImage};
+  /// use screen_printer::printer::*;
+  ///
+  /// let image = DynamicImage::ImageRgb8(RgbImage::new(40, 20));
+  ///
+  /// let grid = Printer::create_grid_from_image(&image, (10, 10), ImageConversion::Luminance);
+  ///
+  /// assert_eq!(Printer::get_rectangular_dimensions(&grid).unwrap(), (10, 3));
+  /// ```
+  pub fn create_grid_from_image(
+    image: &DynamicImage,
+    max_dimensions: (usize, usize),
+    conversion: ImageConversion,
+  ) -> String {
+    Self::create_grid_from_image_with_options(image, max_dimensions, ImageOptions::new(conversion))
+  }
+
+  /// Creates a grid out of the image that fits within the given (width, height), with the given [`ImageOptions`](ImageOptions).
+  ///
+  /// For [`ColoredBlocks`](ImageConversion::ColoredBlocks), the colors of the image are quantized to the options' color depth,
+  /// so the image looks the same on terminals without 24-bit color support as it does with the printer's own
+  /// [`color downconversion`](crate::color::downconvert_colors).
+  ///
+  /// The options' [`Dithering`](Dithering) is applied to the characters of [`Luminance`](ImageConversion::Luminance),
+  /// and to the quantized colors of [`ColoredBlocks`](ImageConversion::ColoredBlocks).
+  ///
+  /// Refer to [`create_grid_from_image`](Printer::create_grid_from_image) for more information.
+  ///
+  /// # Example
+  /// ```
+  /// use image::{DynamicImage, RgbImage};
+  /// use screen_printer::printer::*;
+  ///
+  /// let image = DynamicImage::ImageRgb8(RgbImage::new(4, 4));
+  /// let options =
+  ///   ImageOptions::new(ImageConversion::ColoredBlocks).with_color_depth(ColorDepth::Basic16);
+  ///
+  /// let grid = Printer::create_grid_from_image_with_options(&image, (4, 2), options);
+  ///
+  /// assert!(grid.starts_with("\x1B[0;30;40m"));
+  /// ```
+  pub fn create_grid_from_image_with_options(
+    image: &DynamicImage,
+    (max_width, max_height): (usize, usize),
+    options: ImageOptions,
+  ) -> String {
+    let (grid_width, grid_height) = fit_image_to_cells(image.dimensions(), (max_width, max_height));
+
+    if grid_width == 0 || grid_height == 0 {
+      return String::new();
+    }
+
+    match options.conversion {
+      ImageConversion::Luminance => {
+        let pixels = resize_image(image, grid_width, grid_height);
+        let ramp: Vec<char> = LUMINANCE_RAMP.chars().collect();
+        let step = 255.0 / (ramp.len() - 1) as f64;
+        let luminances: Vec<[f64; 1]> = pixels.pixels().map(|pixel| [luminance(pixel)]).collect();
+        let levels = dither(
+          &luminances,
+          grid_width,
+          options.dithering,
+          step,
+          |[luminance]| {
+            [(luminance / step)
+              .round()
+              .clamp(0.0, (ramp.len() - 1) as f64)
+              * step]
+          },
+        );
+
+        levels
+          .chunks(grid_width)
+          .map(|row| {
+            row
+              .iter()
+              .map(|[level]| ramp[(level / step).round() as usize])
+              .collect::<String>()
+          })
+          .collect::<Vec<String>>()
+          .join("\n")
+      }
+      ImageConversion::ColoredBlocks => {
+        let pixels = resize_image(image, grid_width, grid_height * 2);
+        let colors: Vec<Color> = pixels
+          .pixels()
+          .map(|&Rgb([red, green, blue])| Color::new(red, green, blue))
+          .collect();
+        let colors = dither_colors(&colors, grid_width, &options);
+        let grid = (0..grid_height)
+          .map(|y| {
+            let mut row: String = (0..grid_width)
+              .map(|x| {
+                let top_color = colors[y * 2 * grid_width + x];
+                let bottom_color = colors[(y * 2 + 1) * grid_width + x];
+
+                colored_text(
+                  &UPPER_HALF_BLOCK.to_string(),
+                  Some(top_color),
+                  Some(bottom_color),
+                )
+              })
+              .collect();
+
+            row.push_str(STYLE_RESET);
+
+            row
+          })
+          .collect::<Vec<String>>()
+          .join("\n");
+
+        downconvert_colors(&grid, options.color_depth)
+      }
+    }
+  }
+
+  /// Creates a grid out of the image that fits within the current dimensions of the terminal.
+  ///
+  /// Refer to [`create_grid_from_image`](Printer::create_grid_from_image) for more information.
+  ///
+  /// # Errors
+  ///
+  /// - Whenever the [`terminal's dimensions`](Printer::get_terminal_dimensions) can't be obtained.
+  pub fn create_grid_from_image_for_terminal(
+    image: &DynamicImage,
+    conversion: ImageConversion,
+  ) -> Result<String, PrintingError> {
+    let terminal_dimensions = Printer::get_terminal_dimensions()?;
+
+    Ok(Self::create_grid_from_image(
+      image,
+      terminal_dimensions,
+      conversion,
+    ))
+  }
+
+  /// Plays the images in order at the given frames per second, turning each one into a grid with the given [`ImageOptions`](ImageOptions).
+  ///
+  /// Every image is fit within the part of the terminal grids can be printed in, based on the terminal's dimensions when playback starts.
+  ///
+  /// Refer to [`play_frames`](Printer::play_frames) for how frames are timed and dropped.
+  ///
+  /// # Errors
+  ///
+  /// - Whenever the [`terminal's dimensions`](Printer::get_current_terminal_dimensions) can't be obtained.
+  /// - Any error [`play_frames`](Printer::play_frames) can return.
+  pub fn play_images<I>(
+    &mut self,
+    images: I,
+    options: ImageOptions,
+    frames_per_second: u32,
+  ) -> Result<PlaybackStats, PrintingError>
+  where
+    I: IntoIterator<Item = DynamicImage>,
+  {
+    let printing_area = self.get_printing_area(self.get_current_terminal_dimensions()?);
+    let frames = images
+      .into_iter()
+      .map(|image| Self::create_grid_from_image_with_options(&image, printing_area, options));
+
+    self.play_frames(frames, frames_per_second)
+  }
+}
+
+/// Returns the largest (width, height) in cells the image can be scaled to while fitting within the given dimensions,
+/// where every cell covers one column and two rows of pixels.
+fn fit_image_to_cells(
+  (image_width, image_height): (u32, u32),
+  (max_width, max_height): (usize, usize),
+) -> (usize, usize) {
+  if image_width == 0 || image_height == 0 {
+    return (0, 0);
+  }
+
+  let cell_height = image_height as f64 / 2.0;
+  let scale = (max_width as f64 / image_width as f64).min(max_height as f64 / cell_height);
+
+  let width = (image_width as f64 * scale).round() as usize;
+  let height = (cell_height * scale).round() as usize;
+
+  (width.max(1).min(max_width), height.max(1).min(max_height))
+}
+
+/// Resizes the image to exactly the given dimensions in pixels.
+fn resize_image(image: &DynamicImage, width: usize, height: usize) -> RgbImage {
+  image::imageops::resize(
+    &image.to_rgb8(),
+    width as u32,
+    height as u32,
+    FilterType::Triangle,
+  )
+}
+
+/// Returns the brightness of the pixel, from 0 to 255.
+fn luminance(&Rgb([red, green, blue]): &Rgb<u8>) -> f64 {
+  0.2126 * red as f64 + 0.7152 * green as f64 + 0.0722 * blue as f64
+}
+
+/// Quantizes the colors of an image with the given width to the options' color depth, dithering them with the options' [`Dithering`](Dithering).
+fn dither_colors(colors: &[Color], width: usize, options: &ImageOptions) -> Vec<Color> {
+  let quantized_colors = quantize_colors(colors, options.color_depth, options.quantization);
+  let spread = match options.color_depth {
+    ColorDepth::Monochrome | ColorDepth::TrueColor => return quantized_colors,
+    ColorDepth::Basic16 => 128.0,
+    ColorDepth::Palette256 => 51.0,
+  };
+
+  if options.dithering == Dithering::None {
+    return quantized_colors;
+  }
+
+  let mut palette = quantized_colors;
+  palette.sort_unstable_by_key(|color| (color.red, color.green, color.blue));
+  palette.dedup();
+
+  let channels: Vec<[f64; 3]> = colors
+    .iter()
+    .map(|color| [color.red as f64, color.green as f64, color.blue as f64])
+    .collect();
+  let dithered_channels = dither(&channels, width, options.dithering, spread, |channels| {
+    let [red, green, blue] = channels.map(|channel| channel.round().clamp(0.0, 255.0) as u8);
+    let color = Color::new(red, green, blue);
+    let nearest_color = match options.quantization {
+      Quantization::Nearest => color.to_nearest_displayable(options.color_depth),
+      Quantization::MedianCut => palette
+        .iter()
+        .copied()
+        .min_by_key(|palette_color| palette_color.distance_to(color))
+        .unwrap_or(color),
+    };
+
+    [
+      nearest_color.red as f64,
+      nearest_color.green as f64,
+      nearest_color.blue as f64,
+    ]
+  });
+
+  dithered_channels
+    .into_iter()
+    .map(|[red, green, blue]| Color::new(red as u8, green as u8, blue as u8))
+    .collect()
+}
+
+/// Quantizes every value of a grid with the given width, spreading the error with the given [`Dithering`](Dithering).
+///
+/// The spread is the distance between levels the values are quantized to, which scales the thresholds of
+/// [`Ordered`](Dithering::Ordered) dithering.
+fn dither<const CHANNELS: usize>(
+  values: &[[f64; CHANNELS]],
+  width: usize,
+  dithering: Dithering,
+  spread: f64,
+  quantize: impl Fn([f64; CHANNELS]) -> [f64; CHANNELS],
+) -> Vec<[f64; CHANNELS]> {
+  match dithering {
+    Dithering::None => values.iter().map(|&value| quantize(value)).collect(),
+    Dithering::Ordered => values
+      .iter()
+      .enumerate()
+      .map(|(index, value)| {
+        let (x, y) = index_to_coordinates(index, width);
+        let threshold = (BAYER_MATRIX[y % 4][x % 4] as f64 + 0.5) / 16.0 - 0.5;
+
+        quantize(value.map(|channel| channel + threshold * spread))
+      })
+      .collect(),
+    Dithering::FloydSteinberg => {
+      let mut values = values.to_vec();
+      let height = values.len().div_ceil(width);
+
+      for index in 0..values.len() {
+        let (x, y) = index_to_coordinates(index, width);
+        let value = values[index];
+        let quantized_value = quantize(value);
+        values[index] = quantized_value;
+
+        let mut carry_error = |x: usize, y: usize, weight: f64| {
+          if let Some(neighbor) = values.get_mut(coordinates_to_index((x, y), width)) {
+            for channel in 0..CHANNELS {
+              neighbor[channel] += (value[channel] - quantized_value[channel]) * weight;
+            }
+          }
+        };
+
+        if x + 1 < width {
+          carry_error(x + 1, y, 7.0 / 16.0);
+        }
+
+        if y + 1 < height {
+          if x > 0 {
+            carry_error(x - 1, y + 1, 3.0 / 16.0);
+          }
+
+          carry_error(x, y + 1, 5.0 / 16.0);
+
+          if x + 1 < width {
+            carry_error(x + 1, y + 1, 1.0 / 16.0);
+          }
+        }
+      }
+
+      values
+    }
+  }
+}
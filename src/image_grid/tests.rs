@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod fit_image_to_cells_tests {
+  use super::*;
+
+  #[test]
+  fn wide_image_is_limited_by_width() {
+    assert_eq!(fit_image_to_cells((40, 20), (10, 10)), (10, 3));
+  }
+
+  #[test]
+  fn tall_image_is_limited_by_height() {
+    assert_eq!(fit_image_to_cells((10, 40), (100, 10)), (5, 10));
+  }
+
+  #[test]
+  fn empty_image_has_no_cells() {
+    assert_eq!(fit_image_to_cells((0, 10), (10, 10)), (0, 0));
+  }
+}
+
+#[cfg(test)]
+mod create_grid_from_image_tests {
+  use super::*;
+
+  /// Creates an image where the top half is black, and the bottom half is white.
+  fn get_half_white_image() -> DynamicImage {
+    DynamicImage::ImageRgb8(RgbImage::from_fn(4, 4, |_, y| {
+      if y < 2 {
+        Rgb([0, 0, 0])
+      } else {
+        Rgb([255, 255, 255])
+      }
+    }))
+  }
+
+  #[test]
+  fn luminance_uses_ramp() {
+    let black_image = DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, Rgb([0, 0, 0])));
+    let white_image = DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, Rgb([255, 255, 255])));
+
+    let black_grid =
+      Printer::create_grid_from_image(&black_image, (4, 2), ImageConversion::Luminance);
+    let white_grid =
+      Printer::create_grid_from_image(&white_image, (4, 2), ImageConversion::Luminance);
+
+    assert_eq!(black_grid, "    \n    ");
+    assert_eq!(white_grid, "@@@@\n@@@@");
+  }
+
+  #[test]
+  fn colored_blocks_fit_cell_model() {
+    let grid = Printer::create_grid_from_image(
+      &get_half_white_image(),
+      (4, 2),
+      ImageConversion::ColoredBlocks,
+    );
+
+    assert_eq!(measure_grid(&grid), (4, 2));
+    assert_eq!(strip_ansi(&grid), "▀▀▀▀\n▀▀▀▀");
+  }
+}
+
+#[cfg(test)]
+mod image_options_tests {
+  use super::*;
+
+  #[test]
+  fn colors_are_quantized_to_the_color_depth() {
+    let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(2, 2, Rgb([250, 5, 5])));
+    let options =
+      ImageOptions::new(ImageConversion::ColoredBlocks).with_color_depth(ColorDepth::Palette256);
+
+    let grid = Printer::create_grid_from_image_with_options(&image, (2, 1), options);
+
+    assert_eq!(
+      grid,
+      "\x1B[0;38;5;196;48;5;196m▀\x1B[0;38;5;196;48;5;196m▀\x1B[0m"
+    );
+  }
+
+  #[test]
+  fn default_options_keep_true_color() {
+    let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 2, Rgb([1, 2, 3])));
+
+    let grid = Printer::create_grid_from_image(&image, (1, 1), ImageConversion::ColoredBlocks);
+
+    assert_eq!(grid, "\x1B[0;38;2;1;2;3;48;2;1;2;3m▀\x1B[0m");
+  }
+}
+
+#[cfg(test)]
+mod dithering_tests {
+  use super::*;
+
+  /// Creates a gray image between two characters of the luminance ramp.
+  fn get_gray_image() -> DynamicImage {
+    DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 8, Rgb([127, 127, 127])))
+  }
+
+  fn luminance_grid(dithering: Dithering) -> String {
+    let options = ImageOptions::new(ImageConversion::Luminance).with_dithering(dithering);
+
+    Printer::create_grid_from_image_with_options(&get_gray_image(), (8, 4), options)
+  }
+
+  #[test]
+  fn no_dithering_is_banded() {
+    let grid = luminance_grid(Dithering::None);
+
+    assert!(grid.lines().all(|row| row == "========"));
+  }
+
+  #[test]
+  fn ordered_dithering_mixes_levels() {
+    let grid = luminance_grid(Dithering::Ordered);
+
+    assert!(grid.contains('='));
+    assert!(grid.contains('+'));
+  }
+
+  #[test]
+  fn floyd_steinberg_mixes_levels() {
+    let grid = luminance_grid(Dithering::FloydSteinberg);
+
+    assert!(grid.contains('='));
+    assert!(grid.contains('+'));
+  }
+
+  #[test]
+  fn floyd_steinberg_carries_error_forward() {
+    let values = [[0.4], [0.4], [0.4]];
+
+    let dithered_values = dither(&values, 3, Dithering::FloydSteinberg, 1.0, |[value]| {
+      [value.round()]
+    });
+
+    assert_eq!(dithered_values, vec![[0.0], [1.0], [0.0]]);
+  }
+
+  #[test]
+  fn true_color_is_not_dithered() {
+    let colors = [Color::new(10, 20, 30), Color::new(40, 50, 60)];
+    let options =
+      ImageOptions::new(ImageConversion::ColoredBlocks).with_dithering(Dithering::Ordered);
+
+    assert_eq!(dither_colors(&colors, 2, &options), colors.to_vec());
+  }
+
+  #[test]
+  fn dithered_colors_are_displayable() {
+    let colors = vec![Color::new(100, 100, 100); 16];
+    let options = ImageOptions::new(ImageConversion::ColoredBlocks)
+      .with_color_depth(ColorDepth::Basic16)
+      .with_dithering(Dithering::FloydSteinberg);
+
+    let dithered_colors = dither_colors(&colors, 4, &options);
+
+    assert!(dithered_colors
+      .iter()
+      .all(|&color| color.to_nearest_displayable(ColorDepth::Basic16) == color));
+    assert!(dithered_colors.windows(2).any(|pair| pair[0] != pair[1]));
+  }
+}
@@ -0,0 +1,181 @@
+mod tests;
+
+use crate::dynamic_printer::DynamicPrinterMethods;
+use crate::errors::PrintingError;
+use crate::printer::Printer;
+
+impl Printer {
+  /// Starts printing grids inline, within the given amount of rows starting from the row the cursor is on.
+  ///
+  /// When there aren't enough rows below the cursor, such as when it's on the last row, the terminal is scrolled up to make room for them
+  /// rather than printing over what's above the cursor.
+  /// Grids are then positioned within those rows with the [`PrintingPosition`](crate::printing_position::PrintingPosition)
+  /// and updated in place, the same as any other grid.
+  ///
+  /// Anything printed while in inline mode is expected to go through [`print_above`](Printer::print_above),
+  /// which keeps the grid below everything printed before it, the same way progress bars do.
+  ///
+  /// # Errors
+  ///
+  /// - The cursor's position couldn't be obtained from the terminal.
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  ///
+  /// # Example
+  /// ```rust,no_run
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// printer.enable_inline_mode(2).unwrap();
+  ///
+  /// for crate_number in 1..=3 {
+  ///   printer.print_above(&format!("Compiled crate {crate_number}")).unwrap();
+  ///   printer.dynamic_print(format!("Building\n{crate_number}/3     ")).unwrap();
+  /// }
+  ///
+  /// printer.disable_inline_mode().unwrap();
+  /// ```
+  pub fn enable_inline_mode(&mut self, height: usize) -> Result<(), PrintingError> {
+    self.flush_output()?;
+
+    let (cursor_x, cursor_y) = self.query_cursor_position()?;
+
+    // Starting on the next row keeps the rest of the cursor's row intact.
+    let start_row = if cursor_x > 1 { cursor_y + 1 } else { cursor_y };
+
+    self.allocate_inline_area(start_row, height)?;
+    self.printing_position_changed_since_last_print = true;
+
+    Ok(())
+  }
+
+  /// Stops printing grids inline, leaving the last printed grid in the terminal's history.
+  ///
+  /// The cursor is moved to the start of the row below the inline rows, so anything printed afterwards follows the grid.
+  /// The next grid printed is printed in its entirety, without clearing the previous one.
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn disable_inline_mode(&mut self) -> Result<(), PrintingError> {
+    let Some((top_row, height)) = self.inline_area.take() else {
+      return Ok(());
+    };
+
+    let (_, terminal_height) = self.get_current_terminal_dimensions()?;
+    let emitter = self.get_escape_emitter();
+    let end_row = top_row + height;
+
+    let cursor_movement = if end_row > terminal_height {
+      format!("{}\n", emitter.move_cursor(1, terminal_height))
+    } else {
+      emitter.move_cursor(1, end_row)
+    };
+
+    self.write_output(&cursor_movement)?;
+    self.flush_output()?;
+
+    self.previous_grid.clear();
+    self.last_submitted_grid.clear();
+    self.printing_position_changed_since_last_print = false;
+
+    Ok(())
+  }
+
+  /// Prints the text above the inline rows, moving the rows down below it.
+  ///
+  /// The inline rows are cleared, the text is printed starting where they were, then the rows are made again below the text.
+  /// The last printed grid is then printed again in its entirety.
+  /// A newline is added after the text if it doesn't end with one.
+  ///
+  /// Outside of inline mode the text is printed as is, followed by a newline.
+  ///
+  /// # Errors
+  ///
+  /// - The cursor's position couldn't be obtained from the terminal.
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn print_above(&mut self, text: &str) -> Result<(), PrintingError> {
+    let mut text = text.to_string();
+
+    if !text.ends_with('\n') {
+      text.push('\n');
+    }
+
+    let Some((top_row, height)) = self.get_inline_area() else {
+      self.write_output(&text)?;
+
+      return self.flush_output();
+    };
+
+    let (terminal_width, _) = self.get_current_terminal_dimensions()?;
+
+    self.clear_space_on_terminal((terminal_width, height), (1, top_row))?;
+
+    let text = format!(
+      "{}{text}",
+      self.get_escape_emitter().move_cursor(1, top_row)
+    );
+    self.write_output(&text)?;
+    self.flush_output()?;
+
+    let (_, cursor_y) = self.query_cursor_position()?;
+    self.allocate_inline_area(cursor_y, height)?;
+
+    // The previous grid was cleared, so it's printed again where the rows were moved to.
+    let previous_grid = std::mem::take(&mut self.previous_grid);
+    self.printing_position_changed_since_last_print = false;
+
+    if previous_grid.is_empty() {
+      return self.flush_output();
+    }
+
+    self.print_prepared_grid(previous_grid, None)
+  }
+
+  /// Returns the (top row, height) of the rows grids are printed within while in inline mode.
+  pub fn get_inline_area(&self) -> Option<(usize, usize)> {
+    self.inline_area
+  }
+
+  /// Makes room for the given amount of rows starting from the given row, and moves the cursor to the first of them.
+  ///
+  /// When the rows would go past the bottom of the terminal, the terminal is scrolled up just enough for them to fit,
+  /// keeping everything above the starting row in the terminal's history rather than printing over it.
+  fn allocate_inline_area(&mut self, start_row: usize, height: usize) -> Result<(), PrintingError> {
+    let (_, terminal_height) = self.get_current_terminal_dimensions()?;
+    let top_row = inline_area_top_row(start_row, height, terminal_height);
+    let scrolled_rows = start_row - top_row;
+    let emitter = self.get_escape_emitter();
+    let mut cursor_movement = String::new();
+
+    if scrolled_rows > 0 {
+      cursor_movement.push_str(&emitter.move_cursor(1, terminal_height));
+      cursor_movement.push_str(&emitter.scroll_up(scrolled_rows));
+    }
+
+    cursor_movement.push_str(&emitter.move_cursor(1, top_row));
+
+    self.write_output(&cursor_movement)?;
+    self.flush_output()?;
+
+    self.inline_area = Some((top_row, height));
+
+    Ok(())
+  }
+}
+
+/// Returns the row the inline rows start on when made room for from the given starting row.
+///
+/// When there aren't enough rows below the starting row, the terminal is scrolled until the rows end on the last row of the terminal,
+/// which moves the starting row up by the amount scrolled.
+pub(crate) fn inline_area_top_row(
+  start_row: usize,
+  height: usize,
+  terminal_height: usize,
+) -> usize {
+  start_row
+    .min(terminal_height.saturating_sub(height) + 1)
+    .max(1)
+}
@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod inline_area_top_row_tests {
+  use super::*;
+
+  #[test]
+  fn rows_start_at_the_cursor_when_there_is_room() {
+    assert_eq!(inline_area_top_row(5, 3, 24), 5);
+  }
+
+  #[test]
+  fn terminal_scrolls_when_there_is_no_room() {
+    assert_eq!(inline_area_top_row(23, 3, 24), 22);
+    // Starting below a cursor on the last row starts past the end of the terminal.
+    assert_eq!(inline_area_top_row(25, 3, 24), 22);
+  }
+
+  #[test]
+  fn rows_taller_than_the_terminal_start_at_the_top() {
+    assert_eq!(inline_area_top_row(10, 30, 24), 1);
+  }
+}
+
+#[cfg(test)]
+mod inline_positioning_tests {
+  use super::*;
+  use crate::printing_position::*;
+
+  #[test]
+  fn grids_are_positioned_within_the_inline_rows() {
+    let mut printer = Printer::new_with_printing_position(PrintingPosition::new(
+      XPrintingPosition::Right,
+      YPrintingPosition::Bottom,
+    ));
+    printer.replace_reserved_top_rows(2);
+    printer.replace_reserved_bottom_rows(2);
+    printer.inline_area = Some((10, 4));
+
+    let printing_area = printer.get_printing_area((80, 24));
+    let origin = printer.get_new_origin((5, 2), printing_area);
+
+    assert_eq!(printing_area, (80, 4));
+    assert_eq!(origin, (76, 12));
+  }
+}
@@ -0,0 +1,176 @@
+//! Splitting an area of the terminal into smaller areas from a list of constraints on their sizes.
+
+mod tests;
+
+use crate::printing_position::BoundingBox;
+use alloc::vec::Vec;
+
+/// How much space one part of a [`Layout`](Layout) takes up along the direction it's split in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constraint {
+  /// Exactly the given amount of columns or rows.
+  Fixed(usize),
+  /// The given percentage of the area being split, rounded down.
+  Percentage(usize),
+  /// At least the given amount, growing to share any space left over.
+  Min(usize),
+  /// At most the given amount, growing to share any space left over up to that amount.
+  Max(usize),
+  /// An equal share of whatever space is left over once every other constraint is met.
+  Remainder,
+}
+
+/// Which way a [`Layout`](Layout) splits an area.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+  /// Side by side, splitting the width.
+  #[default]
+  Horizontal,
+  /// Stacked from top to bottom, splitting the height.
+  Vertical,
+}
+
+/// Splits an area into parts along one direction, based on a [`Constraint`](Constraint) for the size of each part.
+///
+/// Fixed and percentage sizes are given out first, along with the least each [`Min`](Constraint::Min) asks for.
+/// Whatever space is left is then shared evenly between the min, [`Max`](Constraint::Max), and
+/// [`Remainder`](Constraint::Remainder) parts, where max parts stop growing at their limit.
+/// When the constraints ask for more space than there is, the last parts are shrunk first.
+///
+/// Layouts are recalculated from the area they're given, so splitting the printing area every frame keeps
+/// every part sized to the terminal after it's resized. Parts can be split again with their own layouts to build grids of panels.
+///
+/// # Example
+/// ```
+/// use screen_printer::layout::*;
+/// use screen_printer::printing_position::BoundingBox;
+///
+/// let area = BoundingBox::new((1, 1), (80, 24));
+///
+/// // A header, a body, and a footer, with the body split into a sidebar and main panel.
+/// let rows = Layout::vertical(vec![Constraint::Fixed(1), Constraint::Remainder, Constraint::Fixed(1)]).split(area);
+/// let columns = Layout::horizontal(vec![Constraint::Percentage(25), Constraint::Remainder]).split(rows[1]);
+///
+/// assert_eq!(rows[1], BoundingBox::new((1, 2), (80, 22)));
+/// assert_eq!(columns[0], BoundingBox::new((1, 2), (20, 22)));
+/// assert_eq!(columns[1], BoundingBox::new((21, 2), (60, 22)));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+  pub direction: Direction,
+  pub constraints: Vec<Constraint>,
+}
+
+impl Layout {
+  /// Creates a layout splitting an area in the given direction.
+  pub fn new(direction: Direction, constraints: Vec<Constraint>) -> Self {
+    Self {
+      direction,
+      constraints,
+    }
+  }
+
+  /// Creates a layout splitting the width of an area, placing the parts side by side.
+  pub fn horizontal(constraints: Vec<Constraint>) -> Self {
+    Self::new(Direction::Horizontal, constraints)
+  }
+
+  /// Creates a layout splitting the height of an area, stacking the parts from top to bottom.
+  pub fn vertical(constraints: Vec<Constraint>) -> Self {
+    Self::new(Direction::Vertical, constraints)
+  }
+
+  /// Returns the size of each part after splitting the given length, in the order of the constraints.
+  ///
+  /// The sizes never add up to more than the length, and only add up to less when nothing can grow to fill it.
+  pub fn split_length(&self, length: usize) -> Vec<usize> {
+    let mut sizes: Vec<usize> = self
+      .constraints
+      .iter()
+      .map(|constraint| match *constraint {
+        Constraint::Fixed(size) | Constraint::Min(size) => size,
+        Constraint::Percentage(percentage) => length * percentage.min(100) / 100,
+        Constraint::Max(_) | Constraint::Remainder => 0,
+      })
+      .collect();
+
+    // Shrinking the last parts first when there isn't enough space.
+    let mut overflow = sizes.iter().sum::<usize>().saturating_sub(length);
+
+    for size in sizes.iter_mut().rev() {
+      let shrunk_size = (*size).min(overflow);
+      *size -= shrunk_size;
+      overflow -= shrunk_size;
+    }
+
+    let mut leftover = length - sizes.iter().sum::<usize>();
+
+    // Sharing what's left between every part that can grow, until nothing can or nothing is left.
+    loop {
+      let growable: Vec<usize> = (0..sizes.len())
+        .filter(|&index| self.room_to_grow(index, sizes[index]) > 0)
+        .collect();
+
+      if leftover == 0 || growable.is_empty() {
+        break;
+      }
+
+      let share = leftover / growable.len();
+      let mut extra = leftover % growable.len();
+
+      for index in growable {
+        let wanted = share + usize::from(extra > 0);
+        let growth = wanted.min(self.room_to_grow(index, sizes[index]));
+        extra = extra.saturating_sub(1);
+
+        sizes[index] += growth;
+        leftover -= growth;
+      }
+    }
+
+    sizes
+  }
+
+  /// Returns the area split into one box per constraint, in the same order.
+  ///
+  /// Every box has the same [`Alignment`](crate::printing_position::Alignment) and fill as the area.
+  pub fn split(&self, area: BoundingBox) -> Vec<BoundingBox> {
+    let (area_x, area_y) = area.position;
+    let (area_width, area_height) = area.dimensions;
+    let length = match self.direction {
+      Direction::Horizontal => area_width,
+      Direction::Vertical => area_height,
+    };
+    let mut offset = 0;
+
+    self
+      .split_length(length)
+      .into_iter()
+      .map(|size| {
+        let (position, dimensions) = match self.direction {
+          Direction::Horizontal => ((area_x + offset, area_y), (size, area_height)),
+          Direction::Vertical => ((area_x, area_y + offset), (area_width, size)),
+        };
+        offset += size;
+
+        BoundingBox {
+          position,
+          dimensions,
+          ..area
+        }
+      })
+      .collect()
+  }
+
+  /// Returns how much more the part at the given index can grow from its current size.
+  fn room_to_grow(&self, index: usize, size: usize) -> usize {
+    match self.constraints[index] {
+      Constraint::Min(_) | Constraint::Remainder => usize::MAX,
+      Constraint::Max(max) => max.saturating_sub(size),
+      Constraint::Fixed(_) | Constraint::Percentage(_) => 0,
+    }
+  }
+}
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod split_length_tests {
+  use super::*;
+  use alloc::vec;
+
+  #[test]
+  fn remainders_share_what_is_left() {
+    let layout = Layout::horizontal(vec![
+      Constraint::Fixed(3),
+      Constraint::Remainder,
+      Constraint::Remainder,
+    ]);
+
+    assert_eq!(layout.split_length(10), vec![3, 4, 3]);
+  }
+
+  #[test]
+  fn percentages_round_down() {
+    let layout = Layout::horizontal(vec![Constraint::Percentage(33), Constraint::Percentage(50)]);
+
+    assert_eq!(layout.split_length(10), vec![3, 5]);
+  }
+
+  #[test]
+  fn max_parts_stop_growing_at_their_limit() {
+    let layout = Layout::horizontal(vec![Constraint::Max(2), Constraint::Min(1)]);
+
+    assert_eq!(layout.split_length(10), vec![2, 8]);
+  }
+
+  #[test]
+  fn fixed_parts_leave_space_unfilled() {
+    let layout = Layout::horizontal(vec![Constraint::Fixed(2), Constraint::Max(3)]);
+
+    assert_eq!(layout.split_length(10), vec![2, 3]);
+  }
+
+  #[test]
+  fn last_parts_shrink_when_there_is_not_enough_space() {
+    let layout = Layout::horizontal(vec![
+      Constraint::Fixed(4),
+      Constraint::Min(4),
+      Constraint::Fixed(4),
+    ]);
+
+    assert_eq!(layout.split_length(6), vec![4, 2, 0]);
+  }
+}
+
+#[cfg(test)]
+mod split_tests {
+  use super::*;
+  use alloc::vec;
+
+  #[test]
+  fn vertical_splits_stack_boxes() {
+    let layout = Layout::vertical(vec![Constraint::Fixed(1), Constraint::Remainder]);
+
+    assert_eq!(
+      layout.split(BoundingBox::new((3, 2), (5, 4))),
+      vec![
+        BoundingBox::new((3, 2), (5, 1)),
+        BoundingBox::new((3, 3), (5, 3))
+      ]
+    );
+  }
+}
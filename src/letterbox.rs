@@ -0,0 +1,118 @@
+mod tests;
+
+use crate::color::{colored_text, downconvert_colors, Color, ColorDepth};
+use crate::escape_emitter::{translate_styles, EscapeEmitter};
+
+/// The fill printed around the grid every frame, covering any leftover content from larger or moved grids.
+///
+/// The area around the grid is the [`BoundingBox`](crate::printing_position::BoundingBox) when one is set,
+/// or the entire printing area of the terminal otherwise.
+///
+/// Set with [`replace_letterbox`](crate::printer::Printer::replace_letterbox).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::deterministic().with_printing_position(PrintingPosition::new(
+///   XPrintingPosition::Middle,
+///   YPrintingPosition::Middle,
+/// ));
+/// printer.replace_letterbox(Some(Letterbox::new(' ').with_background(Color::new(0, 0, 40))));
+///
+/// printer.dynamic_print("abcd\n1234".to_string()).unwrap();
+/// // The area the previous grid took up outside of this one is filled in.
+/// printer.dynamic_print("ab\n12".to_string()).unwrap();
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Letterbox {
+  /// The character printed around the grid, which is expected to take up a single column.
+  pub character: char,
+  /// The background color printed around the grid, if any.
+  pub background: Option<Color>,
+}
+
+impl Letterbox {
+  /// Creates a letterbox of the given character, with no background color.
+  pub fn new(character: char) -> Self {
+    Self {
+      character,
+      background: None,
+    }
+  }
+
+  /// Returns the letterbox with the given background color.
+  pub fn with_background(mut self, background: Color) -> Self {
+    self.background = Some(background);
+
+    self
+  }
+
+  /// Returns the cursor movements and text that fill the area around the grid with the letterbox.
+  ///
+  /// Both the area and grid are given as the (x, y) of their top left, followed by their (width, height).
+  /// The background color is converted down to the given color depth.
+  pub(crate) fn printable_fill(
+    &self,
+    area: ((usize, usize), (usize, usize)),
+    grid: ((usize, usize), (usize, usize)),
+    color_depth: ColorDepth,
+    emitter: &dyn EscapeEmitter,
+  ) -> String {
+    let segments = letterbox_segments(area, grid);
+
+    if segments.is_empty() {
+      return String::new();
+    }
+
+    let mut printable_fill = String::new();
+
+    for ((x, y), width) in segments {
+      let text = self.character.to_string().repeat(width);
+      let text = match self.background {
+        Some(background) => {
+          downconvert_colors(&colored_text(&text, None, Some(background)), color_depth)
+        }
+        None => text,
+      };
+
+      printable_fill.push_str(&emitter.move_cursor(x, y));
+      printable_fill.push_str(&translate_styles(&text, emitter));
+    }
+
+    if self.background.is_some() {
+      printable_fill.push_str(&emitter.reset_style());
+    }
+
+    printable_fill
+  }
+}
+
+/// Returns the (x, y) and width of each row segment of the area that isn't covered by the grid, from top to bottom.
+///
+/// Both the area and grid are given as the (x, y) of their top left, followed by their (width, height).
+pub(crate) fn letterbox_segments(
+  ((area_x, area_y), (area_width, area_height)): ((usize, usize), (usize, usize)),
+  ((grid_x, grid_y), (grid_width, grid_height)): ((usize, usize), (usize, usize)),
+) -> Vec<((usize, usize), usize)> {
+  let area_end_x = area_x + area_width;
+  let grid_end_x = (grid_x + grid_width).clamp(area_x, area_end_x);
+  let grid_x = grid_x.clamp(area_x, area_end_x);
+
+  (area_y..area_y + area_height)
+    .flat_map(|y| {
+      if y < grid_y || y >= grid_y + grid_height {
+        return vec![((area_x, y), area_width)];
+      }
+
+      [
+        ((area_x, y), grid_x - area_x),
+        ((grid_end_x, y), area_end_x - grid_end_x),
+      ]
+      .into_iter()
+      .filter(|(_, width)| *width > 0)
+      .collect()
+    })
+    .filter(|(_, width)| *width > 0)
+    .collect()
+}
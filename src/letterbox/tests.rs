@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use super::*;
+use crate::escape_emitter::AnsiEmitter;
+
+#[cfg(test)]
+mod letterbox_segments_tests {
+  use super::*;
+
+  #[test]
+  fn area_around_the_grid_is_covered() {
+    let segments = letterbox_segments(((1, 1), (6, 4)), ((3, 2), (2, 2)));
+
+    assert_eq!(
+      segments,
+      vec![
+        ((1, 1), 6),
+        ((1, 2), 2),
+        ((5, 2), 2),
+        ((1, 3), 2),
+        ((5, 3), 2),
+        ((1, 4), 6),
+      ]
+    );
+  }
+
+  #[test]
+  fn grid_touching_the_edges_leaves_no_empty_segments() {
+    let segments = letterbox_segments(((1, 1), (4, 2)), ((1, 1), (3, 2)));
+
+    assert_eq!(segments, vec![((4, 1), 1), ((4, 2), 1)]);
+  }
+
+  #[test]
+  fn grid_filling_the_area_has_no_segments() {
+    assert!(letterbox_segments(((2, 2), (3, 3)), ((2, 2), (3, 3))).is_empty());
+  }
+}
+
+#[cfg(test)]
+mod printable_fill_tests {
+  use super::*;
+
+  #[test]
+  fn fill_is_colored_and_reset() {
+    let letterbox = Letterbox::new('.').with_background(Color::new(1, 2, 3));
+
+    let printable_fill = letterbox.printable_fill(
+      ((1, 1), (3, 1)),
+      ((1, 1), (1, 1)),
+      ColorDepth::TrueColor,
+      &AnsiEmitter,
+    );
+
+    assert_eq!(printable_fill, "\x1B[1;2H\x1B[0;48;2;1;2;3m..\x1B[0m");
+  }
+
+  #[test]
+  fn fill_without_background_is_unstyled() {
+    let printable_fill = Letterbox::new('.').printable_fill(
+      ((1, 1), (2, 2)),
+      ((1, 1), (2, 1)),
+      ColorDepth::TrueColor,
+      &AnsiEmitter,
+    );
+
+    assert_eq!(printable_fill, "\x1B[2;1H..");
+  }
+}
@@ -1,7 +1,90 @@
-#![doc = include_str!("../README.md")]
+// The readme's examples use the printer, which requires the `std` feature.
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod accessibility;
+#[cfg(feature = "std")]
+pub mod animation;
+pub mod ansi;
+#[cfg(feature = "ndarray")]
+pub mod array_grid;
+#[cfg(feature = "std")]
+pub mod background_printer;
+#[cfg(feature = "std")]
+pub mod bidi;
+pub mod canvas;
+#[cfg(feature = "std")]
+pub mod capabilities;
+#[cfg(feature = "std")]
+pub mod capture;
+#[cfg(feature = "std")]
+pub mod caret;
+pub mod cells;
+pub mod change_run;
+#[cfg(feature = "std")]
+pub mod color;
+pub mod coordinates;
+pub mod core;
+#[cfg(feature = "std")]
+pub mod diff_strategy;
+#[cfg(feature = "std")]
 pub mod dynamic_printer;
+#[cfg(feature = "std")]
 pub mod errors;
+pub mod escape_emitter;
+#[cfg(feature = "std")]
+pub mod focus;
+#[cfg(feature = "std")]
+pub mod frame_format;
+#[cfg(feature = "std")]
+pub mod frame_log;
+#[cfg(feature = "std")]
+pub mod frame_stats;
+#[cfg(feature = "std")]
+pub mod grid_pool;
+#[cfg(feature = "std")]
+pub mod grid_report;
+#[cfg(feature = "image")]
+pub mod image_grid;
+#[cfg(feature = "std")]
+pub mod inline;
+pub mod layout;
+#[cfg(feature = "std")]
+pub mod letterbox;
+#[cfg(feature = "std")]
+pub mod operating_mode;
+#[cfg(feature = "std")]
+pub mod overlay;
+#[cfg(feature = "std")]
 pub mod prelude;
+#[cfg(feature = "std")]
 pub mod printer;
+#[cfg(feature = "std")]
+pub mod printer_state;
 pub mod printing_position;
+#[cfg(feature = "std")]
+pub mod rate_limit;
+#[cfg(feature = "std")]
+pub mod recording;
+#[cfg(feature = "std")]
+pub mod region;
+#[cfg(feature = "std")]
+pub mod scaling;
+#[cfg(feature = "std")]
+pub mod scrollback;
+#[cfg(feature = "std")]
+pub mod snapshot;
+#[cfg(feature = "std")]
+pub mod sprite_sheet;
+#[cfg(feature = "std")]
+pub mod status_line;
+mod style;
+#[cfg(all(unix, feature = "termion"))]
+mod terminal_query;
+#[cfg(feature = "std")]
+pub mod virtual_terminal;
+#[cfg(feature = "std")]
+pub mod widgets;
@@ -0,0 +1,71 @@
+mod tests;
+
+use crate::ansi::EscapeSanitization;
+use crate::scaling::OverflowPolicy;
+
+/// How forgiving the [`Printer`](crate::printer::Printer) is of grids that can't be printed as they are.
+///
+/// The mode decides the default of every setting that handles irregular grids, each of which can still be overridden on its own:
+///
+/// | Setting | [`Strict`](OperatingMode::Strict) | [`Lenient`](OperatingMode::Lenient) |
+/// |---|---|---|
+/// | [`Row padding`](crate::printer::Printer::set_row_padding) | Off | On |
+/// | [`Line ending normalization`](crate::printer::Printer::set_line_ending_normalization) | Off | On |
+/// | [`EscapeSanitization`](crate::ansi::EscapeSanitization) | [`Off`](crate::ansi::EscapeSanitization::Off) | [`AllowStyles`](crate::ansi::EscapeSanitization::AllowStyles) |
+/// | [`OverflowPolicy`](crate::scaling::OverflowPolicy) | [`Error`](crate::scaling::OverflowPolicy::Error) | [`Crop`](crate::scaling::OverflowPolicy::Crop) |
+///
+/// Set with [`replace_operating_mode`](crate::printer::Printer::replace_operating_mode).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((4, 2));
+/// printer.replace_operating_mode(OperatingMode::Lenient);
+/// // Overrides take priority over the mode.
+/// printer.replace_overflow_policy(OverflowPolicy::Downsample);
+///
+/// // Padded into a rectangle, rather than returning an error.
+/// printer.dynamic_print("abc\nd".to_string()).unwrap();
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum OperatingMode {
+  /// Grids are printed as they are, returning an error on anything irregular, such as non-rectangular grids or grids larger than the terminal.
+  ///
+  /// This is for failing fast, such as in tests.
+  #[default]
+  Strict,
+  /// Grids are fixed up to be printable where possible, by padding rows, removing escape sequences other than styles,
+  /// and cropping anything that doesn't fit.
+  ///
+  /// This is for printing whatever can be printed, such as in production.
+  Lenient,
+}
+
+impl OperatingMode {
+  /// Returns whether rows narrower than the widest row are padded by default.
+  pub(crate) fn row_padding(&self) -> bool {
+    *self == Self::Lenient
+  }
+
+  /// Returns whether line endings are normalized by default.
+  pub(crate) fn line_ending_normalization(&self) -> bool {
+    *self == Self::Lenient
+  }
+
+  /// Returns the default [`EscapeSanitization`](crate::ansi::EscapeSanitization).
+  pub(crate) fn escape_sanitization(&self) -> EscapeSanitization {
+    match self {
+      Self::Strict => EscapeSanitization::Off,
+      Self::Lenient => EscapeSanitization::AllowStyles,
+    }
+  }
+
+  /// Returns the default [`OverflowPolicy`](crate::scaling::OverflowPolicy).
+  pub(crate) fn overflow_policy(&self) -> OverflowPolicy {
+    match self {
+      Self::Strict => OverflowPolicy::Error,
+      Self::Lenient => OverflowPolicy::Crop,
+    }
+  }
+}
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use super::*;
+use crate::dynamic_printer::{DynamicPrinter, DynamicPrinterMethods};
+use crate::errors::PrintingError;
+use crate::printer::Printer;
+
+#[cfg(test)]
+mod operating_mode_tests {
+  use super::*;
+
+  #[test]
+  fn strict_mode_errors_on_irregular_grids() {
+    let mut printer = Printer::on_virtual_terminal((4, 2));
+
+    assert_eq!(
+      printer.dynamic_print("abc\nd".to_string()),
+      Err(PrintingError::NonRectangularGrid)
+    );
+  }
+
+  #[test]
+  fn lenient_mode_fixes_up_irregular_grids() {
+    let mut printer = Printer::on_virtual_terminal((4, 2));
+    printer.replace_operating_mode(OperatingMode::Lenient);
+
+    let grid = printer
+      .prepare_grid("ab\x1B[2J\r\nc\r\n".to_string())
+      .unwrap();
+
+    assert_eq!(grid, "ab\nc ");
+    assert!(printer.dynamic_print("abcdef\nd\ng".to_string()).is_ok());
+  }
+
+  #[test]
+  fn overrides_take_priority_over_the_mode() {
+    let mut printer = Printer::on_virtual_terminal((4, 2));
+    printer.set_row_padding(false);
+    printer.replace_operating_mode(OperatingMode::Lenient);
+
+    assert!(!printer.get_row_padding());
+    assert!(printer.get_line_ending_normalization());
+    assert_eq!(printer.get_overflow_policy(), OverflowPolicy::Crop);
+  }
+
+  #[test]
+  fn resetting_removes_the_overrides() {
+    let mut printer = Printer::new();
+    printer.replace_escape_sanitization(EscapeSanitization::Strip);
+
+    printer.reset();
+
+    assert_eq!(printer.get_escape_sanitization(), EscapeSanitization::Off);
+  }
+}
@@ -0,0 +1,257 @@
+mod tests;
+
+use crate::cells::*;
+use crate::color::{colored_text, downconvert_colors, Color, ColorDepth};
+use crate::printing_position::{Alignment, BoundingBox, CenterRounding};
+use crate::style::StyledTextBuilder;
+use std::rc::Rc;
+
+/// Text composited over a region of every printed grid, such as a caption along the bottom.
+///
+/// The overlay is placed within the grid based on its [`Alignment`](Alignment), and is cut down to fit when it's larger than the grid.
+/// Since it's a part of what's printed, changing only the overlay reprints only the cells it covers.
+///
+/// Set with [`replace_overlay`](crate::printer::Printer::replace_overlay).
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::deterministic();
+/// printer.replace_overlay(Some(
+///   Overlay::new("Hello there", Alignment::Bottom)
+///     .with_transparency(OverlayTransparency::TransparentSpaces)
+///     .with_foreground(Color::new(255, 255, 0)),
+/// ));
+///
+/// printer.dynamic_print("#".repeat(20) + "\n" + &"#".repeat(20)).unwrap();
+///
+/// printer.replace_overlay_text("General Kenobi");
+/// printer.dynamic_print("#".repeat(20) + "\n" + &"#".repeat(20)).unwrap();
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Overlay {
+  /// The text of the overlay, where every line is a row.
+  ///
+  /// Rows shorter than the longest are padded with spaces.
+  pub text: String,
+  /// Where the overlay is placed within the grid.
+  pub alignment: Alignment,
+  pub transparency: OverlayTransparency,
+  /// The foreground color of the overlay's text, if any.
+  pub foreground: Option<Color>,
+  /// The background color of the overlay's text, if any.
+  pub background: Option<Color>,
+}
+
+/// Which parts of an [`Overlay`](Overlay) cover the grid beneath it.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum OverlayTransparency {
+  /// Every cell of the overlay covers the grid, including spaces.
+  #[default]
+  Opaque,
+  /// Spaces in the overlay show the grid beneath them.
+  TransparentSpaces,
+}
+
+/// A column of a row while compositing an overlay over it.
+#[derive(Debug, Clone)]
+enum Column<'a> {
+  Cell(Cell<'a>),
+  /// A column covered by the wide cell before it.
+  Covered,
+}
+
+impl Overlay {
+  /// Creates an opaque, unstyled overlay of the given text, placed within the grid with the given alignment.
+  pub fn new<S: Into<String>>(text: S, alignment: Alignment) -> Self {
+    Self {
+      text: text.into(),
+      alignment,
+      ..Default::default()
+    }
+  }
+
+  /// Returns the overlay with the given [`OverlayTransparency`](OverlayTransparency).
+  pub fn with_transparency(mut self, transparency: OverlayTransparency) -> Self {
+    self.transparency = transparency;
+
+    self
+  }
+
+  /// Returns the overlay with the given foreground color.
+  pub fn with_foreground(mut self, foreground: Color) -> Self {
+    self.foreground = Some(foreground);
+
+    self
+  }
+
+  /// Returns the overlay with the given background color.
+  pub fn with_background(mut self, background: Color) -> Self {
+    self.background = Some(background);
+
+    self
+  }
+
+  /// Returns the grid of the given (width, height) with the overlay composited over it.
+  ///
+  /// The (x, y) [`CenterRounding`](CenterRounding) is used for alignments in the middle of an axis,
+  /// and the overlay's colors are converted down to the given color depth.
+  pub(crate) fn composite(
+    &self,
+    grid: &str,
+    (grid_width, grid_height): (usize, usize),
+    center_rounding: (CenterRounding, CenterRounding),
+    color_depth: ColorDepth,
+    emoji_mode: EmojiMode,
+  ) -> String {
+    let overlay_style = if self.foreground.is_some() || self.background.is_some() {
+      downconvert_colors(
+        &colored_text("", self.foreground, self.background),
+        color_depth,
+      )
+    } else {
+      String::new()
+    };
+    let mut overlay_rows: Vec<Vec<Cell>> = self
+      .text
+      .split('\n')
+      .take(grid_height)
+      .map(|row| {
+        let mut style: Rc<str> = Rc::from(overlay_style.as_str());
+
+        split_row_into_cells(row, emoji_mode, &mut style)
+      })
+      .collect();
+    let overlay_width = overlay_rows
+      .iter()
+      .map(|row| row.iter().map(|cell| cell.width).sum::<usize>())
+      .max()
+      .unwrap_or(0)
+      .min(grid_width);
+    let overlay_height = overlay_rows.len();
+
+    if overlay_width == 0 {
+      return grid.to_string();
+    }
+
+    let overlay_style: Rc<str> = Rc::from(overlay_style);
+
+    for row in &mut overlay_rows {
+      let row_width: usize = row.iter().map(|cell| cell.width).sum();
+
+      row.extend((row_width..overlay_width).map(|_| space_cell(&overlay_style)));
+    }
+
+    let (overlay_x, overlay_y) = BoundingBox::new((1, 1), (grid_width, grid_height))
+      .with_alignment(self.alignment)
+      .align((overlay_width, overlay_height), center_rounding);
+    let (overlay_x, overlay_y) = (overlay_x - 1, overlay_y - 1);
+
+    split_grid_into_cells(grid, emoji_mode)
+      .into_iter()
+      .enumerate()
+      .map(|(y, row)| {
+        let mut columns: Vec<Column> = row
+          .into_iter()
+          .flat_map(|cell| {
+            let covered_width = cell.width - 1;
+
+            std::iter::once(Column::Cell(cell))
+              .chain(std::iter::repeat_n(Column::Covered, covered_width))
+          })
+          .collect();
+
+        if let Some(overlay_row) = y
+          .checked_sub(overlay_y)
+          .and_then(|overlay_row| overlay_rows.get(overlay_row))
+        {
+          let mut x = overlay_x;
+
+          for overlay_cell in overlay_row {
+            let cell_x = x;
+            x += overlay_cell.width;
+
+            if x > overlay_x + overlay_width {
+              // Wide cells cut off by the edge of the overlay leave a space behind.
+              if cell_x < overlay_x + overlay_width {
+                place_cell(&mut columns, cell_x, space_cell(&overlay_cell.style));
+              }
+
+              break;
+            }
+
+            if self.transparency == OverlayTransparency::TransparentSpaces
+              && overlay_cell.text == " "
+            {
+              continue;
+            }
+
+            place_cell(&mut columns, cell_x, overlay_cell.clone());
+          }
+        }
+
+        let mut row_text = StyledTextBuilder::default();
+        columns.iter().for_each(|column| {
+          if let Column::Cell(cell) = column {
+            row_text.push(cell);
+          }
+        });
+
+        row_text.finish()
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
+
+/// Returns a space of the given style.
+fn space_cell<'a>(style: &Rc<str>) -> Cell<'a> {
+  Cell {
+    text: " ",
+    width: 1,
+    style: style.clone(),
+  }
+}
+
+/// Places the cell at the given column of the row.
+///
+/// Any wide cells the new cell covers part of have the rest of their columns replaced with spaces of their style.
+fn place_cell<'a>(columns: &mut Vec<Column<'a>>, x: usize, cell: Cell<'a>) {
+  if x + cell.width > columns.len() {
+    return;
+  }
+
+  for covered_x in x..x + cell.width {
+    break_wide_cell(columns, covered_x);
+  }
+
+  let cell_width = cell.width;
+  columns[x] = Column::Cell(cell);
+  columns[x + 1..x + cell_width].fill(Column::Covered);
+}
+
+/// Replaces the wide cell covering the given column with spaces of its style, if the column is a part of one.
+fn break_wide_cell(columns: &mut [Column], x: usize) {
+  let Some(start_x) = (0..=x)
+    .rev()
+    .find(|&start_x| matches!(columns[start_x], Column::Cell(_)))
+  else {
+    return;
+  };
+
+  let Column::Cell(cell) = &columns[start_x] else {
+    return;
+  };
+
+  if cell.width == 1 {
+    return;
+  }
+
+  let style = cell.style.clone();
+  let end_x = start_x + cell.width;
+
+  for column in &mut columns[start_x..end_x] {
+    *column = Column::Cell(space_cell(&style));
+  }
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod composite_tests {
+  use super::*;
+
+  fn composite(overlay: &Overlay, grid: &str) -> String {
+    overlay.composite(
+      grid,
+      measure_grid(grid),
+      Default::default(),
+      ColorDepth::TrueColor,
+      EmojiMode::Off,
+    )
+  }
+
+  #[test]
+  fn overlay_is_aligned_within_the_grid() {
+    let overlay = Overlay::new("ab", Alignment::Bottom);
+
+    assert_eq!(composite(&overlay, "#####\n#####"), "#####\n#ab##");
+  }
+
+  #[test]
+  fn rows_are_padded_to_the_longest() {
+    let overlay = Overlay::new("abc\nd", Alignment::TopLeft);
+
+    assert_eq!(composite(&overlay, "####\n####\n####"), "abc#\nd  #\n####");
+  }
+
+  #[test]
+  fn transparent_spaces_show_the_grid() {
+    let overlay = Overlay::new("a b", Alignment::TopLeft)
+      .with_transparency(OverlayTransparency::TransparentSpaces);
+
+    assert_eq!(composite(&overlay, "####"), "a#b#");
+  }
+
+  #[test]
+  fn overlay_is_cut_down_to_the_grid() {
+    let overlay = Overlay::new("abcdef\nghi\njkl", Alignment::TopLeft);
+
+    assert_eq!(composite(&overlay, "###\n###"), "abc\nghi");
+  }
+
+  #[test]
+  fn covered_wide_cells_become_spaces() {
+    let overlay = Overlay::new("a", Alignment::TopRight);
+
+    assert_eq!(composite(&overlay, "#漢"), "# a");
+  }
+
+  #[test]
+  fn overlay_is_styled() {
+    let overlay = Overlay::new("a", Alignment::TopLeft).with_foreground(Color::new(1, 2, 3));
+
+    assert_eq!(
+      composite(&overlay, "\x1B[31m##\x1B[0m"),
+      "\x1B[0;38;2;1;2;3ma\x1B[0m\x1B[31m#\x1B[0m"
+    );
+  }
+}
@@ -1,9 +1,69 @@
+pub use crate::accessibility::Accessibility;
+use crate::animation::Animation;
+pub use crate::animation::{FramePacing, PlaybackStats};
+pub use crate::ansi::{sanitize_escapes, strip_ansi, EscapeSanitization, ESCAPE_SYMBOL};
+pub use crate::bidi::BidiMode;
+pub use crate::canvas::Canvas;
+pub use crate::capabilities::TerminalCapabilities;
+pub use crate::capture::CaptureWriter;
+use crate::capture::SuppliedOutput;
+pub use crate::caret::{Caret, CaretStyle};
+use crate::cells::{find_control_character, normalize_characters, row_width};
+pub use crate::cells::{
+  measure_grid, measure_row_width, CharacterNormalization, EmojiMode, GridNormalization,
+};
+pub use crate::change_run::*;
+pub use crate::color::{downconvert_colors, quantize_colors, Color, ColorDepth, Quantization};
+pub use crate::coordinates::*;
+pub use crate::diff_strategy::*;
 pub use crate::dynamic_printer::*;
 pub use crate::errors::*;
+pub use crate::escape_emitter::{AnsiEmitter, EscapeEmitter};
+pub use crate::focus::{FocusHighlight, FocusManager};
+pub use crate::frame_format::FrameDiff;
+pub use crate::frame_log::FrameLog;
+#[cfg(feature = "profiling")]
+pub use crate::frame_stats::PhaseTimings;
+use crate::frame_stats::{next_random_number, Phase, PhaseTimer};
+pub use crate::frame_stats::{
+  AdaptiveRefresh, BudgetDegradation, FrameStats, FullRefreshInterval, Watchdog,
+};
+pub use crate::grid_pool::GridPool;
+pub use crate::grid_report::GridReport;
+#[cfg(feature = "image")]
+pub use crate::image_grid::{Dithering, ImageConversion, ImageOptions, LUMINANCE_RAMP};
+pub use crate::layout::{Constraint, Direction, Layout};
+pub use crate::letterbox::Letterbox;
+pub use crate::operating_mode::OperatingMode;
+pub use crate::overlay::{Overlay, OverlayTransparency};
+pub use crate::printer_state::*;
 pub use crate::printing_position::*;
+use crate::rate_limit::RateLimiter;
+pub use crate::rate_limit::{OutputRateLimit, RateLimitBehavior};
+use crate::recording::ActiveRecording;
+pub use crate::recording::{Marker, RecordedFrame, Recording};
+use crate::region::compose_regions;
+pub use crate::region::Region;
+use crate::scaling::fit_dimensions;
+pub use crate::scaling::OverflowPolicy;
+use crate::scrollback::AnchoredEmitter;
+pub use crate::scrollback::UpdateStrategy;
+pub use crate::status_line::{StatusLine, StatusLinePosition};
+use crate::virtual_terminal::VirtualTerminal;
+use crate::widgets::{Reflow, Widget};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
-use std::{io, io::Write};
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::time::{Duration, Instant};
+use std::{io, io::IsTerminal, io::Write};
+
+/// The (width, height) of the terminal of a [`deterministic`](Printer::deterministic) printer.
+pub const DETERMINISTIC_DIMENSIONS: (usize, usize) = (80, 24);
+
+/// The seed for anything random a printer on a [`virtual terminal`](Printer::on_virtual_terminal) does.
+const DETERMINISTIC_RANDOM_SEED: u64 = 0x5EED;
 
 /// # Screen Printer
 ///
@@ -25,30 +85,31 @@ use std::{io, io::Write};
 /// The core part of this crate is the [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) method.
 /// This will take a rectangular grid of characters, and print only the parts of the grid that have changed since the last print.
 ///
-/// ```rust,no_run
+/// ```rust
 /// use screen_printer::printer::*;
 ///
-/// const WIDTH: usize = 3;
-/// const HEIGHT: usize = 3;
-///
 /// fn main() {
-///   print!("\u{1b}[2J"); // Clear all text on the terminal
+///   // A deterministic printer prints to an 80x24 virtual terminal rather than stdout, so this runs the same everywhere.
+///   // Use `Printer::new()` to print to the terminal instead.
 ///   // The default printing position is the bottom left of the terminal
-///   let mut printer = Printer::new_with_printing_position(PrintingPosition::default());
+///   let mut printer = Printer::deterministic();
 ///
 ///   // Create the first grid to be printed.
 ///   let grid_1 = "abc\n123\nxyz".to_string();
 ///   // print the first grid.
 ///   printer.dynamic_print(grid_1).unwrap();
 ///
-///   // Wait before printing the second grid.
-///   std::thread::sleep(std::time::Duration::from_millis(500));
-///
 ///   // Create the second grid to be printed.
 ///   let grid_2 = "abc\n789\nxyz".to_string();
 ///   // Print the second grid.
-///   // This will only end up printing the difference between the two grids/
+///   // This will only end up printing the difference between the two grids.
 ///   printer.dynamic_print(grid_2).unwrap();
+///
+///   let screen = printer.virtual_screen().unwrap().plain_screen();
+///   let bottom_rows: Vec<&str> = screen.lines().skip(21).map(str::trim_end).collect();
+///
+///   assert_eq!(bottom_rows, vec!["abc", "789", "xyz"]);
+///   assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 3);
 /// }
 /// ```
 ///
@@ -87,7 +148,103 @@ pub struct Printer {
   previous_terminal_dimensions: Option<(usize, usize)>,
 
   printing_position: PrintingPosition,
+  x_center_rounding: CenterRounding,
+  y_center_rounding: CenterRounding,
+  bounding_box: Option<BoundingBox>,
+  letterbox: Option<Letterbox>,
+  overlay: Option<Overlay>,
+  aspect_ratio_fit: bool,
+  overflow_policy: Option<OverflowPolicy>,
+  pub(crate) page: usize,
+  pub(crate) page_count: usize,
+  /// The last grid printed while paginating, before being split into pages.
+  pub(crate) paged_grid: String,
+  resize_redraw: bool,
+  resize_behavior: ResizeBehavior,
+  /// The origin the grid stays at after the terminal was resized with [`ResizeBehavior::KeepOrigin`](ResizeBehavior::KeepOrigin),
+  /// until it has to be moved.
+  pub(crate) kept_origin: Option<(usize, usize)>,
+  /// The last grid passed into the printer, kept for reprinting it when the terminal is resized.
+  pub(crate) redraw_grid: String,
+  pub(crate) controlling_terminal: Option<File>,
+  /// Everything printed while printing to a virtual terminal.
+  pub(crate) capture: Option<CaptureWriter>,
+  /// The writer everything printed to the virtual terminal is also written to, if one was supplied.
+  supplied_output: Option<SuppliedOutput>,
+  /// The (top row, height) of the rows grids are printed within while in inline mode.
+  pub(crate) inline_area: Option<(usize, usize)>,
+  update_strategy: UpdateStrategy,
+  /// Whether the top left of the grid was saved while preserving scrollback.
+  pub(crate) scrollback_anchored: bool,
+  /// The amount of terminal rows the most recently appended grid takes up, if any.
+  pub(crate) appended_rows: Option<usize>,
   pub(crate) printing_position_changed_since_last_print: bool,
+
+  diff_granularity: DiffGranularity,
+  diff_policy: DiffPolicy,
+  normalize_line_endings: Option<bool>,
+  pad_rows: Option<bool>,
+  operating_mode: OperatingMode,
+  cursor_addressing: Option<bool>,
+  /// Whether the terminal was detected to support cursor addressing, once it's been detected.
+  detected_cursor_addressing: Option<bool>,
+  frame_log: Option<FrameLog>,
+  accessibility: Option<Accessibility>,
+  /// The amount of frames logged, and the time the first one was logged at.
+  logged_frames: usize,
+  frame_log_start: Option<Instant>,
+  emoji_mode: EmojiMode,
+  bidi_mode: BidiMode,
+  character_normalization: CharacterNormalization,
+  color_depth: Option<ColorDepth>,
+  strict_character_validation: bool,
+  gap_overwriting: Option<bool>,
+  streaming_diff: bool,
+  reserved_top_rows: usize,
+  reserved_bottom_rows: usize,
+  /// Which edge of the terminal a row is reserved on for the status line, if one is.
+  pub(crate) status_line_position: Option<StatusLinePosition>,
+  pub(crate) status_line_text: String,
+  escape_emitter: Option<Box<dyn EscapeEmitter>>,
+  diff_strategy: Option<Box<dyn DiffStrategy>>,
+  terminal_dimensions: Option<(usize, usize)>,
+  caret: Option<Caret>,
+  last_frame_stats: Option<FrameStats>,
+  adaptive_refresh: Option<AdaptiveRefresh>,
+  refresh_interval: Duration,
+  idle_suppression: bool,
+  keep_alive_interval: Option<Duration>,
+  pub(crate) last_submitted_grid: String,
+  last_print_time: Option<Instant>,
+  debounce_interval: Option<Duration>,
+  frame_pacing: FramePacing,
+  back_buffer: Option<Canvas>,
+  grid_pool: Option<GridPool>,
+  regions: Vec<(String, Region)>,
+  focus_manager: Option<FocusManager>,
+  /// The region and (x, y) position within it that the terminal's cursor is placed at after printing regions.
+  region_cursor: Option<(String, (usize, usize))>,
+  /// The latest grid held back by the debounce interval, which is printed once the interval passes.
+  pub(crate) debounced_grid: Option<String>,
+  pub(crate) idle: bool,
+  full_refresh_interval: Option<FullRefreshInterval>,
+  byte_budget: Option<usize>,
+  rate_limiter: Option<RateLimiter>,
+  /// Everything written for the current frame, held until it's flushed when there's a rate limit.
+  rate_limited_output: String,
+  escape_sanitization: Option<EscapeSanitization>,
+  /// The time spent on each phase of the frame currently being printed.
+  #[cfg(feature = "profiling")]
+  pub(crate) phase_timings: PhaseTimings,
+  frames_since_full_refresh: usize,
+  last_full_refresh_time: Option<Instant>,
+  watchdog: Option<Watchdog>,
+  pub(crate) watchdog_random_state: u64,
+  pub(crate) next_watchdog_row_time: Option<Instant>,
+  pub(crate) next_watchdog_frame_time: Option<Instant>,
+  pub(crate) animations: HashMap<String, Animation>,
+
+  pub(crate) recording: Option<ActiveRecording>,
 }
 
 impl Printer {
@@ -100,6 +257,157 @@ impl Printer {
     }
   }
 
+  /// Creates a new printer that prints to the controlling terminal of the process, rather than stdout.
+  ///
+  /// This opens `/dev/tty`, or `CONOUT$` on Windows, which is used for printing.
+  /// Printing then works the same regardless of where stdin and stdout are redirected to, such as when piped into `tee`.
+  ///
+  /// The terminal's dimensions are only obtained from the opened terminal on unix with the `termion` feature.
+  /// Everywhere else they're still obtained through stdout, so they can't be found while stdout is redirected,
+  /// and have to be set with [`replace_terminal_dimensions`](Printer::replace_terminal_dimensions) instead.
+  ///
+  /// Resetting the printer returns it to printing to stdout.
+  ///
+  /// # Errors
+  ///
+  /// - The process has no controlling terminal, or it couldn't be opened.
+  ///
+  /// # Example
+  /// ```rust,no_run
+  /// use screen_printer::printer::*;
+  ///
+  /// // Still printed to the terminal when running `mytool | tee log`.
+  /// let mut printer = Printer::on_controlling_terminal().unwrap();
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  /// ```
+  pub fn on_controlling_terminal() -> Result<Self, PrintingError> {
+    #[cfg(windows)]
+    const CONTROLLING_TERMINAL_PATH: &str = "CONOUT$";
+    #[cfg(not(windows))]
+    const CONTROLLING_TERMINAL_PATH: &str = "/dev/tty";
+
+    let controlling_terminal = OpenOptions::new()
+      .read(true)
+      .write(true)
+      .open(CONTROLLING_TERMINAL_PATH)?;
+
+    Ok(Self {
+      controlling_terminal: Some(controlling_terminal),
+      ..Default::default()
+    })
+  }
+
+  /// Returns true if the printer prints to the controlling terminal rather than stdout.
+  ///
+  /// Refer to [`on_controlling_terminal`](Printer::on_controlling_terminal) for more information.
+  pub fn is_on_controlling_terminal(&self) -> bool {
+    self.controlling_terminal.is_some()
+  }
+
+  /// Creates a new printer that prints to an in-memory terminal of the given (width, height), rather than stdout.
+  ///
+  /// Printers on a virtual terminal never query the environment or the real terminal, so they behave the same everywhere.
+  /// Unless replaced, the [`color depth`](Printer::get_color_depth) is 24-bit, and the [`capabilities`](Printer::capabilities)
+  /// are those of the [`VirtualTerminal`](crate::virtual_terminal::VirtualTerminal). Anything random, such as the rows the
+  /// [`Watchdog`](crate::frame_stats::Watchdog) picks, is the same on every run.
+  ///
+  /// Everything printed is kept in a [`CaptureWriter`](crate::capture::CaptureWriter), obtained with [`get_capture`](Printer::get_capture),
+  /// and can be interpreted into what the terminal would display with [`virtual_screen`](Printer::virtual_screen).
+  /// This is meant for testing what a program prints, such as with [`assert_screen_eq`](crate::assert_screen_eq).
+  ///
+  /// Resetting the printer returns it to printing to stdout.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((5, 2))
+  ///   .with_printing_position(PrintingPosition::new(XPrintingPosition::Left, YPrintingPosition::Top));
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  ///
+  /// assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "abc  \n123  ");
+  /// ```
+  pub fn on_virtual_terminal(terminal_dimensions: (usize, usize)) -> Self {
+    Self {
+      terminal_dimensions: Some(terminal_dimensions),
+      capture: Some(CaptureWriter::new(terminal_dimensions)),
+      watchdog_random_state: DETERMINISTIC_RANDOM_SEED,
+      ..Default::default()
+    }
+  }
+
+  /// Creates a new printer on a [`virtual terminal`](Printer::on_virtual_terminal) of the [`DETERMINISTIC_DIMENSIONS`](DETERMINISTIC_DIMENSIONS),
+  /// which behaves identically no matter where it's run, such as in doctests and CI.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic();
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  ///
+  /// assert_eq!(printer.get_current_terminal_dimensions().unwrap(), (80, 24));
+  /// assert_eq!(printer.get_capture().unwrap().frame_count(), 1);
+  /// ```
+  pub fn deterministic() -> Self {
+    Self::on_virtual_terminal(DETERMINISTIC_DIMENSIONS)
+  }
+
+  /// Creates a new [`deterministic`](Printer::deterministic) printer that also writes everything it prints to the given output,
+  /// such as a buffer or a file that's compared against afterwards.
+  ///
+  /// The output receives exactly what's [`captured`](Printer::get_capture), and is flushed after every grid.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let output_path = std::env::temp_dir().join("screen_printer_deterministic_output");
+  /// let output = std::fs::File::create(&output_path).unwrap();
+  /// let mut printer = Printer::deterministic_with_output(output);
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  ///
+  /// assert_eq!(
+  ///   std::fs::read(&output_path).unwrap(),
+  ///   printer.get_capture().unwrap().bytes()
+  /// );
+  /// # std::fs::remove_file(output_path).unwrap();
+  /// ```
+  pub fn deterministic_with_output<W: Write + Send + 'static>(output: W) -> Self {
+    Self {
+      supplied_output: Some(SuppliedOutput(Box::new(output))),
+      ..Self::deterministic()
+    }
+  }
+
+  /// Returns true if the printer prints to an in-memory terminal rather than stdout.
+  ///
+  /// Refer to [`on_virtual_terminal`](Printer::on_virtual_terminal) for more information.
+  pub fn is_on_virtual_terminal(&self) -> bool {
+    self.capture.is_some()
+  }
+
+  /// Returns the [`CaptureWriter`](crate::capture::CaptureWriter) holding everything printed to the in-memory terminal,
+  /// or None if the printer isn't printing to one.
+  ///
+  /// Refer to [`on_virtual_terminal`](Printer::on_virtual_terminal) for more information.
+  pub fn get_capture(&self) -> Option<&CaptureWriter> {
+    self.capture.as_ref()
+  }
+
+  /// Returns what the in-memory terminal displays after everything printed to it, or None if the printer isn't printing to one.
+  ///
+  /// The terminal is the size the printer was created with.
+  ///
+  /// Refer to [`on_virtual_terminal`](Printer::on_virtual_terminal) for more information.
+  pub fn virtual_screen(&self) -> Option<VirtualTerminal> {
+    self.capture.as_ref().map(CaptureWriter::final_terminal)
+  }
+
   /// Creates a new printer for the [`dynamic_print()`](Printer::dynamic_print) method with the given printing position.
   ///
   /// PrintingPositons tell the printer where to print any grids passed into it.
@@ -111,125 +419,2194 @@ impl Printer {
     }
   }
 
-  pub fn replace_printing_position(&mut self, printing_position: PrintingPosition) {
+  /// Creates a new printer with the given printing position, for chaining with the other `with_` methods.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let printer = Printer::new()
+  ///   .with_x_printing_position(XPrintingPosition::Middle)
+  ///   .with_y_printing_position(YPrintingPosition::Top);
+  ///
+  /// assert_eq!(
+  ///   printer.get_current_printing_position(),
+  ///   &PrintingPosition::new(XPrintingPosition::Middle, YPrintingPosition::Top)
+  /// );
+  /// ```
+  pub fn with_printing_position(mut self, printing_position: PrintingPosition) -> Self {
+    self.replace_printing_position(printing_position);
+
+    self
+  }
+
+  /// Creates a new printer with the given x printing position, keeping the current y printing position.
+  pub fn with_x_printing_position(mut self, x_printing_position: XPrintingPosition) -> Self {
+    self.replace_x_printing_position(x_printing_position);
+
+    self
+  }
+
+  /// Creates a new printer with the given y printing position, keeping the current x printing position.
+  pub fn with_y_printing_position(mut self, y_printing_position: YPrintingPosition) -> Self {
+    self.replace_y_printing_position(y_printing_position);
+
+    self
+  }
+
+  /// Replaces the printing position, causing the next print to reprint the grid in its entirety.
+  ///
+  /// Returns the printer for chaining other changes.
+  pub fn replace_printing_position(&mut self, printing_position: PrintingPosition) -> &mut Self {
     self.printing_position = printing_position;
     self.printing_position_changed_since_last_print = true;
+
+    self
   }
 
-  /// # Errors
+  /// Replaces the x printing position, keeping the current y printing position.
+  ///
+  /// Returns the printer for chaining other changes.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
   ///
-  /// - There is no defined printing position
+  /// let mut printer = Printer::new();
+  ///
+  /// printer
+  ///   .replace_x_printing_position(XPrintingPosition::Right)
+  ///   .replace_y_printing_position(YPrintingPosition::Middle);
+  /// ```
   pub fn replace_x_printing_position(
     &mut self,
     new_x_printing_position: XPrintingPosition,
-  ) -> Result<(), PrintingError> {
+  ) -> &mut Self {
     self.printing_position.x_printing_position = new_x_printing_position;
     self.printing_position_changed_since_last_print = true;
 
-    Ok(())
+    self
   }
 
-  /// # Errors
+  /// Replaces which way grids printed in the [`Middle`](crate::printing_position::XPrintingPosition::Middle)
+  /// are shifted horizontally when they can't be exactly centered.
   ///
-  /// - There is no defined printing position
-  pub fn replace_y_printing_position(
-    &mut self,
-    new_y_printing_position: YPrintingPosition,
-  ) -> Result<(), PrintingError> {
-    self.printing_position.y_printing_position = new_y_printing_position;
+  /// Refer to [`CenterRounding`](crate::printing_position::CenterRounding) for more information.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// // Two printers side by side, where the right one centers away from the left one.
+  /// let mut left_printer = Printer::new();
+  /// let mut right_printer = Printer::new();
+  ///
+  /// right_printer.replace_x_center_rounding(CenterRounding::TowardsEnd);
+  ///
+  /// assert_eq!(left_printer.get_x_center_rounding(), CenterRounding::TowardsStart);
+  /// ```
+  pub fn replace_x_center_rounding(&mut self, x_center_rounding: CenterRounding) -> &mut Self {
+    self.x_center_rounding = x_center_rounding;
     self.printing_position_changed_since_last_print = true;
 
-    Ok(())
+    self
   }
 
-  /// Returns a reference to the currently stored printing position.
-  pub fn get_current_printing_position(&self) -> &PrintingPosition {
-    &self.printing_position
+  /// Replaces which way grids printed in the [`Middle`](crate::printing_position::YPrintingPosition::Middle)
+  /// are shifted vertically when they can't be exactly centered.
+  ///
+  /// Refer to [`CenterRounding`](crate::printing_position::CenterRounding) for more information.
+  pub fn replace_y_center_rounding(&mut self, y_center_rounding: CenterRounding) -> &mut Self {
+    self.y_center_rounding = y_center_rounding;
+    self.printing_position_changed_since_last_print = true;
+
+    self
   }
 
-  /// Creates a grid of the given size with the given character.
+  /// Replaces where grids are printed after the terminal is resized.
+  ///
+  /// Refer to [`ResizeBehavior`](crate::printing_position::ResizeBehavior) for more information.
   ///
   /// # Example
   /// ```
   /// use screen_printer::printer::*;
   ///
-  /// let character = 'a';
-  /// let expected_grid = "aaa\naaa\naaa";
+  /// let mut printer = Printer::on_virtual_terminal((9, 3));
+  /// printer.replace_printing_position(PrintingPosition::new(XPrintingPosition::Middle, YPrintingPosition::Top));
+  /// printer.replace_resize_behavior(ResizeBehavior::KeepOrigin);
   ///
-  /// let grid = Printer::create_grid_from_single_character(character, 3, 3);
+  /// printer.dynamic_print("abc".to_string()).unwrap();
+  /// printer.replace_terminal_dimensions(Some((7, 3)));
+  /// printer.dynamic_print("abc".to_string()).unwrap();
   ///
-  /// assert_eq!(expected_grid, grid);
+  /// let frame_stats = printer.get_last_frame_stats().unwrap();
+  ///
+  /// assert_eq!(frame_stats.resize_behavior, Some(ResizeBehavior::KeepOrigin));
+  /// assert_eq!(frame_stats.changed_cells, 0);
   /// ```
-  pub fn create_grid_from_single_character(character: char, width: usize, height: usize) -> String {
-    // This was the fastest way I found to create a large 2-dimensional string of 1 character.
-    let pixel_row = character.to_string().repeat(width) + "\n";
-    let mut frame = pixel_row.repeat(height);
-    frame.pop(); // remove new line
+  pub fn replace_resize_behavior(&mut self, resize_behavior: ResizeBehavior) -> &mut Self {
+    self.resize_behavior = resize_behavior;
 
-    frame
+    self
   }
 
-  /// Creates a grid of the given size with the given list of characters
+  /// Returns where grids are printed after the terminal is resized.
+  pub fn get_resize_behavior(&self) -> ResizeBehavior {
+    self.resize_behavior
+  }
+
+  /// Replaces the [`BoundingBox`](crate::printing_position::BoundingBox) grids are aligned within,
+  /// causing the next print to reprint the grid in its entirety.
+  ///
+  /// While a box is set, the [`PrintingPosition`](crate::printing_position::PrintingPosition) is ignored,
+  /// and printing a grid larger than the box returns an error.
+  /// If the box is filled, the fill character is printed over the whole box before the grid whenever the grid is printed in its entirety.
+  ///
+  /// Returns the printer for chaining other changes.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// let bounding_box = BoundingBox::new((1, 1), (40, 10)).with_alignment(Alignment::Center);
+  ///
+  /// printer.replace_bounding_box(Some(bounding_box));
+  ///
+  /// assert_eq!(printer.get_bounding_box(), Some(bounding_box));
+  /// ```
+  pub fn replace_bounding_box(&mut self, bounding_box: Option<BoundingBox>) -> &mut Self {
+    self.bounding_box = bounding_box;
+    self.printing_position_changed_since_last_print = true;
+
+    self
+  }
+
+  /// Returns the [`BoundingBox`](crate::printing_position::BoundingBox) grids are aligned within, if any.
+  pub fn get_bounding_box(&self) -> Option<BoundingBox> {
+    self.bounding_box
+  }
+
+  /// Replaces the [`Letterbox`](crate::letterbox::Letterbox) printed around the grid every frame.
+  ///
+  /// The letterbox fills the [`BoundingBox`](crate::printing_position::BoundingBox) if one is set,
+  /// or the printing area of the terminal otherwise.
+  pub fn replace_letterbox(&mut self, letterbox: Option<Letterbox>) {
+    self.letterbox = letterbox;
+  }
+
+  /// Returns the [`Letterbox`](crate::letterbox::Letterbox) printed around the grid, if any.
+  pub fn get_letterbox(&self) -> Option<Letterbox> {
+    self.letterbox
+  }
+
+  /// Replaces the [`Overlay`](crate::overlay::Overlay) composited over every printed grid.
+  ///
+  /// The overlay is placed within the grid after it's been scaled or fit to the printing area,
+  /// so it stays the same size no matter the size of the grid beneath it.
+  pub fn replace_overlay(&mut self, overlay: Option<Overlay>) {
+    self.overlay = overlay;
+  }
+
+  /// Replaces the text of the [`Overlay`](crate::overlay::Overlay), keeping the rest of its settings.
+  ///
+  /// Does nothing if there's no overlay.
+  pub fn replace_overlay_text<S: Into<String>>(&mut self, text: S) {
+    if let Some(overlay) = &mut self.overlay {
+      overlay.text = text.into();
+    }
+  }
+
+  /// Returns the [`Overlay`](crate::overlay::Overlay) composited over every printed grid, if any.
+  pub fn get_overlay(&self) -> Option<&Overlay> {
+    self.overlay.as_ref()
+  }
+
+  /// Sets whether grids are scaled to the largest size that fits the area they're printed in, keeping their aspect ratio.
+  ///
+  /// The area is the [`BoundingBox`](crate::printing_position::BoundingBox) if one is set, or the printing area of the terminal otherwise.
+  /// Grids are scaled up by whole multiples, or sampled down to fit, every time they're printed.
+  /// This means resizing the terminal rescales the next printed grid.
+  ///
+  /// Refer to [`fit_dimensions`](crate::scaling::fit_dimensions) for the size grids are scaled to.
+  pub fn set_aspect_ratio_fit(&mut self, aspect_ratio_fit: bool) {
+    self.aspect_ratio_fit = aspect_ratio_fit;
+  }
+
+  /// Returns whether grids are scaled to fit the area they're printed in.
+  pub fn get_aspect_ratio_fit(&self) -> bool {
+    self.aspect_ratio_fit
+  }
+
+  /// Replaces the [`OverflowPolicy`](crate::scaling::OverflowPolicy) for grids too large for the area they're printed in,
+  /// overriding the default of the [`OperatingMode`](crate::operating_mode::OperatingMode).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic();
+  /// printer.replace_overflow_policy(OverflowPolicy::Downsample);
+  ///
+  /// // Printed as a coarse view of the whole grid on terminals smaller than 500x500.
+  /// let heatmap = Printer::create_grid_from_single_character('#', 500, 500);
+  /// printer.dynamic_print(heatmap).unwrap();
+  /// ```
+  pub fn replace_overflow_policy(&mut self, overflow_policy: OverflowPolicy) {
+    self.overflow_policy = Some(overflow_policy);
+  }
+
+  /// Returns the [`OverflowPolicy`](crate::scaling::OverflowPolicy) for grids too large for the area they're printed in.
+  pub fn get_overflow_policy(&self) -> OverflowPolicy {
+    self
+      .overflow_policy
+      .unwrap_or_else(|| self.operating_mode.overflow_policy())
+  }
+
+  /// Reprints the last grid on the page after the current one, when [`paginating`](crate::scaling::OverflowPolicy::Paginate).
+  ///
+  /// Nothing is printed when already on the last page.
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn next_page(&mut self) -> Result<(), PrintingError> {
+    if self.page + 1 >= self.page_count {
+      return Ok(());
+    }
+
+    self.page += 1;
+    self.reprint_page()
+  }
+
+  /// Reprints the last grid on the page before the current one, when [`paginating`](crate::scaling::OverflowPolicy::Paginate).
+  ///
+  /// Nothing is printed when already on the first page.
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn prev_page(&mut self) -> Result<(), PrintingError> {
+    if self.page == 0 {
+      return Ok(());
+    }
+
+    self.page -= 1;
+    self.reprint_page()
+  }
+
+  /// Returns the index of the page printed, starting from 0.
+  pub fn get_page(&self) -> usize {
+    self.page
+  }
+
+  /// Returns how many pages the last grid was split into, which is 0 if nothing was printed while paginating.
+  pub fn get_page_count(&self) -> usize {
+    self.page_count
+  }
+
+  /// Prints the current page of the last grid printed while paginating.
+  fn reprint_page(&mut self) -> Result<(), PrintingError> {
+    if self.paged_grid.is_empty() {
+      return Ok(());
+    }
+
+    let paged_grid = self.paged_grid.clone();
+
+    self.print_prepared_grid(paged_grid, None)
+  }
+
+  /// Replaces the [`UpdateStrategy`](crate::scrollback::UpdateStrategy) for moving around the terminal.
+  ///
+  /// The printer forgets the previously printed grid, leaving it on the terminal,
+  /// and the next grid is printed in its entirety.
+  ///
+  /// # Example
+  /// ```rust,no_run
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// printer.replace_update_strategy(UpdateStrategy::ScrollbackPreserving);
+  ///
+  /// println!("Everything printed before the grid stays in the terminal's history.");
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  /// printer.dynamic_print("abc\n456".to_string()).unwrap();
+  /// ```
+  pub fn replace_update_strategy(&mut self, update_strategy: UpdateStrategy) {
+    self.update_strategy = update_strategy;
+    self.scrollback_anchored = false;
+    self.previous_grid.clear();
+    self.last_submitted_grid.clear();
+    self.origin_position = None;
+    self.grid_width = None;
+    self.grid_height = None;
+    self.printing_position_changed_since_last_print = false;
+  }
+
+  /// Returns the [`UpdateStrategy`](crate::scrollback::UpdateStrategy) for moving around the terminal.
+  pub fn get_update_strategy(&self) -> UpdateStrategy {
+    self.update_strategy
+  }
+
+  /// Returns the (x, y) of the top left of the area grids are printed within, followed by its (width, height).
+  ///
+  /// This is the [`BoundingBox`](crate::printing_position::BoundingBox) if one is set, or the given printing area otherwise.
+  pub(crate) fn get_placement_area(
+    &self,
+    printing_area: (usize, usize),
+  ) -> ((usize, usize), (usize, usize)) {
+    match self.get_bounding_box() {
+      Some(BoundingBox {
+        position,
+        dimensions,
+        ..
+      }) => (position, dimensions),
+      None => ((1, self.get_printing_area_top_row()), printing_area),
+    }
+  }
+
+  /// Returns how grids in the middle are shifted horizontally when they can't be exactly centered.
+  pub fn get_x_center_rounding(&self) -> CenterRounding {
+    self.x_center_rounding
+  }
+
+  /// Returns how grids in the middle are shifted vertically when they can't be exactly centered.
+  pub fn get_y_center_rounding(&self) -> CenterRounding {
+    self.y_center_rounding
+  }
+
+  /// Replaces the y printing position, keeping the current x printing position.
+  ///
+  /// Returns the printer for chaining other changes.
+  pub fn replace_y_printing_position(
+    &mut self,
+    new_y_printing_position: YPrintingPosition,
+  ) -> &mut Self {
+    self.printing_position.y_printing_position = new_y_printing_position;
+    self.printing_position_changed_since_last_print = true;
+
+    self
+  }
+
+  /// Returns a summary of the printer's current state, such as where the last grid was printed and how large it was.
+  ///
+  /// Refer to [`PrinterState`](crate::printer_state::PrinterState) for more information.
+  pub fn state(&self) -> PrinterState {
+    PrinterState {
+      origin: self.origin_position,
+      grid_dimensions: self.get_grid_dimensions().ok(),
+      terminal_dimensions: self.previous_terminal_dimensions,
+      printing_position: self.printing_position.clone(),
+      full_reprint_pending: self.previous_grid.is_empty()
+        || self.printing_position_changed_since_last_print,
+    }
+  }
+
+  /// Returns a reference to the currently stored printing position.
+  pub fn get_current_printing_position(&self) -> &PrintingPosition {
+    &self.printing_position
+  }
+
+  /// Replaces how the difference between grids is split up when [`dynamic printing`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  ///
+  /// Refer to [`DiffGranularity`](crate::change_run::DiffGranularity) for more information.
+  pub fn replace_diff_granularity(&mut self, diff_granularity: DiffGranularity) {
+    self.diff_granularity = diff_granularity;
+  }
+
+  /// Returns the currently stored [`DiffGranularity`](crate::change_run::DiffGranularity).
+  pub fn get_diff_granularity(&self) -> DiffGranularity {
+    self.diff_granularity
+  }
+
+  /// Replaces how the styles of cells are compared when [`dynamic printing`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  ///
+  /// Refer to [`DiffPolicy`](crate::change_run::DiffPolicy) for more information.
+  pub fn replace_diff_policy(&mut self, diff_policy: DiffPolicy) {
+    self.diff_policy = diff_policy;
+  }
+
+  /// Returns the currently stored [`DiffPolicy`](crate::change_run::DiffPolicy).
+  pub fn get_diff_policy(&self) -> DiffPolicy {
+    self.diff_policy
+  }
+
+  /// Replaces how the difference between the previous grid and the next one is found when
+  /// [`dynamic printing`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  ///
+  /// While the [`streaming diff`](Printer::set_streaming_diff) is on, the strategy is unused,
+  /// and the difference is found one row at a time with [`CellRunDiff`](crate::diff_strategy::CellRunDiff) instead,
+  /// since strategies find the difference between entire grids at once.
+  ///
+  /// Refer to [`DiffStrategy`](crate::diff_strategy::DiffStrategy) for more information.
+  pub fn replace_diff_strategy<D: DiffStrategy + 'static>(&mut self, diff_strategy: D) {
+    self.diff_strategy = Some(Box::new(diff_strategy));
+  }
+
+  /// Returns the [`DiffStrategy`](crate::diff_strategy::DiffStrategy) in use, which is [`CellRunDiff`](crate::diff_strategy::CellRunDiff) by default.
+  pub fn get_diff_strategy(&self) -> &dyn DiffStrategy {
+    self.diff_strategy.as_deref().unwrap_or(&CellRunDiff)
+  }
+
+  /// Returns the settings the [`DiffStrategy`](crate::diff_strategy::DiffStrategy) finds the difference between grids with.
+  pub(crate) fn get_diff_settings(&self) -> DiffSettings {
+    DiffSettings {
+      granularity: self.get_diff_granularity(),
+      emoji_mode: self.get_emoji_mode(),
+      policy: self.get_diff_policy(),
+    }
+  }
+
+  /// Replaces what produces the escape sequences printed when [`dynamic printing`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// for printing to terminals that don't understand the default ANSI escape sequences.
+  ///
+  /// The next print will reprint the entire grid.
+  ///
+  /// Refer to [`EscapeEmitter`](crate::escape_emitter::EscapeEmitter) for more information.
+  pub fn replace_escape_emitter<E: EscapeEmitter + 'static>(&mut self, escape_emitter: E) {
+    self.escape_emitter = Some(Box::new(escape_emitter));
+    self.printing_position_changed_since_last_print = true;
+  }
+
+  /// Replaces the [`Caret`](crate::caret::Caret) shown in every printed grid, or removes it when None.
+  ///
+  /// Removing a caret shown with the [`terminal's cursor`](crate::caret::CaretStyle::TerminalCursor) leaves the cursor where it was.
+  pub fn replace_caret(&mut self, caret: Option<Caret>) {
+    self.caret = caret;
+    self.last_submitted_grid.clear();
+  }
+
+  /// Returns the currently stored [`Caret`](crate::caret::Caret).
+  pub fn get_caret(&self) -> Option<Caret> {
+    self.caret
+  }
+
+  /// Returns how much of the grid changed in the last [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// or None if nothing was printed yet.
+  pub fn get_last_frame_stats(&self) -> Option<FrameStats> {
+    self.last_frame_stats
+  }
+
+  /// Replaces the settings for lowering how often frames are printed while they barely change, or turns it off when None.
+  ///
+  /// The [`refresh interval`](Printer::get_refresh_interval) starts back at the minimum interval.
+  /// Refer to [`AdaptiveRefresh`](crate::frame_stats::AdaptiveRefresh) for more information.
+  pub fn replace_adaptive_refresh(&mut self, adaptive_refresh: Option<AdaptiveRefresh>) {
+    self.refresh_interval = adaptive_refresh.map_or(Duration::ZERO, |adaptive_refresh| {
+      adaptive_refresh.min_interval
+    });
+    self.adaptive_refresh = adaptive_refresh;
+  }
+
+  /// Returns the currently stored [`AdaptiveRefresh`](crate::frame_stats::AdaptiveRefresh) settings.
+  pub fn get_adaptive_refresh(&self) -> Option<AdaptiveRefresh> {
+    self.adaptive_refresh
+  }
+
+  /// Returns how long to wait before printing the next frame, based on how much the recent frames changed.
+  ///
+  /// Returns None if [`adaptive refresh`](Printer::replace_adaptive_refresh) is off.
+  pub fn get_refresh_interval(&self) -> Option<Duration> {
+    self.adaptive_refresh.map(|_| self.refresh_interval)
+  }
+
+  /// Sets whether grids identical to the last one passed into the printer are skipped without being diffed,
+  /// leaving the printer idle until a different grid is passed in.
+  ///
+  /// Grids are still printed as normal when a full reprint is needed, such as after the terminal is resized.
+  /// This is off by default.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic();
+  /// printer.set_idle_suppression(true);
+  ///
+  /// printer.dynamic_print("abc".to_string()).unwrap();
+  /// printer.dynamic_print("abc".to_string()).unwrap();
+  ///
+  /// assert!(printer.is_idle());
+  /// ```
+  pub fn set_idle_suppression(&mut self, idle_suppression: bool) {
+    self.idle_suppression = idle_suppression;
+    self.last_submitted_grid.clear();
+  }
+
+  /// Returns true if identical grids are skipped without being diffed.
+  pub fn get_idle_suppression(&self) -> bool {
+    self.idle_suppression
+  }
+
+  /// Replaces how long the printer can stay [`idle`](Printer::set_idle_suppression) before the next identical grid
+  /// reprints everything, to repair anything else printed over the grid in the meantime.
+  ///
+  /// None, the default, never reprints identical grids.
+  pub fn replace_keep_alive_interval(&mut self, keep_alive_interval: Option<Duration>) {
+    self.keep_alive_interval = keep_alive_interval;
+  }
+
+  /// Returns how long the printer can stay idle before reprinting everything.
+  pub fn get_keep_alive_interval(&self) -> Option<Duration> {
+    self.keep_alive_interval
+  }
+
+  /// Returns true if the last grid passed into the printer was skipped for being identical to the one before it.
+  pub fn is_idle(&self) -> bool {
+    self.idle
+  }
+
+  /// Replaces how the printer waits for frames to come due while playing them, which is [`precise`](FramePacing::Precise) by default.
+  pub fn replace_frame_pacing(&mut self, frame_pacing: FramePacing) {
+    self.frame_pacing = frame_pacing;
+  }
+
+  /// Returns how the printer waits for frames to come due while playing them.
+  pub fn get_frame_pacing(&self) -> FramePacing {
+    self.frame_pacing
+  }
+
+  /// Replaces the shortest time between grids being printed, or prints every grid when None, which is the default.
+  ///
+  /// Grids passed into [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) sooner than this after the last print
+  /// are held back, with each one replacing the last, so a burst of updates is coalesced into printing only the latest.
+  /// The held grid is printed by the next call to `dynamic_print` after the interval passes, which prints the newer grid instead,
+  /// or by [`print_debounced_grid`](Printer::print_debounced_grid).
+  /// A [`BackgroundPrinter`](crate::background_printer::BackgroundPrinter) prints held grids on its own once the interval passes.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  /// use std::time::Duration;
+  ///
+  /// let mut printer = Printer::deterministic();
+  /// printer.replace_debounce_interval(Some(Duration::from_secs(60)));
+  ///
+  /// for count in 0..50 {
+  ///   printer.dynamic_print(format!("{count:>2}")).unwrap();
+  /// }
+  ///
+  /// assert_eq!(printer.get_capture().unwrap().frame_count(), 1);
+  ///
+  /// printer.print_debounced_grid().unwrap();
+  ///
+  /// assert_eq!(printer.get_capture().unwrap().frame_count(), 2);
+  /// ```
+  pub fn replace_debounce_interval(&mut self, debounce_interval: Option<Duration>) {
+    self.debounce_interval = debounce_interval;
+  }
+
+  /// Returns the shortest time between grids being printed, if any.
+  pub fn get_debounce_interval(&self) -> Option<Duration> {
+    self.debounce_interval
+  }
+
+  /// Returns true if a grid is being held back by the [`debounce interval`](Printer::replace_debounce_interval).
+  pub fn has_debounced_grid(&self) -> bool {
+    self.debounced_grid.is_some()
+  }
+
+  /// Prints the grid held back by the [`debounce interval`](Printer::replace_debounce_interval) right away, if there is one.
+  ///
+  /// # Errors
+  ///
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  pub fn print_debounced_grid(&mut self) -> Result<(), PrintingError> {
+    let Some(grid) = self.debounced_grid.take() else {
+      return Ok(());
+    };
+
+    // Printing without the interval, which would only hold the grid back again.
+    let debounce_interval = self.debounce_interval.take();
+    let result = self.dynamic_print(grid);
+    self.debounce_interval = debounce_interval;
+
+    result
+  }
+
+  /// Returns how long until the [`debounce interval`](Printer::replace_debounce_interval) since the last print passes,
+  /// which is None when grids can be printed right away.
+  pub(crate) fn get_remaining_debounce_time(&self) -> Option<Duration> {
+    let remaining_time = self
+      .debounce_interval?
+      .checked_sub(self.last_print_time?.elapsed())?;
+
+    (!remaining_time.is_zero()).then_some(remaining_time)
+  }
+
+  /// Marks the grid as printed right now, for the [`keep alive interval`](Printer::replace_keep_alive_interval).
+  pub(crate) fn update_last_print_time(&mut self) {
+    self.last_print_time = Some(Instant::now());
+  }
+
+  /// Returns true if the grid can be skipped for being identical to the last grid passed in,
+  /// and nothing calls for a full reprint. Grids due for a keep alive repaint are marked for a full reprint instead.
+  pub(crate) fn should_suppress_idle_grid(
+    &mut self,
+    grid: &str,
+    terminal_dimensions: (usize, usize),
+  ) -> bool {
+    if !self.idle_suppression
+      || self.printing_position_changed_since_last_print
+      || self.last_submitted_grid != grid
+      || self.get_terminal_dimensions_from_previous_print().ok() != Some(terminal_dimensions)
+    {
+      return false;
+    }
+
+    let keep_alive_due = self
+      .keep_alive_interval
+      .zip(self.last_print_time)
+      .is_some_and(|(keep_alive_interval, last_print_time)| {
+        last_print_time.elapsed() >= keep_alive_interval
+      });
+
+    if keep_alive_due {
+      self.printing_position_changed_since_last_print = true;
+    }
+
+    !keep_alive_due
+  }
+
+  /// Replaces how often the entire grid is reprinted even when little changed, or stops doing so when None, which is the default.
+  ///
+  /// Refer to [`FullRefreshInterval`](crate::frame_stats::FullRefreshInterval) for more information.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  /// use std::time::Duration;
+  ///
+  /// let mut printer = Printer::new();
+  /// printer.replace_full_refresh_interval(Some(FullRefreshInterval::Time(Duration::from_secs(10))));
+  /// ```
+  pub fn replace_full_refresh_interval(
+    &mut self,
+    full_refresh_interval: Option<FullRefreshInterval>,
+  ) {
+    self.full_refresh_interval = full_refresh_interval;
+  }
+
+  /// Returns the currently stored [`FullRefreshInterval`](crate::frame_stats::FullRefreshInterval).
+  pub fn get_full_refresh_interval(&self) -> Option<FullRefreshInterval> {
+    self.full_refresh_interval
+  }
+
+  /// Returns true if the entire grid is due to be reprinted based on the [`FullRefreshInterval`](crate::frame_stats::FullRefreshInterval).
+  pub(crate) fn is_full_refresh_due(&self) -> bool {
+    let time_since_refresh = self
+      .last_full_refresh_time
+      .map_or(Duration::MAX, |last_full_refresh_time| {
+        last_full_refresh_time.elapsed()
+      });
+
+    self
+      .full_refresh_interval
+      .is_some_and(|interval| interval.is_due(self.frames_since_full_refresh, time_since_refresh))
+  }
+
+  /// Counts a printed frame towards the next full refresh, restarting the count if the entire grid was printed.
+  pub(crate) fn update_full_refresh_count(&mut self, full_reprint: bool) {
+    if full_reprint {
+      self.frames_since_full_refresh = 0;
+      self.last_full_refresh_time = Some(Instant::now());
+    } else {
+      self.frames_since_full_refresh += 1;
+    }
+  }
+
+  /// Replaces the most bytes printing the difference of a single frame can take, or removes the limit when None, which is the default.
+  ///
+  /// Frames whose difference goes over the budget are printed in the cheapest way that fits, refer to
+  /// [`BudgetDegradation`](crate::frame_stats::BudgetDegradation) for the ways they can be printed.
+  /// The way the last frame was printed is found in its [`FrameStats`](crate::frame_stats::FrameStats).
+  ///
+  /// This is meant for slow connections, such as serial links or laggy SSH sessions, where large frames would stall the output.
+  /// Grids printed in their entirety for other reasons, such as the first grid or grids that moved, aren't limited.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// // About a tenth of a second over a 9600 baud connection.
+  /// printer.replace_byte_budget(Some(120));
+  /// ```
+  pub fn replace_byte_budget(&mut self, byte_budget: Option<usize>) {
+    self.byte_budget = byte_budget;
+  }
+
+  /// Returns the most bytes printing the difference of a single frame can take, if there's a limit.
+  pub fn get_byte_budget(&self) -> Option<usize> {
+    self.byte_budget
+  }
+
+  /// Replaces the [`OutputRateLimit`](crate::rate_limit::OutputRateLimit) on how fast the printer writes to the terminal,
+  /// or removes the limit when None, which is the default.
+  ///
+  /// The limit starts out with a full burst available.
+  pub fn replace_output_rate_limit(&mut self, output_rate_limit: Option<OutputRateLimit>) {
+    self.rate_limiter = output_rate_limit.map(|limit| RateLimiter::new(limit, Instant::now()));
+  }
+
+  /// Returns the currently stored [`OutputRateLimit`](crate::rate_limit::OutputRateLimit).
+  pub fn get_output_rate_limit(&self) -> Option<OutputRateLimit> {
+    self
+      .rate_limiter
+      .as_ref()
+      .map(|rate_limiter| rate_limiter.limit)
+  }
+
+  /// Returns the most bytes the difference of the next frame can take, based on the byte budget
+  /// and the bytes a [`degrading`](crate::rate_limit::RateLimitBehavior::Degrade) rate limit currently allows.
+  pub(crate) fn get_frame_byte_budget(&self) -> Option<usize> {
+    let available_bytes = self
+      .rate_limiter
+      .as_ref()
+      .filter(|rate_limiter| rate_limiter.limit.behavior == RateLimitBehavior::Degrade)
+      .map(|rate_limiter| rate_limiter.available_bytes(Instant::now()));
+
+    match (self.byte_budget, available_bytes) {
+      (Some(byte_budget), Some(available_bytes)) => Some(byte_budget.min(available_bytes)),
+      (byte_budget, available_bytes) => byte_budget.or(available_bytes),
+    }
+  }
+
+  /// Replaces the [`Watchdog`](crate::frame_stats::Watchdog) that reprints parts of the grid on occasion, or stops it when None, which is the default.
+  ///
+  /// The watchdog's intervals start over from the next print.
+  pub fn replace_watchdog(&mut self, watchdog: Option<Watchdog>) {
+    self.watchdog = watchdog;
+    self.next_watchdog_row_time = None;
+    self.next_watchdog_frame_time = None;
+  }
+
+  /// Returns the currently stored [`Watchdog`](crate::frame_stats::Watchdog).
+  pub fn get_watchdog(&self) -> Option<Watchdog> {
+    self.watchdog
+  }
+
+  /// Returns the time until the next repair of the [`Watchdog`](crate::frame_stats::Watchdog), scheduling it if there is none.
+  ///
+  /// The given interval is jittered based on the watchdog's settings.
+  pub(crate) fn schedule_watchdog_repair(
+    &mut self,
+    next_repair_time: Option<Instant>,
+    interval: Duration,
+  ) -> Instant {
+    match (next_repair_time, self.watchdog) {
+      (Some(next_repair_time), _) => next_repair_time,
+      (None, Some(watchdog)) => {
+        let random_number = next_random_number(&mut self.watchdog_random_state);
+
+        Instant::now() + watchdog.jittered_interval(interval, random_number)
+      }
+      (None, None) => Instant::now() + interval,
+    }
+  }
+
+  /// Adds the time since the timer started to the phase of the frame being printed, with the `profiling` feature.
+  pub(crate) fn record_phase(&mut self, phase: Phase, timer: PhaseTimer) {
+    #[cfg(feature = "profiling")]
+    self.phase_timings.add(phase, timer);
+
+    #[cfg(not(feature = "profiling"))]
+    let _ = (phase, timer);
+  }
+
+  /// Stores the stats of a frame that was just printed, along with the time spent on each phase of it with the `profiling` feature, adapting the refresh interval to them.
+  pub(crate) fn update_frame_stats(&mut self, frame_stats: FrameStats) {
+    #[cfg(feature = "profiling")]
+    let frame_stats = FrameStats {
+      phase_timings: std::mem::take(&mut self.phase_timings),
+      ..frame_stats
+    };
+
+    if let Some(adaptive_refresh) = &self.adaptive_refresh {
+      self.refresh_interval = adaptive_refresh.next_interval(self.refresh_interval, &frame_stats);
+    }
+
+    self.last_frame_stats = Some(frame_stats);
+  }
+
+  /// Returns the currently stored [`EscapeEmitter`](crate::escape_emitter::EscapeEmitter),
+  /// which is an [`AnsiEmitter`](crate::escape_emitter::AnsiEmitter) unless replaced.
+  ///
+  /// While [`preserving scrollback`](crate::scrollback::UpdateStrategy::ScrollbackPreserving),
+  /// this is an emitter that moves the cursor relative to the top left of the grid instead.
+  pub fn get_escape_emitter(&self) -> &dyn EscapeEmitter {
+    if self.update_strategy == UpdateStrategy::ScrollbackPreserving {
+      return &AnchoredEmitter;
+    }
+
+    self.escape_emitter.as_deref().unwrap_or(&AnsiEmitter)
+  }
+
+  /// Sets whether grids passed into the printer have their line endings [`normalized`](Printer::normalize_line_endings)
+  /// before being validated.
+  ///
+  /// This overrides the default of the [`OperatingMode`](crate::operating_mode::OperatingMode), which is off unless lenient.
+  pub fn set_line_ending_normalization(&mut self, normalize_line_endings: bool) {
+    self.normalize_line_endings = Some(normalize_line_endings);
+  }
+
+  /// Returns true if grids passed into the printer have their line endings normalized.
+  pub fn get_line_ending_normalization(&self) -> bool {
+    self
+      .normalize_line_endings
+      .unwrap_or_else(|| self.operating_mode.line_ending_normalization())
+  }
+
+  /// Sets whether rows of grids passed into the printer are padded with whitespace to the width of the widest row,
+  /// rather than returning an error for non-rectangular grids.
+  ///
+  /// This overrides the default of the [`OperatingMode`](crate::operating_mode::OperatingMode), which is off unless lenient.
+  pub fn set_row_padding(&mut self, pad_rows: bool) {
+    self.pad_rows = Some(pad_rows);
+  }
+
+  /// Returns true if rows of grids passed into the printer are padded to the width of the widest row.
+  pub fn get_row_padding(&self) -> bool {
+    self
+      .pad_rows
+      .unwrap_or_else(|| self.operating_mode.row_padding())
+  }
+
+  /// Replaces the [`OperatingMode`](crate::operating_mode::OperatingMode), which decides how forgiving the printer is of irregular grids.
+  ///
+  /// Settings that were set on their own keep overriding the mode, until the printer is [`reset`](Printer::reset).
+  pub fn replace_operating_mode(&mut self, operating_mode: OperatingMode) {
+    self.operating_mode = operating_mode;
+  }
+
+  /// Returns the [`OperatingMode`](crate::operating_mode::OperatingMode), which decides how forgiving the printer is of irregular grids.
+  pub fn get_operating_mode(&self) -> OperatingMode {
+    self.operating_mode
+  }
+
+  /// Replaces how escape sequences within grids passed into the printer are treated,
+  /// overriding the default of the [`OperatingMode`](crate::operating_mode::OperatingMode),
+  /// which is [`EscapeSanitization::Off`](crate::ansi::EscapeSanitization::Off) unless lenient.
+  ///
+  /// Grids containing untrusted text, such as chat messages or filenames, can otherwise move the cursor or change
+  /// the terminal's title, which desyncs the printer from what's displayed. This also applies to the text of the
+  /// [`Overlay`](crate::overlay::Overlay).
+  ///
+  /// Only the grids themselves are sanitized, the sequences the printer prints to control the terminal are unaffected.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::assert_screen_eq;
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((3, 1));
+  /// printer.replace_escape_sanitization(EscapeSanitization::AllowStyles);
+  ///
+  /// printer.dynamic_print("ab\x1B[2Jc".to_string()).unwrap();
+  ///
+  /// assert_screen_eq!(printer, "abc");
+  /// ```
+  pub fn replace_escape_sanitization(&mut self, escape_sanitization: EscapeSanitization) {
+    self.escape_sanitization = Some(escape_sanitization);
+  }
+
+  /// Returns how escape sequences within grids passed into the printer are treated.
+  pub fn get_escape_sanitization(&self) -> EscapeSanitization {
+    self
+      .escape_sanitization
+      .unwrap_or_else(|| self.operating_mode.escape_sanitization())
+  }
+
+  /// Replaces how emoji made out of multiple characters are handled.
+  ///
+  /// Refer to [`EmojiMode`](crate::cells::EmojiMode) for more information.
+  pub fn replace_emoji_mode(&mut self, emoji_mode: EmojiMode) {
+    self.emoji_mode = emoji_mode;
+    self.printing_position_changed_since_last_print = true;
+  }
+
+  /// Returns the currently stored [`EmojiMode`](crate::cells::EmojiMode).
+  pub fn get_emoji_mode(&self) -> EmojiMode {
+    self.emoji_mode
+  }
+
+  /// Replaces how right-to-left text is handled.
+  ///
+  /// Refer to [`BidiMode`](crate::bidi::BidiMode) for more information.
+  pub fn replace_bidi_mode(&mut self, bidi_mode: BidiMode) {
+    self.bidi_mode = bidi_mode;
+    self.printing_position_changed_since_last_print = true;
+  }
+
+  /// Returns the currently stored [`BidiMode`](crate::bidi::BidiMode).
+  pub fn get_bidi_mode(&self) -> BidiMode {
+    self.bidi_mode
+  }
+
+  /// Replaces how characters in grids passed into the printer are [`normalized`](Printer::normalize_grid).
+  ///
+  /// Refer to [`CharacterNormalization`](crate::cells::CharacterNormalization) for more information.
+  pub fn replace_character_normalization(
+    &mut self,
+    character_normalization: CharacterNormalization,
+  ) {
+    self.character_normalization = character_normalization;
+  }
+
+  /// Returns the currently stored [`CharacterNormalization`](crate::cells::CharacterNormalization).
+  pub fn get_character_normalization(&self) -> CharacterNormalization {
+    self.character_normalization
+  }
+
+  /// Replaces the color depth that colors in grids passed into the printer are [`converted down to`](crate::color::downconvert_colors).
+  ///
+  /// When this is None, the color depth is [`detected`](crate::color::ColorDepth::detect) from the environment.
+  ///
+  /// This is None by default.
+  pub fn replace_color_depth(&mut self, color_depth: Option<ColorDepth>) {
+    self.color_depth = color_depth;
+    self.printing_position_changed_since_last_print = true;
+  }
+
+  /// Returns the color depth grids are converted down to, which is either the one that was set,
+  /// or the one [`detected`](crate::color::ColorDepth::detect) from the environment.
+  ///
+  /// Printers on a [`virtual terminal`](Printer::on_virtual_terminal) default to 24-bit color instead of detecting it.
+  pub fn get_color_depth(&self) -> ColorDepth {
+    match self.color_depth {
+      Some(color_depth) => color_depth,
+      None if self.is_on_virtual_terminal() => ColorDepth::TrueColor,
+      None => ColorDepth::detect(),
+    }
+  }
+
+  /// Returns what the terminal is able to display, such as how many colors it supports.
+  ///
+  /// The color depth is the printer's [`color depth`](Printer::get_color_depth), which accounts for any that was set.
+  /// Refer to [`TerminalCapabilities`](crate::capabilities::TerminalCapabilities) for more information.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// printer.replace_color_depth(Some(ColorDepth::Palette256));
+  ///
+  /// assert_eq!(printer.capabilities().color_depth, ColorDepth::Palette256);
+  /// ```
+  pub fn capabilities(&self) -> TerminalCapabilities {
+    let detected_capabilities = match &self.controlling_terminal {
+      _ if self.is_on_virtual_terminal() => TerminalCapabilities {
+        color_depth: ColorDepth::TrueColor,
+        synchronized_updates: false,
+        cursor_addressing: true,
+      },
+      Some(controlling_terminal) => {
+        TerminalCapabilities::detect_with_terminal(controlling_terminal.is_terminal())
+      }
+      None => TerminalCapabilities::detect(),
+    };
+
+    TerminalCapabilities {
+      color_depth: self.get_color_depth(),
+      ..detected_capabilities
+    }
+  }
+
+  /// Sets whether the terminal can move the cursor to any position, overriding what's [`detected`](crate::capabilities::TerminalCapabilities::cursor_addressing).
+  ///
+  /// Without cursor addressing, such as on `dumb` terminals or when printing into a file, escape sequences would be displayed as is.
+  /// Grids are instead printed in their entirety every time they change, without any escape sequences:
+  /// grids of a single row are printed over the previous one with a carriage return,
+  /// and taller grids are printed below the previous one.
+  /// The [`PrintingPosition`](crate::printing_position::PrintingPosition) and anything else that places grids on the terminal is unused,
+  /// though grids larger than the terminal, when its dimensions can be obtained, still return an error.
+  ///
+  /// Cursor addressing is only detected when nothing was set, and the terminal's dimensions weren't
+  /// [`supplied`](Printer::replace_terminal_dimensions). Printers on a [`virtual terminal`](Printer::on_virtual_terminal),
+  /// or with supplied dimensions, always use cursor addressing unless set otherwise.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::assert_screen_eq;
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 2));
+  /// printer.set_cursor_addressing(false);
+  ///
+  /// for percent in [0, 50, 100] {
+  ///   printer.dynamic_print(format!("{percent:>3}% done")).unwrap();
+  /// }
+  ///
+  /// assert_screen_eq!(printer, "100% done");
+  /// ```
+  pub fn set_cursor_addressing(&mut self, cursor_addressing: bool) {
+    self.cursor_addressing = Some(cursor_addressing);
+  }
+
+  /// Returns whether the terminal can move the cursor to any position,
+  /// which is what was [`set`](Printer::set_cursor_addressing) or detected otherwise.
+  pub fn get_cursor_addressing(&self) -> bool {
+    self
+      .cursor_addressing
+      .or(self.terminal_dimensions.map(|_| true))
+      .or(self.detected_cursor_addressing)
+      .unwrap_or_else(|| self.capabilities().cursor_addressing)
+  }
+
+  /// Replaces the [`FrameLog`](crate::frame_log::FrameLog) that prints every frame in its entirety as plain text, or stops logging when None, which is the default.
+  ///
+  /// While logging, nothing that controls the terminal is printed, regardless of the printer's other settings.
+  /// Frame numbers and timestamps start over whenever a frame log is set.
+  pub fn replace_frame_log(&mut self, frame_log: Option<FrameLog>) {
+    self.frame_log = frame_log;
+    self.logged_frames = 0;
+    self.frame_log_start = None;
+    self.previous_grid.clear();
+  }
+
+  /// Returns the [`FrameLog`](crate::frame_log::FrameLog) that prints every frame in its entirety as plain text, if any.
+  pub fn get_frame_log(&self) -> Option<&FrameLog> {
+    self.frame_log.as_ref()
+  }
+
+  /// Replaces the [`Accessibility`](crate::accessibility::Accessibility) mode that prints changed rows as whole lines for screen readers,
+  /// or stops using it when None, which is the default.
+  ///
+  /// While on, nothing that controls the terminal is printed, regardless of the printer's other settings,
+  /// other than a [`FrameLog`](crate::frame_log::FrameLog), which takes priority.
+  /// The next grid is printed in its entirety.
+  pub fn replace_accessibility(&mut self, accessibility: Option<Accessibility>) {
+    self.accessibility = accessibility;
+    self.previous_grid.clear();
+  }
+
+  /// Returns the [`Accessibility`](crate::accessibility::Accessibility) mode that prints changed rows as whole lines for screen readers, if it's on.
+  pub fn get_accessibility(&self) -> Option<Accessibility> {
+    self.accessibility
+  }
+
+  /// Returns the grid in the format of the [`FrameLog`](crate::frame_log::FrameLog) as the next logged frame, if frames are being logged.
+  pub(crate) fn log_frame(&mut self, grid: &str) -> Option<String> {
+    let frame_log = self.frame_log.as_ref()?;
+    let start_time = *self.frame_log_start.get_or_insert_with(Instant::now);
+    self.logged_frames += 1;
+
+    Some(frame_log.format_frame(grid, self.logged_frames, start_time.elapsed()))
+  }
+
+  /// Returns whether the terminal can move the cursor to any position, detecting it only the first time it's needed.
+  pub(crate) fn uses_cursor_addressing(&mut self) -> bool {
+    if self.frame_log.is_some() || self.accessibility.is_some() {
+      return false;
+    }
+
+    if self.cursor_addressing.is_none()
+      && self.terminal_dimensions.is_none()
+      && self.detected_cursor_addressing.is_none()
+    {
+      self.detected_cursor_addressing = Some(self.capabilities().cursor_addressing);
+    }
+
+    self.get_cursor_addressing()
+  }
+
+  /// Sets whether grids passed into the printer are [`validated`](Printer::validate_characters) for control characters
+  /// before being printed.
+  ///
+  /// Control characters such as `\t` or `\r` move the cursor without the printer knowing,
+  /// which leaves everything printed after them in the wrong place.
+  ///
+  /// This is off by default.
+  pub fn set_strict_character_validation(&mut self, strict_character_validation: bool) {
+    self.strict_character_validation = strict_character_validation;
+  }
+
+  /// Returns true if grids passed into the printer are validated for control characters.
+  pub fn get_strict_character_validation(&self) -> bool {
+    self.strict_character_validation
+  }
+
+  /// Sets whether unchanged cells between two changes on the same row are reprinted
+  /// instead of moving the cursor over them, whenever that takes fewer bytes.
+  ///
+  /// Only gaps of up to 3 unstyled cells are reprinted.
+  ///
+  /// This is on by default.
+  pub fn set_gap_overwriting(&mut self, gap_overwriting: bool) {
+    self.gap_overwriting = Some(gap_overwriting);
+  }
+
+  /// Returns true if short gaps of unchanged cells are reprinted instead of moving the cursor over them.
+  pub fn get_gap_overwriting(&self) -> bool {
+    self.gap_overwriting.unwrap_or(true)
+  }
+
+  /// Sets whether the difference between grids is found and printed one row at a time,
+  /// rather than finding the entire difference before printing any of it.
+  ///
+  /// This bounds the memory used while printing the difference to about a row of each grid,
+  /// no matter how large the grids are, at the cost of writing to the output once for every changed row.
+  /// The difference is always found with [`CellRunDiff`](crate::diff_strategy::CellRunDiff),
+  /// regardless of the [`DiffStrategy`](Printer::replace_diff_strategy), and is never [`scrolled`](crate::escape_emitter::EscapeEmitter::scroll_region_up) or cut down to the
+  /// [`byte budget`](Printer::replace_byte_budget).
+  ///
+  /// This is off by default.
+  pub fn set_streaming_diff(&mut self, streaming_diff: bool) {
+    self.streaming_diff = streaming_diff;
+  }
+
+  /// Returns true if the difference between grids is found and printed one row at a time.
+  pub fn get_streaming_diff(&self) -> bool {
+    self.streaming_diff
+  }
+
+  /// Sets whether the printer keeps the last grid printed so it can be reprinted at a recalculated origin
+  /// with [`redraw_if_resized`](Printer::redraw_if_resized) when the terminal is resized.
+  ///
+  /// A [`BackgroundPrinter`](crate::background_printer::BackgroundPrinter) with
+  /// [`resize polling`](crate::background_printer::BackgroundPrinter::with_resize_polling) redraws on its own.
+  ///
+  /// This is off by default.
+  pub fn set_resize_redraw(&mut self, resize_redraw: bool) {
+    self.resize_redraw = resize_redraw;
+
+    if !resize_redraw {
+      self.redraw_grid = String::new();
+    }
+  }
+
+  /// Returns true if the last grid printed is kept for reprinting it when the terminal is resized.
+  pub fn get_resize_redraw(&self) -> bool {
+    self.resize_redraw
+  }
+
+  /// Reprints the last grid at a recalculated origin if the terminal's dimensions changed since it was printed,
+  /// returning true if it was reprinted.
+  ///
+  /// This is for calling whenever the application is polled, such as in its input loop,
+  /// so the screen is fixed after a resize without the application having to resubmit the grid.
+  /// The grid is placed according to the [`ResizeBehavior`](crate::printing_position::ResizeBehavior).
+  /// Nothing is reprinted unless [`resize redrawing`](Printer::set_resize_redraw) is on, or the grid was
+  /// [`cleared`](crate::dynamic_printer::DynamicPrinter::clear_grid).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((9, 3));
+  /// printer.set_resize_redraw(true);
+  ///
+  /// printer.dynamic_print("abc".to_string()).unwrap();
+  ///
+  /// assert!(!printer.redraw_if_resized().unwrap());
+  ///
+  /// printer.replace_terminal_dimensions(Some((5, 3)));
+  ///
+  /// assert!(printer.redraw_if_resized().unwrap());
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  ///   such as the grid no longer fitting in the terminal.
+  pub fn redraw_if_resized(&mut self) -> Result<bool, PrintingError> {
+    if self.redraw_grid.is_empty() || !self.uses_cursor_addressing() {
+      return Ok(false);
+    }
+
+    let terminal_dimensions = self.get_printing_area(self.get_current_terminal_dimensions()?);
+
+    if self.get_terminal_dimensions_from_previous_print().ok() == Some(terminal_dimensions) {
+      return Ok(false);
+    }
+
+    let redraw_grid = self.redraw_grid.clone();
+    self.print_prepared_grid(redraw_grid, None)?;
+
+    Ok(true)
+  }
+
+  /// Reserves the given amount of rows at the bottom of the terminal, which the printer will never print over.
+  ///
+  /// This is for sharing the terminal with things such as progress bars from other libraries, which draw on the bottom rows.
+  /// Grids are positioned as if the terminal ended above the reserved rows, and grids that don't fit above them
+  /// return a [`GridLargerThanTerminal`](crate::errors::PrintingError::GridLargerThanTerminal) error.
+  ///
+  /// This is 0 by default.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic();
+  ///
+  /// // Leaves the bottom row for a progress bar.
+  /// printer.replace_reserved_bottom_rows(1);
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  /// ```
+  pub fn replace_reserved_bottom_rows(&mut self, reserved_bottom_rows: usize) {
+    self.reserved_bottom_rows = reserved_bottom_rows;
+    self.printing_position_changed_since_last_print = true;
+  }
+
+  /// Returns the amount of rows reserved at the bottom of the terminal.
+  pub fn get_reserved_bottom_rows(&self) -> usize {
+    self.reserved_bottom_rows
+  }
+
+  /// Replaces the amount of rows at the top of the terminal that the printer will never print over.
+  ///
+  /// This leaves room for things such as a status line printed elsewhere, and works along with the
+  /// [`reserved bottom rows`](Printer::replace_reserved_bottom_rows), which are set separately.
+  /// Every [`PrintingPosition`](crate::printing_position::PrintingPosition) is calculated within the rows between the reserved ones,
+  /// including custom y positions, which start from the first row below the reserved top rows.
+  /// Grids that don't fit between the reserved rows return a [`GridLargerThanTerminal`](crate::errors::PrintingError::GridLargerThanTerminal) error.
+  ///
+  /// [`Bounding boxes`](crate::printing_position::BoundingBox) are positioned on the terminal as is, regardless of any reserved rows.
+  ///
+  /// This is 0 by default.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic().with_printing_position(PrintingPosition::new(
+  ///   XPrintingPosition::Middle,
+  ///   YPrintingPosition::Middle,
+  /// ));
+  ///
+  /// // Leaves the top row for a status line, and the bottom 2 for the shell prompt.
+  /// printer.replace_reserved_top_rows(1);
+  /// printer.replace_reserved_bottom_rows(2);
+  ///
+  /// printer.dynamic_print("abc\n123".to_string()).unwrap();
+  /// ```
+  pub fn replace_reserved_top_rows(&mut self, reserved_top_rows: usize) {
+    self.reserved_top_rows = reserved_top_rows;
+    self.printing_position_changed_since_last_print = true;
+  }
+
+  /// Returns the amount of rows reserved at the top of the terminal.
+  pub fn get_reserved_top_rows(&self) -> usize {
+    self.reserved_top_rows
+  }
+
+  /// Returns a handle to the [`StatusLine`](crate::status_line::StatusLine), reserving a row for it at the bottom of the terminal
+  /// if it wasn't already reserved.
+  ///
+  /// Reserving the row causes the next print to reprint the grid in its entirety, within the smaller printing area.
+  pub fn status_line(&mut self) -> StatusLine<'_> {
+    let position = self.status_line_position.unwrap_or_default();
+
+    self.status_line_at(position)
+  }
+
+  /// Returns a handle to the [`StatusLine`](crate::status_line::StatusLine), reserving a row for it at the given edge of the terminal,
+  /// or moving it there if it was reserved on the other edge.
+  ///
+  /// Reserving or moving the row causes the next print to reprint the grid in its entirety, within the new printing area.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((8, 2));
+  ///
+  /// printer.status_line_at(StatusLinePosition::Top).set_text("Title").unwrap();
+  /// printer.dynamic_print("abc".to_string()).unwrap();
+  ///
+  /// assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "Title   \nabc     ");
+  /// ```
+  pub fn status_line_at(&mut self, position: StatusLinePosition) -> StatusLine<'_> {
+    if self.status_line_position != Some(position) {
+      self.status_line_position = Some(position);
+      self.printing_position_changed_since_last_print = true;
+    }
+
+    StatusLine::new(self)
+  }
+
+  /// Clears the [`StatusLine`](crate::status_line::StatusLine) off of the terminal and stops reserving a row for it,
+  /// causing the next print to reprint the grid in its entirety.
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn remove_status_line(&mut self) -> Result<(), PrintingError> {
+    if self.status_line_position.is_none() {
+      return Ok(());
+    }
+
+    self.status_line().set_text("")?;
+    self.status_line_position = None;
+    self.printing_position_changed_since_last_print = true;
+
+    Ok(())
+  }
+
+  /// Returns true if a row is reserved for the [`StatusLine`](crate::status_line::StatusLine).
+  pub fn has_status_line(&self) -> bool {
+    self.status_line_position.is_some()
+  }
+
+  /// Creates a grid of the given size with the given character.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let character = 'a';
+  /// let expected_grid = "aaa\naaa\naaa";
+  ///
+  /// let grid = Printer::create_grid_from_single_character(character, 3, 3);
+  ///
+  /// assert_eq!(expected_grid, grid);
+  /// ```
+  pub fn create_grid_from_single_character(character: char, width: usize, height: usize) -> String {
+    // This was the fastest way I found to create a large 2-dimensional string of 1 character.
+    let pixel_row = character.to_string().repeat(width) + "\n";
+    let mut frame = pixel_row.repeat(height);
+    frame.pop(); // remove new line
+
+    frame
+  }
+
+  /// Writes a grid of the given size with the given character to the end of the string, without allocating anything else.
+  pub(crate) fn write_grid_from_single_character(
+    grid: &mut String,
+    character: char,
+    width: usize,
+    height: usize,
+  ) {
+    for y in 0..height {
+      if y > 0 {
+        grid.push('\n');
+      }
+
+      grid.extend(std::iter::repeat_n(character, width));
+    }
+  }
+
+  /// Creates a grid of the given size with the given list of characters
+  ///
+  /// The [`CharacterOrdering`](CharacterOrdering) determines whether the list is laid out row by row,
+  /// or column by column.
+  /// The characters are used as they are, use
+  /// [`create_grid_from_full_character_list_with_normalization`](Printer::create_grid_from_full_character_list_with_normalization)
+  /// to normalize them.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let characters = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i"];
+  ///
+  /// let row_major_grid =
+  ///   Printer::create_grid_from_full_character_list(&characters, 3, 3, CharacterOrdering::RowMajor)
+  ///     .unwrap();
+  /// let column_major_grid =
+  ///   Printer::create_grid_from_full_character_list(&characters, 3, 3, CharacterOrdering::ColumnMajor)
+  ///     .unwrap();
+  ///
+  /// assert_eq!(row_major_grid, "abc\ndef\nghi");
+  /// assert_eq!(column_major_grid, "adg\nbeh\ncfi");
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// - When the amount of characters passed in doesn't fit the expected grid dimensions.
+  pub fn create_grid_from_full_character_list<T>(
+    characters: &[T],
+    width: usize,
+    height: usize,
+    ordering: CharacterOrdering,
+  ) -> Result<String, PrintingError>
+  where
+    T: fmt::Display,
+  {
+    let mut grid = String::new();
+    Self::write_grid_from_full_character_list(&mut grid, characters, width, height, ordering)?;
+
+    Ok(grid)
+  }
+
+  /// Creates a grid of the given size with the given list of characters, the same as
+  /// [`create_grid_from_full_character_list`](Printer::create_grid_from_full_character_list),
+  /// with each character normalized based on the given [`GridNormalization`](crate::cells::GridNormalization).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let characters = vec!["e\u{301}", "\t", "x", "y"];
+  /// let normalization = GridNormalization::new(CharacterNormalization::Composed)
+  ///   .with_control_character_replacement(' ');
+  ///
+  /// let grid = Printer::create_grid_from_full_character_list_with_normalization(
+  ///   &characters,
+  ///   2,
+  ///   2,
+  ///   CharacterOrdering::RowMajor,
+  ///   normalization,
+  /// )
+  /// .unwrap();
+  ///
+  /// assert_eq!(grid, "\u{e9} \nxy");
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// - When the amount of characters passed in doesn't fit the expected grid dimensions.
+  pub fn create_grid_from_full_character_list_with_normalization<T>(
+    characters: &[T],
+    width: usize,
+    height: usize,
+    ordering: CharacterOrdering,
+    normalization: GridNormalization,
+  ) -> Result<String, PrintingError>
+  where
+    T: fmt::Display,
+  {
+    let characters: Vec<String> = characters
+      .iter()
+      .map(|character| normalization.normalize(&character.to_string()))
+      .collect();
+
+    Self::create_grid_from_full_character_list(&characters, width, height, ordering)
+  }
+
+  /// Writes a grid of the given size with the given list of characters to the end of the string.
+  ///
+  /// # Errors
+  ///
+  /// - When the amount of characters passed in doesn't fit the expected grid dimensions.
+  pub(crate) fn write_grid_from_full_character_list<T>(
+    grid: &mut String,
+    characters: &[T],
+    width: usize,
+    height: usize,
+    ordering: CharacterOrdering,
+  ) -> Result<(), PrintingError>
+  where
+    T: fmt::Display,
+  {
+    let grid_size = width * height;
+
+    match characters.len().cmp(&grid_size) {
+      Ordering::Less => Err(PrintingError::TooLittleCharacters(LengthErrorData::new(
+        characters.len(),
+        grid_size,
+      ))),
+      Ordering::Greater => Err(PrintingError::TooManyCharacters(LengthErrorData::new(
+        characters.len(),
+        grid_size,
+      ))),
+      Ordering::Equal => {
+        match ordering {
+          CharacterOrdering::RowMajor => write_grid_from_characters(grid, characters, width),
+          CharacterOrdering::ColumnMajor => {
+            write_grid_from_column_major_characters(grid, characters, width, height)
+          }
+        }
+
+        Ok(())
+      }
+    }
+  }
+
+  /// Moves the cursor up by the given height and prints the given grid.
+  ///
+  /// This is for printing over the previously printed grid, where the height is that grid's height.
+  /// With a height of 0 the grid is printed from the start of the cursor's row, as there's nothing to print over.
+  /// It's recommended to add some whitespace before your first print so the grid
+  /// doesn't print into anything that was printed before this method was called.
+  ///
+  /// To print a grid at a specific position instead, use [`print_at`](Printer::print_at).
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular.
+  /// - Writing to stdout failed.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let height = 10;
+  /// let width = 10;
+  /// let grid = Printer::create_grid_from_single_character('a', width, height);
+  ///
+  /// print!("{}", "\n".repeat(height + 5)); // add some space for the grid
+  /// Printer::print_over_previous_grid(grid, height).unwrap();
+  /// ```
+  pub fn print_over_previous_grid(grid: String, height: usize) -> Result<(), PrintingError> {
+    Self::write_over_previous_grid(&mut io::stdout(), &grid, height)
+  }
+
+  /// Does the same as [`print_over_previous_grid`](Printer::print_over_previous_grid), writing to the given writer instead of stdout.
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular.
+  /// - Writing to the writer failed.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut output = Vec::new();
+  ///
+  /// Printer::write_over_previous_grid(&mut output, "ab\ncd", 2).unwrap();
+  ///
+  /// assert_eq!(output, b"\x1B[1A\rab\ncd");
+  /// ```
+  pub fn write_over_previous_grid<W: Write>(
+    writer: &mut W,
+    grid: &str,
+    height: usize,
+  ) -> Result<(), PrintingError> {
+    let printable_grid = Self::get_printable_grid_over_previous(grid, height)?;
+
+    writer.write_all(printable_grid.as_bytes())?;
+    writer.flush()?;
+
+    Ok(())
+  }
+
+  /// Does the same as [`print_over_previous_grid`](Printer::print_over_previous_grid), writing to wherever this printer prints.
+  ///
+  /// That's the [`controlling terminal`](Printer::on_controlling_terminal) or a [`virtual terminal`](Printer::on_virtual_terminal) when used.
+  /// The grid isn't compared against or stored as this printer's previous grid.
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular.
+  /// - Writing to the terminal failed.
+  pub fn print_over_previous_grid_to_output(
+    &mut self,
+    grid: &str,
+    height: usize,
+  ) -> Result<(), PrintingError> {
+    let printable_grid = Self::get_printable_grid_over_previous(grid, height)?;
+
+    self.write_output(&printable_grid)?;
+    self.flush_output()
+  }
+
+  /// Returns the grid with the cursor movements needed to print it over the previous grid of the given height.
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular.
+  pub(crate) fn get_printable_grid_over_previous(
+    grid: &str,
+    height: usize,
+  ) -> Result<String, PrintingError> {
+    Self::get_rectangular_dimensions(grid)?;
+
+    // The cursor is on the last row of the previous grid, so only the rows above it are moved over.
+    let rows_up = height.saturating_sub(1);
+    let cursor_movement = if rows_up == 0 {
+      String::new()
+    } else {
+      format!("\x1B[{rows_up}A")
+    };
+
+    Ok(format!("{cursor_movement}\r{grid}"))
+  }
+
+  /// Prints the grid with its top left at the given (x, y) position, where (1, 1) is the top left of the terminal.
+  ///
+  /// This is for one-off prints, such as a notification or a label, that aren't part of what a printer is printing.
+  /// Nothing is compared against or stored, so the grid is printed in its entirety every time.
+  ///
+  /// # Errors
+  ///
+  /// - The grid wasn't rectangular.
+  /// - Writing to the terminal failed.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let label = "Score: 10\nLives: 3 ".to_string();
+  ///
+  /// Printer::print_at(label, (5, 2)).unwrap();
+  /// ```
+  pub fn print_at(grid: String, position: (usize, usize)) -> Result<(), PrintingError> {
+    let printable_grid = Self::get_printable_grid_at(&grid, position)?;
+    let mut stdout = io::stdout();
+
+    stdout.write_all(printable_grid.as_bytes())?;
+    stdout.flush()?;
+
+    Ok(())
+  }
+
+  /// Returns the [`Canvas`](crate::canvas::Canvas) the next frame is drawn into before being [`presented`](Printer::present).
+  ///
+  /// The back buffer is owned by the printer and kept between frames, so each frame only has to draw what changed since the last.
+  /// It's created the first time it's needed, the same size as the last printed grid,
+  /// or the printing area of the terminal if nothing was printed. It can be resized with [`Canvas::resize`](crate::canvas::Canvas::resize).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((6, 2));
+  ///
+  /// printer.back_buffer().write_text((0, 0), "Score:");
+  ///
+  /// for score in 0..3 {
+  ///   printer.back_buffer().write_text((0, 1), &score.to_string());
+  ///   printer.present().unwrap();
+  /// }
+  ///
+  /// assert_eq!(printer.get_capture().unwrap().frame_count(), 3);
+  /// ```
+  pub fn back_buffer(&mut self) -> &mut Canvas {
+    if self.back_buffer.is_none() {
+      let dimensions = self
+        .get_grid_dimensions()
+        .or_else(|_| {
+          self
+            .get_current_terminal_dimensions()
+            .map(|terminal_dimensions| self.get_printing_area(terminal_dimensions))
+        })
+        .unwrap_or_default();
+
+      self.back_buffer = Some(Canvas::new(dimensions));
+    }
+
+    self.back_buffer.get_or_insert_with(Canvas::default)
+  }
+
+  /// Changes the [`back buffer`](Printer::back_buffer) in place with the given function, then prints it.
+  ///
+  /// The back buffer keeps track of which cells were [`touched`](crate::canvas::Canvas::is_touched) since it was last printed,
+  /// so only the rows containing touched cells are compared cell by cell against what's displayed, rather than the entire grid.
+  /// This makes frames where few cells change cheap to print, no matter the size of the grid.
+  ///
+  /// The entire grid is compared the same way as [`present`](Printer::present) when what's displayed
+  /// differs from the back buffer outside of the touched rows, such as when a different grid was printed in between,
+  /// or when the grid would be held back or printed without cursor addressing.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 10));
+  ///
+  /// printer.modify(|grid| grid.fill(".")).unwrap();
+  ///
+  /// for x in 0..10 {
+  ///   printer.modify(|grid| grid.set((x, x), "#")).unwrap();
+  ///
+  ///   assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 1);
+  /// }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  pub fn modify<F>(&mut self, modify: F) -> Result<(), PrintingError>
+  where
+    F: FnOnce(&mut Canvas),
+  {
+    let back_buffer = self.back_buffer();
+    modify(back_buffer);
+    let touched_rows = back_buffer.touched_rows();
+    let grid = back_buffer.to_grid();
+    back_buffer.clear_touched();
+
+    if self.get_remaining_debounce_time().is_some()
+      || self.get_idle_suppression()
+      || !self.uses_cursor_addressing()
+      || self.previous_grid.is_empty()
+    {
+      return self.dynamic_print(grid);
+    }
+
+    let timer = PhaseTimer::start();
+    let grid = self.prepare_grid(grid)?;
+    self.record_phase(Phase::Validation, timer);
+
+    let timer = PhaseTimer::start();
+    let change_runs = touched_row_change_runs(
+      &self.previous_grid,
+      &grid,
+      &touched_rows,
+      self.get_diff_settings(),
+    );
+    self.record_phase(Phase::Diffing, timer);
+
+    self.debounced_grid = None;
+    self.print_prepared_grid(grid, change_runs)
+  }
+
+  /// Prints the widget with [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// after [`reflowing`](crate::widgets::Reflow::reflow) it to fill the printing area if its dimensions don't match.
+  ///
+  /// Calling this for every frame lays the widget out again whenever the terminal is resized,
+  /// such as re-wrapping a [`Paragraph`](crate::widgets::Paragraph) to the new width, rather than clipping it.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  /// use screen_printer::widgets::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 2));
+  /// let mut paragraph = Paragraph::new("The quick brown fox", 10, 2);
+  ///
+  /// printer.print_reflowed(&mut paragraph).unwrap();
+  ///
+  /// printer.replace_terminal_dimensions(Some((6, 4)));
+  /// printer.print_reflowed(&mut paragraph).unwrap();
+  ///
+  /// assert_eq!(paragraph.get_lines(), ["The", "quick", "brown", "fox"]);
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  pub fn print_reflowed<W: Reflow>(&mut self, widget: &mut W) -> Result<(), PrintingError> {
+    let printing_area = self.get_printing_area(self.get_current_terminal_dimensions()?);
+
+    if widget.dimensions() != printing_area {
+      widget.reflow(printing_area);
+    }
+
+    self.dynamic_print(widget.render())
+  }
+
+  /// Returns the [`Region`](crate::region::Region) with the given name after placing it within the given box,
+  /// registering a new empty region if there isn't one with that name.
+  ///
+  /// New regions are drawn over every region registered before them.
+  pub fn region(&mut self, name: &str, bounds: BoundingBox) -> &mut Region {
+    let index = match self
+      .regions
+      .iter()
+      .position(|(region_name, _)| region_name == name)
+    {
+      Some(index) => index,
+      None => {
+        self.regions.push((name.to_string(), Region::new(bounds)));
+
+        self.regions.len() - 1
+      }
+    };
+
+    self.regions[index].1.replace_bounds(bounds)
+  }
+
+  /// Returns the [`Region`](crate::region::Region) with the given name, if one is registered.
+  pub fn get_region(&self, name: &str) -> Option<&Region> {
+    self
+      .regions
+      .iter()
+      .find(|(region_name, _)| region_name == name)
+      .map(|(_, region)| region)
+  }
+
+  /// Returns the [`Region`](crate::region::Region) with the given name for changing its grid, if one is registered.
+  pub fn get_region_mut(&mut self, name: &str) -> Option<&mut Region> {
+    self
+      .regions
+      .iter_mut()
+      .find(|(region_name, _)| region_name == name)
+      .map(|(_, region)| region)
+  }
+
+  /// Removes the [`Region`](crate::region::Region) with the given name, returning it if it was registered.
+  pub fn remove_region(&mut self, name: &str) -> Option<Region> {
+    let index = self
+      .regions
+      .iter()
+      .position(|(region_name, _)| region_name == name)?;
+
+    if self
+      .region_cursor
+      .as_ref()
+      .is_some_and(|(region_name, _)| region_name == name)
+    {
+      self.remove_terminal_cursor();
+    }
+
+    Some(self.regions.remove(index).1)
+  }
+
+  /// Places the terminal's cursor at the given (x, y) position within the [`Region`](crate::region::Region) with the given name,
+  /// where (0, 0) is the region's top left, such as at the end of the text typed into a field.
+  ///
+  /// After every [`print_regions`](Printer::print_regions), the cursor is moved to that cell and shown,
+  /// following the region when it's moved. The position is kept within the region.
+  /// This replaces any [`Caret`](crate::caret::Caret) with one shown with the [`terminal's cursor`](crate::caret::CaretStyle::TerminalCursor).
+  ///
+  /// # Errors
+  ///
+  /// - No region is registered under the given name.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 1));
+  /// let layout = Layout::horizontal(vec![Constraint::Fixed(4), Constraint::Remainder]);
+  /// printer.layout_regions(&layout, &["label", "field"]).unwrap();
+  /// printer.get_region_mut("label").unwrap().replace_grid("Name".to_string());
+  /// printer.get_region_mut("field").unwrap().replace_grid("abc".to_string());
+  ///
+  /// printer.place_terminal_cursor("field", 3, 0).unwrap();
+  /// printer.print_regions().unwrap();
+  ///
+  /// assert_eq!(printer.virtual_screen().unwrap().cursor_position(), (7, 0));
+  /// ```
+  pub fn place_terminal_cursor(
+    &mut self,
+    region: &str,
+    x: usize,
+    y: usize,
+  ) -> Result<(), PrintingError> {
+    if self.get_region(region).is_none() {
+      return Err(PrintingError::RegionNotFound(region.to_string()));
+    }
+
+    self.region_cursor = Some((region.to_string(), (x, y)));
+
+    Ok(())
+  }
+
+  /// Stops placing the terminal's cursor within a region, removing the [`Caret`](crate::caret::Caret) that showed it.
+  ///
+  /// The cursor is left where it was.
+  pub fn remove_terminal_cursor(&mut self) {
+    if self.region_cursor.take().is_some() {
+      self.caret = None;
+    }
+  }
+
+  /// Returns the name of the [`Region`](crate::region::Region) under the given (x, y) position on the terminal,
+  /// along with the (x, y) position of the cell within that region, where (0, 0) is the region's top left.
+  ///
+  /// The position on the terminal starts from (1, 1) in the top left, the same as the position of a mouse event
+  /// reported by the terminal. When regions overlap, the one drawn on top is returned.
+  ///
+  /// Returns None when the position isn't within any region, or whenever [`cell_at`](Printer::cell_at) would,
+  /// such as when nothing has been printed yet.
+  /// The position is only accurate after the regions were last printed with [`print_regions`](Printer::print_regions).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 1));
+  /// let layout = Layout::horizontal(vec![Constraint::Fixed(4), Constraint::Remainder]);
+  /// printer.layout_regions(&layout, &["menu", "content"]).unwrap();
+  /// printer.print_regions().unwrap();
+  ///
+  /// // The terminal reported a click on the 6th column.
+  /// assert_eq!(printer.region_at(6, 1), Some(("content", 1, 0)));
+  /// ```
+  pub fn region_at(&self, x: usize, y: usize) -> Option<(&str, usize, usize)> {
+    let (grid_x, grid_y) = self.cell_at(x, y)?;
+
+    self.regions.iter().rev().find_map(|(name, region)| {
+      let BoundingBox {
+        position: (region_x, region_y),
+        dimensions: (width, height),
+        ..
+      } = region.get_bounds();
+      let cell_x = (grid_x + 1)
+        .checked_sub(region_x)
+        .filter(|&cell_x| cell_x < width)?;
+      let cell_y = (grid_y + 1)
+        .checked_sub(region_y)
+        .filter(|&cell_y| cell_y < height)?;
+
+      Some((name.as_str(), cell_x, cell_y))
+    })
+  }
+
+  /// Splits the printing area of the terminal with the [`Layout`](crate::layout::Layout),
+  /// placing the [`Region`](crate::region::Region) with each of the given names in the part of the same index.
+  ///
+  /// Regions that aren't registered yet are registered empty, and registered regions keep their alignment and fill.
+  /// Calling this before every [`print_regions`](Printer::print_regions) resizes the regions along with the terminal.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 2));
+  /// let layout = Layout::horizontal(vec![Constraint::Fixed(3), Constraint::Remainder]);
+  ///
+  /// printer.layout_regions(&layout, &["sidebar", "main"]).unwrap();
+  ///
+  /// assert_eq!(printer.get_region("main").unwrap().get_bounds(), BoundingBox::new((4, 1), (7, 2)));
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  pub fn layout_regions(&mut self, layout: &Layout, names: &[&str]) -> Result<(), PrintingError> {
+    let printing_area = self.get_printing_area(self.get_current_terminal_dimensions()?);
+    let parts = layout.split(BoundingBox::new((1, 1), printing_area));
+
+    for (name, part) in names.iter().zip(parts) {
+      let bounds = match self.get_region(name) {
+        Some(region) => BoundingBox {
+          position: part.position,
+          dimensions: part.dimensions,
+          ..region.get_bounds()
+        },
+        None => part,
+      };
+
+      self.region(name, bounds);
+    }
+
+    Ok(())
+  }
+
+  /// Replaces the [`FocusManager`](crate::focus::FocusManager) tracking which region is focused,
+  /// or stops highlighting any region when None, which is the default.
+  pub fn replace_focus_manager(&mut self, focus_manager: Option<FocusManager>) {
+    self.focus_manager = focus_manager;
+  }
+
+  /// Returns the [`FocusManager`](crate::focus::FocusManager) tracking which region is focused, if any.
+  pub fn focus_manager(&mut self) -> Option<&mut FocusManager> {
+    self.focus_manager.as_mut()
+  }
+
+  /// Draws every [`Region`](crate::region::Region) into a single grid and prints it with
+  /// [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// so the changes in every region are found and written to the terminal at once.
+  ///
+  /// The grid is just large enough to hold every region, and is placed on the terminal the same way as any other grid.
+  ///
+  /// # Errors
+  ///
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  pub fn print_regions(&mut self) -> Result<(), PrintingError> {
+    let highlighted_region = self.focus_manager.as_ref().and_then(|focus_manager| {
+      let focused = focus_manager.get_focused()?;
+      let index = self
+        .regions
+        .iter()
+        .position(|(region_name, _)| region_name == focused)?;
+
+      Some((index, focus_manager.get_highlight()))
+    });
+
+    if let Some((name, (x, y))) = &self.region_cursor {
+      let region_bounds = self.get_region(name).map(Region::get_bounds);
+
+      if let Some(BoundingBox {
+        position: (region_x, region_y),
+        dimensions: (width, height),
+        ..
+      }) = region_bounds
+      {
+        let position = (
+          region_x - 1 + (*x).min(width.saturating_sub(1)),
+          region_y - 1 + (*y).min(height.saturating_sub(1)),
+        );
+
+        self.caret = Some(Caret::new(position, CaretStyle::TerminalCursor));
+      }
+    }
+
+    let grid = compose_regions(
+      self.regions.iter().map(|(_, region)| region),
+      (self.get_x_center_rounding(), self.get_y_center_rounding()),
+      self.get_emoji_mode(),
+      highlighted_region,
+    );
+
+    self.dynamic_print(grid)
+  }
+
+  /// Renders each [`Widget`](crate::widgets::Widget) into the [`Region`](crate::region::Region) with the name it's paired with,
+  /// at the size of the region, then [`prints every region`](Printer::print_regions) at once.
+  ///
+  /// Regions not given a widget keep the grid they had.
+  ///
+  /// # Errors
+  ///
+  /// - A widget was paired with a name that no region is registered under, in which case nothing is printed.
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  pub fn print_widgets(&mut self, widgets: &[(&str, &dyn Widget)]) -> Result<(), PrintingError> {
+    if let Some((name, _)) = widgets
+      .iter()
+      .find(|(name, _)| self.get_region(name).is_none())
+    {
+      return Err(PrintingError::RegionNotFound(name.to_string()));
+    }
+
+    for (name, widget) in widgets {
+      if let Some(region) = self.get_region_mut(name) {
+        let grid = widget.render(region.get_bounds().dimensions);
+        region.replace_grid(grid);
+      }
+    }
+
+    self.print_regions()
+  }
+
+  /// Replaces the [`GridPool`](crate::grid_pool::GridPool) that grids the printer is done with are recycled into,
+  /// or stops recycling them when None, which is the default.
+  pub fn replace_grid_pool(&mut self, grid_pool: Option<GridPool>) {
+    self.grid_pool = grid_pool;
+  }
+
+  /// Returns the [`GridPool`](crate::grid_pool::GridPool) that grids the printer is done with are recycled into, if any.
+  pub fn grid_pool(&mut self) -> Option<&mut GridPool> {
+    self.grid_pool.as_mut()
+  }
+
+  /// Gives the grid back to the [`GridPool`](crate::grid_pool::GridPool), if there is one.
+  pub(crate) fn recycle_grid(&mut self, grid: String) {
+    if let Some(grid_pool) = &mut self.grid_pool {
+      grid_pool.recycle(grid);
+    }
+  }
+
+  /// Prints the [`back buffer`](Printer::back_buffer) with [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// which reprints only what differs from what's displayed.
+  ///
+  /// The back buffer is left as it is, ready for the next frame to be drawn over it.
+  ///
+  /// # Errors
+  ///
+  /// - Any of the errors from [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  pub fn present(&mut self) -> Result<(), PrintingError> {
+    let grid = self.back_buffer().to_grid();
+
+    self.dynamic_print(grid)
+  }
+
+  /// Returns an error if the grid wouldn't be printed by [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print)
+  /// with the printer's current settings, without printing anything.
+  ///
+  /// The grid goes through the same checks as when printing it, such as character validation and whether it's rectangular,
+  /// and is measured after [`aspect ratio fitting`](Printer::set_aspect_ratio_fit) and the [`OverflowPolicy`](crate::scaling::OverflowPolicy)
+  /// have been applied. This is for choosing between layouts before printing a frame.
+  ///
+  /// # Errors
+  ///
+  /// - The grid contained invalid characters while [`strictly validating characters`](Printer::set_strict_character_validation).
+  /// - The grid wasn't rectangular.
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - The grid was larger than the printing area of the terminal or the [`BoundingBox`](crate::printing_position::BoundingBox).
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 2));
+  ///
+  /// let large_layout = "Health: 10\nMana:   20\nLevel:   3".to_string();
+  /// let compact_layout = "HP 10 MP 20\nLV 3       ".to_string();
+  /// let small_layout = "HP10 MP20\nLV3      ".to_string();
+  ///
+  /// assert!(printer.will_fit(&large_layout).is_err());
+  /// assert!(printer.will_fit(&compact_layout).is_err());
+  /// assert!(printer.will_fit(&small_layout).is_ok());
+  /// ```
+  pub fn will_fit(&self, grid: &str) -> Result<(), PrintingError> {
+    let grid = self.prepare_grid(grid.to_string())?;
+    let terminal_dimensions = self.get_printing_area(self.get_current_terminal_dimensions()?);
+    let mut grid_dimensions = self.measure_rectangular_dimensions(&grid)?;
+    let (_, area_dimensions) = self.get_placement_area(terminal_dimensions);
+
+    if self.get_aspect_ratio_fit() {
+      grid_dimensions = fit_dimensions(grid_dimensions, area_dimensions);
+    }
+
+    grid_dimensions = self
+      .get_overflow_policy()
+      .fitted_dimensions(grid_dimensions, area_dimensions);
+
+    self.validate_grid_dimensions(grid_dimensions, terminal_dimensions)
+  }
+
+  /// Returns a [`GridReport`](crate::grid_report::GridReport) describing the grid,
+  /// including the width of every row and whether it would be printed with the printer's current settings.
+  ///
+  /// This is for finding out why a grid can't be printed, such as which rows make it non-rectangular.
+  /// Nothing is printed.
   ///
   /// # Example
   /// ```
   /// use screen_printer::printer::*;
   ///
-  /// let characters = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i"];
-  /// let expected_grid = "abc\ndef\nghi";
-  ///
-  /// let grid = Printer::create_grid_from_full_character_list(&characters, 3, 3).unwrap();
+  /// let printer = Printer::on_virtual_terminal((10, 5));
+  /// let report = printer.validate_grid("abc\nab");
   ///
-  /// assert_eq!(expected_grid, grid);
+  /// assert_eq!(report.mismatched_rows(), vec![1]);
+  /// assert!(matches!(report.printable, Err(PrintingError::NonRectangularGrid)));
   /// ```
+  pub fn validate_grid(&self, grid: &str) -> GridReport {
+    GridReport::new(grid, self.get_emoji_mode(), self.will_fit(grid))
+  }
+
+  /// Returns an error if the grid wouldn't fit on the terminal when printed with its top left at the given (x, y) position,
+  /// where (1, 1) is the top left of the terminal, the same as with [`print_at`](Printer::print_at).
+  ///
+  /// The grid goes through the same checks as with [`will_fit`](Printer::will_fit), other than the size of the area it's printed in,
+  /// which is the part of the terminal from the position to the bottom right.
   ///
   /// # Errors
   ///
-  /// - When the amount of characters passed in doesn't fit the expected grid dimensions.
-  pub fn create_grid_from_full_character_list<T>(
-    characters: &Vec<T>,
-    width: usize,
-    height: usize,
-  ) -> Result<String, PrintingError>
-  where
-    T: fmt::Display,
-  {
-    let grid_size = width * height;
+  /// - The grid contained invalid characters while [`strictly validating characters`](Printer::set_strict_character_validation).
+  /// - The grid wasn't rectangular.
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - The grid was larger than the terminal from the given position.
+  pub fn fits_at(&self, grid: &str, (x, y): (usize, usize)) -> Result<(), PrintingError> {
+    let grid = self.prepare_grid(grid.to_string())?;
+    let (terminal_width, terminal_height) = self.get_current_terminal_dimensions()?;
+    let grid_dimensions = self.measure_rectangular_dimensions(&grid)?;
+    let available_dimensions = (
+      (terminal_width + 1).saturating_sub(x.max(1)),
+      (terminal_height + 1).saturating_sub(y.max(1)),
+    );
 
-    match characters.len().cmp(&grid_size) {
-      Ordering::Less => Err(PrintingError::TooLittleCharacters(LengthErrorData::new(
-        characters.len(),
-        grid_size,
-      ))),
-      Ordering::Greater => Err(PrintingError::TooManyCharacters(LengthErrorData::new(
-        characters.len(),
-        grid_size,
-      ))),
-      Ordering::Equal => Ok(create_grid_from_characters(characters, width)),
+    if grid_dimensions.0 > available_dimensions.0 || grid_dimensions.1 > available_dimensions.1 {
+      return Err(PrintingError::GridLargerThanTerminal(
+        DimensionsErrorData::new(grid_dimensions, available_dimensions),
+      ));
     }
+
+    Ok(())
   }
 
-  /// Moves the cursor up by the given height and prints the given grid.
-  ///
-  /// This is for printing over the previously printed grid.
-  /// It's recommended to add some whitespace before your first print so the grid
-  /// doesn't print into anything that was printed before this method was called.
-  ///
-  /// # Example
-  /// ```
-  /// use screen_printer::printer::*;
+  /// Returns the grid with the cursor movements needed to print it at the given position.
   ///
-  /// let height = 10;
-  /// let width = 10;
-  /// let grid = Printer::create_grid_from_single_character('a', width, height);
+  /// # Errors
   ///
-  /// print!("{}", "\n".repeat(height + 5)); // add some space for the grid
-  /// Printer::print_over_previous_grid(grid, height);
-  /// ```
-  pub fn print_over_previous_grid(grid: String, height: usize) {
-    print!("\x1b[{};A", height - 1);
-    print!("\r{grid}");
-    let _ = io::stdout().flush();
+  /// - The grid wasn't rectangular.
+  pub(crate) fn get_printable_grid_at(
+    grid: &str,
+    position: (usize, usize),
+  ) -> Result<String, PrintingError> {
+    Self::get_rectangular_dimensions(grid)?;
+
+    Ok(get_printable_grid(
+      grid,
+      position,
+      BidiMode::Off,
+      &AnsiEmitter,
+    ))
   }
 
   /// Returns the currently stored grid's dimensions.
@@ -247,6 +2624,59 @@ impl Printer {
     Ok((width, height))
   }
 
+  /// Returns the (x, y) cell of the printed grid at the given position on the terminal, if the position is within the grid.
+  ///
+  /// Terminal positions start from (1, 1) at the top left of the terminal, the same as mouse events,
+  /// and grid cells start from (0, 0) at the top left of the grid, where x is the column within the grid.
+  ///
+  /// None is returned when nothing was printed yet, or when [`preserving scrollback`](crate::scrollback::UpdateStrategy::ScrollbackPreserving),
+  /// as the grid's position on the terminal isn't known then.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 5));
+  /// printer.replace_printing_position(PrintingPosition::new(
+  ///   XPrintingPosition::Right,
+  ///   YPrintingPosition::Bottom,
+  /// ));
+  ///
+  /// printer.dynamic_print("abcd\n1234".to_string()).unwrap();
+  ///
+  /// assert_eq!(printer.cell_at(8, 4), Some((1, 0)));
+  /// assert_eq!(printer.cell_at(1, 1), None);
+  /// assert_eq!(printer.terminal_position_of(1, 0), Some((8, 4)));
+  /// ```
+  pub fn cell_at(&self, terminal_x: usize, terminal_y: usize) -> Option<(usize, usize)> {
+    let origin = self.get_printed_origin()?;
+    let (grid_width, grid_height) = self.get_grid_dimensions().ok()?;
+    let (grid_x, grid_y) = terminal_to_grid((terminal_x, terminal_y), origin)?;
+
+    (grid_x < grid_width && grid_y < grid_height).then_some((grid_x, grid_y))
+  }
+
+  /// Returns the position on the terminal of the given (x, y) cell of the printed grid, if the cell is within the grid.
+  ///
+  /// This is the inverse of [`cell_at`](Printer::cell_at), and follows the same rules.
+  pub fn terminal_position_of(&self, grid_x: usize, grid_y: usize) -> Option<(usize, usize)> {
+    let origin = self.get_printed_origin()?;
+    let (grid_width, grid_height) = self.get_grid_dimensions().ok()?;
+
+    (grid_x < grid_width && grid_y < grid_height)
+      .then(|| grid_to_terminal((grid_x, grid_y), origin))
+  }
+
+  /// Returns the origin of the printed grid on the terminal, if there's a printed grid and its position on the terminal is known.
+  fn get_printed_origin(&self) -> Option<(usize, usize)> {
+    if self.previous_grid.is_empty() || self.update_strategy == UpdateStrategy::ScrollbackPreserving
+    {
+      return None;
+    }
+
+    self.get_origin_position().ok()
+  }
+
   /// Returns the currently stored origin positions.
   ///
   /// If no position has been defined, an error is returned.
@@ -269,26 +2699,30 @@ impl Printer {
   /// Returns the dimensions of the passed in string.
   /// An error is returned if the string is [`non-rectangular`](Printer::is_rectangular)
   ///
+  /// The width of each row is measured with [`measure_row_width`](crate::cells::measure_row_width).
+  ///
   /// # Errors
   ///
   /// - The passed in string is non-rectangular.
   pub fn get_rectangular_dimensions(
     rectangle_shape: &str,
   ) -> Result<(usize, usize), PrintingError> {
-    if rectangle_shape.is_empty() {
-      return Err(PrintingError::NonRectangularGrid);
-    }
-
-    let rows: Vec<&str> = rectangle_shape.split('\n').collect();
-    let model_width = rows[0].chars().count();
-
-    let rows_have_same_lengths = rows.iter().all(|row| row.chars().count() == model_width);
+    get_rectangular_dimensions_with_emoji_mode(rectangle_shape, EmojiMode::Off)
+  }
 
-    if rows_have_same_lengths {
-      Ok((model_width, rows.len()))
-    } else {
-      Err(PrintingError::NonRectangularGrid)
-    }
+  /// Returns the dimensions of the passed in string, the same way the printer would measure it when printing.
+  ///
+  /// This is the same as [`get_rectangular_dimensions`](Printer::get_rectangular_dimensions),
+  /// except emoji sequences are measured based on the printer's current [`EmojiMode`](crate::cells::EmojiMode).
+  ///
+  /// # Errors
+  ///
+  /// - The passed in string is non-rectangular.
+  pub fn measure_rectangular_dimensions(
+    &self,
+    rectangle_shape: &str,
+  ) -> Result<(usize, usize), PrintingError> {
+    get_rectangular_dimensions_with_emoji_mode(rectangle_shape, self.emoji_mode)
   }
 
   /// Returns true if the passed in string is rectangular in shape.
@@ -302,12 +2736,44 @@ impl Printer {
     Self::get_rectangular_dimensions(rectangle_shape).is_ok()
   }
 
+  /// Checks the grid for any control characters other than newlines.
+  ///
+  /// Escape sequences, such as those used for color, are allowed.
+  /// The row and column of the first invalid character are returned in the error, both starting from 0.
+  /// The column is the one the character would be printed in, which accounts for wide characters before it.
+  ///
+  /// # Errors
+  ///
+  /// - The grid contains a control character.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// assert!(Printer::validate_characters("\x1B[31mabc\x1B[0m\n123").is_ok());
+  ///
+  /// let Err(PrintingError::InvalidCharacter { ch, row, column }) = Printer::validate_characters("abc\n1\t3") else {
+  ///   panic!("The tab wasn't found.");
+  /// };
+  ///
+  /// assert_eq!((ch, row, column), ('\t', 1, 1));
+  /// ```
+  pub fn validate_characters(grid: &str) -> Result<(), PrintingError> {
+    validate_characters_with_emoji_mode(grid, EmojiMode::Off)
+  }
+
   /// Returns the current dimensions of the terminal.
   ///
+  /// Requires the `termion` feature, which is enabled by default.
+  /// Without it, this always returns an error, and the dimensions must be supplied with
+  /// [`replace_terminal_dimensions`](Printer::replace_terminal_dimensions) instead.
+  ///
   /// # Errors
   ///
   /// - Whenever [`termion::terminal_size`](https://docs.rs/termion/2.0.1/termion/fn.terminal_size.html) can fail. They don't document it themselves.
+  /// - The `termion` feature is disabled.
   pub fn get_terminal_dimensions() -> Result<(usize, usize), PrintingError> {
+    #[cfg(feature = "termion")]
     match termion::terminal_size() {
       Ok(terminal_dimensions) => Ok((
         terminal_dimensions.0 as usize,
@@ -317,6 +2783,135 @@ impl Printer {
         io_error.to_string(),
       )),
     }
+
+    #[cfg(not(feature = "termion"))]
+    Err(PrintingError::FailedToGetTerminalDimensions(
+      "The `termion` feature is disabled, and no dimensions were supplied.".to_string(),
+    ))
+  }
+
+  /// Replaces the (width, height) of the terminal used when printing, instead of obtaining them from the terminal itself.
+  ///
+  /// This is for printing to something other than the current terminal, or when building without the `termion` feature.
+  /// Passing in None goes back to [`obtaining the dimensions`](Printer::get_terminal_dimensions) from the terminal.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::new();
+  /// printer.replace_terminal_dimensions(Some((80, 24)));
+  ///
+  /// assert_eq!(printer.get_current_terminal_dimensions().unwrap(), (80, 24));
+  /// ```
+  pub fn replace_terminal_dimensions(&mut self, terminal_dimensions: Option<(usize, usize)>) {
+    self.terminal_dimensions = terminal_dimensions;
+  }
+
+  /// Returns the dimensions [`supplied`](Printer::replace_terminal_dimensions) to the printer,
+  /// or the [`current dimensions`](Printer::get_terminal_dimensions) of the terminal if none were supplied.
+  ///
+  /// When printing to the [`controlling terminal`](Printer::on_controlling_terminal) on unix with the `termion` feature,
+  /// the dimensions are obtained from the controlling terminal rather than stdout.
+  ///
+  /// # Errors
+  ///
+  /// - No dimensions were supplied, and the terminal's dimensions couldn't be obtained.
+  pub fn get_current_terminal_dimensions(&self) -> Result<(usize, usize), PrintingError> {
+    match (self.terminal_dimensions, &self.controlling_terminal) {
+      (Some(terminal_dimensions), _) => Ok(terminal_dimensions),
+      #[cfg(all(unix, feature = "termion"))]
+      (None, Some(controlling_terminal)) => get_file_terminal_dimensions(controlling_terminal),
+      _ => Self::get_terminal_dimensions(),
+    }
+  }
+
+  /// Returns the (x, y) position of the terminal's cursor, starting from (1, 1) at the top left of the terminal.
+  ///
+  /// Everything written so far is flushed first, so the position is where the next print would start.
+  /// The terminal is asked for the position by printing a query, and is put into raw mode while waiting for the response,
+  /// then restored to how it was afterwards, even if the query failed.
+  /// The query is sent to the [`controlling terminal`](Printer::on_controlling_terminal) when used.
+  ///
+  /// When printing to a [`virtual terminal`](Printer::on_virtual_terminal), the virtual terminal's cursor position is returned.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
+  /// - The terminal didn't respond in time, or responded with something other than its cursor position.
+  /// - Built without the `termion` feature, or on a system other than unix.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((10, 5));
+  /// printer.append_print("abc".to_string()).unwrap();
+  ///
+  /// assert_eq!(printer.query_cursor_position().unwrap(), (1, 2));
+  /// ```
+  pub fn query_cursor_position(&mut self) -> Result<(usize, usize), PrintingError> {
+    self.flush_output()?;
+
+    if let Some(capture) = &self.capture {
+      let (x, y) = capture.final_terminal().cursor_position();
+
+      return Ok((x + 1, y + 1));
+    }
+
+    #[cfg(all(unix, feature = "termion"))]
+    {
+      let query_result = match &mut self.controlling_terminal {
+        Some(controlling_terminal) => {
+          crate::terminal_query::query_cursor_position(controlling_terminal)
+        }
+        None => std::fs::OpenOptions::new()
+          .read(true)
+          .write(true)
+          .open("/dev/tty")
+          .and_then(|mut terminal| crate::terminal_query::query_cursor_position(&mut terminal)),
+      };
+
+      query_result
+        .map_err(|io_error| PrintingError::FailedToGetCursorPosition(io_error.to_string()))
+    }
+
+    #[cfg(not(all(unix, feature = "termion")))]
+    Err(PrintingError::FailedToGetCursorPosition(
+      "Querying the cursor position requires the `termion` feature on a unix system.".to_string(),
+    ))
+  }
+
+  /// Returns the (width, height) of the part of the terminal grids can be printed in,
+  /// which excludes any [`reserved rows`](Printer::replace_reserved_top_rows).
+  ///
+  /// The area starts on the row below the reserved top rows, or is the inline rows while in [`inline mode`](Printer::enable_inline_mode).
+  pub(crate) fn get_printing_area(
+    &self,
+    (terminal_width, terminal_height): (usize, usize),
+  ) -> (usize, usize) {
+    if let Some((_, inline_height)) = self.inline_area {
+      return (terminal_width, inline_height.min(terminal_height));
+    }
+
+    let status_line_rows = usize::from(self.status_line_position.is_some());
+
+    (
+      terminal_width,
+      terminal_height
+        .saturating_sub(self.reserved_top_rows + self.reserved_bottom_rows + status_line_rows),
+    )
+  }
+
+  /// Returns the row the [`printing area`](Printer::get_printing_area) starts on.
+  pub(crate) fn get_printing_area_top_row(&self) -> usize {
+    match self.inline_area {
+      Some((top_row, _)) => top_row,
+      None if self.status_line_position == Some(StatusLinePosition::Top) => {
+        self.reserved_top_rows + 2
+      }
+      None => self.reserved_top_rows + 1,
+    }
   }
 
   /// Resets all data for the printer.
@@ -357,14 +2952,11 @@ impl Printer {
   /// assert_eq!(&grid, "xxx\nxx \nx  ");
   /// ```
   pub fn pad_rows_for_rectangle(grid: &mut String) {
-    let Some(largest_row) = grid.split('\n').max_by_key(|row| row.chars().count()) else {
-      return;
-    };
-    let largest_row_size = largest_row.chars().count();
+    let (largest_row_size, _) = measure_grid(grid);
     let padded_grid: String = grid
       .lines()
       .map(|row| {
-        let padding = " ".repeat(largest_row_size - row.chars().count());
+        let padding = " ".repeat(largest_row_size - measure_row_width(row));
 
         format!("{row}{padding}")
       })
@@ -374,6 +2966,172 @@ impl Printer {
     *grid = padded_grid;
   }
 
+  /// Replaces every `\r\n` in the grid with `\n`, and removes any trailing newlines.
+  ///
+  /// This is for grids read from sources such as Windows files, which would otherwise not be considered
+  /// [`rectangular`](Printer::is_rectangular) due to the invisible `\r` characters.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut grid = "xxx\r\nxxx\r\n".to_string();
+  ///
+  /// Printer::normalize_line_endings(&mut grid);
+  ///
+  /// assert!(Printer::is_rectangular(&grid));
+  /// assert_eq!(&grid, "xxx\nxxx");
+  /// ```
+  pub fn normalize_line_endings(grid: &mut String) {
+    if grid.contains('\r') {
+      *grid = grid.replace("\r\n", "\n");
+    }
+
+    let trimmed_length = grid.trim_end_matches('\n').len();
+    grid.truncate(trimmed_length);
+  }
+
+  /// Normalizes the characters of the grid based on the given [`CharacterNormalization`](crate::cells::CharacterNormalization).
+  ///
+  /// This is for making the same text from different sources compare equal, and take up the same amount of cells.
+  /// Grids built out of characters with combining marks can be normalized with this before being measured or compared,
+  /// or while being built with builders such as
+  /// [`create_grid_from_full_character_list_with_normalization`](Printer::create_grid_from_full_character_list_with_normalization).
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut grid = "e\u{301}x\nxx".to_string();
+  ///
+  /// Printer::normalize_grid(&mut grid, CharacterNormalization::Composed);
+  ///
+  /// assert!(Printer::is_rectangular(&grid));
+  /// assert_eq!(&grid, "\u{e9}x\nxx");
+  /// ```
+  pub fn normalize_grid(grid: &mut String, normalization: CharacterNormalization) {
+    if normalization != CharacterNormalization::Off {
+      *grid = normalize_characters(grid, normalization);
+    }
+  }
+
+  /// Writes the output to the terminal.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
+  ///
+  /// With an [`OutputRateLimit`](crate::rate_limit::OutputRateLimit), the output is held until it's flushed.
+  pub(crate) fn write_output(&mut self, output: &str) -> Result<(), PrintingError> {
+    if self.rate_limiter.is_some() {
+      self.rate_limited_output.push_str(output);
+
+      return Ok(());
+    }
+
+    let timer = PhaseTimer::start();
+    self.write_to_terminal(output.as_bytes())?;
+    self.record_phase(Phase::Writing, timer);
+
+    Ok(())
+  }
+
+  /// Writes the bytes to the terminal, or the capture if there is one, along with any supplied output.
+  fn write_to_terminal(&mut self, bytes: &[u8]) -> io::Result<()> {
+    if let Some(SuppliedOutput(supplied_output)) = &mut self.supplied_output {
+      supplied_output.write_all(bytes)?;
+    }
+
+    match (&mut self.capture, &mut self.controlling_terminal) {
+      (Some(capture), _) => capture.write_all(bytes),
+      (None, Some(controlling_terminal)) => controlling_terminal.write_all(bytes),
+      (None, None) => io::stdout().write_all(bytes),
+    }
+  }
+
+  /// Writes the output held for the [`OutputRateLimit`](crate::rate_limit::OutputRateLimit), if there's any.
+  ///
+  /// When spreading frames out, this waits until the output is allowed to be written,
+  /// writing output larger than the burst a burst at a time.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
+  fn write_rate_limited_output(&mut self) -> Result<(), PrintingError> {
+    let Some(rate_limiter) = &self.rate_limiter else {
+      return Ok(());
+    };
+    let output = std::mem::take(&mut self.rate_limited_output);
+    let chunk_size = match rate_limiter.limit.behavior {
+      RateLimitBehavior::Spread => rate_limiter.limit.burst_bytes,
+      RateLimitBehavior::Degrade => output.len(),
+    };
+    let mut remaining_output = output.as_str();
+
+    while !remaining_output.is_empty() {
+      let chunk_end = (1..=chunk_size.min(remaining_output.len()))
+        .rev()
+        .find(|&index| remaining_output.is_char_boundary(index))
+        .unwrap_or_else(|| remaining_output.chars().next().map_or(0, char::len_utf8));
+      let (chunk, rest) = remaining_output.split_at(chunk_end);
+
+      if let Some(rate_limiter) = &self.rate_limiter {
+        let wait_time = match rate_limiter.limit.behavior {
+          RateLimitBehavior::Spread => rate_limiter.wait_time(chunk.len(), Instant::now()),
+          RateLimitBehavior::Degrade => Duration::ZERO,
+        };
+
+        if !wait_time.is_zero() {
+          self.get_frame_pacing().wait(wait_time);
+        }
+      }
+
+      let timer = PhaseTimer::start();
+      self.write_to_terminal(chunk.as_bytes())?;
+      self.record_phase(Phase::Writing, timer);
+
+      if let Some(rate_limiter) = &mut self.rate_limiter {
+        rate_limiter.consume(chunk.len(), Instant::now());
+      }
+
+      remaining_output = rest;
+    }
+
+    // Keeps the buffer's capacity for the next frame.
+    self.rate_limited_output = output;
+    self.rate_limited_output.clear();
+
+    Ok(())
+  }
+
+  /// Flushes everything written to the terminal.
+  ///
+  /// # Errors
+  ///
+  /// - Writing the output held for the [`OutputRateLimit`](crate::rate_limit::OutputRateLimit) failed.
+  /// - Flushing the terminal's output failed.
+  pub(crate) fn flush_output(&mut self) -> Result<(), PrintingError> {
+    self.write_rate_limited_output()?;
+
+    let timer = PhaseTimer::start();
+
+    if let Some(SuppliedOutput(supplied_output)) = &mut self.supplied_output {
+      supplied_output.flush()?;
+    }
+
+    match (&mut self.capture, &mut self.controlling_terminal) {
+      (Some(capture), _) => capture.flush()?,
+      (None, Some(controlling_terminal)) => controlling_terminal.flush()?,
+      (None, None) => io::stdout().flush()?,
+    }
+
+    self.record_phase(Phase::Writing, timer);
+
+    Ok(())
+  }
+
   /// Assigns the passed in new_origin and changes the printing_position_changed_since_last_print field to true
   /// if the passed in origin is different from the previous one.
   pub(crate) fn update_origin(&mut self, new_origin: (usize, usize)) {
@@ -417,17 +3175,115 @@ impl Printer {
   }
 }
 
-/// Creates a grid of the given width out of the given 1D array of characters.
-fn create_grid_from_characters<T: fmt::Display>(characters: &[T], width: usize) -> String {
-  characters
-    .chunks(width)
-    .map(|row| {
-      row.iter().fold(String::new(), |mut row, character| {
-        row.push_str(format!("{character}").as_str());
+/// Returns the dimensions of the passed in string, where emoji sequences are measured based on the given [`EmojiMode`](crate::cells::EmojiMode).
+///
+/// # Errors
+///
+/// - The passed in string is non-rectangular.
+fn get_rectangular_dimensions_with_emoji_mode(
+  rectangle_shape: &str,
+  emoji_mode: EmojiMode,
+) -> Result<(usize, usize), PrintingError> {
+  if rectangle_shape.is_empty() {
+    return Err(PrintingError::NonRectangularGrid);
+  }
+
+  let rows: Vec<&str> = rectangle_shape.split('\n').collect();
+  let model_width = row_width(rows[0], emoji_mode);
+
+  let rows_have_same_lengths = rows
+    .iter()
+    .all(|row| row_width(row, emoji_mode) == model_width);
+
+  if rows_have_same_lengths {
+    Ok((model_width, rows.len()))
+  } else {
+    Err(PrintingError::NonRectangularGrid)
+  }
+}
+
+/// Checks the grid for control characters, where columns are measured based on the given [`EmojiMode`](crate::cells::EmojiMode).
+///
+/// # Errors
+///
+/// - The grid contains a control character.
+pub(crate) fn validate_characters_with_emoji_mode(
+  grid: &str,
+  emoji_mode: EmojiMode,
+) -> Result<(), PrintingError> {
+  match find_control_character(grid, emoji_mode) {
+    Some((ch, row, column)) => Err(PrintingError::InvalidCharacter { ch, row, column }),
+    None => Ok(()),
+  }
+}
 
-        row
-      })
-    })
-    .collect::<Vec<String>>()
-    .join("\n")
+/// The order that a list of characters is laid out in when creating a grid out of it.
+///
+/// Used for [`create_grid_from_full_character_list`](Printer::create_grid_from_full_character_list).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CharacterOrdering {
+  /// Every row of the grid is stored one after the other.
+  #[default]
+  RowMajor,
+  /// Every column of the grid is stored one after the other.
+  ///
+  /// This is how data from most numerical libraries is laid out.
+  ColumnMajor,
+}
+
+/// Writes a grid of the given width out of the given 1D array of characters to the end of the string,
+/// where every row of the grid comes one after the other.
+fn write_grid_from_characters<T: fmt::Display>(grid: &mut String, characters: &[T], width: usize) {
+  for (y, row) in characters.chunks(width.max(1)).enumerate() {
+    if y > 0 {
+      grid.push('\n');
+    }
+
+    row.iter().for_each(|character| {
+      let _ = write!(grid, "{character}");
+    });
+  }
+}
+
+/// Writes a grid of the given dimensions out of the given 1D array of characters to the end of the string,
+/// where every column of the grid comes one after the other.
+fn write_grid_from_column_major_characters<T: fmt::Display>(
+  grid: &mut String,
+  characters: &[T],
+  width: usize,
+  height: usize,
+) {
+  for y in 0..height {
+    if y > 0 {
+      grid.push('\n');
+    }
+
+    (0..width).for_each(|x| {
+      let _ = write!(grid, "{}", characters[x * height + y]);
+    });
+  }
+}
+
+/// Returns the (width, height) of the terminal the file refers to.
+#[cfg(all(unix, feature = "termion"))]
+fn get_file_terminal_dimensions(file: &File) -> Result<(usize, usize), PrintingError> {
+  use std::os::fd::AsRawFd;
+
+  let mut window_size = libc::winsize {
+    ws_row: 0,
+    ws_col: 0,
+    ws_xpixel: 0,
+    ws_ypixel: 0,
+  };
+
+  // SAFETY: TIOCGWINSZ only writes a winsize to the given pointer, which points to a valid winsize.
+  let result = unsafe { libc::ioctl(file.as_raw_fd(), libc::TIOCGWINSZ, &mut window_size) };
+
+  if result == -1 {
+    return Err(PrintingError::FailedToGetTerminalDimensions(
+      io::Error::last_os_error().to_string(),
+    ));
+  }
+
+  Ok((window_size.ws_col as usize, window_size.ws_row as usize))
 }
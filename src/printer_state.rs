@@ -0,0 +1,56 @@
+mod tests;
+
+use crate::printing_position::PrintingPosition;
+use std::fmt;
+
+/// A summary of everything the [`Printer`](crate::printer::Printer) knows about the last grid it printed.
+///
+/// Obtained from [`Printer::state`](crate::printer::Printer::state).
+/// The [`Display`](std::fmt::Display) implementation writes everything on a single line, for logging when something is printed incorrectly.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let printer = Printer::new();
+/// let state = printer.state();
+///
+/// assert_eq!(state.origin, None);
+/// assert!(state.full_reprint_pending);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrinterState {
+  /// The (x, y) position of the top left of the last printed grid on the terminal.
+  pub origin: Option<(usize, usize)>,
+  /// The (width, height) of the last printed grid.
+  pub grid_dimensions: Option<(usize, usize)>,
+  /// The (width, height) of the terminal when the last grid was printed.
+  pub terminal_dimensions: Option<(usize, usize)>,
+  /// Where grids are printed on the terminal.
+  pub printing_position: PrintingPosition,
+  /// Whether the next print will reprint the grid in its entirety, rather than only what changed.
+  pub full_reprint_pending: bool,
+}
+
+impl fmt::Display for PrinterState {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      formatter,
+      "origin: {}, grid: {}, terminal: {}, position: ({:?}, {:?}), full reprint pending: {}",
+      format_pair(self.origin, ","),
+      format_pair(self.grid_dimensions, "x"),
+      format_pair(self.terminal_dimensions, "x"),
+      self.printing_position.x_printing_position,
+      self.printing_position.y_printing_position,
+      self.full_reprint_pending,
+    )
+  }
+}
+
+/// Formats both values with the separator between them, or `none` if there are no values.
+fn format_pair(pair: Option<(usize, usize)>, separator: &str) -> String {
+  match pair {
+    Some((first, second)) => format!("{first}{separator}{second}"),
+    None => "none".to_string(),
+  }
+}
@@ -0,0 +1,25 @@
+#![cfg(test)]
+
+use super::*;
+use crate::printing_position::*;
+
+#[cfg(test)]
+mod display_tests {
+  use super::*;
+
+  #[test]
+  fn state_is_displayed_on_one_line() {
+    let state = PrinterState {
+      origin: Some((4, 2)),
+      grid_dimensions: Some((10, 5)),
+      terminal_dimensions: None,
+      printing_position: PrintingPosition::new(XPrintingPosition::Middle, YPrintingPosition::Top),
+      full_reprint_pending: false,
+    };
+
+    assert_eq!(
+      state.to_string(),
+      "origin: 4,2, grid: 10x5, terminal: none, position: (Middle, Top), full reprint pending: false"
+    );
+  }
+}
@@ -1,14 +1,19 @@
+mod tests;
+
 /// The PrintingPosition is a way to preset a place to print a grid on the screen with the [`Printer`](crate::printer::Printer).
 ///
 /// By combining an x and y position you can print any grid in 9 positions on the grid.
 ///
 /// Say if you wanted to print your grid to the very center of the screen, you'd set that up like so:
 /// ```
+/// # #[cfg(feature = "std")]
+/// # {
 /// use screen_printer::prelude::*;
 ///
 /// let printing_position =
 ///   PrintingPosition::new(XPrintingPosition::Middle, YPrintingPosition::Middle);
 /// let mut printer = Printer::new_with_printing_position(printing_position);
+/// # }
 /// ```
 ///
 /// This would make any following use of the [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) method print the grid to the center
@@ -17,7 +22,8 @@
 /// For more information about adjusting the PrintingPosition, refer to the examples on [`github`](https://github.com/LinkTheDot/screen_printer/blob/master/examples/printing_positions.rs).
 ///
 /// For more information about printing, refer to documentation on the [`Printer`](crate::printer::Printer) and [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print) method.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrintingPosition {
   pub x_printing_position: XPrintingPosition,
   pub y_printing_position: YPrintingPosition,
@@ -27,6 +33,7 @@ pub struct PrintingPosition {
 ///
 /// Used for [`PrintingPosition`](PrintingPosition).
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum XPrintingPosition {
   #[default]
   Left,
@@ -39,6 +46,7 @@ pub enum XPrintingPosition {
 ///
 /// Used for [`PrintingPosition`](PrintingPosition).
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YPrintingPosition {
   Top,
   Middle,
@@ -47,6 +55,162 @@ pub enum YPrintingPosition {
   Custom(usize),
 }
 
+/// Which way a grid printed in the [`Middle`](XPrintingPosition::Middle) of the terminal is shifted when it can't be exactly centered.
+///
+/// This happens when the space left over around the grid is odd, leaving one more column or row on one side than the other.
+///
+/// Set for each axis with [`Printer::replace_x_center_rounding`](crate::printer::Printer::replace_x_center_rounding)
+/// and [`Printer::replace_y_center_rounding`](crate::printer::Printer::replace_y_center_rounding).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CenterRounding {
+  /// The grid is shifted left or up, leaving the extra space on the right or bottom.
+  #[default]
+  TowardsStart,
+  /// The grid is shifted right or down, leaving the extra space on the left or top.
+  TowardsEnd,
+}
+
+impl CenterRounding {
+  /// Returns the position, starting from 1, that centers something of the given length within the available length.
+  pub(crate) fn center(&self, length: usize, available_length: usize) -> usize {
+    let leftover_length = available_length.saturating_sub(length);
+
+    match self {
+      Self::TowardsStart => leftover_length / 2 + 1,
+      Self::TowardsEnd => leftover_length.div_ceil(2) + 1,
+    }
+  }
+}
+
+/// Where a grid is printed after the terminal is resized.
+///
+/// Set with [`Printer::replace_resize_behavior`](crate::printer::Printer::replace_resize_behavior),
+/// and reported for the first frame printed after a resize in its [`FrameStats`](crate::frame_stats::FrameStats).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResizeBehavior {
+  /// The grid is moved to where its [`PrintingPosition`](PrintingPosition) places it in the resized terminal,
+  /// clearing the old grid and printing the new one in its entirety.
+  #[default]
+  Recenter,
+  /// The grid stays at the same position on the terminal, so only what changed is printed.
+  ///
+  /// The grid is still moved when it no longer fits at its position, when its dimensions change,
+  /// or when anything else changes where it's placed.
+  KeepOrigin,
+}
+
+/// The 9 ways a grid can be aligned within a [`BoundingBox`](BoundingBox).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Alignment {
+  #[default]
+  TopLeft,
+  Top,
+  TopRight,
+  Left,
+  Center,
+  Right,
+  BottomLeft,
+  Bottom,
+  BottomRight,
+}
+
+/// A fixed area of the terminal that grids are aligned within, in place of the [`PrintingPosition`](PrintingPosition).
+///
+/// The box can be filled with a background character, which is printed over the entire box
+/// whenever the grid is printed in its entirety.
+///
+/// # Example
+/// ```
+/// use screen_printer::core::*;
+///
+/// // A 20x5 box starting at column 10, row 2, with the grid in the bottom right of it.
+/// let bounding_box = BoundingBox::new((10, 2), (20, 5))
+///   .with_alignment(Alignment::BottomRight)
+///   .with_fill('.');
+///
+/// assert_eq!(bounding_box.align((4, 2), Default::default()), (26, 5));
+/// ```
+///
+/// For more information on printing within a bounding box, refer to [`Printer::replace_bounding_box`](crate::printer::Printer::replace_bounding_box).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox {
+  /// The (x, y) of the top left of the box, starting from 1.
+  pub position: (usize, usize),
+  /// The (width, height) of the box.
+  pub dimensions: (usize, usize),
+  pub alignment: Alignment,
+  /// The character printed over the box around the grid, if any.
+  pub fill: Option<char>,
+}
+
+impl BoundingBox {
+  /// Creates a new unfilled box at the given (x, y) position with the given (width, height), aligning grids to the top left.
+  ///
+  /// A position of 0 on either axis is treated as 1.
+  pub fn new(position: (usize, usize), dimensions: (usize, usize)) -> Self {
+    Self {
+      position: (position.0.max(1), position.1.max(1)),
+      dimensions,
+      alignment: Alignment::default(),
+      fill: None,
+    }
+  }
+
+  /// Returns the box with grids aligned to the given [`Alignment`](Alignment).
+  pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+    self.alignment = alignment;
+
+    self
+  }
+
+  /// Returns the box filled with the given character.
+  ///
+  /// The character is expected to take up a single column.
+  pub fn with_fill(mut self, fill: char) -> Self {
+    self.fill = Some(fill);
+
+    self
+  }
+
+  /// Returns the (x, y) position to print a grid of the given (width, height) at to align it within the box.
+  ///
+  /// The (x, y) [`CenterRounding`](CenterRounding) is used for alignments in the middle of an axis.
+  pub fn align(
+    &self,
+    (grid_width, grid_height): (usize, usize),
+    (x_center_rounding, y_center_rounding): (CenterRounding, CenterRounding),
+  ) -> (usize, usize) {
+    let (box_x, box_y) = self.position;
+    let (box_width, box_height) = self.dimensions;
+
+    let x_offset = match self.alignment {
+      Alignment::TopLeft | Alignment::Left | Alignment::BottomLeft => 0,
+      Alignment::Top | Alignment::Center | Alignment::Bottom => {
+        x_center_rounding.center(grid_width, box_width) - 1
+      }
+      Alignment::TopRight | Alignment::Right | Alignment::BottomRight => {
+        box_width.saturating_sub(grid_width)
+      }
+    };
+
+    let y_offset = match self.alignment {
+      Alignment::TopLeft | Alignment::Top | Alignment::TopRight => 0,
+      Alignment::Left | Alignment::Center | Alignment::Right => {
+        y_center_rounding.center(grid_height, box_height) - 1
+      }
+      Alignment::BottomLeft | Alignment::Bottom | Alignment::BottomRight => {
+        box_height.saturating_sub(grid_height)
+      }
+    };
+
+    (box_x + x_offset, box_y + y_offset)
+  }
+}
+
 impl PrintingPosition {
   /// Creates a new [`PrintingPosition`](PrintingPosition) with the given [`X`](XPrintingPosition) and [`Y`](YPrintingPosition) positions.
   pub fn new(
@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod center_rounding_tests {
+  use super::*;
+
+  #[test]
+  fn odd_leftover_space_is_rounded() {
+    assert_eq!(CenterRounding::TowardsStart.center(4, 9), 3);
+    assert_eq!(CenterRounding::TowardsEnd.center(4, 9), 4);
+  }
+
+  #[test]
+  fn even_leftover_space_is_exactly_centered() {
+    assert_eq!(CenterRounding::TowardsStart.center(4, 10), 4);
+    assert_eq!(CenterRounding::TowardsEnd.center(4, 10), 4);
+  }
+
+  #[test]
+  fn grid_filling_the_space_starts_at_first_position() {
+    assert_eq!(CenterRounding::TowardsEnd.center(10, 10), 1);
+  }
+}
+
+#[cfg(test)]
+mod bounding_box_tests {
+  use super::*;
+
+  fn align(alignment: Alignment) -> (usize, usize) {
+    BoundingBox::new((3, 2), (10, 6))
+      .with_alignment(alignment)
+      .align((4, 3), Default::default())
+  }
+
+  #[test]
+  fn corners_touch_the_box_edges() {
+    assert_eq!(align(Alignment::TopLeft), (3, 2));
+    assert_eq!(align(Alignment::TopRight), (9, 2));
+    assert_eq!(align(Alignment::BottomLeft), (3, 5));
+    assert_eq!(align(Alignment::BottomRight), (9, 5));
+  }
+
+  #[test]
+  fn centered_alignments_use_center_rounding() {
+    let bounding_box = BoundingBox::new((3, 2), (10, 6)).with_alignment(Alignment::Center);
+
+    assert_eq!(align(Alignment::Center), (6, 3));
+    assert_eq!(
+      bounding_box.align(
+        (4, 3),
+        (CenterRounding::TowardsEnd, CenterRounding::TowardsEnd)
+      ),
+      (6, 4)
+    );
+  }
+
+  #[test]
+  fn grid_larger_than_the_box_starts_at_the_box() {
+    let bounding_box = BoundingBox::new((3, 2), (2, 2)).with_alignment(Alignment::BottomRight);
+
+    assert_eq!(bounding_box.align((4, 3), Default::default()), (3, 2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use super::*;
+
+  #[test]
+  fn printing_position_round_trips_through_json() {
+    let printing_position =
+      PrintingPosition::new(XPrintingPosition::Custom(4), YPrintingPosition::Middle);
+
+    let json = serde_json::to_string(&printing_position).unwrap();
+
+    assert_eq!(
+      json,
+      r#"{"x_printing_position":{"Custom":4},"y_printing_position":"Middle"}"#
+    );
+    assert_eq!(
+      serde_json::from_str::<PrintingPosition>(&json).unwrap(),
+      printing_position
+    );
+  }
+}
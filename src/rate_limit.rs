@@ -0,0 +1,136 @@
+mod tests;
+
+use std::time::{Duration, Instant};
+
+/// What the printer does with a frame that needs more bytes than the [`OutputRateLimit`](OutputRateLimit) currently allows.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum RateLimitBehavior {
+  /// The printer waits until the frame can be written, blocking the thread.
+  ///
+  /// Frames larger than the burst are written a burst at a time, waiting between each part.
+  #[default]
+  Spread,
+  /// The difference of the frame is degraded to fit within the bytes currently allowed, without waiting,
+  /// the same way as it is for the [`byte budget`](crate::printer::Printer::replace_byte_budget).
+  ///
+  /// Frames printed in their entirety, such as the first frame, are still written,
+  /// which leaves less for the frames after them until the rate limit catches up.
+  Degrade,
+}
+
+/// The most bytes per second a [`Printer`](crate::printer::Printer) writes to the terminal.
+///
+/// Set with [`replace_output_rate_limit`](crate::printer::Printer::replace_output_rate_limit).
+/// Everything written for a frame is held until the frame is done, then written at once when the rate allows,
+/// so a frame is never cut off partway through by the limit unless it's larger than the burst.
+///
+/// This is meant for serial links and SSH sessions, where writing faster than the connection can send
+/// fills up buffers along the way, which makes everything printed afterwards lag behind.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::new();
+/// // 9600 baud, with 10 bits sent per byte.
+/// printer.replace_output_rate_limit(Some(
+///   OutputRateLimit::new(960).with_behavior(RateLimitBehavior::Degrade),
+/// ));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OutputRateLimit {
+  /// The most bytes written per second on average.
+  pub bytes_per_second: usize,
+  /// The most bytes written at once after nothing was written for a while.
+  pub burst_bytes: usize,
+  /// What happens to frames that need more bytes than currently allowed.
+  pub behavior: RateLimitBehavior,
+}
+
+impl OutputRateLimit {
+  /// Creates a rate limit that spreads frames out, with a burst of a tenth of a second's worth of bytes.
+  ///
+  /// The rate is at least one byte per second.
+  pub fn new(bytes_per_second: usize) -> Self {
+    let bytes_per_second = bytes_per_second.max(1);
+
+    Self {
+      bytes_per_second,
+      burst_bytes: (bytes_per_second / 10).max(1),
+      behavior: RateLimitBehavior::default(),
+    }
+  }
+
+  /// Returns the rate limit with the given burst, which is at least one byte.
+  pub fn with_burst(mut self, burst_bytes: usize) -> Self {
+    self.burst_bytes = burst_bytes.max(1);
+
+    self
+  }
+
+  /// Returns the rate limit with the given behavior for frames that need more bytes than currently allowed.
+  pub fn with_behavior(mut self, behavior: RateLimitBehavior) -> Self {
+    self.behavior = behavior;
+
+    self
+  }
+}
+
+/// Keeps track of how many bytes the [`OutputRateLimit`](OutputRateLimit) allows to be written.
+///
+/// The allowance refills at the rate limit's bytes per second, up to the burst,
+/// and goes below zero when more is written than allowed.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+  pub(crate) limit: OutputRateLimit,
+  allowance: f64,
+  refill_time: Instant,
+}
+
+impl RateLimiter {
+  /// Creates a rate limiter that starts with a full burst.
+  ///
+  /// A rate or burst of 0 set through the limit's fields is treated as 1, the same as when creating the limit.
+  pub(crate) fn new(mut limit: OutputRateLimit, now: Instant) -> Self {
+    limit.bytes_per_second = limit.bytes_per_second.max(1);
+    limit.burst_bytes = limit.burst_bytes.max(1);
+
+    Self {
+      limit,
+      allowance: limit.burst_bytes as f64,
+      refill_time: now,
+    }
+  }
+
+  /// Returns how many bytes can be written at the given time, which is zero while over the limit.
+  pub(crate) fn available_bytes(&self, now: Instant) -> usize {
+    self.allowance_at(now).max(0.0) as usize
+  }
+
+  /// Returns how long to wait from the given time until the given amount of bytes can be written,
+  /// where anything larger than the burst waits for a full burst.
+  pub(crate) fn wait_time(&self, bytes: usize, now: Instant) -> Duration {
+    let missing_bytes = bytes.min(self.limit.burst_bytes) as f64 - self.allowance_at(now);
+
+    match missing_bytes > 0.0 {
+      true => Duration::from_secs_f64(missing_bytes / self.limit.bytes_per_second as f64),
+      false => Duration::ZERO,
+    }
+  }
+
+  /// Counts the bytes as written at the given time.
+  pub(crate) fn consume(&mut self, bytes: usize, now: Instant) {
+    self.allowance = self.allowance_at(now) - bytes as f64;
+    self.refill_time = now;
+  }
+
+  /// Returns the allowance refilled up to the given time.
+  fn allowance_at(&self, now: Instant) -> f64 {
+    let refilled_bytes = now
+      .saturating_duration_since(self.refill_time)
+      .as_secs_f64()
+      * self.limit.bytes_per_second as f64;
+
+    (self.allowance + refilled_bytes).min(self.limit.burst_bytes as f64)
+  }
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod rate_limiter_tests {
+  use super::*;
+
+  #[test]
+  fn the_allowance_refills_up_to_the_burst() {
+    let start_time = Instant::now();
+    let mut rate_limiter = RateLimiter::new(OutputRateLimit::new(1000).with_burst(50), start_time);
+
+    assert_eq!(rate_limiter.available_bytes(start_time), 50);
+
+    rate_limiter.consume(80, start_time);
+
+    assert_eq!(rate_limiter.available_bytes(start_time), 0);
+    assert_eq!(
+      rate_limiter.available_bytes(start_time + Duration::from_millis(40)),
+      10
+    );
+    assert_eq!(
+      rate_limiter.available_bytes(start_time + Duration::from_secs(1)),
+      50
+    );
+  }
+
+  #[test]
+  fn waits_are_at_most_a_full_burst() {
+    let start_time = Instant::now();
+    let mut rate_limiter = RateLimiter::new(OutputRateLimit::new(1000).with_burst(50), start_time);
+    rate_limiter.consume(50, start_time);
+
+    assert_eq!(
+      rate_limiter.wait_time(20, start_time),
+      Duration::from_millis(20)
+    );
+    assert_eq!(
+      rate_limiter.wait_time(500, start_time),
+      Duration::from_millis(50)
+    );
+    assert_eq!(
+      rate_limiter.wait_time(20, start_time + Duration::from_millis(20)),
+      Duration::ZERO
+    );
+  }
+
+  #[test]
+  fn a_rate_of_zero_is_one_byte_per_second() {
+    let limit = OutputRateLimit::new(0);
+    let zeroed_limit = OutputRateLimit {
+      bytes_per_second: 0,
+      ..limit
+    };
+
+    assert_eq!(limit.bytes_per_second, 1);
+    assert_eq!(RateLimiter::new(zeroed_limit, Instant::now()).limit, limit);
+  }
+
+  #[test]
+  fn spreading_at_a_rate_of_zero_still_waits() {
+    let start_time = Instant::now();
+    let mut rate_limiter = RateLimiter::new(OutputRateLimit::new(0), start_time);
+    rate_limiter.consume(1, start_time);
+
+    assert_eq!(
+      rate_limiter.wait_time(1, start_time),
+      Duration::from_secs(1)
+    );
+  }
+
+  #[test]
+  fn degrading_at_a_rate_of_zero_still_refills() {
+    let start_time = Instant::now();
+    let limit = OutputRateLimit::new(0).with_behavior(RateLimitBehavior::Degrade);
+    let mut rate_limiter = RateLimiter::new(limit, start_time);
+    rate_limiter.consume(1, start_time);
+
+    assert_eq!(rate_limiter.available_bytes(start_time), 0);
+    assert_eq!(
+      rate_limiter.available_bytes(start_time + Duration::from_secs(1)),
+      1
+    );
+  }
+}
+
+#[cfg(test)]
+mod printer_rate_limit_tests {
+  use crate::printer::*;
+  use std::time::{Duration, Instant};
+
+  #[test]
+  fn large_frames_are_spread_over_time() {
+    let mut printer = Printer::on_virtual_terminal((10, 3));
+    printer.replace_output_rate_limit(Some(OutputRateLimit::new(1000).with_burst(20)));
+    let start_time = Instant::now();
+
+    printer
+      .dynamic_print("abcdefghij\nklmnopqrst\nuvwxyz1234".to_string())
+      .unwrap();
+
+    // The first burst is written right away, while the rest has to wait.
+    let written_bytes = printer.get_capture().unwrap().bytes().len();
+    assert!(start_time.elapsed() >= Duration::from_millis((written_bytes as u64 - 20) * 9 / 10));
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "abcdefghij\nklmnopqrst\nuvwxyz1234"
+    );
+  }
+
+  #[test]
+  fn frames_over_the_limit_are_degraded() {
+    let mut printer = Printer::on_virtual_terminal((10, 1));
+    printer.replace_output_rate_limit(Some(
+      OutputRateLimit::new(10)
+        .with_burst(10)
+        .with_behavior(RateLimitBehavior::Degrade),
+    ));
+
+    printer.dynamic_print("abcdefghij".to_string()).unwrap();
+    printer.dynamic_print("xbcdefghiy".to_string()).unwrap();
+
+    assert_eq!(
+      printer.get_last_frame_stats().unwrap().budget_degradation,
+      Some(BudgetDegradation::SkippedFrame)
+    );
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "abcdefghij"
+    );
+  }
+
+  #[test]
+  fn removing_the_limit_writes_immediately() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+    printer.replace_output_rate_limit(Some(OutputRateLimit::new(1)));
+    printer.replace_output_rate_limit(None);
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    assert_eq!(printer.get_output_rate_limit(), None);
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "abc");
+  }
+}
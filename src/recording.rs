@@ -0,0 +1,274 @@
+pub mod asciicast;
+pub mod format;
+mod tests;
+
+use crate::cells::measure_grid;
+use crate::dynamic_printer::DynamicPrinter;
+use crate::errors::PrintingError;
+use crate::printer::Printer;
+pub use format::RecordingCompression;
+use std::time::{Duration, Instant};
+
+/// A single grid printed while recording, along with when it was printed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RecordedFrame {
+  /// The grid that was printed.
+  pub grid: String,
+  /// How long after the recording started the grid was printed.
+  pub time: Duration,
+}
+
+/// A label placed in a recording, for finding a point of interest when playing it back.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Marker {
+  /// The text of the marker.
+  pub label: String,
+  /// How long after the recording started the marker was placed.
+  pub time: Duration,
+  /// The index of the first frame printed after the marker was placed.
+  ///
+  /// This is the amount of frames in the recording if no frames were printed after the marker.
+  pub frame_index: usize,
+}
+
+/// Every grid printed by a [`Printer`](crate::printer::Printer) while it was recording,
+/// along with any [`markers`](Marker) placed during it.
+///
+/// Recordings are started with [`start_recording`](Printer::start_recording), and obtained with [`stop_recording`](Printer::stop_recording).
+///
+/// # Example
+/// ```
+/// use screen_printer::recording::*;
+/// use std::time::Duration;
+///
+/// let mut recording = Recording::new();
+///
+/// recording.push_frame("abc", Duration::from_millis(0));
+/// recording.push_marker("level 2 start", Duration::from_millis(500));
+/// recording.push_frame("xyz", Duration::from_millis(1000));
+///
+/// let marker = recording.find_marker("level 2 start").unwrap();
+///
+/// assert_eq!(recording.get_frames()[marker.frame_index].grid, "xyz");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Recording {
+  frames: Vec<RecordedFrame>,
+  markers: Vec<Marker>,
+}
+
+impl Recording {
+  /// Creates an empty recording.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a frame to the end of the recording.
+  pub fn push_frame<S: Into<String>>(&mut self, grid: S, time: Duration) {
+    self.frames.push(RecordedFrame {
+      grid: grid.into(),
+      time,
+    });
+  }
+
+  /// Adds a marker before the next frame added to the recording.
+  pub fn push_marker<S: Into<String>>(&mut self, label: S, time: Duration) {
+    self.markers.push(Marker {
+      label: label.into(),
+      time,
+      frame_index: self.frames.len(),
+    });
+  }
+
+  /// Adds a marker before the frame at the given index.
+  pub(crate) fn push_marker_at_frame(&mut self, label: String, time: Duration, frame_index: usize) {
+    self.markers.push(Marker {
+      label,
+      time,
+      frame_index,
+    });
+  }
+
+  /// Returns every frame of the recording, in the order they were printed.
+  pub fn get_frames(&self) -> &[RecordedFrame] {
+    &self.frames
+  }
+
+  /// Returns every marker of the recording, in the order they were placed.
+  pub fn get_markers(&self) -> &[Marker] {
+    &self.markers
+  }
+
+  /// Returns the first marker with the given label.
+  pub fn find_marker(&self, label: &str) -> Option<&Marker> {
+    self.markers.iter().find(|marker| marker.label == label)
+  }
+
+  /// Returns the markers placed right before the frame at the given index.
+  pub fn markers_before_frame(&self, frame_index: usize) -> impl Iterator<Item = &Marker> {
+    self
+      .markers
+      .iter()
+      .filter(move |marker| marker.frame_index == frame_index)
+  }
+
+  /// Returns the (width, height) of the largest frame in the recording.
+  pub fn dimensions(&self) -> (usize, usize) {
+    self.frames.iter().fold((0, 0), |(width, height), frame| {
+      let (frame_width, frame_height) = measure_grid(&frame.grid);
+
+      (width.max(frame_width), height.max(frame_height))
+    })
+  }
+
+  /// Returns the recording in the [`asciicast v2`](https://docs.asciinema.org/manual/asciicast/v2/) format,
+  /// which can be played back with tools such as asciinema.
+  ///
+  /// Every frame is printed in its entirety at the top left of a cleared screen,
+  /// and every marker is written as a marker event with its label.
+  pub fn to_asciicast(&self) -> String {
+    let (width, height) = self.dimensions();
+    let mut asciicast = format!("{{\"version\": 2, \"width\": {width}, \"height\": {height}}}\n");
+    let mut markers = self.markers.iter().peekable();
+
+    for (frame_index, frame) in self.frames.iter().enumerate() {
+      while let Some(marker) = markers.next_if(|marker| marker.frame_index <= frame_index) {
+        asciicast.push_str(&asciicast_event(marker.time, "m", &marker.label));
+      }
+
+      let output = format!("\x1B[H\x1B[2J{}", frame.grid.replace('\n', "\r\n"));
+      asciicast.push_str(&asciicast_event(frame.time, "o", &output));
+    }
+
+    for marker in markers {
+      asciicast.push_str(&asciicast_event(marker.time, "m", &marker.label));
+    }
+
+    asciicast
+  }
+}
+
+/// The recording a printer is currently adding frames to.
+#[derive(Debug, Clone)]
+pub(crate) struct ActiveRecording {
+  recording: Recording,
+  start_time: Instant,
+}
+
+impl ActiveRecording {
+  /// Adds the grid to the recording as printed right now.
+  pub(crate) fn push_frame(&mut self, grid: &str) {
+    let time = self.start_time.elapsed();
+
+    self.recording.push_frame(grid, time);
+  }
+}
+
+impl Printer {
+  /// Starts recording every grid printed with [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print).
+  ///
+  /// Any recording already in progress is discarded.
+  pub fn start_recording(&mut self) {
+    self.recording = Some(ActiveRecording {
+      recording: Recording::new(),
+      start_time: Instant::now(),
+    });
+  }
+
+  /// Stops recording, returning everything recorded since [`start_recording`](Printer::start_recording) was called.
+  ///
+  /// Returns None if the printer wasn't recording.
+  pub fn stop_recording(&mut self) -> Option<Recording> {
+    self
+      .recording
+      .take()
+      .map(|active_recording| active_recording.recording)
+  }
+
+  /// Returns true if the printer is currently recording.
+  pub fn is_recording(&self) -> bool {
+    self.recording.is_some()
+  }
+
+  /// Places a marker with the given label in the current recording, before the next grid printed.
+  ///
+  /// Does nothing if the printer isn't recording.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::deterministic();
+  /// printer.start_recording();
+  ///
+  /// printer.dynamic_print("abc".to_string()).unwrap();
+  /// printer.mark("level 2 start");
+  /// printer.dynamic_print("xyz".to_string()).unwrap();
+  ///
+  /// let recording = printer.stop_recording().unwrap();
+  ///
+  /// assert_eq!(recording.get_markers()[0].frame_index, 1);
+  /// ```
+  pub fn mark<S: Into<String>>(&mut self, label: S) {
+    if let Some(active_recording) = &mut self.recording {
+      let time = active_recording.start_time.elapsed();
+
+      active_recording.recording.push_marker(label, time);
+    }
+  }
+
+  /// Prints every frame of the recording with [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// waiting between frames with the [`FramePacing`](crate::animation::FramePacing) so they're printed with the same timing they were recorded with.
+  ///
+  /// This blocks until the last frame is printed.
+  ///
+  /// # Errors
+  ///
+  /// - Any error returned when printing a frame, after which the rest of the recording isn't played.
+  pub fn replay(&mut self, recording: &Recording) -> Result<(), PrintingError> {
+    let start_time = Instant::now();
+
+    for frame in recording.get_frames() {
+      if let Some(wait_time) = frame.time.checked_sub(start_time.elapsed()) {
+        self.get_frame_pacing().wait(wait_time);
+      }
+
+      self.dynamic_print(frame.grid.clone())?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Returns a line of an asciicast file for an event of the given type at the given time.
+fn asciicast_event(time: Duration, event_type: &str, data: &str) -> String {
+  format!(
+    "[{:.6}, \"{event_type}\", {}]\n",
+    time.as_secs_f64(),
+    json_string(data)
+  )
+}
+
+/// Returns the text as a JSON string, including the surrounding quotes.
+pub(crate) fn json_string(text: &str) -> String {
+  let mut json = String::with_capacity(text.len() + 2);
+  json.push('"');
+
+  for character in text.chars() {
+    match character {
+      '"' => json.push_str("\\\""),
+      '\\' => json.push_str("\\\\"),
+      '\n' => json.push_str("\\n"),
+      '\r' => json.push_str("\\r"),
+      '\t' => json.push_str("\\t"),
+      character if character.is_control() => {
+        json.push_str(&format!("\\u{:04x}", character as u32));
+      }
+      character => json.push(character),
+    }
+  }
+
+  json.push('"');
+
+  json
+}
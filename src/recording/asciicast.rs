@@ -0,0 +1,303 @@
+//! Importing [`asciicast v2`](https://docs.asciinema.org/manual/asciicast/v2/) files as [`Recordings`](crate::recording::Recording).
+//!
+//! The output of the file is played through a [`VirtualTerminal`](crate::virtual_terminal::VirtualTerminal)
+//! of the size in its header, and the screen is captured after every output event.
+//! Input and resize events are ignored, and marker events become [`Markers`](crate::recording::Marker).
+
+use crate::errors::PrintingError;
+use crate::recording::Recording;
+use crate::virtual_terminal::VirtualTerminal;
+use std::io;
+use std::iter::Peekable;
+use std::str::Chars;
+use std::time::Duration;
+
+impl Recording {
+  /// Creates a recording from the contents of an asciicast v2 file, where every frame is the
+  /// top left of the recorded screen clipped to the given (width, height).
+  ///
+  /// Clipping lets a recorded session be played in a region of a larger UI with
+  /// [`replay`](crate::printer::Printer::replay), no matter the size of the terminal it was recorded in.
+  /// A frame is only added when the visible part of the screen changes.
+  ///
+  /// # Errors
+  ///
+  /// - The text isn't a valid asciicast v2 file.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::recording::Recording;
+  ///
+  /// let asciicast = r#"{"version": 2, "width": 10, "height": 2}
+  /// [0.5, "o", "hello\r\nworld"]
+  /// [1.0, "m", "greeted"]
+  /// "#;
+  ///
+  /// let recording = Recording::from_asciicast(asciicast, (3, 2)).unwrap();
+  ///
+  /// assert_eq!(recording.get_frames()[0].grid, "hel\nwor");
+  /// assert_eq!(recording.find_marker("greeted").unwrap().frame_index, 1);
+  /// ```
+  pub fn from_asciicast(
+    asciicast: &str,
+    region_size: (usize, usize),
+  ) -> Result<Self, PrintingError> {
+    let mut lines = asciicast.lines().filter(|line| !line.trim().is_empty());
+    let header = parse_json(lines.next().unwrap_or_default())?;
+
+    if header.field("version").and_then(JsonValue::as_number) != Some(2.0) {
+      return Err(invalid_asciicast("The asciicast isn't of version 2.").into());
+    }
+
+    let (Some(width), Some(height)) = (
+      header.field("width").and_then(JsonValue::as_number),
+      header.field("height").and_then(JsonValue::as_number),
+    ) else {
+      return Err(invalid_asciicast("The asciicast header has no width or height.").into());
+    };
+
+    let mut terminal = VirtualTerminal::new(width as usize, height as usize);
+    let mut recording = Recording::new();
+
+    for line in lines {
+      let event = parse_json(line)?;
+
+      let JsonValue::Array(fields) = &event else {
+        return Err(invalid_asciicast("An asciicast event isn't an array.").into());
+      };
+      let (Some(time), Some(event_type), Some(data)) = (
+        fields.first().and_then(JsonValue::as_number),
+        fields.get(1).and_then(JsonValue::as_str),
+        fields.get(2).and_then(JsonValue::as_str),
+      ) else {
+        return Err(
+          invalid_asciicast("An asciicast event is missing its time, type, or data.").into(),
+        );
+      };
+      let time = Duration::from_secs_f64(time.max(0.0));
+
+      match event_type {
+        "o" => {
+          terminal.write(data);
+
+          let grid = terminal.region((0, 0), region_size);
+
+          if recording.get_frames().last().map(|frame| &frame.grid) != Some(&grid) {
+            recording.push_frame(grid, time);
+          }
+        }
+        "m" => recording.push_marker(data, time),
+        _ => (),
+      }
+    }
+
+    Ok(recording)
+  }
+}
+
+/// A value parsed from JSON.
+#[derive(Debug, PartialEq, Clone)]
+enum JsonValue {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<JsonValue>),
+  Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+  fn as_number(&self) -> Option<f64> {
+    match self {
+      Self::Number(number) => Some(*number),
+      _ => None,
+    }
+  }
+
+  fn as_str(&self) -> Option<&str> {
+    match self {
+      Self::String(text) => Some(text),
+      _ => None,
+    }
+  }
+
+  /// Returns the value of the field if this is an object that has it.
+  fn field(&self, name: &str) -> Option<&JsonValue> {
+    match self {
+      Self::Object(fields) => fields
+        .iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, value)| value),
+      _ => None,
+    }
+  }
+}
+
+/// Parses a single JSON value that takes up the whole text.
+fn parse_json(text: &str) -> io::Result<JsonValue> {
+  let mut characters = text.chars().peekable();
+  let value = parse_value(&mut characters)?;
+
+  skip_whitespace(&mut characters);
+
+  match characters.next() {
+    None => Ok(value),
+    Some(_) => Err(invalid_asciicast(
+      "The asciicast has text after a JSON value.",
+    )),
+  }
+}
+
+fn parse_value(characters: &mut Peekable<Chars>) -> io::Result<JsonValue> {
+  skip_whitespace(characters);
+
+  match characters.peek() {
+    Some('{') => {
+      characters.next();
+      let mut fields = Vec::new();
+
+      parse_list(characters, '}', |characters| {
+        skip_whitespace(characters);
+        let name = parse_string(characters)?;
+        skip_whitespace(characters);
+
+        if characters.next() != Some(':') {
+          return Err(invalid_asciicast("A JSON object is missing a colon."));
+        }
+
+        fields.push((name, parse_value(characters)?));
+
+        Ok(())
+      })?;
+
+      Ok(JsonValue::Object(fields))
+    }
+    Some('[') => {
+      characters.next();
+      let mut values = Vec::new();
+
+      parse_list(characters, ']', |characters| {
+        values.push(parse_value(characters)?);
+
+        Ok(())
+      })?;
+
+      Ok(JsonValue::Array(values))
+    }
+    Some('"') => Ok(JsonValue::String(parse_string(characters)?)),
+    Some(_) => {
+      let mut word = String::new();
+
+      while let Some(character) = characters
+        .next_if(|character| character.is_ascii_alphanumeric() || "+-.".contains(*character))
+      {
+        word.push(character);
+      }
+
+      match word.as_str() {
+        "null" => Ok(JsonValue::Null),
+        "true" => Ok(JsonValue::Bool(true)),
+        "false" => Ok(JsonValue::Bool(false)),
+        word => word
+          .parse()
+          .map(JsonValue::Number)
+          .map_err(|_| invalid_asciicast("The asciicast has an invalid JSON value.")),
+      }
+    }
+    None => Err(invalid_asciicast(
+      "The asciicast ended in the middle of a JSON value.",
+    )),
+  }
+}
+
+/// Parses the comma separated items of an array or object up to the closing character,
+/// where the opening character was already parsed.
+fn parse_list<F>(
+  characters: &mut Peekable<Chars>,
+  closing: char,
+  mut parse_item: F,
+) -> io::Result<()>
+where
+  F: FnMut(&mut Peekable<Chars>) -> io::Result<()>,
+{
+  skip_whitespace(characters);
+
+  if characters.next_if_eq(&closing).is_some() {
+    return Ok(());
+  }
+
+  loop {
+    parse_item(characters)?;
+    skip_whitespace(characters);
+
+    match characters.next() {
+      Some(',') => continue,
+      Some(character) if character == closing => return Ok(()),
+      _ => {
+        return Err(invalid_asciicast(
+          "A JSON list is missing a comma or its end.",
+        ))
+      }
+    }
+  }
+}
+
+fn parse_string(characters: &mut Peekable<Chars>) -> io::Result<String> {
+  if characters.next() != Some('"') {
+    return Err(invalid_asciicast("The asciicast is missing a JSON string."));
+  }
+
+  let mut text = String::new();
+
+  loop {
+    match characters.next() {
+      Some('"') => return Ok(text),
+      Some('\\') => match characters.next() {
+        Some('n') => text.push('\n'),
+        Some('r') => text.push('\r'),
+        Some('t') => text.push('\t'),
+        Some('b') => text.push('\x08'),
+        Some('f') => text.push('\x0C'),
+        Some('u') => {
+          let mut code = parse_unicode_escape(characters)?;
+
+          // Characters outside of the basic plane are escaped as a surrogate pair.
+          if (0xD800..0xDC00).contains(&code)
+            && characters.next() == Some('\\')
+            && characters.next() == Some('u')
+          {
+            let low_surrogate = parse_unicode_escape(characters)?;
+            code = 0x10000 + ((code - 0xD800) << 10) + (low_surrogate.wrapping_sub(0xDC00) & 0x3FF);
+          }
+
+          text.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+        Some(character) => text.push(character),
+        None => break,
+      },
+      Some(character) => text.push(character),
+      None => break,
+    }
+  }
+
+  Err(invalid_asciicast("A JSON string never ends."))
+}
+
+/// Parses the 4 hex digits following a `\u`.
+fn parse_unicode_escape(characters: &mut Peekable<Chars>) -> io::Result<u32> {
+  let digits: String = characters.by_ref().take(4).collect();
+
+  u32::from_str_radix(&digits, 16)
+    .map_err(|_| invalid_asciicast("A JSON string has an invalid unicode escape."))
+}
+
+fn skip_whitespace(characters: &mut Peekable<Chars>) {
+  while characters
+    .next_if(|character| character.is_whitespace())
+    .is_some()
+  {}
+}
+
+fn invalid_asciicast(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,234 @@
+//! The compact binary format for [`Recordings`](crate::recording::Recording).
+//!
+//! Every frame is stored as a [`FrameDiff`](crate::frame_format::FrameDiff) from the frame before it,
+//! so recordings where little changes between frames stay small.
+//!
+//! All numbers are little endian, and all text is UTF-8 prefixed by its length in bytes as a `u32`.
+//!
+//! ```text
+//! header:
+//!   magic        4 bytes, "SPRC"
+//!   version      u8, currently 2
+//!   compression  u8, 0 for none, 1 for gzip
+//! body, compressed as the header says:
+//!   frame count  u32
+//!   frames:
+//!     time       u64, microseconds since the recording started
+//!     difference the body of the frame diff from the frame before it, or an empty frame for the first frame
+//!   marker count u32
+//!   markers:
+//!     time       u64, microseconds since the recording started
+//!     frame      u32, the index of the frame after the marker
+//!     label      text
+//! ```
+//!
+//! The body of each frame diff is the same as in the [`frame_format`](crate::frame_format) module, without its header.
+//!
+//! Recordings of version 1 can still be read, where each frame is stored as the rows that changed instead:
+//!
+//! ```text
+//! frames:
+//!   time       u64, microseconds since the recording started
+//!   row count  u32, the amount of rows in the frame
+//!   changes    u32, the amount of changed rows that follow
+//!   changed rows:
+//!     index    u32, the row that changed
+//!     text     the new text of the row
+//! ```
+//!
+//! The rows of a frame start as the rows of the frame before it, or no rows for the first frame.
+//! Rows are then added or removed to match the row count, and the changed rows are replaced.
+
+use crate::errors::PrintingError;
+use crate::frame_format::{invalid_data, read_text, read_u32, write_text, write_u32, FrameDiff};
+use crate::recording::Recording;
+use std::io::{self, Read, Write};
+use std::time::Duration;
+
+const MAGIC: &[u8; 4] = b"SPRC";
+const VERSION: u8 = 2;
+/// The version that stored the rows that changed in each frame, rather than a frame diff.
+const ROW_VERSION: u8 = 1;
+
+/// How the body of a recording is compressed when written with [`write_to`](Recording::write_to).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RecordingCompression {
+  /// The body isn't compressed.
+  #[default]
+  None,
+  /// The body is compressed with gzip.
+  ///
+  /// Requires the `compression` feature.
+  #[cfg(feature = "compression")]
+  Gzip,
+}
+
+impl Recording {
+  /// Writes the recording in the format described in the [`format`](crate::recording::format) module.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the writer failed.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::recording::*;
+  /// use std::time::Duration;
+  ///
+  /// let mut recording = Recording::new();
+  /// recording.push_frame("abc\n123", Duration::ZERO);
+  /// recording.push_frame("abc\n456", Duration::from_millis(100));
+  ///
+  /// let mut bytes = Vec::new();
+  /// recording.write_to(&mut bytes, RecordingCompression::None).unwrap();
+  ///
+  /// assert_eq!(Recording::read_from(bytes.as_slice()).unwrap(), recording);
+  /// ```
+  pub fn write_to<W: Write>(
+    &self,
+    mut writer: W,
+    compression: RecordingCompression,
+  ) -> Result<(), PrintingError> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION])?;
+
+    match compression {
+      RecordingCompression::None => {
+        writer.write_all(&[0])?;
+        self.write_body(&mut writer)?;
+      }
+      #[cfg(feature = "compression")]
+      RecordingCompression::Gzip => {
+        writer.write_all(&[1])?;
+
+        let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+        self.write_body(&mut encoder)?;
+        encoder.finish()?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Reads a recording written with [`write_to`](Recording::write_to).
+  ///
+  /// # Errors
+  ///
+  /// - Reading from the reader failed.
+  /// - The data isn't a recording, or is of a newer version.
+  /// - The recording is compressed, but the `compression` feature isn't enabled.
+  pub fn read_from<R: Read>(mut reader: R) -> Result<Self, PrintingError> {
+    let mut header = [0; 6];
+    reader.read_exact(&mut header)?;
+
+    if &header[..4] != MAGIC {
+      return Err(invalid_data("The data isn't a recording.").into());
+    }
+
+    let version = header[4];
+
+    if version != VERSION && version != ROW_VERSION {
+      return Err(invalid_data("The recording is of an unsupported version.").into());
+    }
+
+    match header[5] {
+      0 => Ok(Self::read_body(&mut reader, version)?),
+      #[cfg(feature = "compression")]
+      1 => Ok(Self::read_body(
+        &mut flate2::read::GzDecoder::new(reader),
+        version,
+      )?),
+      _ => Err(invalid_data("The recording's compression is unsupported.").into()),
+    }
+  }
+
+  /// Writes every frame and marker of the recording.
+  fn write_body<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+    let mut previous_grid = "";
+
+    write_u32(writer, self.get_frames().len())?;
+
+    for frame in self.get_frames() {
+      writer.write_all(&(frame.time.as_micros() as u64).to_le_bytes())?;
+      FrameDiff::from_grids(previous_grid, &frame.grid).write_body(writer)?;
+
+      previous_grid = &frame.grid;
+    }
+
+    write_u32(writer, self.get_markers().len())?;
+
+    for marker in self.get_markers() {
+      writer.write_all(&(marker.time.as_micros() as u64).to_le_bytes())?;
+      write_u32(writer, marker.frame_index)?;
+      write_text(writer, &marker.label)?;
+    }
+
+    Ok(())
+  }
+
+  /// Reads every frame and marker of a recording of the given version.
+  fn read_body<R: Read>(reader: &mut R, version: u8) -> io::Result<Self> {
+    let mut recording = match version {
+      ROW_VERSION => Self::read_row_frames(reader)?,
+      _ => Self::read_frame_diffs(reader)?,
+    };
+
+    for _ in 0..read_u32(reader)? {
+      let time = read_duration(reader)?;
+      let frame_index = read_u32(reader)?;
+      let label = read_text(reader)?;
+
+      recording.push_marker_at_frame(label, time, frame_index);
+    }
+
+    Ok(recording)
+  }
+
+  /// Reads every frame of a recording, where each frame is a frame diff from the one before it.
+  fn read_frame_diffs<R: Read>(reader: &mut R) -> io::Result<Self> {
+    let mut recording = Recording::new();
+    let mut grid = String::new();
+
+    for _ in 0..read_u32(reader)? {
+      let time = read_duration(reader)?;
+      grid = FrameDiff::read_body(reader)?.apply(&grid);
+
+      recording.push_frame(grid.clone(), time);
+    }
+
+    Ok(recording)
+  }
+
+  /// Reads every frame of a version 1 recording, where each frame is the rows that changed from the one before it.
+  fn read_row_frames<R: Read>(reader: &mut R) -> io::Result<Self> {
+    let mut recording = Recording::new();
+    let mut rows: Vec<String> = Vec::new();
+
+    for _ in 0..read_u32(reader)? {
+      let time = read_duration(reader)?;
+      let row_count = read_u32(reader)?;
+
+      rows.resize(row_count, String::new());
+
+      for _ in 0..read_u32(reader)? {
+        let index = read_u32(reader)?;
+        let row = rows
+          .get_mut(index)
+          .ok_or_else(|| invalid_data("A changed row is outside of the frame."))?;
+
+        *row = read_text(reader)?;
+      }
+
+      recording.push_frame(rows.join("\n"), time);
+    }
+
+    Ok(recording)
+  }
+}
+
+fn read_duration<R: Read>(reader: &mut R) -> io::Result<Duration> {
+  let mut bytes = [0; 8];
+  reader.read_exact(&mut bytes)?;
+
+  Ok(Duration::from_micros(u64::from_le_bytes(bytes)))
+}
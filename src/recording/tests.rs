@@ -0,0 +1,217 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod marker_tests {
+  use super::*;
+
+  #[test]
+  fn markers_are_placed_before_next_frame() {
+    let mut recording = Recording::new();
+    recording.push_frame("a", Duration::ZERO);
+    recording.push_marker("first", Duration::from_secs(1));
+    recording.push_marker("second", Duration::from_secs(1));
+    recording.push_frame("b", Duration::from_secs(2));
+    recording.push_marker("end", Duration::from_secs(3));
+
+    let labels: Vec<&str> = recording
+      .markers_before_frame(1)
+      .map(|marker| marker.label.as_str())
+      .collect();
+
+    assert_eq!(labels, vec!["first", "second"]);
+    assert_eq!(recording.find_marker("end").unwrap().frame_index, 2);
+  }
+
+  #[test]
+  fn marking_without_recording_does_nothing() {
+    let mut printer = Printer::new();
+    printer.mark("ignored");
+
+    printer.start_recording();
+    printer.mark("kept");
+
+    let recording = printer.stop_recording().unwrap();
+
+    assert_eq!(recording.get_markers().len(), 1);
+    assert!(!printer.is_recording());
+  }
+}
+
+#[cfg(test)]
+mod asciicast_tests {
+  use super::*;
+
+  #[test]
+  fn markers_are_exported_as_marker_events() {
+    let mut recording = Recording::new();
+    recording.push_frame("ab\ncd", Duration::ZERO);
+    recording.push_marker("level \"2\"", Duration::from_millis(1500));
+    recording.push_frame("xy\nzw", Duration::from_secs(2));
+
+    let expected_asciicast = [
+      r#"{"version": 2, "width": 2, "height": 2}"#,
+      r#"[0.000000, "o", "\u001b[H\u001b[2Jab\r\ncd"]"#,
+      r#"[1.500000, "m", "level \"2\""]"#,
+      r#"[2.000000, "o", "\u001b[H\u001b[2Jxy\r\nzw"]"#,
+    ]
+    .join("\n")
+      + "\n";
+
+    assert_eq!(recording.to_asciicast(), expected_asciicast);
+  }
+}
+
+#[cfg(test)]
+mod format_tests {
+  use super::*;
+
+  /// Returns a recording where frames change in size and content.
+  fn get_changing_recording() -> Recording {
+    let mut recording = Recording::new();
+    recording.push_frame("abc\n123", Duration::ZERO);
+    recording.push_marker("grows", Duration::from_micros(1500));
+    recording.push_frame("abc\n456\nxyz", Duration::from_millis(2));
+    recording.push_frame("\x1B[31mab\x1B[0m", Duration::from_secs(3));
+    recording.push_marker("ended", Duration::from_secs(4));
+
+    recording
+  }
+
+  #[test]
+  fn recording_round_trips() {
+    let recording = get_changing_recording();
+    let mut bytes = Vec::new();
+
+    recording
+      .write_to(&mut bytes, RecordingCompression::None)
+      .unwrap();
+
+    assert_eq!(Recording::read_from(bytes.as_slice()).unwrap(), recording);
+  }
+
+  #[test]
+  fn unchanged_rows_are_not_stored() {
+    let mut recording = Recording::new();
+    recording.push_frame("a".repeat(100), Duration::ZERO);
+    recording.push_frame("a".repeat(100), Duration::ZERO);
+    let mut bytes = Vec::new();
+
+    recording
+      .write_to(&mut bytes, RecordingCompression::None)
+      .unwrap();
+
+    assert!(bytes.len() < 200);
+  }
+
+  #[test]
+  fn row_version_recordings_are_read() {
+    let mut bytes = b"SPRC\x01\x00".to_vec();
+    bytes.extend(2u32.to_le_bytes());
+    // The first frame, with both of its rows changed.
+    bytes.extend(0u64.to_le_bytes());
+    bytes.extend([2u32, 2, 0, 2].map(u32::to_le_bytes).concat());
+    bytes.extend(b"ab");
+    bytes.extend([1u32, 2].map(u32::to_le_bytes).concat());
+    bytes.extend(b"cd");
+    // The second frame, with only its first row changed.
+    bytes.extend(1000u64.to_le_bytes());
+    bytes.extend([2u32, 1, 0, 2].map(u32::to_le_bytes).concat());
+    bytes.extend(b"xy");
+    bytes.extend(0u32.to_le_bytes());
+
+    let recording = Recording::read_from(bytes.as_slice()).unwrap();
+    let grids: Vec<&str> = recording
+      .get_frames()
+      .iter()
+      .map(|frame| frame.grid.as_str())
+      .collect();
+
+    assert_eq!(grids, ["ab\ncd", "xy\ncd"]);
+  }
+
+  #[test]
+  fn other_data_is_rejected() {
+    let result = Recording::read_from(b"not a recording".as_slice());
+
+    assert!(matches!(result, Err(PrintingError::Io(_))));
+  }
+
+  #[cfg(feature = "compression")]
+  #[test]
+  fn compressed_recording_round_trips() {
+    let recording = get_changing_recording();
+    let mut bytes = Vec::new();
+
+    recording
+      .write_to(&mut bytes, RecordingCompression::Gzip)
+      .unwrap();
+
+    assert_eq!(Recording::read_from(bytes.as_slice()).unwrap(), recording);
+  }
+}
+
+#[cfg(test)]
+mod asciicast_import_tests {
+  use super::*;
+
+  #[test]
+  fn frames_are_clipped_to_region() {
+    let asciicast = "{\"version\": 2, \"width\": 6, \"height\": 3}\n\
+      [0.0, \"o\", \"abcdef\\r\\n\"]\n\
+      [0.5, \"i\", \"ignored\"]\n\
+      [1.0, \"o\", \"\\u001b[31mxyz\\u001b[0m\"]\n";
+
+    let recording = Recording::from_asciicast(asciicast, (2, 2)).unwrap();
+    let frames = recording.get_frames();
+
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0].grid, "ab\n  ");
+    assert_eq!(frames[1].grid, "ab\n\x1B[31mxy\x1B[0m");
+    assert_eq!(frames[1].time, Duration::from_secs(1));
+  }
+
+  #[test]
+  fn unchanged_region_adds_no_frame() {
+    let asciicast = "{\"version\": 2, \"width\": 6, \"height\": 1}\n\
+      [0.0, \"o\", \"ab\"]\n\
+      [1.0, \"o\", \"cdef\"]\n";
+
+    let recording = Recording::from_asciicast(asciicast, (2, 1)).unwrap();
+
+    assert_eq!(recording.get_frames().len(), 1);
+  }
+
+  #[test]
+  fn exported_recording_imports_the_same() {
+    let mut recording = Recording::new();
+    recording.push_frame("ab\n\"c", Duration::ZERO);
+    recording.push_marker("middle", Duration::from_secs(1));
+    recording.push_frame("\u{1F600}\nd ", Duration::from_secs(2));
+
+    let imported = Recording::from_asciicast(&recording.to_asciicast(), (2, 2)).unwrap();
+
+    assert_eq!(imported, recording);
+  }
+
+  #[test]
+  fn invalid_asciicast_is_an_error() {
+    let invalid_files = [
+      "",
+      "{\"version\": 1, \"width\": 2, \"height\": 2}",
+      "{\"version\": 2}",
+      "{\"version\": 2, \"width\": 2, \"height\": 2}\n[0.0, \"o\"]",
+      "{\"version\": 2, \"width\": 2, \"height\": 2}\n[0.0, \"o\", \"abc]",
+    ];
+
+    for invalid_file in invalid_files {
+      let result = Recording::from_asciicast(invalid_file, (2, 2));
+
+      assert!(
+        matches!(result, Err(PrintingError::Io(_))),
+        "{invalid_file:?}"
+      );
+    }
+  }
+}
@@ -0,0 +1,228 @@
+//! Named areas of a grid that are each given their own grid, then printed together as one.
+
+mod tests;
+
+use crate::ansi::STYLE_RESET;
+use crate::canvas::Canvas;
+use crate::cells::{split_grid_into_cells, EmojiMode};
+use crate::color::Color;
+use crate::focus::FocusHighlight;
+use crate::printing_position::{BoundingBox, CenterRounding};
+
+/// An area of the grid printed with [`print_regions`](crate::printer::Printer::print_regions),
+/// registered by name with [`Printer::region`](crate::printer::Printer::region).
+///
+/// Each region holds its own grid, aligned within the region's [`BoundingBox`](crate::printing_position::BoundingBox)
+/// by the box's [`Alignment`](crate::printing_position::Alignment). Anything of the grid outside of the box is cut off,
+/// and the rest of the box is filled with the box's fill character, or spaces if it has none.
+///
+/// Unlike a bounding box given to the printer, the position of a region's box is relative to the top left of the printed grid,
+/// which is placed on the terminal the same way as any other grid. Regions registered later are drawn over earlier ones.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((10, 2));
+///
+/// printer
+///   .region("sidebar", BoundingBox::new((1, 1), (3, 2)).with_fill('|'))
+///   .replace_grid("ab".to_string());
+/// printer
+///   .region("main", BoundingBox::new((4, 1), (7, 2)).with_alignment(Alignment::Center))
+///   .replace_grid("main".to_string());
+///
+/// printer.print_regions().unwrap();
+///
+/// assert_eq!(
+///   printer.virtual_screen().unwrap().plain_screen(),
+///   "ab| main  \n|||       "
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Region {
+  bounds: BoundingBox,
+  grid: String,
+}
+
+impl Region {
+  /// Creates an empty region within the given box.
+  pub fn new(bounds: BoundingBox) -> Self {
+    Self {
+      bounds,
+      grid: String::new(),
+    }
+  }
+
+  /// Replaces the grid printed within the region.
+  pub fn replace_grid(&mut self, grid: String) -> &mut Self {
+    self.grid = grid;
+
+    self
+  }
+
+  /// Returns the grid printed within the region.
+  pub fn get_grid(&self) -> &str {
+    &self.grid
+  }
+
+  /// Replaces the box the region's grid is aligned within.
+  pub fn replace_bounds(&mut self, bounds: BoundingBox) -> &mut Self {
+    self.bounds = bounds;
+
+    self
+  }
+
+  /// Returns the box the region's grid is aligned within.
+  pub fn get_bounds(&self) -> BoundingBox {
+    self.bounds
+  }
+
+  /// Draws the region onto the canvas, where the canvas's top left is (1, 1), highlighted if it's focused.
+  fn draw(
+    &self,
+    canvas: &mut Canvas,
+    center_rounding: (CenterRounding, CenterRounding),
+    emoji_mode: EmojiMode,
+    highlight: Option<FocusHighlight>,
+  ) {
+    let (box_x, box_y) = self.bounds.position;
+    let (box_width, box_height) = self.bounds.dimensions;
+    let highlight_style = highlight
+      .map(|highlight| highlight.cell_style())
+      .unwrap_or_default();
+    let fill = styled_cell(
+      "",
+      &highlight_style,
+      &self.bounds.fill.unwrap_or(' ').to_string(),
+    );
+
+    for y in box_y - 1..box_y - 1 + box_height {
+      for x in box_x - 1..box_x - 1 + box_width {
+        canvas.set((x, y), fill.as_str());
+      }
+    }
+
+    let rows = split_grid_into_cells(&self.grid, emoji_mode);
+    let grid_width = rows
+      .iter()
+      .map(|row| row.iter().map(|cell| cell.width).sum::<usize>())
+      .max()
+      .unwrap_or(0);
+    let (grid_x, grid_y) = self.bounds.align((grid_width, rows.len()), center_rounding);
+
+    for (row_index, row) in rows.iter().enumerate().filter(|_| !self.grid.is_empty()) {
+      let y = grid_y - 1 + row_index;
+      let mut x = grid_x - 1;
+
+      if y >= box_y - 1 + box_height {
+        break;
+      }
+
+      for cell in row {
+        if x + cell.width > box_x - 1 + box_width {
+          break;
+        }
+
+        canvas.set(
+          (x, y),
+          styled_cell(&cell.style, &highlight_style, cell.text),
+        );
+        (1..cell.width).for_each(|covered_x| canvas.set((x + covered_x, y), ""));
+        x += cell.width;
+      }
+    }
+
+    if let Some(FocusHighlight::Border(color)) = highlight {
+      self.draw_border(canvas, color);
+    }
+  }
+
+  /// Draws a border over the outermost cells of the region, in the given color if any.
+  fn draw_border(&self, canvas: &mut Canvas, color: Option<Color>) {
+    let (left, top) = (self.bounds.position.0 - 1, self.bounds.position.1 - 1);
+    let (box_width, box_height) = self.bounds.dimensions;
+
+    if box_width == 0 || box_height == 0 {
+      return;
+    }
+
+    let (right, bottom) = (left + box_width - 1, top + box_height - 1);
+    let border_style = color
+      .map(|Color { red, green, blue }| format!("\x1B[38;2;{red};{green};{blue}m"))
+      .unwrap_or_default();
+
+    for y in top..=bottom {
+      for x in left..=right {
+        let border_character = match (y == top, y == bottom, x == left, x == right) {
+          (true, _, true, _) => '┌',
+          (true, _, _, true) => '┐',
+          (_, true, true, _) => '└',
+          (_, true, _, true) => '┘',
+          (true, ..) | (_, true, ..) => '─',
+          (.., true, _) | (.., true) => '│',
+          _ => continue,
+        };
+
+        // Wide characters cut in half by the border are replaced with spaces.
+        if canvas.get((x, y)) == Some("") && x > 0 {
+          canvas.set((x - 1, y), " ");
+        }
+
+        if canvas.get((x + 1, y)) == Some("") {
+          canvas.set((x + 1, y), " ");
+        }
+
+        canvas.set(
+          (x, y),
+          styled_cell(&border_style, "", &border_character.to_string()),
+        );
+      }
+    }
+  }
+}
+
+/// Returns the text of a cell with the given styles applied, followed by a reset if any styling was applied.
+fn styled_cell(style: &str, highlight_style: &str, text: &str) -> String {
+  match style.is_empty() && highlight_style.is_empty() {
+    true => text.to_string(),
+    false => format!("{style}{highlight_style}{text}{STYLE_RESET}"),
+  }
+}
+
+/// Returns a grid with every region drawn onto it in order, just large enough to hold all of them.
+///
+/// The region at the given index is highlighted with the [`FocusHighlight`](crate::focus::FocusHighlight), if any.
+pub(crate) fn compose_regions<'a, I>(
+  regions: I,
+  center_rounding: (CenterRounding, CenterRounding),
+  emoji_mode: EmojiMode,
+  highlighted_region: Option<(usize, FocusHighlight)>,
+) -> String
+where
+  I: IntoIterator<Item = &'a Region> + Clone,
+{
+  let dimensions = regions
+    .clone()
+    .into_iter()
+    .map(|region| {
+      let (x, y) = region.bounds.position;
+      let (width, height) = region.bounds.dimensions;
+
+      (x - 1 + width, y - 1 + height)
+    })
+    .fold((0, 0), |(width, height), (region_width, region_height)| {
+      (width.max(region_width), height.max(region_height))
+    });
+  let mut canvas = Canvas::new(dimensions);
+
+  for (index, region) in regions.into_iter().enumerate() {
+    let highlight = highlighted_region
+      .filter(|(highlighted_index, _)| *highlighted_index == index)
+      .map(|(_, highlight)| highlight);
+
+    region.draw(&mut canvas, center_rounding, emoji_mode, highlight);
+  }
+
+  canvas.to_grid()
+}
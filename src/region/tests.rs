@@ -0,0 +1,271 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod compose_regions_tests {
+  use super::*;
+  use crate::printing_position::Alignment;
+
+  fn compose(regions: &[Region]) -> String {
+    compose_regions(regions, Default::default(), EmojiMode::default(), None)
+  }
+
+  #[test]
+  fn grids_are_aligned_within_their_region() {
+    let mut region =
+      Region::new(BoundingBox::new((2, 1), (4, 3)).with_alignment(Alignment::BottomRight));
+    region.replace_grid("ab".to_string());
+
+    assert_eq!(compose(&[region]), "     \n     \n   ab");
+  }
+
+  #[test]
+  fn grids_are_cut_off_at_the_edge_of_their_region() {
+    let mut region = Region::new(BoundingBox::new((1, 1), (3, 1)));
+    region.replace_grid("ab字\ncd".to_string());
+
+    assert_eq!(compose(&[region]), "ab ");
+  }
+
+  #[test]
+  fn later_regions_are_drawn_over_earlier_ones() {
+    let mut bottom = Region::new(BoundingBox::new((1, 1), (4, 1)).with_fill('.'));
+    bottom.replace_grid("abcd".to_string());
+    let mut top = Region::new(BoundingBox::new((2, 1), (2, 1)));
+    top.replace_grid("x".to_string());
+
+    assert_eq!(compose(&[bottom, top]), "ax d");
+  }
+
+  #[test]
+  fn styles_are_kept_within_each_cell() {
+    let mut region = Region::new(BoundingBox::new((1, 1), (3, 1)));
+    region.replace_grid("\x1B[31mab\x1B[0mc".to_string());
+
+    assert_eq!(compose(&[region]), "\x1B[31ma\x1B[0m\x1B[31mb\x1B[0mc");
+  }
+}
+
+#[cfg(test)]
+mod printer_region_tests {
+  use crate::printer::*;
+
+  #[test]
+  fn regions_are_replaced_by_name() {
+    let mut printer = Printer::on_virtual_terminal((4, 1));
+    printer
+      .region("a", BoundingBox::new((1, 1), (2, 1)))
+      .replace_grid("ab".to_string());
+    printer.region("a", BoundingBox::new((3, 1), (2, 1)));
+
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.get_region("a").unwrap().get_grid(), "ab");
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "  ab");
+    assert!(printer.remove_region("a").is_some());
+    assert!(printer.get_region("a").is_none());
+  }
+
+  #[test]
+  fn changes_in_every_region_are_printed_together() {
+    let mut printer = Printer::on_virtual_terminal((4, 1));
+    printer
+      .region("left", BoundingBox::new((1, 1), (2, 1)))
+      .replace_grid("ab".to_string());
+    printer
+      .region("right", BoundingBox::new((3, 1), (2, 1)))
+      .replace_grid("cd".to_string());
+    printer.print_regions().unwrap();
+    let frame_count = printer.get_capture().unwrap().frame_count();
+
+    printer
+      .region("left", BoundingBox::new((1, 1), (2, 1)))
+      .replace_grid("xb".to_string());
+    printer
+      .region("right", BoundingBox::new((3, 1), (2, 1)))
+      .replace_grid("cx".to_string());
+    printer.print_regions().unwrap();
+
+    assert_eq!(
+      printer.get_capture().unwrap().frame_count(),
+      frame_count + 1
+    );
+    assert_eq!(printer.get_last_frame_stats().unwrap().changed_cells, 2);
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "xbcx");
+  }
+}
+
+#[cfg(test)]
+mod layout_regions_tests {
+  use crate::printer::*;
+
+  #[test]
+  fn regions_follow_the_layout_after_resizing() {
+    let mut printer = Printer::on_virtual_terminal((6, 1));
+    let layout = Layout::horizontal(vec![Constraint::Percentage(50), Constraint::Remainder]);
+    printer
+      .region(
+        "right",
+        BoundingBox::new((1, 1), (1, 1)).with_alignment(Alignment::Right),
+      )
+      .replace_grid("b".to_string());
+
+    printer.layout_regions(&layout, &["left", "right"]).unwrap();
+    printer
+      .get_region_mut("left")
+      .unwrap()
+      .replace_grid("a".to_string());
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "a    b");
+
+    printer.replace_terminal_dimensions(Some((4, 1)));
+    printer.layout_regions(&layout, &["left", "right"]).unwrap();
+    printer.print_regions().unwrap();
+
+    assert_eq!(
+      printer.get_region("right").unwrap().get_bounds(),
+      BoundingBox::new((3, 1), (2, 1)).with_alignment(Alignment::Right)
+    );
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "a  b  ");
+  }
+}
+
+#[cfg(test)]
+mod print_widgets_tests {
+  use crate::printer::*;
+  use crate::widgets::*;
+
+  #[test]
+  fn widgets_are_rendered_at_the_size_of_their_region() {
+    let mut printer = Printer::on_virtual_terminal((7, 1));
+    let layout = Layout::horizontal(vec![Constraint::Fixed(3), Constraint::Remainder]);
+    printer
+      .layout_regions(&layout, &["marquee", "field"])
+      .unwrap();
+    let marquee = Marquee::new("abcdef", 1);
+    let mut text_field = TextField::new(1);
+    text_field.replace_content("xyz");
+
+    printer
+      .print_widgets(&[("marquee", &marquee), ("field", &text_field)])
+      .unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "abcxyz ");
+  }
+
+  #[test]
+  fn unregistered_regions_print_nothing() {
+    let mut printer = Printer::on_virtual_terminal((3, 1));
+    let paragraph = Paragraph::new("abc", 3, 1);
+
+    assert!(matches!(
+      printer.print_widgets(&[("missing", &paragraph)]),
+      Err(PrintingError::RegionNotFound(name)) if name == "missing"
+    ));
+    assert_eq!(printer.get_capture().unwrap().frame_count(), 0);
+  }
+}
+
+#[cfg(test)]
+mod place_terminal_cursor_tests {
+  use crate::printer::*;
+
+  #[test]
+  fn the_cursor_follows_its_region() {
+    let mut printer = Printer::on_virtual_terminal((6, 2));
+    printer.replace_y_printing_position(YPrintingPosition::Top);
+    printer
+      .region("field", BoundingBox::new((1, 1), (3, 1)))
+      .replace_grid("ab".to_string());
+    printer.place_terminal_cursor("field", 2, 0).unwrap();
+
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().cursor_position(), (2, 0));
+    assert!(printer
+      .get_capture()
+      .unwrap()
+      .bytes()
+      .ends_with(b"\x1B[?25h"));
+
+    printer.region("field", BoundingBox::new((4, 2), (3, 1)));
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().cursor_position(), (5, 1));
+  }
+
+  #[test]
+  fn the_cursor_is_kept_within_its_region() {
+    let mut printer = Printer::on_virtual_terminal((6, 2));
+    printer.replace_y_printing_position(YPrintingPosition::Top);
+    printer.region("field", BoundingBox::new((2, 1), (3, 2)));
+    printer.place_terminal_cursor("field", 10, 10).unwrap();
+
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.virtual_screen().unwrap().cursor_position(), (3, 1));
+  }
+
+  #[test]
+  fn removing_the_region_removes_the_cursor() {
+    let mut printer = Printer::on_virtual_terminal((4, 1));
+    printer.region("field", BoundingBox::new((1, 1), (2, 1)));
+    printer.place_terminal_cursor("field", 1, 0).unwrap();
+    printer.print_regions().unwrap();
+
+    printer.remove_region("field");
+
+    assert!(printer.get_caret().is_none());
+    assert!(matches!(
+      printer.place_terminal_cursor("field", 0, 0),
+      Err(PrintingError::RegionNotFound(name)) if name == "field"
+    ));
+  }
+}
+
+#[cfg(test)]
+mod region_at_tests {
+  use crate::printer::*;
+
+  #[test]
+  fn positions_are_mapped_from_where_the_grid_was_printed() {
+    let mut printer = Printer::on_virtual_terminal((6, 3));
+    printer.replace_y_printing_position(YPrintingPosition::Bottom);
+    printer.region("left", BoundingBox::new((1, 1), (2, 2)));
+    printer.region("right", BoundingBox::new((3, 1), (2, 2)));
+
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.region_at(1, 2), Some(("left", 0, 0)));
+    assert_eq!(printer.region_at(4, 3), Some(("right", 1, 1)));
+    assert_eq!(printer.region_at(4, 1), None);
+    assert_eq!(printer.region_at(5, 2), None);
+  }
+
+  #[test]
+  fn overlapping_regions_resolve_to_the_top_one() {
+    let mut printer = Printer::on_virtual_terminal((4, 1));
+    printer.region("bottom", BoundingBox::new((1, 1), (4, 1)));
+    printer.region("top", BoundingBox::new((2, 1), (2, 1)));
+
+    assert_eq!(printer.region_at(1, 1), None);
+
+    printer.print_regions().unwrap();
+
+    assert_eq!(printer.region_at(1, 1), Some(("bottom", 0, 0)));
+    assert_eq!(printer.region_at(3, 1), Some(("top", 1, 0)));
+  }
+
+  #[test]
+  fn positions_are_unknown_when_preserving_scrollback() {
+    let mut printer = Printer::on_virtual_terminal((4, 1));
+    printer.region("region", BoundingBox::new((1, 1), (4, 1)));
+    printer.print_regions().unwrap();
+
+    printer.replace_update_strategy(UpdateStrategy::ScrollbackPreserving);
+
+    assert_eq!(printer.region_at(1, 1), None);
+  }
+}
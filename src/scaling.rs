@@ -0,0 +1,300 @@
+mod tests;
+
+use crate::cells::*;
+use crate::style::StyledTextBuilder;
+use std::iter;
+
+/// What the printer does with grids larger than the area they're printed in.
+///
+/// The area is the [`BoundingBox`](crate::printing_position::BoundingBox) if one is set, or the printing area of the terminal otherwise.
+///
+/// Set with [`replace_overflow_policy`](crate::printer::Printer::replace_overflow_policy),
+/// otherwise it's decided by the [`OperatingMode`](crate::operating_mode::OperatingMode).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum OverflowPolicy {
+  /// Printing the grid returns an error.
+  #[default]
+  Error,
+  /// The rows and columns of the grid past the edges of the area are cut off.
+  Crop,
+  /// The grid is sampled down to the size of the area on every axis it's too large on, picking the nearest cell for each cell printed.
+  ///
+  /// This shows a coarse view of the entire grid, rather than a detailed view of part of it.
+  Downsample,
+  /// The grid is split into pages the size of the area, read left to right then top to bottom,
+  /// and only the current page is printed.
+  ///
+  /// Pages are changed with [`next_page`](crate::printer::Printer::next_page) and [`prev_page`](crate::printer::Printer::prev_page).
+  /// With the page indicator, the bottom row of the area is left for showing which page is printed, such as `Page 2/6`.
+  Paginate { page_indicator: bool },
+}
+
+impl OverflowPolicy {
+  /// Returns the grid fit within the given area based on the policy, along with its new dimensions.
+  ///
+  /// The given page is the one printed when paginating, which is expected to be less than the [`page count`](OverflowPolicy::page_count).
+  ///
+  /// Returns None if the grid already fits, or the policy is to return an error.
+  pub(crate) fn fit_grid(
+    &self,
+    grid: &str,
+    (grid_width, grid_height): (usize, usize),
+    (area_width, area_height): (usize, usize),
+    page: usize,
+    emoji_mode: EmojiMode,
+  ) -> Option<(String, (usize, usize))> {
+    if grid_width <= area_width && grid_height <= area_height {
+      return None;
+    }
+
+    let fitted_dimensions = (grid_width.min(area_width), grid_height.min(area_height));
+
+    match self {
+      Self::Error => None,
+      Self::Crop => Some((
+        crop_grid(grid, (0, 0), fitted_dimensions, emoji_mode),
+        fitted_dimensions,
+      )),
+      Self::Downsample => Some((
+        scale_grid(grid, fitted_dimensions, emoji_mode),
+        fitted_dimensions,
+      )),
+      Self::Paginate { page_indicator } => {
+        let (page_width, page_height) = page_dimensions((area_width, area_height), *page_indicator);
+        let horizontal_pages = grid_width.div_ceil(page_width.max(1));
+        let page_position = (
+          (page % horizontal_pages) * page_width,
+          (page / horizontal_pages) * page_height,
+        );
+        let mut page_grid = crop_grid(grid, page_position, (page_width, page_height), emoji_mode);
+
+        if *page_indicator {
+          let page_count = self.page_count((grid_width, grid_height), (area_width, area_height));
+          let indicator = format!("Page {}/{page_count}", page + 1);
+
+          if page_height > 0 {
+            page_grid.push('\n');
+          }
+
+          page_grid.push_str(&fit_row_to_width(&indicator, page_width));
+        }
+
+        Some((page_grid, (page_width, area_height)))
+      }
+    }
+  }
+
+  /// Returns the (width, height) a grid of the given dimensions ends up with when printed in the given area,
+  /// without fitting the grid itself.
+  pub(crate) fn fitted_dimensions(
+    &self,
+    (grid_width, grid_height): (usize, usize),
+    (area_width, area_height): (usize, usize),
+  ) -> (usize, usize) {
+    match self {
+      Self::Error => (grid_width, grid_height),
+      _ => (grid_width.min(area_width), grid_height.min(area_height)),
+    }
+  }
+
+  /// Returns how many pages a grid of the given dimensions is split into when printed in the given area.
+  ///
+  /// This is 1 for every policy other than [`Paginate`](OverflowPolicy::Paginate), and for grids that fit in the area.
+  pub(crate) fn page_count(
+    &self,
+    (grid_width, grid_height): (usize, usize),
+    (area_width, area_height): (usize, usize),
+  ) -> usize {
+    let Self::Paginate { page_indicator } = self else {
+      return 1;
+    };
+
+    if grid_width <= area_width && grid_height <= area_height {
+      return 1;
+    }
+
+    let (page_width, page_height) = page_dimensions((area_width, area_height), *page_indicator);
+
+    (grid_width.div_ceil(page_width.max(1)) * grid_height.div_ceil(page_height.max(1))).max(1)
+  }
+}
+
+/// Returns the (width, height) of the part of each page the grid is printed in, leaving a row for the page indicator if there is one.
+fn page_dimensions(
+  (area_width, area_height): (usize, usize),
+  page_indicator: bool,
+) -> (usize, usize) {
+  if page_indicator {
+    (area_width, area_height.saturating_sub(1))
+  } else {
+    (area_width, area_height)
+  }
+}
+
+/// Returns the largest (width, height) a grid of the given (width, height) can be scaled to
+/// while keeping its aspect ratio and fitting within the given area.
+///
+/// Grids smaller than the area are only scaled up by whole multiples, so every cell is repeated the same amount of times.
+/// Grids larger than the area are scaled down to fit, keeping at least 1 cell on each axis.
+///
+/// # Example
+/// ```
+/// use screen_printer::scaling::fit_dimensions;
+///
+/// assert_eq!(fit_dimensions((4, 2), (13, 7)), (12, 6));
+/// assert_eq!(fit_dimensions((40, 20), (10, 10)), (10, 5));
+/// ```
+pub fn fit_dimensions(
+  (grid_width, grid_height): (usize, usize),
+  (area_width, area_height): (usize, usize),
+) -> (usize, usize) {
+  if grid_width == 0 || grid_height == 0 {
+    return (grid_width, grid_height);
+  }
+
+  let scale = (area_width / grid_width).min(area_height / grid_height);
+
+  if scale >= 1 {
+    return (grid_width * scale, grid_height * scale);
+  }
+
+  let scale = (area_width as f64 / grid_width as f64).min(area_height as f64 / grid_height as f64);
+
+  (
+    ((grid_width as f64 * scale).floor() as usize).clamp(1, grid_width),
+    ((grid_height as f64 * scale).floor() as usize).clamp(1, grid_height),
+  )
+}
+
+/// Returns the grid scaled to the given (width, height), picking the nearest cell of the original grid for every cell of the scaled one.
+///
+/// Styles are kept with the cells they apply to.
+/// A wide cell that lands where only one column is left in its place is replaced with a space of the same style,
+/// as is the second column of a wide cell that was sampled without its first.
+pub(crate) fn scale_grid(
+  grid: &str,
+  (scaled_width, scaled_height): (usize, usize),
+  emoji_mode: EmojiMode,
+) -> String {
+  let rows = split_grid_into_cells(grid, emoji_mode);
+  // The cell starting at each column of each row, or None for the columns covered by the second half of a wide cell.
+  let columns: Vec<Vec<Option<&Cell>>> = rows
+    .iter()
+    .map(|cells| {
+      cells
+        .iter()
+        .flat_map(|cell| iter::once(Some(cell)).chain(iter::repeat_n(None, cell.width - 1)))
+        .collect()
+    })
+    .collect();
+  let height = columns.len();
+  let width = columns.first().map(Vec::len).unwrap_or(0);
+
+  if width == 0 || height == 0 {
+    return grid.to_string();
+  }
+
+  (0..scaled_height)
+    .map(|scaled_y| {
+      let row = &columns[scaled_y * height / scaled_height];
+      let mut scaled_cells: Vec<Cell> = Vec::with_capacity(scaled_width);
+      let mut scaled_x = 0;
+
+      while scaled_x < scaled_width {
+        let x = scaled_x * width / scaled_width;
+
+        match row[x] {
+          Some(cell) if scaled_x + cell.width <= scaled_width => scaled_cells.push(cell.clone()),
+          _ => {
+            // Continuation columns always follow the start of their wide cell.
+            let style = row[..=x]
+              .iter()
+              .rev()
+              .find_map(|cell| cell.map(|cell| cell.style.clone()))
+              .unwrap_or_default();
+
+            scaled_cells.push(Cell {
+              text: " ",
+              width: 1,
+              style,
+            });
+          }
+        }
+
+        scaled_x += scaled_cells.last().map(|cell| cell.width).unwrap_or(1);
+      }
+
+      let mut scaled_row = StyledTextBuilder::default();
+      scaled_cells.iter().for_each(|cell| scaled_row.push(cell));
+
+      scaled_row.finish()
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+/// Returns the part of the grid starting at the given (x, y), cut down to the given (width, height).
+///
+/// Styles are kept with the cells they apply to.
+/// A wide cell cut in half by either edge is replaced with a space of the same style,
+/// and the result is padded with whitespace if the grid ends before reaching the given (width, height).
+pub(crate) fn crop_grid(
+  grid: &str,
+  (crop_x, crop_y): (usize, usize),
+  (cropped_width, cropped_height): (usize, usize),
+  emoji_mode: EmojiMode,
+) -> String {
+  let mut rows = split_grid_into_cells(grid, emoji_mode);
+  rows.resize(crop_y + cropped_height, Vec::new());
+
+  rows
+    .into_iter()
+    .skip(crop_y)
+    .map(|cells| {
+      let mut cropped_cells = Vec::with_capacity(cropped_width);
+      let crop_end_x = crop_x + cropped_width;
+      let mut x = 0;
+
+      for cell in cells {
+        let cell_end_x = x + cell.width;
+
+        if cell_end_x <= crop_x {
+          x = cell_end_x;
+
+          continue;
+        }
+
+        if x >= crop_end_x {
+          break;
+        }
+
+        if x < crop_x || cell_end_x > crop_end_x {
+          // Only the columns of the cell within the crop are replaced.
+          let visible_width = cell_end_x.min(crop_end_x) - x.max(crop_x);
+
+          for _ in 0..visible_width {
+            cropped_cells.push(Cell {
+              text: " ",
+              width: 1,
+              style: cell.style.clone(),
+            });
+          }
+        } else {
+          cropped_cells.push(cell);
+        }
+
+        x = cell_end_x;
+      }
+
+      let mut cropped_row = StyledTextBuilder::default();
+      cropped_cells.iter().for_each(|cell| cropped_row.push(cell));
+      let cropped_row_width: usize = cropped_cells.iter().map(|cell| cell.width).sum();
+
+      let mut cropped_row = cropped_row.finish();
+      cropped_row.push_str(&" ".repeat(cropped_width - cropped_row_width));
+
+      cropped_row
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod fit_dimensions_tests {
+  use super::*;
+
+  #[test]
+  fn small_grids_are_scaled_up_by_whole_multiples() {
+    assert_eq!(fit_dimensions((3, 2), (10, 10)), (9, 6));
+  }
+
+  #[test]
+  fn large_grids_are_scaled_down_to_fit() {
+    assert_eq!(fit_dimensions((100, 10), (50, 50)), (50, 5));
+  }
+
+  #[test]
+  fn scaled_down_grids_keep_a_cell_on_each_axis() {
+    assert_eq!(fit_dimensions((100, 1), (10, 10)), (10, 1));
+  }
+}
+
+#[cfg(test)]
+mod scale_grid_tests {
+  use super::*;
+
+  #[test]
+  fn scaling_up_repeats_cells() {
+    assert_eq!(
+      scale_grid("ab\ncd", (4, 4), EmojiMode::Off),
+      "aabb\naabb\nccdd\nccdd"
+    );
+  }
+
+  #[test]
+  fn scaling_down_samples_cells() {
+    assert_eq!(
+      scale_grid("abcd\nefgh\nijkl\nmnop", (2, 2), EmojiMode::Off),
+      "ac\nik"
+    );
+  }
+
+  #[test]
+  fn styles_are_kept_with_their_cells() {
+    assert_eq!(
+      scale_grid("\x1B[31mab\x1B[0m", (4, 1), EmojiMode::Off),
+      "\x1B[31maabb\x1B[0m"
+    );
+  }
+
+  #[test]
+  fn wide_cells_that_do_not_fit_are_replaced_with_spaces() {
+    assert_eq!(scale_grid("a日", (2, 1), EmojiMode::Off), "a ");
+  }
+}
+
+#[cfg(test)]
+mod overflow_policy_tests {
+  use super::*;
+
+  const GRID: &str = "abcd\nefgh\nijkl";
+
+  #[test]
+  fn grids_that_fit_are_unchanged() {
+    let fitted_grid = OverflowPolicy::Downsample.fit_grid(GRID, (4, 3), (4, 3), 0, EmojiMode::Off);
+
+    assert_eq!(fitted_grid, None);
+  }
+
+  #[test]
+  fn error_policy_leaves_grids_unchanged() {
+    assert_eq!(
+      OverflowPolicy::Error.fit_grid(GRID, (4, 3), (2, 2), 0, EmojiMode::Off),
+      None
+    );
+  }
+
+  #[test]
+  fn crop_policy_keeps_the_top_left() {
+    let fitted_grid = OverflowPolicy::Crop.fit_grid(GRID, (4, 3), (2, 2), 0, EmojiMode::Off);
+
+    assert_eq!(fitted_grid, Some(("ab\nef".to_string(), (2, 2))));
+  }
+
+  #[test]
+  fn downsample_policy_samples_the_whole_grid() {
+    let fitted_grid = OverflowPolicy::Downsample.fit_grid(GRID, (4, 3), (2, 3), 0, EmojiMode::Off);
+
+    assert_eq!(fitted_grid, Some(("ac\neg\nik".to_string(), (2, 3))));
+  }
+
+  #[test]
+  fn cropped_wide_cells_are_replaced_with_spaces() {
+    assert_eq!(crop_grid("a日b", (0, 0), (2, 1), EmojiMode::Off), "a ");
+    assert_eq!(crop_grid("a日b", (2, 0), (2, 1), EmojiMode::Off), " b");
+  }
+
+  #[test]
+  fn crops_past_the_end_of_the_grid_are_padded() {
+    assert_eq!(
+      crop_grid("ab\ncd", (1, 1), (2, 2), EmojiMode::Off),
+      "d \n  "
+    );
+  }
+}
+
+#[cfg(test)]
+mod paginate_tests {
+  use super::*;
+
+  const GRID: &str = "abcd\nefgh\nijkl";
+  const PAGINATE: OverflowPolicy = OverflowPolicy::Paginate {
+    page_indicator: false,
+  };
+
+  #[test]
+  fn pages_are_read_left_to_right_then_top_to_bottom() {
+    let pages: Vec<String> = (0..4)
+      .map(|page| {
+        PAGINATE
+          .fit_grid(GRID, (4, 3), (2, 2), page, EmojiMode::Off)
+          .unwrap()
+          .0
+      })
+      .collect();
+
+    assert_eq!(PAGINATE.page_count((4, 3), (2, 2)), 4);
+    assert_eq!(pages, ["ab\nef", "cd\ngh", "ij\n  ", "kl\n  "]);
+  }
+
+  #[test]
+  fn page_indicator_takes_the_bottom_row() {
+    let policy = OverflowPolicy::Paginate {
+      page_indicator: true,
+    };
+
+    let page = policy.fit_grid(GRID, (4, 3), (4, 2), 1, EmojiMode::Off);
+
+    assert_eq!(policy.page_count((4, 3), (4, 2)), 3);
+    assert_eq!(page, Some(("efgh\nPage".to_string(), (4, 2))));
+  }
+
+  #[test]
+  fn grids_that_fit_have_one_page() {
+    assert_eq!(PAGINATE.page_count((4, 3), (4, 3)), 1);
+  }
+}
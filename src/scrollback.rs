@@ -0,0 +1,275 @@
+mod tests;
+
+use crate::ansi::ESCAPE;
+use crate::bidi::{isolate_text, BidiMode};
+use crate::cells::{row_width, EmojiMode};
+use crate::dynamic_printer::DynamicPrinterMethods;
+use crate::errors::PrintingError;
+use crate::escape_emitter::{translate_styles, AnsiEmitter, EscapeEmitter};
+use crate::printer::Printer;
+
+/// The sequence that saves the position of the cursor.
+const SAVE_CURSOR: &str = "\x1B7";
+/// The sequence that moves the cursor back to the saved position.
+const RESTORE_CURSOR: &str = "\x1B8";
+
+/// How the printer moves around the terminal when updating grids.
+///
+/// Set with [`replace_update_strategy`](crate::printer::Printer::replace_update_strategy).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum UpdateStrategy {
+  /// Grids are placed with the [`PrintingPosition`](crate::printing_position::PrintingPosition),
+  /// moving the cursor to positions on the screen as is.
+  #[default]
+  Absolute,
+  /// Grids are printed starting from the cursor, the same as any other text, and are only ever updated within their own rectangle.
+  ///
+  /// The screen is never cleared and the cursor is never moved to a position on the screen as is,
+  /// so everything printed before the grid stays in the terminal's history.
+  /// The position of the top left of the grid is saved, and every update moves the cursor relative to it.
+  ///
+  /// The [`PrintingPosition`](crate::printing_position::PrintingPosition), [`BoundingBox`](crate::printing_position::BoundingBox),
+  /// and [`Letterbox`](crate::letterbox::Letterbox) are ignored, as is any [`EscapeEmitter`](crate::escape_emitter::EscapeEmitter),
+  /// since ANSI escape sequences are needed to save the cursor's position.
+  /// Anything else that saves the cursor's position while the grid is printed breaks updates to it.
+  ScrollbackPreserving,
+}
+
+/// The emitter used when [`preserving scrollback`](UpdateStrategy::ScrollbackPreserving),
+/// which moves the cursor relative to the top left of the grid.
+///
+/// Positions are given as if the top left of the grid was at (1, 1).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct AnchoredEmitter;
+
+impl EscapeEmitter for AnchoredEmitter {
+  fn move_cursor(&self, x: usize, y: usize) -> String {
+    let mut cursor_movement = RESTORE_CURSOR.to_string();
+
+    if y > 1 {
+      cursor_movement.push_str(&format!("\x1B[{}B", y - 1));
+    }
+
+    if x > 1 {
+      cursor_movement.push_str(&format!("\x1B[{}C", x - 1));
+    }
+
+    cursor_movement
+  }
+
+  fn erase_screen(&self) -> String {
+    AnsiEmitter.erase_screen()
+  }
+
+  fn erase_line(&self) -> String {
+    AnsiEmitter.erase_line()
+  }
+
+  fn reset_style(&self) -> String {
+    AnsiEmitter.reset_style()
+  }
+
+  fn hide_cursor(&self) -> String {
+    AnsiEmitter.hide_cursor()
+  }
+
+  fn show_cursor(&self) -> String {
+    AnsiEmitter.show_cursor()
+  }
+
+  fn begin_synchronized_update(&self) -> String {
+    AnsiEmitter.begin_synchronized_update()
+  }
+
+  fn end_synchronized_update(&self) -> String {
+    AnsiEmitter.end_synchronized_update()
+  }
+}
+
+/// Returns the grid printed from the start of the cursor's row, or the saved top left of the previous grid if there is one,
+/// followed by saving the position of the grid's top left.
+///
+/// Rows are separated by newlines, which scroll the terminal to make room for the grid rather than printing over anything before it.
+pub(crate) fn anchored_grid(grid: &str, has_anchor: bool, bidi_mode: BidiMode) -> String {
+  let mut printable_grid = if has_anchor {
+    RESTORE_CURSOR.to_string()
+  } else {
+    String::new()
+  };
+  let height = grid.split('\n').count();
+
+  printable_grid.push('\r');
+
+  let rows: Vec<String> = grid
+    .split('\n')
+    .map(|row| translate_styles(&isolate_text(row, bidi_mode), &AnsiEmitter))
+    .collect();
+  printable_grid.push_str(&rows.join("\r\n"));
+
+  // Stops any styling left on by the grid from applying to anything printed after it.
+  if grid.contains(ESCAPE) {
+    printable_grid.push_str(&AnsiEmitter.reset_style());
+  }
+
+  if height > 1 {
+    printable_grid.push_str(&format!("\x1B[{}A", height - 1));
+  }
+
+  printable_grid.push('\r');
+  printable_grid.push_str(SAVE_CURSOR);
+
+  printable_grid
+}
+
+impl Printer {
+  /// Prints the grid below everything printed before it, letting the terminal scroll the same as any other text.
+  ///
+  /// The grid is printed from the start of the cursor's row, and the cursor is left at the start of the row below it.
+  /// Everything appended stays in the terminal's history, except for the most recently appended grid,
+  /// which can be printed over with [`reprint_last_append`](Printer::reprint_last_append)
+  /// or removed with [`clear_last_append`](Printer::clear_last_append).
+  ///
+  /// Grids don't need to be rectangular, and rows wider than the terminal are counted as the rows they wrap into.
+  /// Nothing else is expected to be printed between appending a grid and reprinting or clearing it,
+  /// and grids taller than the terminal can't be reprinted in their entirety once they've scrolled out of view.
+  ///
+  /// Appending is separate from [`dynamic printing`](crate::dynamic_printer::DynamicPrinter::dynamic_print),
+  /// and the two aren't meant to be mixed on the same printer.
+  ///
+  /// # Errors
+  ///
+  /// - The grid contained invalid characters while [`strictly validating characters`](Printer::set_strict_character_validation).
+  /// - Writing to the terminal failed.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let mut printer = Printer::on_virtual_terminal((20, 3));
+  ///
+  /// for crate_number in 1..=3 {
+  ///   printer.clear_last_append().unwrap();
+  ///   printer.append_print(format!("Compiled crate {crate_number}")).unwrap();
+  ///   printer.append_print(format!("Building {crate_number}/3")).unwrap();
+  /// }
+  ///
+  /// printer.reprint_last_append("Finished".to_string()).unwrap();
+  /// ```
+  pub fn append_print(&mut self, grid: String) -> Result<(), PrintingError> {
+    self.print_appended_block(grid, false)
+  }
+
+  /// Prints the grid over the most recently [`appended`](Printer::append_print) grid, which it then replaces as the most recent.
+  ///
+  /// The grid is appended as normal when nothing was appended yet.
+  ///
+  /// # Errors
+  ///
+  /// - The grid contained invalid characters while [`strictly validating characters`](Printer::set_strict_character_validation).
+  /// - Writing to the terminal failed.
+  pub fn reprint_last_append(&mut self, grid: String) -> Result<(), PrintingError> {
+    self.print_appended_block(grid, true)
+  }
+
+  /// Erases the most recently [`appended`](Printer::append_print) grid, moving the cursor to where it started.
+  ///
+  /// Whatever's appended afterwards is printed where the grid was.
+  /// Does nothing when nothing was appended since the last clear.
+  ///
+  /// # Errors
+  ///
+  /// - Writing to the terminal failed.
+  pub fn clear_last_append(&mut self) -> Result<(), PrintingError> {
+    let Some(appended_rows) = self.appended_rows.take() else {
+      return Ok(());
+    };
+
+    self.write_output(&erased_rows_above(appended_rows))?;
+    self.flush_output()
+  }
+
+  /// Returns the amount of terminal rows the most recently [`appended`](Printer::append_print) grid takes up, if any.
+  pub fn get_last_append_height(&self) -> Option<usize> {
+    self.appended_rows
+  }
+
+  fn print_appended_block(
+    &mut self,
+    grid: String,
+    replace_last: bool,
+  ) -> Result<(), PrintingError> {
+    let grid = self.prepare_grid(grid)?;
+    let terminal_width = self
+      .get_current_terminal_dimensions()
+      .ok()
+      .map(|(terminal_width, _)| terminal_width);
+    let mut printable_block = String::new();
+
+    if let (true, Some(appended_rows)) = (replace_last, self.appended_rows) {
+      printable_block.push_str(&erased_rows_above(appended_rows));
+    }
+
+    printable_block.push_str(&appended_block(&grid, self.get_bidi_mode()));
+
+    self.write_output(&printable_block)?;
+    self.flush_output()?;
+
+    self.appended_rows = Some(wrapped_row_count(
+      &grid,
+      terminal_width,
+      self.get_emoji_mode(),
+    ));
+
+    Ok(())
+  }
+}
+
+/// Returns the grid printed from the start of the cursor's row, followed by moving the cursor to the start of the row below it.
+///
+/// Rows are separated by newlines, which scroll the terminal when printed on its last row.
+pub(crate) fn appended_block(grid: &str, bidi_mode: BidiMode) -> String {
+  let rows: Vec<String> = grid
+    .split('\n')
+    .map(|row| translate_styles(&isolate_text(row, bidi_mode), &AnsiEmitter))
+    .collect();
+  let mut printable_block = format!("\r{}", rows.join("\r\n"));
+
+  // Stops any styling left on by the grid from applying to anything printed after it.
+  if grid.contains(ESCAPE) {
+    printable_block.push_str(&AnsiEmitter.reset_style());
+  }
+
+  printable_block.push_str("\r\n");
+
+  printable_block
+}
+
+/// Returns the sequence that moves the cursor up by the given amount of rows to the start of the row, then erases everything below it.
+pub(crate) fn erased_rows_above(rows: usize) -> String {
+  let mut erased_rows = String::new();
+
+  if rows > 0 {
+    erased_rows.push_str(&format!("\x1B[{rows}A"));
+  }
+
+  erased_rows.push_str("\r\x1B[J");
+
+  erased_rows
+}
+
+/// Returns the amount of terminal rows the grid takes up, counting rows wider than the terminal as the rows they wrap into.
+pub(crate) fn wrapped_row_count(
+  grid: &str,
+  terminal_width: Option<usize>,
+  emoji_mode: EmojiMode,
+) -> usize {
+  grid
+    .split('\n')
+    .map(|row| match terminal_width {
+      Some(terminal_width) if terminal_width > 0 => {
+        row_width(row, emoji_mode).div_ceil(terminal_width).max(1)
+      }
+      _ => 1,
+    })
+    .sum()
+}
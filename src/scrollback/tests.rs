@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod anchored_emitter_tests {
+  use super::*;
+
+  #[test]
+  fn moves_are_relative_to_the_saved_position() {
+    assert_eq!(AnchoredEmitter.move_cursor(1, 1), "\x1B8");
+    assert_eq!(AnchoredEmitter.move_cursor(4, 3), "\x1B8\x1B[2B\x1B[3C");
+  }
+}
+
+#[cfg(test)]
+mod anchored_grid_tests {
+  use super::*;
+
+  #[test]
+  fn grid_is_printed_with_newlines_then_anchored() {
+    assert_eq!(
+      anchored_grid("ab\ncd\nef", false, BidiMode::Off),
+      "\rab\r\ncd\r\nef\x1B[2A\r\x1B7"
+    );
+  }
+
+  #[test]
+  fn existing_anchor_is_printed_from() {
+    assert_eq!(anchored_grid("ab", true, BidiMode::Off), "\x1B8\rab\r\x1B7");
+  }
+
+  #[test]
+  fn styles_are_reset_after_the_grid() {
+    assert_eq!(
+      anchored_grid("\x1B[31mab", false, BidiMode::Off),
+      "\r\x1B[31mab\x1B[0m\r\x1B7"
+    );
+  }
+}
+
+#[cfg(test)]
+mod update_strategy_tests {
+  use crate::dynamic_printer::DynamicPrinterMethods;
+  use crate::printer::*;
+
+  #[test]
+  fn grids_are_printed_relative_to_their_top_left() {
+    let mut printer = Printer::new_with_printing_position(PrintingPosition::new(
+      XPrintingPosition::Middle,
+      YPrintingPosition::Bottom,
+    ));
+    printer.replace_update_strategy(UpdateStrategy::ScrollbackPreserving);
+
+    assert_eq!(printer.get_new_origin((5, 3), (80, 24)), (1, 1));
+    assert_eq!(
+      printer.get_escape_emitter().move_cursor(2, 2),
+      "\x1B8\x1B[1B\x1B[1C"
+    );
+  }
+
+  #[test]
+  fn replacing_the_strategy_forgets_the_previous_grid() {
+    let mut printer = Printer::new();
+    printer.previous_grid = "abc".to_string();
+    printer.update_origin((3, 4));
+
+    printer.replace_update_strategy(UpdateStrategy::ScrollbackPreserving);
+
+    assert!(printer.previous_grid.is_empty());
+    assert_eq!(
+      printer.get_origin_position(),
+      Err(PrintingError::OriginNotDefined)
+    );
+  }
+}
+
+#[cfg(test)]
+mod append_print_tests {
+  use super::*;
+
+  #[test]
+  fn blocks_end_on_the_row_below_them() {
+    assert_eq!(appended_block("ab\ncd", BidiMode::Off), "\rab\r\ncd\r\n");
+  }
+
+  #[test]
+  fn erasing_moves_up_to_the_start_of_the_block() {
+    assert_eq!(erased_rows_above(2), "\x1B[2A\r\x1B[J");
+    assert_eq!(erased_rows_above(0), "\r\x1B[J");
+  }
+
+  #[test]
+  fn wide_rows_count_the_rows_they_wrap_into() {
+    assert_eq!(wrapped_row_count("abcde\n\nab", Some(2), EmojiMode::Off), 5);
+    assert_eq!(wrapped_row_count("abcde\n\nab", None, EmojiMode::Off), 3);
+  }
+
+  #[test]
+  fn last_append_is_reprinted_while_history_stays() {
+    let mut printer = Printer::on_virtual_terminal((10, 5));
+
+    printer.append_print("one".to_string()).unwrap();
+    printer.append_print("footer\n1".to_string()).unwrap();
+    printer.clear_last_append().unwrap();
+    printer.append_print("two".to_string()).unwrap();
+    printer.append_print("footer\n2".to_string()).unwrap();
+    printer.reprint_last_append("done".to_string()).unwrap();
+
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "one\ntwo\ndone");
+    assert_eq!(printer.get_last_append_height(), Some(1));
+  }
+}
@@ -0,0 +1,201 @@
+//! Assertions on what a [`Printer`](crate::printer::Printer) on a [`virtual terminal`](crate::printer::Printer::on_virtual_terminal) displays,
+//! for testing programs built with this crate.
+//!
+//! Screens are compared as plain text, with the whitespace at the end of every row and any empty rows at the bottom removed.
+//! This way the expected screen only needs to include what was printed.
+//!
+//! # Example
+//! ```
+//! use screen_printer::assert_screen_eq;
+//! use screen_printer::printer::*;
+//!
+//! let mut printer = Printer::on_virtual_terminal((10, 4))
+//!   .with_printing_position(PrintingPosition::new(XPrintingPosition::Left, YPrintingPosition::Top));
+//!
+//! printer.dynamic_print("abc\n123".to_string()).unwrap();
+//!
+//! assert_screen_eq!(printer, "abc\n123");
+//! ```
+
+mod tests;
+
+use crate::printer::Printer;
+use std::{env, fs, path::Path};
+
+/// The environment variable that makes [`assert_screen_snapshot`](crate::assert_screen_snapshot) overwrite snapshot files
+/// with the current screen, rather than comparing against them, when set to anything other than `0`.
+pub const UPDATE_SNAPSHOTS_VARIABLE: &str = "SCREEN_PRINTER_UPDATE_SNAPSHOTS";
+
+/// Asserts that what the printer's [`virtual terminal`](crate::printer::Printer::on_virtual_terminal) displays
+/// is the expected screen.
+///
+/// Both screens have the whitespace at the end of every row, and any empty rows at the bottom, removed before being compared.
+/// Styles aren't compared.
+///
+/// # Panics
+///
+/// - The screens are different, showing both screens.
+/// - The printer isn't printing to a virtual terminal.
+///
+/// # Example
+/// ```
+/// use screen_printer::assert_screen_eq;
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((4, 3));
+///
+/// // Grids are printed to the bottom left by default.
+/// printer.dynamic_print("ab".to_string()).unwrap();
+///
+/// assert_screen_eq!(printer, "\n\nab");
+/// assert_screen_eq!(printer, "\n\nab", "printed {} grid", "one");
+/// ```
+#[macro_export]
+macro_rules! assert_screen_eq {
+  ($printer:expr, $expected:expr $(,)?) => {
+    $crate::snapshot::assert_screen_eq(&$printer, &$expected, None)
+  };
+  ($printer:expr, $expected:expr, $($message:tt)+) => {
+    $crate::snapshot::assert_screen_eq(&$printer, &$expected, Some(format!($($message)+)))
+  };
+}
+
+/// Asserts that what the printer's [`virtual terminal`](crate::printer::Printer::on_virtual_terminal) displays
+/// is the screen stored in the snapshot file with the given name.
+///
+/// Snapshots are stored in `tests/snapshots/<name>.snap` of the crate the assertion is in.
+/// Snapshots that don't exist yet are created from the current screen, and every snapshot is overwritten with the
+/// current screen while the [`UPDATE_SNAPSHOTS_VARIABLE`](crate::snapshot::UPDATE_SNAPSHOTS_VARIABLE) is set.
+///
+/// Screens are compared the same way as with [`assert_screen_eq`](crate::assert_screen_eq).
+///
+/// # Panics
+///
+/// - The screens are different, showing both screens.
+/// - The printer isn't printing to a virtual terminal.
+/// - The snapshot file couldn't be read or written.
+///
+/// # Example
+/// ```rust,no_run
+/// use screen_printer::assert_screen_snapshot;
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((80, 24));
+///
+/// printer.dynamic_print("abc\n123".to_string()).unwrap();
+///
+/// assert_screen_snapshot!(printer, "abc_grid");
+/// ```
+#[macro_export]
+macro_rules! assert_screen_snapshot {
+  ($printer:expr, $name:expr $(,)?) => {
+    $crate::snapshot::assert_screen_snapshot(
+      &$printer,
+      &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.snap", $name)),
+    )
+  };
+}
+
+/// Returns what the printer's virtual terminal displays, with the whitespace at the end of every row and any empty rows at the bottom removed.
+///
+/// # Panics
+///
+/// - The printer isn't printing to a virtual terminal.
+#[track_caller]
+pub fn trimmed_screen(printer: &Printer) -> String {
+  let Some(virtual_terminal) = printer.virtual_screen() else {
+    panic!("The printer isn't printing to a virtual terminal, create it with `Printer::on_virtual_terminal`.");
+  };
+
+  trim_screen(&virtual_terminal.plain_screen())
+}
+
+/// The function behind [`assert_screen_eq`](crate::assert_screen_eq).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_screen_eq(printer: &Printer, expected_screen: &str, message: Option<String>) {
+  let screen = trimmed_screen(printer);
+  let expected_screen = trim_screen(expected_screen);
+
+  if screen != expected_screen {
+    let message = message.map_or(String::new(), |message| format!(": {message}"));
+
+    panic!(
+      "The screens are different{message}\n{}",
+      screen_comparison(&expected_screen, &screen)
+    );
+  }
+}
+
+/// The function behind [`assert_screen_snapshot`](crate::assert_screen_snapshot).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_screen_snapshot(printer: &Printer, snapshot_path: &Path) {
+  let screen = trimmed_screen(printer);
+  let update_snapshots = env::var(UPDATE_SNAPSHOTS_VARIABLE).is_ok_and(|value| value != "0");
+
+  if update_snapshots || !snapshot_path.exists() {
+    if let Some(snapshot_directory) = snapshot_path.parent() {
+      fs::create_dir_all(snapshot_directory).unwrap_or_else(|error| {
+        panic!("Failed to create {}: {error}", snapshot_directory.display())
+      });
+    }
+
+    fs::write(snapshot_path, &screen)
+      .unwrap_or_else(|error| panic!("Failed to write {}: {error}", snapshot_path.display()));
+
+    return;
+  }
+
+  let expected_screen = fs::read_to_string(snapshot_path)
+    .unwrap_or_else(|error| panic!("Failed to read {}: {error}", snapshot_path.display()));
+  let expected_screen = trim_screen(&expected_screen);
+
+  if screen != expected_screen {
+    panic!(
+      "The screen is different from the snapshot {}, set {UPDATE_SNAPSHOTS_VARIABLE}=1 to update it.\n{}",
+      snapshot_path.display(),
+      screen_comparison(&expected_screen, &screen)
+    );
+  }
+}
+
+/// Removes the whitespace at the end of every row, and any empty rows at the bottom, of the screen.
+pub(crate) fn trim_screen(screen: &str) -> String {
+  let rows: Vec<&str> = screen.lines().map(str::trim_end).collect();
+  let row_count = rows.len() - rows.iter().rev().take_while(|row| row.is_empty()).count();
+
+  rows[..row_count].join("\n")
+}
+
+/// Returns both screens one after the other, marking the rows that are different.
+fn screen_comparison(expected_screen: &str, screen: &str) -> String {
+  let expected_rows: Vec<&str> = expected_screen.lines().collect();
+  let rows: Vec<&str> = screen.lines().collect();
+  let row_count = expected_rows.len().max(rows.len());
+
+  let mark_rows = |rows: &[&str], other_rows: &[&str]| -> String {
+    (0..row_count)
+      .map(|index| {
+        let row = rows.get(index).copied().unwrap_or_default();
+        let marker = if Some(&row) == other_rows.get(index) {
+          ' '
+        } else {
+          '>'
+        };
+
+        format!("{marker} |{row}|")
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  };
+
+  format!(
+    "Expected:\n{}\nDisplayed:\n{}",
+    mark_rows(&expected_rows, &rows),
+    mark_rows(&rows, &expected_rows)
+  )
+}
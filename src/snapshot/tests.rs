@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use super::*;
+use crate::dynamic_printer::DynamicPrinter;
+use crate::printing_position::*;
+
+/// Creates a printer on a virtual terminal that prints to the top left.
+fn get_top_left_printer() -> Printer {
+  Printer::on_virtual_terminal((6, 4)).with_printing_position(PrintingPosition::new(
+    XPrintingPosition::Left,
+    YPrintingPosition::Top,
+  ))
+}
+
+#[cfg(test)]
+mod assert_screen_eq_tests {
+  use super::*;
+
+  #[test]
+  fn matching_screens_pass() {
+    let mut printer = get_top_left_printer();
+
+    printer.dynamic_print("abc\n123".to_string()).unwrap();
+    printer.dynamic_print("abx\n123".to_string()).unwrap();
+
+    crate::assert_screen_eq!(printer, "abx\n123");
+  }
+
+  #[test]
+  #[should_panic(expected = "The screens are different: after the second grid")]
+  fn different_screens_panic() {
+    let mut printer = get_top_left_printer();
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    crate::assert_screen_eq!(printer, "abx", "after the {} grid", "second");
+  }
+
+  #[test]
+  #[should_panic(expected = "isn't printing to a virtual terminal")]
+  fn printers_without_a_virtual_terminal_panic() {
+    trimmed_screen(&Printer::new());
+  }
+
+  #[test]
+  fn screens_are_trimmed() {
+    assert_eq!(trim_screen("ab  \n    \n c \n   \n"), "ab\n\n c");
+  }
+}
+
+#[cfg(test)]
+mod assert_screen_snapshot_tests {
+  use super::*;
+
+  /// Returns a path for a snapshot that doesn't exist yet.
+  fn get_snapshot_path(name: &str) -> std::path::PathBuf {
+    let snapshot_path = env::temp_dir()
+      .join(format!("screen_printer_snapshots_{}", std::process::id()))
+      .join(format!("{name}.snap"));
+    let _ = fs::remove_file(&snapshot_path);
+
+    snapshot_path
+  }
+
+  #[test]
+  fn missing_snapshots_are_created() {
+    let snapshot_path = get_snapshot_path("created");
+    let mut printer = get_top_left_printer();
+
+    printer.dynamic_print("abc\n123".to_string()).unwrap();
+    assert_screen_snapshot(&printer, &snapshot_path);
+
+    assert_eq!(fs::read_to_string(&snapshot_path).unwrap(), "abc\n123");
+  }
+
+  #[test]
+  #[should_panic(expected = "The screen is different from the snapshot")]
+  fn different_snapshots_panic() {
+    let snapshot_path = get_snapshot_path("different");
+    fs::create_dir_all(snapshot_path.parent().unwrap()).unwrap();
+    fs::write(&snapshot_path, "xyz").unwrap();
+    let mut printer = get_top_left_printer();
+
+    printer.dynamic_print("abc".to_string()).unwrap();
+
+    assert_screen_snapshot(&printer, &snapshot_path);
+  }
+}
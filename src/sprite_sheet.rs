@@ -0,0 +1,239 @@
+//! Loading named grids from sprite sheet files, so art can be kept out of the code.
+//!
+//! A sprite sheet is plain text, where each sprite starts with a marker line made of `---` followed by the sprite's name.
+//! Every line after the marker, up to the next marker, is a row of the sprite. Empty lines at the end of a sprite are ignored.
+//!
+//! The sheet can start with settings written as TOML between two `+++` lines:
+//!
+//! - `marker`: The text marker lines start with instead of `---`.
+//! - `pad`: Whether rows shorter than the widest row of their sprite are padded with whitespace, rather than being an error.
+//!
+//! ```text
+//! +++
+//! marker = "=="
+//! pad = true
+//! +++
+//! == idle
+//!  o
+//! /|\
+//! / \
+//! == wave
+//!  o/
+//! /|
+//! / \
+//! ```
+
+mod tests;
+
+use crate::cells::measure_row_width;
+use crate::errors::PrintingError;
+use crate::printer::Printer;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// The text that starts the marker line of every sprite, unless replaced by the sheet's settings.
+const DEFAULT_MARKER: &str = "---";
+/// The line before and after the settings of a sheet.
+const SETTINGS_DELIMITER: &str = "+++";
+
+/// The settings at the start of a sprite sheet.
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct SheetSettings {
+  marker: String,
+  pad: bool,
+}
+
+impl Default for SheetSettings {
+  fn default() -> Self {
+    Self {
+      marker: DEFAULT_MARKER.to_string(),
+      pad: false,
+    }
+  }
+}
+
+impl Printer {
+  /// Reads the sprite sheet file at the given path, returning every sprite in it by name.
+  ///
+  /// Refer to the [`sprite_sheet`](crate::sprite_sheet) module for the format of the file.
+  ///
+  /// # Errors
+  ///
+  /// - The file couldn't be read.
+  /// - The file isn't a valid sprite sheet.
+  pub fn load_sprite_sheet<P: AsRef<Path>>(
+    path: P,
+  ) -> Result<HashMap<String, String>, PrintingError> {
+    Self::parse_sprite_sheet(&fs::read_to_string(path)?)
+  }
+
+  /// Parses the text as a sprite sheet, returning every sprite in it by name.
+  ///
+  /// Refer to the [`sprite_sheet`](crate::sprite_sheet) module for the format of the text.
+  ///
+  /// # Errors
+  ///
+  /// - The settings are invalid.
+  /// - There's text before the first sprite.
+  /// - Multiple sprites have the same name, or a sprite has no name.
+  /// - A sprite isn't rectangular, while padding is off.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::printer::*;
+  ///
+  /// let sprites = Printer::parse_sprite_sheet("--- box\n+-+\n+-+\n--- dot\n.").unwrap();
+  ///
+  /// assert_eq!(sprites["box"], "+-+\n+-+");
+  /// assert_eq!(sprites["dot"], ".");
+  /// ```
+  pub fn parse_sprite_sheet(text: &str) -> Result<HashMap<String, String>, PrintingError> {
+    let mut lines = text.lines().enumerate().peekable();
+    let mut settings = SheetSettings::default();
+
+    if lines
+      .next_if(|(_, line)| line.trim_end() == SETTINGS_DELIMITER)
+      .is_some()
+    {
+      settings = parse_settings(&mut lines)?;
+    }
+
+    let mut sprites = HashMap::new();
+    let mut current_sprite: Option<(usize, String, Vec<&str>)> = None;
+
+    for (line_index, line) in lines {
+      if let Some(name) = line.strip_prefix(settings.marker.as_str()) {
+        let name = name.trim();
+
+        if name.is_empty() {
+          return Err(invalid_sheet(line_index, "A sprite has no name."));
+        }
+
+        if let Some(sprite) = current_sprite.take() {
+          insert_sprite(&mut sprites, sprite, settings.pad)?;
+        }
+
+        current_sprite = Some((line_index, name.to_string(), Vec::new()));
+
+        continue;
+      }
+
+      match &mut current_sprite {
+        Some((_, _, rows)) => rows.push(line),
+        None if line.trim().is_empty() => (),
+        None => {
+          return Err(invalid_sheet(
+            line_index,
+            "There's text before the first sprite.",
+          ))
+        }
+      }
+    }
+
+    if let Some(sprite) = current_sprite {
+      insert_sprite(&mut sprites, sprite, settings.pad)?;
+    }
+
+    Ok(sprites)
+  }
+}
+
+/// Parses the settings up to and including the closing delimiter, where the opening delimiter was already parsed.
+fn parse_settings<'a, I>(lines: &mut I) -> Result<SheetSettings, PrintingError>
+where
+  I: Iterator<Item = (usize, &'a str)>,
+{
+  let mut settings = SheetSettings::default();
+
+  for (line_index, line) in lines {
+    let line = line
+      .split_once('#')
+      .map_or(line, |(setting, _)| setting)
+      .trim();
+
+    if line == SETTINGS_DELIMITER {
+      return Ok(settings);
+    }
+
+    if line.is_empty() {
+      continue;
+    }
+
+    let Some((key, value)) = line.split_once('=') else {
+      return Err(invalid_sheet(line_index, "A setting is missing its value."));
+    };
+
+    match (key.trim(), value.trim()) {
+      ("marker", value) => {
+        settings.marker = value
+          .strip_prefix('"')
+          .and_then(|value| value.strip_suffix('"'))
+          .filter(|marker| !marker.is_empty())
+          .ok_or_else(|| invalid_sheet(line_index, "The marker isn't a string with text in it."))?
+          .to_string();
+      }
+      ("pad", "true") => settings.pad = true,
+      ("pad", "false") => settings.pad = false,
+      ("pad", _) => return Err(invalid_sheet(line_index, "Pad isn't true or false.")),
+      (key, _) => {
+        return Err(invalid_sheet(
+          line_index,
+          &format!("Unknown setting {key:?}."),
+        ))
+      }
+    }
+  }
+
+  Err(invalid_sheet(0, "The settings never end."))
+}
+
+/// Adds the sprite made out of the rows to the sprites, padding the rows if enabled.
+fn insert_sprite(
+  sprites: &mut HashMap<String, String>,
+  (line_index, name, mut rows): (usize, String, Vec<&str>),
+  pad: bool,
+) -> Result<(), PrintingError> {
+  while rows.last().is_some_and(|row| row.is_empty()) {
+    rows.pop();
+  }
+
+  let width = rows
+    .iter()
+    .map(|row| measure_row_width(row))
+    .max()
+    .unwrap_or(0);
+  let mut padded_rows = Vec::with_capacity(rows.len());
+
+  for row in rows {
+    let row_width = measure_row_width(row);
+
+    if row_width != width && !pad {
+      return Err(invalid_sheet(
+        line_index,
+        &format!("The sprite {name:?} isn't rectangular."),
+      ));
+    }
+
+    padded_rows.push(format!("{row}{}", " ".repeat(width - row_width)));
+  }
+
+  if sprites.contains_key(&name) {
+    return Err(invalid_sheet(
+      line_index,
+      &format!("There are multiple sprites named {name:?}."),
+    ));
+  }
+
+  sprites.insert(name, padded_rows.join("\n"));
+
+  Ok(())
+}
+
+/// Returns an error for the sprite sheet being invalid at the line of the given index.
+fn invalid_sheet(line_index: usize, reason: &str) -> PrintingError {
+  PrintingError::InvalidSpriteSheet {
+    line: line_index + 1,
+    reason: reason.to_string(),
+  }
+}
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod parse_sprite_sheet_tests {
+  use super::*;
+
+  /// Returns the line and reason of the error returned when parsing the sheet.
+  fn get_error(sheet: &str) -> (usize, String) {
+    match Printer::parse_sprite_sheet(sheet) {
+      Err(PrintingError::InvalidSpriteSheet { line, reason }) => (line, reason),
+      result => panic!("Expected an InvalidSpriteSheet error, got {result:?}"),
+    }
+  }
+
+  #[test]
+  fn settings_replace_marker_and_pad() {
+    let sheet = "+++\nmarker = \"==\" # comment\npad = true\n+++\n\n== a\nx\nxyz\n\n== b\n---";
+
+    let sprites = Printer::parse_sprite_sheet(sheet).unwrap();
+
+    assert_eq!(sprites["a"], "x  \nxyz");
+    assert_eq!(sprites["b"], "---");
+  }
+
+  #[test]
+  fn ragged_sprite_is_an_error_without_padding() {
+    assert_eq!(
+      get_error("--- a\nab\n--- b\nab\nc"),
+      (3, "The sprite \"b\" isn't rectangular.".to_string())
+    );
+  }
+
+  #[test]
+  fn invalid_sheets_are_errors() {
+    assert_eq!(get_error("text\n--- a\nb").0, 1);
+    assert_eq!(get_error("--- a\nb\n--- a\nc").0, 3);
+    assert_eq!(get_error("--- \nb").0, 1);
+    assert_eq!(get_error("+++\nsize = 2\n+++").0, 2);
+    assert_eq!(get_error("+++\npad = true").0, 1);
+  }
+
+  #[test]
+  fn missing_file_is_an_io_error() {
+    let result = Printer::load_sprite_sheet("/nonexistent/sprites.txt");
+
+    assert!(matches!(result, Err(PrintingError::Io(_))));
+  }
+}
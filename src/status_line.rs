@@ -0,0 +1,112 @@
+mod tests;
+
+use crate::ansi::sanitize_escapes;
+use crate::cells::fit_row_to_width;
+use crate::errors::PrintingError;
+use crate::printer::{EscapeSanitization, Printer};
+
+/// Which edge of the terminal the [`StatusLine`](StatusLine) is reserved on.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusLinePosition {
+  /// The first row of the terminal, above any other reserved rows.
+  Top,
+  /// The last row of the terminal, below any other reserved rows.
+  #[default]
+  Bottom,
+}
+
+/// A handle to the single row of the terminal reserved for a status line, obtained from [`Printer::status_line`](Printer::status_line).
+///
+/// The row is excluded from the printing area the same way as [`reserved rows`](Printer::replace_reserved_top_rows), so grids are never printed over it.
+/// Setting its text prints only the status line, without affecting the grid or waiting for the next grid to be printed.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+///
+/// let mut printer = Printer::on_virtual_terminal((12, 3));
+///
+/// printer.status_line().set_text("\x1B[1mReady\x1B[0m").unwrap();
+/// printer.dynamic_print("abc\n123".to_string()).unwrap();
+/// printer.status_line().set_text("Saving...").unwrap();
+///
+/// assert_eq!(
+///   printer.virtual_screen().unwrap().plain_screen(),
+///   "abc         \n123         \nSaving...   "
+/// );
+/// ```
+#[derive(Debug)]
+pub struct StatusLine<'a> {
+  printer: &'a mut Printer,
+}
+
+impl<'a> StatusLine<'a> {
+  pub(crate) fn new(printer: &'a mut Printer) -> Self {
+    Self { printer }
+  }
+
+  /// Replaces the text of the status line and prints it, cut down or padded to the width of the terminal.
+  ///
+  /// The text can be styled, and any styling is reset at the end of the line.
+  /// Escape sequences are sanitized the same way as grids, based on the printer's
+  /// [`EscapeSanitization`](crate::ansi::EscapeSanitization).
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn set_text(&mut self, text: &str) -> Result<(), PrintingError> {
+    self.printer.status_line_text = match self.printer.get_escape_sanitization() {
+      EscapeSanitization::Off => text.to_string(),
+      escape_sanitization => sanitize_escapes(text, escape_sanitization),
+    };
+
+    self.redraw()
+  }
+
+  /// Returns the text of the status line.
+  pub fn get_text(&self) -> &str {
+    &self.printer.status_line_text
+  }
+
+  /// Returns which edge of the terminal the status line is on.
+  pub fn get_position(&self) -> StatusLinePosition {
+    self.printer.status_line_position.unwrap_or_default()
+  }
+
+  /// Returns the row of the terminal the status line is on, starting from 1 at the top.
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  pub fn get_row(&self) -> Result<usize, PrintingError> {
+    match self.get_position() {
+      StatusLinePosition::Top => Ok(1),
+      StatusLinePosition::Bottom => {
+        let (_, terminal_height) = self.printer.get_current_terminal_dimensions()?;
+
+        Ok(terminal_height.max(1))
+      }
+    }
+  }
+
+  /// Prints the status line again, such as after the terminal was resized or cleared.
+  ///
+  /// # Errors
+  ///
+  /// - The terminal's dimensions couldn't be obtained.
+  /// - Writing to the terminal failed.
+  pub fn redraw(&mut self) -> Result<(), PrintingError> {
+    let (terminal_width, _) = self.printer.get_current_terminal_dimensions()?;
+    let row = self.get_row()?;
+    let printable_text = format!(
+      "{}{}",
+      self.printer.get_escape_emitter().move_cursor(1, row),
+      fit_row_to_width(&self.printer.status_line_text, terminal_width)
+    );
+
+    self.printer.write_output(&printable_text)?;
+    self.printer.flush_output()
+  }
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod status_line_tests {
+  use super::*;
+  use crate::dynamic_printer::DynamicPrinter;
+
+  #[test]
+  fn status_lines_are_excluded_from_the_printing_area() {
+    let mut printer = Printer::on_virtual_terminal((3, 2));
+    printer.status_line();
+
+    assert!(matches!(
+      printer.dynamic_print("abc\n123".to_string()),
+      Err(PrintingError::GridLargerThanTerminal(_))
+    ));
+  }
+
+  #[test]
+  fn setting_text_only_prints_the_status_line() {
+    let mut printer = Printer::on_virtual_terminal((4, 2));
+    printer.status_line().set_text("a").unwrap();
+    printer.dynamic_print("xyz".to_string()).unwrap();
+    let frame_count = printer.get_capture().unwrap().frame_count();
+
+    printer.status_line().set_text("\x1B[31mbcdef").unwrap();
+
+    assert_eq!(
+      printer.get_capture().unwrap().frame_count(),
+      frame_count + 1
+    );
+    assert_eq!(
+      printer.virtual_screen().unwrap().plain_screen(),
+      "xyz \nbcde"
+    );
+    assert_eq!(printer.status_line().get_text(), "\x1B[31mbcdef");
+  }
+
+  #[test]
+  fn status_lines_can_move_between_edges() {
+    let mut printer = Printer::on_virtual_terminal((3, 3));
+    printer.status_line();
+
+    assert_eq!(printer.status_line().get_row().unwrap(), 3);
+
+    let status_line = printer.status_line_at(StatusLinePosition::Top);
+
+    assert_eq!(status_line.get_position(), StatusLinePosition::Top);
+    assert_eq!(status_line.get_row().unwrap(), 1);
+    assert_eq!(printer.get_printing_area_top_row(), 2);
+  }
+
+  #[test]
+  fn removed_status_lines_are_cleared() {
+    let mut printer = Printer::on_virtual_terminal((3, 2));
+    printer.status_line().set_text("abc").unwrap();
+
+    printer.remove_status_line().unwrap();
+
+    assert!(!printer.has_status_line());
+    assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "   \n   ");
+    printer.dynamic_print("abc\n123".to_string()).unwrap();
+  }
+}
@@ -0,0 +1,50 @@
+use crate::ansi::{is_style_reset, STYLE_RESET};
+use crate::cells::Cell;
+use alloc::{format, rc::Rc, string::String};
+
+/// Builds printable text out of cells, adding the escape sequences needed to style each one.
+#[derive(Default)]
+pub(crate) struct StyledTextBuilder<'a> {
+  text: String,
+  style: &'a str,
+}
+
+impl<'a> StyledTextBuilder<'a> {
+  /// Adds the cell's text, changing the style beforehand if it differs from the previous cell's.
+  pub(crate) fn push(&mut self, cell: &'a Cell) {
+    if *cell.style != *self.style {
+      if !self.style.is_empty() {
+        self.text.push_str(STYLE_RESET);
+      }
+
+      self.text.push_str(&cell.style);
+      self.style = &cell.style;
+    }
+
+    self.text.push_str(cell.text);
+  }
+
+  /// Returns the built text, resetting the style at the end if any was applied.
+  pub(crate) fn finish(mut self) -> String {
+    if !self.style.is_empty() {
+      self.text.push_str(STYLE_RESET);
+    }
+
+    self.text
+  }
+}
+
+/// Applies the style sequence on top of the current style.
+///
+/// Any sequence that starts by resetting the style replaces the current style entirely.
+pub(crate) fn apply_style_sequence(style: &mut Rc<str>, sequence: &str) {
+  let parameters = &sequence[2..sequence.len() - 1];
+
+  if parameters.bytes().all(|byte| byte == b'0') {
+    *style = Rc::from("");
+  } else if is_style_reset(sequence) {
+    *style = Rc::from(sequence);
+  } else {
+    *style = Rc::from(format!("{style}{sequence}"));
+  }
+}
@@ -0,0 +1,96 @@
+mod tests;
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::fd::AsRawFd;
+use std::time::{Duration, Instant};
+
+/// How long to wait for the terminal to report the cursor position before giving up.
+const CURSOR_POSITION_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Returns the (x, y) position of the cursor on the given terminal, starting from 1.
+///
+/// The terminal is put into raw mode while waiting for its response, and restored afterwards even if the query failed.
+pub(crate) fn query_cursor_position(terminal: &mut File) -> io::Result<(usize, usize)> {
+  let file_descriptor = terminal.as_raw_fd();
+  // SAFETY: termios is plain data, and is fully written by tcgetattr before being read.
+  let mut original_attributes: libc::termios = unsafe { std::mem::zeroed() };
+
+  // SAFETY: The file descriptor is open for as long as the terminal is borrowed.
+  if unsafe { libc::tcgetattr(file_descriptor, &mut original_attributes) } == -1 {
+    return Err(io::Error::last_os_error());
+  }
+
+  let mut raw_attributes = original_attributes;
+  raw_attributes.c_lflag &= !(libc::ICANON | libc::ECHO);
+  // Reads return after at most 100ms, even if nothing was read.
+  raw_attributes.c_cc[libc::VMIN] = 0;
+  raw_attributes.c_cc[libc::VTIME] = 1;
+
+  // SAFETY: Same as above.
+  if unsafe { libc::tcsetattr(file_descriptor, libc::TCSANOW, &raw_attributes) } == -1 {
+    return Err(io::Error::last_os_error());
+  }
+
+  let cursor_position = request_cursor_position(terminal, CURSOR_POSITION_TIMEOUT);
+
+  // SAFETY: Same as above.
+  unsafe { libc::tcsetattr(file_descriptor, libc::TCSANOW, &original_attributes) };
+
+  cursor_position
+}
+
+/// Asks the terminal for the cursor position, and returns the (x, y) position it reported, starting from 1.
+///
+/// The terminal is expected to already be in raw mode, with reads that return after a short while even if nothing was read.
+fn request_cursor_position<T: Read + Write>(
+  terminal: &mut T,
+  timeout: Duration,
+) -> io::Result<(usize, usize)> {
+  let report = read_cursor_position_report(terminal, timeout)?;
+
+  parse_cursor_position_report(&report).ok_or_else(|| {
+    io::Error::new(
+      io::ErrorKind::InvalidData,
+      "The terminal's cursor position report was malformed.",
+    )
+  })
+}
+
+/// Asks the terminal for the cursor position, and returns everything it sent back up to the end of the report.
+fn read_cursor_position_report<T: Read + Write>(
+  terminal: &mut T,
+  timeout: Duration,
+) -> io::Result<String> {
+  terminal.write_all(b"\x1B[6n")?;
+  terminal.flush()?;
+
+  let start_time = Instant::now();
+  let mut report = Vec::new();
+  let mut byte = [0];
+
+  while report.last() != Some(&b'R') {
+    if start_time.elapsed() > timeout {
+      return Err(io::Error::new(
+        io::ErrorKind::TimedOut,
+        "The terminal didn't report the cursor position.",
+      ));
+    }
+
+    if terminal.read(&mut byte)? == 1 {
+      report.push(byte[0]);
+    }
+  }
+
+  Ok(String::from_utf8_lossy(&report).into_owned())
+}
+
+/// Returns the (x, y) position in a report of the form `ESC [ y ; x R`.
+///
+/// Anything before the report, such as input the user typed, is ignored.
+pub(crate) fn parse_cursor_position_report(report: &str) -> Option<(usize, usize)> {
+  let report = &report[report.rfind("\x1B[")? + 2..];
+  let (y, x) = report.strip_suffix('R')?.split_once(';')?;
+
+  Some((x.parse().ok()?, y.parse().ok()?))
+}
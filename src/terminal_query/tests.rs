@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod parse_cursor_position_report_tests {
+  use super::*;
+
+  #[test]
+  fn report_is_parsed_as_x_then_y() {
+    assert_eq!(parse_cursor_position_report("\x1B[12;40R"), Some((40, 12)));
+  }
+
+  #[test]
+  fn input_before_the_report_is_ignored() {
+    assert_eq!(parse_cursor_position_report("abc\x1B[3;1R"), Some((1, 3)));
+  }
+
+  #[test]
+  fn malformed_reports_are_rejected() {
+    assert_eq!(parse_cursor_position_report("\x1B[3R"), None);
+    assert_eq!(parse_cursor_position_report("3;1R"), None);
+  }
+}
+
+#[cfg(test)]
+mod request_cursor_position_tests {
+  use super::*;
+
+  /// A terminal that replies with the given bytes once anything was written to it, and reads nothing before then.
+  struct ScriptedTerminal {
+    reply: io::Cursor<Vec<u8>>,
+    written: Vec<u8>,
+  }
+
+  impl ScriptedTerminal {
+    fn new(reply: &str) -> Self {
+      Self {
+        reply: io::Cursor::new(reply.as_bytes().to_vec()),
+        written: Vec::new(),
+      }
+    }
+  }
+
+  impl Read for ScriptedTerminal {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+      match self.written.is_empty() {
+        true => Ok(0),
+        false => self.reply.read(buffer),
+      }
+    }
+  }
+
+  impl Write for ScriptedTerminal {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+      self.written.write(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  const TIMEOUT: Duration = Duration::from_millis(20);
+
+  #[test]
+  fn reply_is_parsed_after_the_query() {
+    let mut terminal = ScriptedTerminal::new("typed\x1B[7;15R");
+
+    assert_eq!(
+      request_cursor_position(&mut terminal, TIMEOUT).unwrap(),
+      (15, 7)
+    );
+    assert_eq!(terminal.written, b"\x1B[6n");
+  }
+
+  #[test]
+  fn malformed_reply_is_invalid_data() {
+    let mut terminal = ScriptedTerminal::new("\x1B[7R");
+
+    let error = request_cursor_position(&mut terminal, TIMEOUT).unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+  }
+
+  #[test]
+  fn missing_reply_times_out() {
+    let mut terminal = ScriptedTerminal::new("\x1B[7;15");
+
+    let error = request_cursor_position(&mut terminal, TIMEOUT).unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::TimedOut);
+  }
+}
@@ -0,0 +1,337 @@
+mod tests;
+
+use crate::ansi::{escape_sequence_length, is_style_sequence, ESCAPE, STYLE_RESET};
+use crate::style::apply_style_sequence;
+use std::rc::Rc;
+use unicode_width::UnicodeWidthChar;
+
+/// A single cell of a [`VirtualTerminal`](VirtualTerminal).
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct VirtualCell {
+  /// The characters in the cell, which is empty for the columns covered by the rest of a wide character.
+  text: String,
+  /// Every style escape sequence that applies to this cell.
+  style: Rc<str>,
+}
+
+impl Default for VirtualCell {
+  fn default() -> Self {
+    Self {
+      text: " ".to_string(),
+      style: Rc::from(""),
+    }
+  }
+}
+
+/// An in-memory terminal that interprets text and escape sequences the way a real terminal would.
+///
+/// This supports what's commonly used for positioning and styling text, including everything the
+/// [`Printer`](crate::printer::Printer) prints: cursor movement, erasing, and styles.
+/// Unsupported escape sequences are ignored.
+///
+/// Positions are (x, y) starting from (0, 0) at the top left, unlike the escape sequences themselves which start from 1.
+///
+/// # Example
+/// ```
+/// use screen_printer::virtual_terminal::VirtualTerminal;
+///
+/// let mut terminal = VirtualTerminal::new(5, 2);
+///
+/// terminal.write("abc\r\n123");
+/// terminal.write("\x1B[1;2Hx");
+///
+/// assert_eq!(terminal.plain_screen(), "axc  \n123  ");
+/// assert_eq!(terminal.cursor_position(), (2, 0));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VirtualTerminal {
+  width: usize,
+  height: usize,
+  cells: Vec<Vec<VirtualCell>>,
+  cursor: (usize, usize),
+  saved_cursor: (usize, usize),
+  /// The first and last rows that scroll, which is the entire screen unless a scrolling region was set.
+  scrolling_region: (usize, usize),
+  style: Rc<str>,
+}
+
+impl VirtualTerminal {
+  /// Creates an empty terminal of the given width and height, with the cursor at the top left.
+  pub fn new(width: usize, height: usize) -> Self {
+    Self {
+      width,
+      height,
+      cells: vec![vec![VirtualCell::default(); width]; height],
+      cursor: (0, 0),
+      saved_cursor: (0, 0),
+      scrolling_region: (0, height.saturating_sub(1)),
+      style: Rc::from(""),
+    }
+  }
+
+  /// Returns the (width, height) of the terminal.
+  pub fn dimensions(&self) -> (usize, usize) {
+    (self.width, self.height)
+  }
+
+  /// Returns the (x, y) position of the cursor.
+  pub fn cursor_position(&self) -> (usize, usize) {
+    (
+      self.cursor.0.min(self.width.saturating_sub(1)),
+      self.cursor.1,
+    )
+  }
+
+  /// Interprets the text as if it were printed to the terminal.
+  pub fn write(&mut self, text: &str) {
+    let mut index = 0;
+
+    while let Some(character) = text[index..].chars().next() {
+      if character == ESCAPE {
+        let sequence_length = escape_sequence_length(&text[index..]);
+        self.apply_escape_sequence(&text[index..index + sequence_length]);
+        index += sequence_length;
+
+        continue;
+      }
+
+      match character {
+        '\r' => self.cursor.0 = 0,
+        '\n' => self.line_feed(),
+        '\x08' => self.cursor.0 = self.cursor.0.saturating_sub(1),
+        '\t' => self.cursor.0 = ((self.cursor.0 / 8 + 1) * 8).min(self.width.saturating_sub(1)),
+        character if character.is_control() => (),
+        character => self.print_character(character),
+      }
+
+      index += character.len_utf8();
+    }
+  }
+
+  /// Returns the screen as a grid, including the styles of every cell.
+  pub fn screen(&self) -> String {
+    self.region((0, 0), (self.width, self.height))
+  }
+
+  /// Returns the screen as a grid, without any styling.
+  pub fn plain_screen(&self) -> String {
+    self
+      .cells
+      .iter()
+      .map(|row| {
+        row
+          .iter()
+          .map(|cell| cell.text.as_str())
+          .collect::<String>()
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Returns the part of the screen of the given (width, height), starting at the given (x, y) position, including the styles of every cell.
+  ///
+  /// Any part of the region outside of the screen is filled with whitespace.
+  /// Wide characters cut off by the edges of the region are replaced with whitespace.
+  pub fn region(&self, (x, y): (usize, usize), (width, height): (usize, usize)) -> String {
+    (y..y + height)
+      .map(|row_index| {
+        let mut row_text = String::new();
+        let mut style: &str = "";
+
+        for column in x..x + width {
+          let cell = self.cells.get(row_index).and_then(|row| row.get(column));
+          let (text, cell_style) = match cell {
+            Some(cell) if cell.text.is_empty() && column == x => (" ", ""),
+            Some(cell) if self.is_cut_off(cell, column, x + width) => (" ", ""),
+            Some(cell) => (cell.text.as_str(), &*cell.style),
+            None => (" ", ""),
+          };
+
+          if cell_style != style {
+            if !style.is_empty() {
+              row_text.push_str(STYLE_RESET);
+            }
+
+            row_text.push_str(cell_style);
+            style = cell_style;
+          }
+
+          row_text.push_str(text);
+        }
+
+        if !style.is_empty() {
+          row_text.push_str(STYLE_RESET);
+        }
+
+        row_text
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Returns true if the cell is a wide character that doesn't fully fit before the end column.
+  fn is_cut_off(&self, cell: &VirtualCell, column: usize, end_column: usize) -> bool {
+    let width = cell
+      .text
+      .chars()
+      .next()
+      .and_then(|character| character.width())
+      .unwrap_or(1);
+
+    column + width > end_column
+  }
+
+  /// Places the character at the cursor, wrapping to the next line if it doesn't fit.
+  fn print_character(&mut self, character: char) {
+    if self.width == 0 || self.height == 0 {
+      return;
+    }
+
+    let character_width = character.width().unwrap_or(0);
+
+    if character_width == 0 {
+      // Characters with no width are a part of the cell before them.
+      let (x, y) = (self.cursor.0.saturating_sub(1), self.cursor.1);
+      self.cells[y][x].text.push(character);
+
+      return;
+    }
+
+    if self.cursor.0 + character_width > self.width {
+      self.cursor.0 = 0;
+      self.line_feed();
+    }
+
+    let (x, y) = self.cursor;
+    self.cells[y][x] = VirtualCell {
+      text: character.to_string(),
+      style: self.style.clone(),
+    };
+
+    for column in x + 1..(x + character_width).min(self.width) {
+      self.cells[y][column] = VirtualCell {
+        text: String::new(),
+        style: self.style.clone(),
+      };
+    }
+
+    // A cursor past the last column wraps onto the next line when the next character is printed.
+    self.cursor.0 = x + character_width;
+  }
+
+  /// Moves the cursor down a line, scrolling the scrolling region up if it's on the last line of it.
+  fn line_feed(&mut self) {
+    if self.cursor.1 == self.scrolling_region.1 {
+      self.scroll_up(1);
+    } else if self.cursor.1 + 1 < self.height {
+      self.cursor.1 += 1;
+    }
+  }
+
+  /// Scrolls the rows of the scrolling region up by the given amount, adding empty rows at the bottom of it.
+  fn scroll_up(&mut self, rows: usize) {
+    let (top, bottom) = self.scrolling_region;
+
+    if bottom >= self.height {
+      return;
+    }
+
+    for _ in 0..rows.min(bottom + 1 - top) {
+      self.cells.remove(top);
+      self
+        .cells
+        .insert(bottom, vec![VirtualCell::default(); self.width]);
+    }
+  }
+
+  /// Applies the escape sequence, ignoring any that aren't supported.
+  fn apply_escape_sequence(&mut self, sequence: &str) {
+    match sequence {
+      "\x1B7" => return self.saved_cursor = self.cursor,
+      "\x1B8" => return self.cursor = self.saved_cursor,
+      _ => (),
+    }
+
+    if is_style_sequence(sequence) {
+      return apply_style_sequence(&mut self.style, sequence);
+    }
+
+    let Some(body) = sequence.strip_prefix("\x1B[") else {
+      return;
+    };
+    let Some(command) = body.chars().last() else {
+      return;
+    };
+
+    let parameters: Vec<usize> = body[..body.len() - command.len_utf8()]
+      .split(';')
+      .map(|parameter| parameter.parse().unwrap_or(0))
+      .collect();
+    let parameter = |index: usize, default: usize| match parameters.get(index) {
+      Some(0) | None => default,
+      Some(value) => *value,
+    };
+
+    let (x, y) = (
+      self.cursor.0.min(self.width.saturating_sub(1)),
+      self.cursor.1,
+    );
+    let last_column = self.width.saturating_sub(1);
+    let last_row = self.height.saturating_sub(1);
+
+    match command {
+      'H' | 'f' => {
+        self.cursor = (
+          (parameter(1, 1) - 1).min(last_column),
+          (parameter(0, 1) - 1).min(last_row),
+        )
+      }
+      'A' => self.cursor = (x, y.saturating_sub(parameter(0, 1))),
+      'B' => self.cursor = (x, (y + parameter(0, 1)).min(last_row)),
+      'C' => self.cursor = ((x + parameter(0, 1)).min(last_column), y),
+      'D' => self.cursor = (x.saturating_sub(parameter(0, 1)), y),
+      'E' => self.cursor = (0, (y + parameter(0, 1)).min(last_row)),
+      'F' => self.cursor = (0, y.saturating_sub(parameter(0, 1))),
+      'G' => self.cursor = ((parameter(0, 1) - 1).min(last_column), y),
+      'd' => self.cursor = (x, (parameter(0, 1) - 1).min(last_row)),
+      'r' => {
+        let top = parameter(0, 1) - 1;
+        let bottom = (parameter(1, self.height) - 1).min(last_row);
+
+        if top < bottom {
+          self.scrolling_region = (top, bottom);
+          self.cursor = (0, 0);
+        }
+      }
+      'S' => self.scroll_up(parameter(0, 1)),
+      's' => self.saved_cursor = self.cursor,
+      'u' => self.cursor = self.saved_cursor,
+      'J' => match parameter(0, 0) {
+        0 => {
+          self.erase_row(y, x, self.width);
+          (y + 1..self.height).for_each(|row| self.erase_row(row, 0, self.width));
+        }
+        1 => {
+          (0..y).for_each(|row| self.erase_row(row, 0, self.width));
+          self.erase_row(y, 0, x + 1);
+        }
+        _ => (0..self.height).for_each(|row| self.erase_row(row, 0, self.width)),
+      },
+      'K' => match parameter(0, 0) {
+        0 => self.erase_row(y, x, self.width),
+        1 => self.erase_row(y, 0, x + 1),
+        _ => self.erase_row(y, 0, self.width),
+      },
+      _ => (),
+    }
+  }
+
+  /// Replaces the cells of the row from the start column up to the end column with whitespace.
+  fn erase_row(&mut self, y: usize, start_column: usize, end_column: usize) {
+    if let Some(row) = self.cells.get_mut(y) {
+      for cell in row.iter_mut().take(end_column).skip(start_column) {
+        *cell = VirtualCell::default();
+      }
+    }
+  }
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod write_tests {
+  use super::*;
+
+  #[test]
+  fn text_wraps_and_scrolls() {
+    let mut terminal = VirtualTerminal::new(3, 2);
+
+    terminal.write("abcdefgh");
+
+    assert_eq!(terminal.plain_screen(), "def\ngh ");
+    assert_eq!(terminal.cursor_position(), (2, 1));
+  }
+
+  #[test]
+  fn cursor_movement_is_clamped_to_screen() {
+    let mut terminal = VirtualTerminal::new(4, 3);
+
+    terminal.write("\x1B[10;10Ha\x1B[2A\x1B[9Db\x1B[1Bc");
+
+    assert_eq!(terminal.plain_screen(), "b   \n c  \n   a");
+  }
+
+  #[test]
+  fn scrolling_regions_scroll_only_their_rows() {
+    let mut terminal = VirtualTerminal::new(3, 4);
+
+    terminal.write("abc\r\ndef\r\nghi\r\njkl\x1B[2;3r\x1B[S\x1B[r");
+
+    assert_eq!(terminal.plain_screen(), "abc\nghi\n   \njkl");
+
+    terminal.write("\x1B[1;2r\x1B[2;1H\nx");
+
+    assert_eq!(terminal.plain_screen(), "ghi\nx  \n   \njkl");
+  }
+
+  #[test]
+  fn erasing_clears_cells() {
+    let mut terminal = VirtualTerminal::new(3, 3);
+
+    terminal.write("abc\r\ndef\r\nghi\x1B[2;2H\x1B[K\x1B[1J");
+
+    assert_eq!(terminal.plain_screen(), "   \n   \nghi");
+  }
+
+  #[test]
+  fn wide_characters_take_two_columns() {
+    let mut terminal = VirtualTerminal::new(3, 1);
+
+    terminal.write("a\u{1F600}");
+
+    assert_eq!(terminal.plain_screen(), "a\u{1F600}");
+    assert_eq!(terminal.region((0, 0), (2, 1)), "a ");
+    assert_eq!(terminal.region((2, 0), (1, 1)), " ");
+  }
+
+  #[test]
+  fn styles_apply_to_printed_cells() {
+    let mut terminal = VirtualTerminal::new(4, 1);
+
+    terminal.write("\x1B[31mab\x1B[1mc\x1B[0md");
+
+    assert_eq!(
+      terminal.screen(),
+      "\x1B[31mab\x1B[0m\x1B[31m\x1B[1mc\x1B[0md"
+    );
+  }
+
+  #[test]
+  fn region_outside_screen_is_whitespace() {
+    let mut terminal = VirtualTerminal::new(2, 1);
+
+    terminal.write("ab");
+
+    assert_eq!(terminal.region((1, 0), (2, 2)), "b \n  ");
+  }
+}
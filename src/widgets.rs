@@ -0,0 +1,89 @@
+//! Helpers for turning common kinds of data into grids, such as charts and lists.
+//!
+//! Every widget renders into a rectangular grid, which can be printed with [`dynamic_print`](crate::dynamic_printer::DynamicPrinter::dynamic_print)
+//! on its own or combined with other grids.
+//!
+//! Widgets that implement [`Reflow`](Reflow) can be printed with [`Printer::print_reflowed`](crate::printer::Printer::print_reflowed),
+//! which lays them out again to fit the terminal whenever it's resized.
+//! Every [`Widget`](Widget) can be rendered into a [`Region`](crate::region::Region) of any size with
+//! [`Printer::print_widgets`](crate::printer::Printer::print_widgets), for building dashboards out of a [`Layout`](crate::layout::Layout).
+
+pub mod gauge;
+pub mod heatmap;
+pub mod line_chart;
+pub mod list_view;
+pub mod marquee;
+pub mod paragraph;
+pub mod text_field;
+pub mod tree_view;
+
+pub use gauge::*;
+pub use heatmap::*;
+pub use line_chart::*;
+pub use list_view::*;
+pub use marquee::*;
+pub use paragraph::*;
+pub use text_field::*;
+pub use tree_view::*;
+
+/// A widget whose content can be laid out again to fit new dimensions, such as text re-wrapped to a new width,
+/// rather than being clipped when the space it's printed in changes.
+///
+/// Printed with [`Printer::print_reflowed`](crate::printer::Printer::print_reflowed),
+/// which reflows the widget to the printing area whenever the terminal is resized.
+pub trait Reflow {
+  /// Lays the content out again to fit within the given (width, height).
+  fn reflow(&mut self, dimensions: (usize, usize));
+
+  /// Returns the (width, height) the content is laid out to fit within.
+  fn dimensions(&self) -> (usize, usize);
+
+  /// Renders the content into a grid of the widget's dimensions.
+  fn render(&self) -> String;
+}
+
+/// Anything that can be rendered into a grid of any given size,
+/// such as to fill a [`Region`](crate::region::Region) with [`Printer::print_widgets`](crate::printer::Printer::print_widgets).
+///
+/// Every widget that can [`Reflow`](Reflow) is a widget, rendered by reflowing a copy of it to the given dimensions.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+/// use screen_printer::widgets::*;
+///
+/// /// Shows how large of an area it was given.
+/// struct SizeDisplay;
+///
+/// impl Widget for SizeDisplay {
+///   fn render(&self, (width, height): (usize, usize)) -> String {
+///     let text = format!("{width}x{height}");
+///
+///     Printer::create_grid_from_single_character(' ', width, height - 1) + "\n" + &text
+///   }
+/// }
+///
+/// let mut printer = Printer::on_virtual_terminal((10, 2));
+/// let layout = Layout::horizontal(vec![Constraint::Fixed(6), Constraint::Remainder]);
+/// printer.layout_regions(&layout, &["text", "size"]).unwrap();
+///
+/// let paragraph = Paragraph::new("The quick brown fox", 1, 1);
+/// printer.print_widgets(&[("text", &paragraph), ("size", &SizeDisplay)]).unwrap();
+///
+/// assert_eq!(printer.virtual_screen().unwrap().plain_screen(), "The       \nquick 4x2 ");
+/// ```
+pub trait Widget {
+  /// Renders the widget into a grid that fits within the given (width, height).
+  ///
+  /// Anything rendered outside of the dimensions is cut off when printed in a region.
+  fn render(&self, dimensions: (usize, usize)) -> String;
+}
+
+impl<T: Reflow + Clone> Widget for T {
+  fn render(&self, dimensions: (usize, usize)) -> String {
+    let mut widget = self.clone();
+    widget.reflow(dimensions);
+
+    Reflow::render(&widget)
+  }
+}
@@ -0,0 +1,177 @@
+mod tests;
+
+use crate::ansi::STYLE_RESET;
+use crate::color::{colored_text, Color};
+use std::f64::consts::PI;
+
+/// The characters used to fill a cell of a horizontal gauge, from an eighth of a cell to a full cell.
+const PARTIAL_BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// The character used for the filled part of an arc gauge.
+const FILLED_CELL: char = '█';
+/// The character used for the unfilled part of an arc gauge.
+const UNFILLED_CELL: char = '░';
+
+/// The shape a [`Gauge`](Gauge) is drawn in.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GaugeStyle {
+  /// A single row that fills from left to right.
+  #[default]
+  Horizontal,
+  /// A half circle that fills from the left end to the right end.
+  ///
+  /// The arc is about a quarter as tall as it is wide, to account for cells being taller than they are wide.
+  Arc,
+}
+
+/// Renders a value within a range as a meter, such as for showing CPU or memory usage.
+///
+/// Thresholds change the color of the gauge once the value reaches them.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+/// use screen_printer::widgets::*;
+///
+/// let gauge = Gauge::new(10, (0.0, 100.0))
+///   .with_threshold(50.0, Color::new(255, 255, 0))
+///   .with_threshold(90.0, Color::new(255, 0, 0));
+///
+/// assert_eq!(strip_ansi(&gauge.render(25.0)), "██▌       ");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gauge {
+  width: usize,
+  range: (f64, f64),
+  style: GaugeStyle,
+  thresholds: Vec<(f64, Color)>,
+}
+
+impl Gauge {
+  /// Creates a gauge of the given width, showing values between the (minimum, maximum) of the range.
+  pub fn new(width: usize, range: (f64, f64)) -> Self {
+    Self {
+      width,
+      range,
+      style: GaugeStyle::default(),
+      thresholds: Vec::new(),
+    }
+  }
+
+  /// Replaces the shape the gauge is drawn in.
+  ///
+  /// Refer to [`GaugeStyle`](GaugeStyle) for more information.
+  pub fn with_style(mut self, style: GaugeStyle) -> Self {
+    self.style = style;
+
+    self
+  }
+
+  /// Colors the gauge with the given color when the value is at or above the threshold.
+  ///
+  /// When multiple thresholds are reached, the color of the highest one is used.
+  /// Values below every threshold are left uncolored.
+  pub fn with_threshold(mut self, threshold: f64, color: Color) -> Self {
+    self.thresholds.push((threshold, color));
+    self
+      .thresholds
+      .sort_by(|(first, _), (second, _)| first.total_cmp(second));
+
+    self
+  }
+
+  /// Returns the height of the grids this gauge renders.
+  pub fn height(&self) -> usize {
+    match self.style {
+      GaugeStyle::Horizontal => 1,
+      GaugeStyle::Arc => self.width.div_ceil(4),
+    }
+  }
+
+  /// Renders the gauge filled up to the given value, which is clamped within the gauge's range.
+  pub fn render(&self, value: f64) -> String {
+    let fraction = self.fraction_of(value);
+    let color = self
+      .thresholds
+      .iter()
+      .rev()
+      .find(|(threshold, _)| value >= *threshold)
+      .map(|(_, color)| *color);
+
+    let rows = match self.style {
+      GaugeStyle::Horizontal => vec![self.horizontal_row(fraction)],
+      GaugeStyle::Arc => self.arc_rows(fraction),
+    };
+
+    rows
+      .into_iter()
+      .map(|row| match color {
+        Some(color) => format!("{}{STYLE_RESET}", colored_text(&row, Some(color), None)),
+        None => row,
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Returns how far along the gauge's range the value is, between 0.0 and 1.0.
+  fn fraction_of(&self, value: f64) -> f64 {
+    let (minimum, maximum) = self.range;
+
+    if maximum > minimum && value.is_finite() {
+      ((value - minimum) / (maximum - minimum)).clamp(0.0, 1.0)
+    } else {
+      0.0
+    }
+  }
+
+  /// Returns a row filled up to the given fraction of its width, using partial blocks for the last cell.
+  fn horizontal_row(&self, fraction: f64) -> String {
+    let filled_eighths = (fraction * (self.width * 8) as f64).round() as usize;
+    let (full_cells, remaining_eighths) = (filled_eighths / 8, filled_eighths % 8);
+
+    let mut row = PARTIAL_BLOCKS[7].to_string().repeat(full_cells);
+
+    if remaining_eighths > 0 {
+      row.push(PARTIAL_BLOCKS[remaining_eighths - 1]);
+    }
+
+    let empty_cells = self.width - full_cells - usize::from(remaining_eighths > 0);
+    row.push_str(&" ".repeat(empty_cells));
+
+    row
+  }
+
+  /// Returns the rows of a half circle, where the part of the arc within the given fraction is filled.
+  fn arc_rows(&self, fraction: f64) -> Vec<String> {
+    let height = self.height();
+    let outer_radius = self.width as f64 / 2.0;
+    let inner_radius = outer_radius * 0.6;
+    let filled_angle = fraction * PI;
+
+    (0..height)
+      .map(|y| {
+        (0..self.width)
+          .map(|x| {
+            // Measured from the bottom middle of the gauge, where cells are counted as twice as tall as they are wide.
+            let offset_x = x as f64 + 0.5 - outer_radius;
+            let offset_y = (height - y) as f64 * 2.0 - 1.0;
+            let distance = offset_x.hypot(offset_y);
+
+            if distance > outer_radius || distance < inner_radius {
+              return ' ';
+            }
+
+            // The angle from the left end of the arc, going over the top to the right end.
+            let angle = PI - offset_y.atan2(offset_x);
+
+            if angle <= filled_angle {
+              FILLED_CELL
+            } else {
+              UNFILLED_CELL
+            }
+          })
+          .collect()
+      })
+      .collect()
+  }
+}
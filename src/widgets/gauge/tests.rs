@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use super::*;
+use crate::printer::*;
+
+#[cfg(test)]
+mod horizontal_gauge_tests {
+  use super::*;
+
+  #[test]
+  fn value_is_clamped_to_range() {
+    let gauge = Gauge::new(4, (0.0, 10.0));
+
+    assert_eq!(gauge.render(-3.0), "    ");
+    assert_eq!(gauge.render(30.0), "████");
+  }
+
+  #[test]
+  fn partial_cells_use_eighths() {
+    let gauge = Gauge::new(2, (0.0, 16.0));
+
+    assert_eq!(gauge.render(11.0), "█▍");
+  }
+
+  #[test]
+  fn highest_reached_threshold_colors_gauge() {
+    let gauge = Gauge::new(2, (0.0, 1.0))
+      .with_threshold(0.9, Color::new(255, 0, 0))
+      .with_threshold(0.5, Color::new(255, 255, 0));
+
+    assert_eq!(gauge.render(0.25), "▌ ");
+    assert_eq!(gauge.render(0.75), "\x1B[0;38;2;255;255;0m█▌\x1B[0m");
+    assert_eq!(gauge.render(1.0), "\x1B[0;38;2;255;0;0m██\x1B[0m");
+  }
+}
+
+#[cfg(test)]
+mod arc_gauge_tests {
+  use super::*;
+
+  #[test]
+  fn arc_is_rectangular() {
+    let gauge = Gauge::new(16, (0.0, 1.0)).with_style(GaugeStyle::Arc);
+
+    let grid = gauge.render(0.5);
+
+    assert_eq!(Printer::get_rectangular_dimensions(&grid).unwrap(), (16, 4));
+  }
+
+  #[test]
+  fn arc_fills_from_the_left() {
+    let gauge = Gauge::new(16, (0.0, 1.0)).with_style(GaugeStyle::Arc);
+
+    let grid = gauge.render(0.5);
+    let bottom_row = grid.lines().next_back().unwrap();
+
+    assert!(bottom_row.trim_start().starts_with(FILLED_CELL));
+    assert!(bottom_row.trim_end().ends_with(UNFILLED_CELL));
+  }
+}
@@ -0,0 +1,175 @@
+mod tests;
+
+use crate::ansi::STYLE_RESET;
+use crate::color::{colored_text, Color};
+
+/// The character used for every cell of a heatmap.
+const FULL_BLOCK: char = '█';
+
+/// A range of colors that values are mapped onto, from the lowest value to the highest.
+#[derive(Default, Debug, PartialEq, Eq, Clone)]
+pub enum ColorScale {
+  /// Dark purple through blue and green to yellow.
+  #[default]
+  Viridis,
+  /// Black through purple and orange to pale yellow.
+  Magma,
+  /// Black to white.
+  Grayscale,
+  /// The given colors, evenly spaced from the lowest value to the highest.
+  ///
+  /// An empty list of colors maps every value to black.
+  Custom(Vec<Color>),
+}
+
+const VIRIDIS: [Color; 5] = [
+  Color::new(68, 1, 84),
+  Color::new(59, 82, 139),
+  Color::new(33, 145, 140),
+  Color::new(94, 201, 98),
+  Color::new(253, 231, 37),
+];
+
+const MAGMA: [Color; 6] = [
+  Color::new(0, 0, 4),
+  Color::new(59, 15, 112),
+  Color::new(140, 41, 129),
+  Color::new(222, 73, 104),
+  Color::new(254, 159, 109),
+  Color::new(252, 253, 191),
+];
+
+const GRAYSCALE: [Color; 2] = [Color::new(0, 0, 0), Color::new(255, 255, 255)];
+
+impl ColorScale {
+  /// Returns the color the given fraction of the way along the scale.
+  ///
+  /// The fraction is clamped between 0.0 and 1.0.
+  ///
+  /// # Example
+  /// ```
+  /// use screen_printer::color::Color;
+  /// use screen_printer::widgets::*;
+  ///
+  /// assert_eq!(ColorScale::Grayscale.color_at(0.0), Color::new(0, 0, 0));
+  /// assert_eq!(ColorScale::Grayscale.color_at(1.0), Color::new(255, 255, 255));
+  /// ```
+  pub fn color_at(&self, fraction: f64) -> Color {
+    let colors: &[Color] = match self {
+      Self::Viridis => &VIRIDIS,
+      Self::Magma => &MAGMA,
+      Self::Grayscale => &GRAYSCALE,
+      Self::Custom(colors) => colors,
+    };
+
+    match colors {
+      [] => Color::default(),
+      [color] => *color,
+      _ => {
+        let position = fraction.clamp(0.0, 1.0) * (colors.len() - 1) as f64;
+        let index = (position.floor() as usize).min(colors.len() - 2);
+
+        colors[index].blend(colors[index + 1], position - index as f64)
+      }
+    }
+  }
+}
+
+/// Renders 2-dimensional numeric data into a grid of colored cells.
+///
+/// Every value takes up a single cell, colored based on where it lands on the [`ColorScale`](ColorScale).
+/// Values that aren't finite, such as NaN, are left blank.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+/// use screen_printer::widgets::*;
+///
+/// let values = vec![vec![0.0, 0.5], vec![0.75, 1.0]];
+///
+/// let grid = Heatmap::new(ColorScale::Viridis).render(&values);
+///
+/// assert_eq!(strip_ansi(&grid), "██\n██");
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct Heatmap {
+  color_scale: ColorScale,
+  range: Option<(f64, f64)>,
+}
+
+impl Heatmap {
+  /// Creates a new heatmap that colors values with the given [`ColorScale`](ColorScale).
+  ///
+  /// By default, the lowest and highest values in the data are used as the ends of the scale.
+  pub fn new(color_scale: ColorScale) -> Self {
+    Self {
+      color_scale,
+      range: None,
+    }
+  }
+
+  /// Uses the given minimum and maximum as the ends of the scale, rather than the lowest and highest values in the data.
+  ///
+  /// Values outside of the range are given the color at the closest end of the scale.
+  /// This keeps colors consistent between frames of data that change over time.
+  pub fn with_range(mut self, minimum: f64, maximum: f64) -> Self {
+    self.range = Some((minimum, maximum));
+
+    self
+  }
+
+  /// Renders the values into a grid, where each inner list is a row.
+  ///
+  /// Rows shorter than the longest are padded with blank cells to keep the grid rectangular.
+  pub fn render<R: AsRef<[f64]>>(&self, values: &[R]) -> String {
+    let width = values
+      .iter()
+      .map(|row| row.as_ref().len())
+      .max()
+      .unwrap_or(0);
+    let (minimum, maximum) = self.range.unwrap_or_else(|| value_range(values));
+
+    values
+      .iter()
+      .map(|row| {
+        let row = row.as_ref();
+        let mut rendered_row = String::new();
+
+        for value in row {
+          if value.is_finite() {
+            let fraction = if maximum > minimum {
+              (value - minimum) / (maximum - minimum)
+            } else {
+              0.0
+            };
+            let color = self.color_scale.color_at(fraction);
+
+            rendered_row.push_str(&colored_text(&FULL_BLOCK.to_string(), Some(color), None));
+          } else {
+            rendered_row.push_str(STYLE_RESET);
+            rendered_row.push(' ');
+          }
+        }
+
+        rendered_row.push_str(STYLE_RESET);
+        rendered_row.push_str(&" ".repeat(width - row.len()));
+
+        rendered_row
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
+
+/// Returns the lowest and highest finite values, or (0.0, 0.0) if there are none.
+fn value_range<R: AsRef<[f64]>>(values: &[R]) -> (f64, f64) {
+  values
+    .iter()
+    .flat_map(|row| row.as_ref().iter())
+    .filter(|value| value.is_finite())
+    .fold(None, |range: Option<(f64, f64)>, &value| match range {
+      Some((minimum, maximum)) => Some((minimum.min(value), maximum.max(value))),
+      None => Some((value, value)),
+    })
+    .unwrap_or((0.0, 0.0))
+}
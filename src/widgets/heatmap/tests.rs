@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use super::*;
+use crate::printer::*;
+
+#[cfg(test)]
+mod color_scale_tests {
+  use super::*;
+
+  #[test]
+  fn custom_scale_blends_between_colors() {
+    let color_scale = ColorScale::Custom(vec![
+      Color::new(0, 0, 0),
+      Color::new(200, 0, 0),
+      Color::new(200, 200, 0),
+    ]);
+
+    assert_eq!(color_scale.color_at(0.25), Color::new(100, 0, 0));
+    assert_eq!(color_scale.color_at(0.75), Color::new(200, 100, 0));
+    assert_eq!(color_scale.color_at(2.0), Color::new(200, 200, 0));
+  }
+}
+
+#[cfg(test)]
+mod render_tests {
+  use super::*;
+
+  #[test]
+  fn values_are_scaled_to_data_range() {
+    let heatmap = Heatmap::new(ColorScale::Grayscale);
+
+    let grid = heatmap.render(&[[10.0, 20.0]]);
+
+    assert_eq!(grid, "\x1B[0;38;2;0;0;0m█\x1B[0;38;2;255;255;255m█\x1B[0m");
+  }
+
+  #[test]
+  fn values_outside_range_are_clamped() {
+    let heatmap = Heatmap::new(ColorScale::Grayscale).with_range(0.0, 1.0);
+
+    let grid = heatmap.render(&[[-5.0, 5.0]]);
+
+    assert_eq!(grid, "\x1B[0;38;2;0;0;0m█\x1B[0;38;2;255;255;255m█\x1B[0m");
+  }
+
+  #[test]
+  fn uneven_rows_are_padded() {
+    let values = vec![vec![1.0, f64::NAN, 3.0], vec![2.0]];
+
+    let grid = Heatmap::default().render(&values);
+
+    assert_eq!(strip_ansi(&grid), "█ █\n█  ");
+    assert!(Printer::is_rectangular(&grid));
+  }
+}
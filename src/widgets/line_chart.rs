@@ -0,0 +1,325 @@
+mod tests;
+
+use crate::ansi::STYLE_RESET;
+use crate::color::{colored_text, Color};
+use crate::printer::Printer;
+use crate::widgets::Reflow;
+
+/// The characters used to draw the lines of a [`LineChart`](LineChart).
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ChartMarker {
+  /// Braille characters, which fit 2 by 4 points in every cell.
+  ///
+  /// These give the smoothest lines, but not every font supports them.
+  #[default]
+  Braille,
+  /// Half block characters, which fit 1 by 2 points in every cell.
+  Block,
+}
+
+impl ChartMarker {
+  /// Returns how many (columns, rows) of points fit in a single cell.
+  fn points_per_cell(&self) -> (usize, usize) {
+    match self {
+      Self::Braille => (2, 4),
+      Self::Block => (1, 2),
+    }
+  }
+
+  /// Returns the character for a cell with the given points filled in, where the points are indexed by [column][row].
+  fn character_for(&self, points: &[[bool; 4]; 2]) -> char {
+    match self {
+      Self::Braille => {
+        // The bit for each dot of a braille character, indexed by [column][row].
+        const DOT_BITS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+        let bits = (0..2)
+          .flat_map(|x| (0..4).map(move |y| (x, y)))
+          .filter(|&(x, y)| points[x][y])
+          .fold(0, |bits, (x, y)| bits | DOT_BITS[x][y]);
+
+        char::from_u32(0x2800 + bits).unwrap_or(' ')
+      }
+      Self::Block => match (points[0][0], points[0][1]) {
+        (true, true) => '█',
+        (true, false) => '▀',
+        (false, true) => '▄',
+        (false, false) => ' ',
+      },
+    }
+  }
+}
+
+/// A single line of a [`LineChart`](LineChart).
+#[derive(Debug, PartialEq, Clone)]
+struct Series {
+  values: Vec<f64>,
+  color: Option<Color>,
+}
+
+/// A single cell of a chart's plot.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+struct PlotCell {
+  /// The points filled in for this cell, indexed by [column][row].
+  points: [[bool; 4]; 2],
+  /// The color of the last series drawn in this cell.
+  color: Option<Color>,
+}
+
+/// Renders one or more series of numbers into a line chart of a given size.
+///
+/// Every series is spread across the full width of the chart.
+/// The left side of the chart has the highest and lowest values labeled, and the bottom has an axis line.
+/// Values that aren't finite, such as NaN, leave a gap in the line.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+/// use screen_printer::widgets::*;
+///
+/// let grid = LineChart::new(20, 6)
+///   .with_series(vec![1.0, 3.0, 2.0, 5.0, 4.0], None)
+///   .with_series(vec![0.0, 1.0, 0.0, 1.0], Some(Color::new(255, 0, 0)))
+///   .render();
+///
+/// assert_eq!(Printer::get_rectangular_dimensions(&grid).unwrap(), (20, 6));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct LineChart {
+  width: usize,
+  height: usize,
+  series: Vec<Series>,
+  marker: ChartMarker,
+  range: Option<(f64, f64)>,
+}
+
+impl LineChart {
+  /// Creates an empty chart that renders into a grid of the given width and height, including the axes.
+  pub fn new(width: usize, height: usize) -> Self {
+    Self {
+      width,
+      height,
+      series: Vec::new(),
+      marker: ChartMarker::default(),
+      range: None,
+    }
+  }
+
+  /// Adds a series of values to the chart, drawn in the given color.
+  ///
+  /// Series added later are drawn over earlier ones.
+  pub fn with_series(mut self, values: Vec<f64>, color: Option<Color>) -> Self {
+    self.series.push(Series { values, color });
+
+    self
+  }
+
+  /// Replaces the characters used to draw the lines.
+  ///
+  /// Refer to [`ChartMarker`](ChartMarker) for more information.
+  pub fn with_marker(mut self, marker: ChartMarker) -> Self {
+    self.marker = marker;
+
+    self
+  }
+
+  /// Uses the given minimum and maximum as the bottom and top of the chart,
+  /// rather than the lowest and highest values of every series.
+  pub fn with_range(mut self, minimum: f64, maximum: f64) -> Self {
+    self.range = Some((minimum, maximum));
+
+    self
+  }
+
+  /// Renders the chart into a grid of the chart's width and height.
+  ///
+  /// If the chart is too small to fit its labels and axes, the grid is left blank.
+  pub fn render(&self) -> String {
+    let (minimum, maximum) = self.range.unwrap_or_else(|| self.value_range());
+    let maximum_label = format_label(maximum);
+    let minimum_label = format_label(minimum);
+    let label_width = maximum_label
+      .chars()
+      .count()
+      .max(minimum_label.chars().count());
+
+    if self.width <= label_width + 1 || self.height < 2 {
+      return Printer::create_grid_from_single_character(' ', self.width, self.height);
+    }
+
+    let plot_width = self.width - label_width - 1;
+    let plot_height = self.height - 1;
+    let cells = self.plot_cells((plot_width, plot_height), (minimum, maximum));
+
+    let mut rows: Vec<String> = cells
+      .iter()
+      .enumerate()
+      .map(|(y, row)| {
+        let label = if y == 0 {
+          maximum_label.as_str()
+        } else if y == plot_height - 1 {
+          minimum_label.as_str()
+        } else {
+          ""
+        };
+
+        format!("{label:>label_width$}│{}", self.render_row(row))
+      })
+      .collect();
+
+    rows.push(format!(
+      "{}└{}",
+      " ".repeat(label_width),
+      "─".repeat(plot_width)
+    ));
+
+    rows.join("\n")
+  }
+
+  /// Returns every cell of the plot with each series drawn in it.
+  fn plot_cells(
+    &self,
+    (plot_width, plot_height): (usize, usize),
+    (minimum, maximum): (f64, f64),
+  ) -> Vec<Vec<PlotCell>> {
+    let (cell_columns, cell_rows) = self.marker.points_per_cell();
+    let (point_width, point_height) = (plot_width * cell_columns, plot_height * cell_rows);
+    let mut cells = vec![vec![PlotCell::default(); plot_width]; plot_height];
+
+    for series in &self.series {
+      let points: Vec<Option<(usize, usize)>> = series
+        .values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+          if !value.is_finite() {
+            return None;
+          }
+
+          let x = if series.values.len() > 1 {
+            index * (point_width - 1) / (series.values.len() - 1)
+          } else {
+            0
+          };
+          let fraction = if maximum > minimum {
+            ((value - minimum) / (maximum - minimum)).clamp(0.0, 1.0)
+          } else {
+            0.0
+          };
+          let y = point_height - 1 - (fraction * (point_height - 1) as f64).round() as usize;
+
+          Some((x, y))
+        })
+        .collect();
+
+      let mut plot_point = |(x, y): (usize, usize)| {
+        let cell = &mut cells[y / cell_rows][x / cell_columns];
+
+        cell.points[x % cell_columns][y % cell_rows] = true;
+        cell.color = series.color;
+      };
+
+      for pair in points.windows(2) {
+        if let [Some(start), Some(end)] = pair {
+          line_points(*start, *end)
+            .into_iter()
+            .for_each(&mut plot_point);
+        }
+      }
+
+      // Points that aren't connected to any others still need to be drawn.
+      points.iter().flatten().for_each(|&point| plot_point(point));
+    }
+
+    cells
+  }
+
+  /// Renders a single row of plot cells, coloring each one.
+  fn render_row(&self, row: &[PlotCell]) -> String {
+    let mut rendered_row = String::new();
+    let mut is_styled = false;
+
+    for PlotCell { points, color } in row {
+      let character = self.marker.character_for(points);
+
+      match color {
+        Some(color) if character != ' ' => {
+          rendered_row.push_str(&colored_text(&character.to_string(), Some(*color), None));
+          is_styled = true;
+        }
+        _ => {
+          if is_styled {
+            rendered_row.push_str(STYLE_RESET);
+            is_styled = false;
+          }
+
+          rendered_row.push(character);
+        }
+      }
+    }
+
+    if is_styled {
+      rendered_row.push_str(STYLE_RESET);
+    }
+
+    rendered_row
+  }
+
+  /// Returns the lowest and highest finite values of every series, or (0.0, 0.0) if there are none.
+  fn value_range(&self) -> (f64, f64) {
+    self
+      .series
+      .iter()
+      .flat_map(|series| series.values.iter())
+      .filter(|value| value.is_finite())
+      .fold(None, |range: Option<(f64, f64)>, &value| match range {
+        Some((minimum, maximum)) => Some((minimum.min(value), maximum.max(value))),
+        None => Some((value, value)),
+      })
+      .unwrap_or((0.0, 0.0))
+  }
+}
+
+impl Reflow for LineChart {
+  /// Resizes the chart, scaling the series to fit the new dimensions.
+  fn reflow(&mut self, (width, height): (usize, usize)) {
+    self.width = width;
+    self.height = height;
+  }
+
+  fn dimensions(&self) -> (usize, usize) {
+    (self.width, self.height)
+  }
+
+  fn render(&self) -> String {
+    LineChart::render(self)
+  }
+}
+
+/// Formats a value for the labels on the side of the chart, removing any unneeded decimals.
+fn format_label(value: f64) -> String {
+  let label = format!("{value:.2}");
+
+  label
+    .trim_end_matches('0')
+    .trim_end_matches('.')
+    .to_string()
+}
+
+/// Returns every point on the straight line between the start and end, including both.
+fn line_points(
+  (start_x, start_y): (usize, usize),
+  (end_x, end_y): (usize, usize),
+) -> Vec<(usize, usize)> {
+  let steps = start_x.abs_diff(end_x).max(start_y.abs_diff(end_y)).max(1);
+
+  (0..=steps)
+    .map(|step| {
+      let fraction = step as f64 / steps as f64;
+      let x = start_x as f64 + (end_x as f64 - start_x as f64) * fraction;
+      let y = start_y as f64 + (end_y as f64 - start_y as f64) * fraction;
+
+      (x.round() as usize, y.round() as usize)
+    })
+    .collect()
+}
@@ -0,0 +1,40 @@
+#![cfg(test)]
+
+use super::*;
+use crate::printer::*;
+
+#[cfg(test)]
+mod render_tests {
+  use super::*;
+
+  #[test]
+  fn block_chart_has_labels_and_axes() {
+    let grid = LineChart::new(6, 3)
+      .with_series(vec![0.0, 1.0, 2.0, 3.0], None)
+      .with_marker(ChartMarker::Block)
+      .render();
+
+    let expected_grid = ["3│  ▄▀", "0│▄▀  ", " └────"].join("\n");
+
+    assert_eq!(grid, expected_grid);
+  }
+
+  #[test]
+  fn braille_chart_fits_dimensions() {
+    let grid = LineChart::new(12, 5)
+      .with_series(vec![1.5, f64::NAN, 0.25, 7.0], Some(Color::new(0, 255, 0)))
+      .render();
+
+    assert_eq!(Printer::get_rectangular_dimensions(&grid).unwrap(), (12, 5));
+    assert!(strip_ansi(&grid).starts_with("   7│"));
+  }
+
+  #[test]
+  fn chart_too_small_for_labels_is_blank() {
+    let grid = LineChart::new(2, 3)
+      .with_series(vec![100.0, 200.0], None)
+      .render();
+
+    assert_eq!(grid, "  \n  \n  ");
+  }
+}
@@ -0,0 +1,231 @@
+mod tests;
+
+use crate::ansi::{strip_ansi, STYLE_RESET};
+use crate::cells::fit_row_to_width;
+use crate::widgets::Reflow;
+
+/// The style sequence used to highlight the selected item, which swaps the foreground and background colors.
+const HIGHLIGHT_STYLE: &str = "\x1B[7m";
+
+/// The character for the part of the scrollbar showing which items are in view.
+const SCROLLBAR_THUMB: char = '█';
+/// The character for the rest of the scrollbar.
+const SCROLLBAR_TRACK: char = '│';
+
+/// A scrollable list of items, where one item can be selected and highlighted.
+///
+/// The list keeps track of its scroll offset, scrolling just enough to keep the selected item in view
+/// whenever the selection changes.
+/// Since only the rows that changed between renders are printed, moving the selection only reprints the affected rows.
+///
+/// # Example
+/// ```
+/// use screen_printer::printer::*;
+/// use screen_printer::widgets::*;
+///
+/// let mut list_view = ListView::new(6, 2).with_items(vec!["one", "two", "three"]);
+///
+/// list_view.select_next();
+/// list_view.select_next();
+/// list_view.select_next();
+///
+/// assert_eq!(list_view.get_selected(), Some(2));
+/// assert_eq!(list_view.get_scroll_offset(), 1);
+/// assert_eq!(strip_ansi(&list_view.render()), "two   \nthree ");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ListView {
+  items: Vec<String>,
+  width: usize,
+  height: usize,
+  selected: Option<usize>,
+  scroll_offset: usize,
+  show_scrollbar: bool,
+}
+
+impl ListView {
+  /// Creates an empty list that renders into a grid of the given width and height.
+  pub fn new(width: usize, height: usize) -> Self {
+    Self {
+      width,
+      height,
+      ..Default::default()
+    }
+  }
+
+  /// Replaces the items of the list.
+  pub fn with_items<S: Into<String>>(mut self, items: Vec<S>) -> Self {
+    self.replace_items(items);
+
+    self
+  }
+
+  /// Sets whether a scrollbar is drawn in the rightmost column of the list.
+  ///
+  /// The scrollbar is only drawn when there are more items than fit in the list.
+  pub fn with_scrollbar(mut self, show_scrollbar: bool) -> Self {
+    self.show_scrollbar = show_scrollbar;
+
+    self
+  }
+
+  /// Replaces the items of the list, keeping the selection and scroll offset within the new items.
+  pub fn replace_items<S: Into<String>>(&mut self, items: Vec<S>) {
+    self.items = items.into_iter().map(Into::into).collect();
+
+    match self.selected {
+      Some(selected) => self.select(selected),
+      None => self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset()),
+    }
+  }
+
+  /// Returns the items of the list.
+  pub fn get_items(&self) -> &[String] {
+    &self.items
+  }
+
+  /// Selects the item at the given index, or the last item if the index is past the end of the list.
+  ///
+  /// The list is scrolled to keep the selected item in view.
+  pub fn select(&mut self, index: usize) {
+    if self.items.is_empty() {
+      self.selected = None;
+      self.scroll_offset = 0;
+
+      return;
+    }
+
+    let index = index.min(self.items.len() - 1);
+    self.selected = Some(index);
+
+    if index < self.scroll_offset {
+      self.scroll_offset = index;
+    } else if self.height > 0 && index >= self.scroll_offset + self.height {
+      self.scroll_offset = index + 1 - self.height;
+    }
+
+    self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+  }
+
+  /// Removes the selection, leaving the scroll offset where it is.
+  pub fn clear_selection(&mut self) {
+    self.selected = None;
+  }
+
+  /// Selects the item after the currently selected one, or the first item if nothing is selected.
+  pub fn select_next(&mut self) {
+    self.select(self.selected.map_or(0, |selected| selected + 1));
+  }
+
+  /// Selects the item before the currently selected one, or the first item if nothing is selected.
+  pub fn select_previous(&mut self) {
+    self.select(
+      self
+        .selected
+        .map_or(0, |selected| selected.saturating_sub(1)),
+    );
+  }
+
+  /// Returns the index of the selected item.
+  pub fn get_selected(&self) -> Option<usize> {
+    self.selected
+  }
+
+  /// Scrolls the list so the item at the given index is at the top, without changing the selection.
+  ///
+  /// The scroll offset is kept within the list, so the last item can't be scrolled above the bottom row.
+  pub fn replace_scroll_offset(&mut self, scroll_offset: usize) {
+    self.scroll_offset = scroll_offset.min(self.max_scroll_offset());
+  }
+
+  /// Returns the index of the item at the top of the list.
+  pub fn get_scroll_offset(&self) -> usize {
+    self.scroll_offset
+  }
+
+  /// Renders the items in view, highlighting the selected item.
+  ///
+  /// Items wider than the list are cut off. The selected item is highlighted without any of its own styling.
+  pub fn render(&self) -> String {
+    let scrollbar = self.scrollbar();
+    let item_width = if scrollbar.is_some() {
+      self.width.saturating_sub(1)
+    } else {
+      self.width
+    };
+
+    (0..self.height)
+      .map(|row| {
+        let index = self.scroll_offset + row;
+        let item = self.items.get(index).map_or("", String::as_str);
+
+        let mut rendered_row = if self.selected == Some(index) {
+          format!(
+            "{HIGHLIGHT_STYLE}{}{STYLE_RESET}",
+            fit_row_to_width(&strip_ansi(item), item_width)
+          )
+        } else {
+          fit_row_to_width(item, item_width)
+        };
+
+        if let Some(scrollbar) = &scrollbar {
+          rendered_row.push(scrollbar[row]);
+        }
+
+        rendered_row
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Returns the largest scroll offset that still fills the list with items.
+  fn max_scroll_offset(&self) -> usize {
+    self.items.len().saturating_sub(self.height)
+  }
+
+  /// Returns the character for every row of the scrollbar, or None if there shouldn't be one.
+  fn scrollbar(&self) -> Option<Vec<char>> {
+    if !self.show_scrollbar || self.width == 0 || self.items.len() <= self.height {
+      return None;
+    }
+
+    let thumb_height = (self.height * self.height / self.items.len()).max(1);
+    let max_thumb_start = self.height - thumb_height;
+    let thumb_start = (self.scroll_offset * max_thumb_start)
+      .checked_div(self.max_scroll_offset())
+      .unwrap_or(0);
+
+    Some(
+      (0..self.height)
+        .map(|row| {
+          if (thumb_start..thumb_start + thumb_height).contains(&row) {
+            SCROLLBAR_THUMB
+          } else {
+            SCROLLBAR_TRACK
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+impl Reflow for ListView {
+  /// Resizes the list, scrolling to keep the selected item in view.
+  fn reflow(&mut self, (width, height): (usize, usize)) {
+    self.width = width;
+    self.height = height;
+
+    match self.selected {
+      Some(selected) => self.select(selected),
+      None => self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset()),
+    }
+  }
+
+  fn dimensions(&self) -> (usize, usize) {
+    (self.width, self.height)
+  }
+
+  fn render(&self) -> String {
+    ListView::render(self)
+  }
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use super::*;
+
+/// Returns a list of 10 items, named after their index.
+fn get_numbered_list(height: usize) -> ListView {
+  ListView::new(4, height).with_items((0..10).map(|index| index.to_string()).collect())
+}
+
+#[cfg(test)]
+mod selection_tests {
+  use super::*;
+
+  #[test]
+  fn selecting_above_view_scrolls_up() {
+    let mut list_view = get_numbered_list(3);
+    list_view.replace_scroll_offset(5);
+
+    list_view.select(2);
+
+    assert_eq!(list_view.get_scroll_offset(), 2);
+  }
+
+  #[test]
+  fn selection_is_clamped_to_items() {
+    let mut list_view = get_numbered_list(3);
+
+    list_view.select(50);
+
+    assert_eq!(list_view.get_selected(), Some(9));
+    assert_eq!(list_view.get_scroll_offset(), 7);
+  }
+
+  #[test]
+  fn replacing_items_keeps_selection_in_list() {
+    let mut list_view = get_numbered_list(3);
+    list_view.select(8);
+
+    list_view.replace_items(vec!["a", "b"]);
+
+    assert_eq!(list_view.get_selected(), Some(1));
+    assert_eq!(list_view.get_scroll_offset(), 0);
+  }
+}
+
+#[cfg(test)]
+mod render_tests {
+  use super::*;
+
+  #[test]
+  fn selected_item_is_highlighted() {
+    let mut list_view = ListView::new(3, 2).with_items(vec!["a", "\x1B[31mb\x1B[0m"]);
+    list_view.select(1);
+
+    assert_eq!(list_view.render(), "a  \n\x1B[7mb  \x1B[0m");
+  }
+
+  #[test]
+  fn scrollbar_shows_position() {
+    let mut list_view = get_numbered_list(5).with_scrollbar(true);
+
+    assert_eq!(list_view.render(), "0  █\n1  █\n2  │\n3  │\n4  │");
+
+    list_view.replace_scroll_offset(5);
+
+    assert_eq!(list_view.render(), "5  │\n6  │\n7  │\n8  █\n9  █");
+  }
+
+  #[test]
+  fn short_list_is_padded() {
+    let list_view = ListView::new(2, 3)
+      .with_items(vec!["a"])
+      .with_scrollbar(true);
+
+    assert_eq!(list_view.render(), "a \n  \n  ");
+  }
+}
+
+#[cfg(test)]
+mod reflow_tests {
+  use super::*;
+
+  #[test]
+  fn reflowing_keeps_the_selection_in_view() {
+    let mut list_view = ListView::new(3, 3).with_items(vec!["a", "b", "c"]);
+    list_view.select(2);
+
+    list_view.reflow((2, 1));
+
+    assert_eq!(list_view.dimensions(), (2, 1));
+    assert_eq!(list_view.get_scroll_offset(), 2);
+    assert_eq!(crate::ansi::strip_ansi(&Reflow::render(&list_view)), "c ");
+  }
+}
@@ -0,0 +1,159 @@
+mod tests;
+
+use crate::ansi::strip_ansi;
+use crate::widgets::Reflow;
+use unicode_width::UnicodeWidthChar;
+
+/// The amount of whitespace between the end of the text and the start of its next repetition, unless replaced.
+const DEFAULT_GAP: usize = 3;
+
+/// Text scrolling horizontally through a single row of a fixed width, such as a long message in a status bar.
+///
+/// Each [`tick`](Marquee::tick) moves the text one column to the left, wrapping back around after a gap.
+/// Text that fits within the width doesn't scroll.
+/// Since only one row changes between ticks, it can be printed as part of a larger grid without reprinting the rest.
+///
+/// Any styling in the text is removed.
+///
+/// # Example
+/// ```
+/// use screen_printer::widgets::*;
+///
+/// let mut marquee = Marquee::new("Hello", 4).with_gap(1);
+///
+/// assert_eq!(marquee.tick(), "Hell");
+/// assert_eq!(marquee.tick(), "ello");
+/// assert_eq!(marquee.tick(), "llo ");
+/// assert_eq!(marquee.tick(), "lo H");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Marquee {
+  /// Every column of the text followed by its gap, where None is the column covered by the rest of a wide character.
+  columns: Vec<Option<char>>,
+  text_width: usize,
+  width: usize,
+  gap: usize,
+  offset: usize,
+}
+
+impl Marquee {
+  /// Creates a marquee of the given width, showing the start of the text.
+  pub fn new(text: &str, width: usize) -> Self {
+    let mut marquee = Self {
+      width,
+      gap: DEFAULT_GAP,
+      ..Default::default()
+    };
+    marquee.replace_text(text);
+
+    marquee
+  }
+
+  /// Replaces the amount of whitespace between the end of the text and the start of its next repetition.
+  pub fn with_gap(mut self, gap: usize) -> Self {
+    self.gap = gap;
+    self.update_gap();
+
+    self
+  }
+
+  /// Replaces the text of the marquee, starting back at the start of the text.
+  pub fn replace_text(&mut self, text: &str) {
+    self.columns.clear();
+
+    for character in strip_ansi(text).chars() {
+      match character.width() {
+        Some(0) | None => continue,
+        Some(character_width) => {
+          self.columns.push(Some(character));
+          self.columns.extend((1..character_width).map(|_| None));
+        }
+      }
+    }
+
+    self.text_width = self.columns.len();
+    self.offset = 0;
+    self.update_gap();
+  }
+
+  /// Returns the column of the text shown at the left of the marquee.
+  pub fn get_offset(&self) -> usize {
+    self.offset
+  }
+
+  /// Returns true if the text is wider than the marquee, which is when it scrolls.
+  pub fn is_scrolling(&self) -> bool {
+    self.text_width > self.width
+  }
+
+  /// Renders the current frame, then moves the text one column to the left for the next frame.
+  pub fn tick(&mut self) -> String {
+    let frame = self.render();
+
+    if self.is_scrolling() {
+      self.offset = (self.offset + 1) % self.columns.len();
+    }
+
+    frame
+  }
+
+  /// Renders the current frame into a single row of the marquee's width, without moving the text.
+  ///
+  /// Wide characters cut off by either edge are replaced with whitespace.
+  pub fn render(&self) -> String {
+    if !self.is_scrolling() {
+      let text: String = self.columns[..self.text_width].iter().flatten().collect();
+
+      return format!("{text}{}", " ".repeat(self.width - self.text_width));
+    }
+
+    let column_at = |column: usize| self.columns[(self.offset + column) % self.columns.len()];
+    let mut frame = String::with_capacity(self.width);
+    let mut column = 0;
+
+    while column < self.width {
+      match column_at(column) {
+        Some(character) => {
+          let character_width = character.width().unwrap_or(1);
+
+          if column + character_width > self.width {
+            frame.push_str(&" ".repeat(self.width - column));
+          } else {
+            frame.push(character);
+          }
+
+          column += character_width;
+        }
+        // The rest of a wide character that started before the left edge.
+        None => {
+          frame.push(' ');
+          column += 1;
+        }
+      }
+    }
+
+    frame
+  }
+
+  /// Replaces the gap after the text with the current amount of whitespace.
+  fn update_gap(&mut self) {
+    self.columns.truncate(self.text_width);
+    self.columns.extend((0..self.gap).map(|_| Some(' ')));
+    self.offset = self.offset.min(self.columns.len().saturating_sub(1));
+  }
+}
+
+impl Reflow for Marquee {
+  /// Changes the width of the marquee, which is always a single row tall.
+  fn reflow(&mut self, (width, _): (usize, usize)) {
+    self.width = width;
+  }
+
+  fn dimensions(&self) -> (usize, usize) {
+    (self.width, 1)
+  }
+
+  fn render(&self) -> String {
+    Marquee::render(self)
+  }
+}
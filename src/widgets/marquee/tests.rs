@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod tick_tests {
+  use super::*;
+
+  #[test]
+  fn text_wraps_around_after_gap() {
+    let mut marquee = Marquee::new("abc", 2).with_gap(1);
+
+    let frames: Vec<String> = (0..5).map(|_| marquee.tick()).collect();
+
+    assert_eq!(frames, vec!["ab", "bc", "c ", " a", "ab"]);
+  }
+
+  #[test]
+  fn short_text_does_not_scroll() {
+    let mut marquee = Marquee::new("ab", 4);
+
+    assert!(!marquee.is_scrolling());
+    assert_eq!(marquee.tick(), "ab  ");
+    assert_eq!(marquee.tick(), "ab  ");
+    assert_eq!(marquee.get_offset(), 0);
+  }
+
+  #[test]
+  fn cut_off_wide_characters_are_whitespace() {
+    let mut marquee = Marquee::new("a日本", 2).with_gap(0);
+
+    let frames: Vec<String> = (0..4).map(|_| marquee.tick()).collect();
+
+    assert_eq!(frames, vec!["a ", "日", "  ", "本"]);
+  }
+
+  #[test]
+  fn styles_are_removed() {
+    let marquee = Marquee::new("\x1B[31mabc\x1B[0m", 3);
+
+    assert_eq!(marquee.render(), "abc");
+  }
+}
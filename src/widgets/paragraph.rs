@@ -0,0 +1,163 @@
+mod tests;
+
+use crate::cells::{fit_row_to_width, measure_row_width};
+use crate::widgets::Reflow;
+use unicode_width::UnicodeWidthChar;
+
+/// A block of text wrapped to a fixed width, showing as many lines as fit in its height.
+///
+/// Scrolling moves which lines are shown. Since the lines are only wrapped when the text changes,
+/// scrolling is cheap, and printing each new frame only reprints the rows that changed.
+///
+/// # Example
+/// ```
+/// use screen_printer::widgets::*;
+///
+/// let mut paragraph = Paragraph::new("The quick brown fox jumps over the lazy dog.", 10, 2);
+///
+/// assert_eq!(paragraph.render(), "The quick \nbrown fox ");
+///
+/// paragraph.scroll_down(2);
+///
+/// assert_eq!(paragraph.render(), "jumps over\nthe lazy  ");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Paragraph {
+  text: String,
+  lines: Vec<String>,
+  width: usize,
+  height: usize,
+  scroll_offset: usize,
+}
+
+impl Paragraph {
+  /// Creates a paragraph out of the text, wrapped to fit within the given width, and showing the given amount of lines.
+  ///
+  /// Text is wrapped between words where possible, and words longer than the width are split across lines.
+  /// Newlines in the text always start a new line.
+  pub fn new(text: &str, width: usize, height: usize) -> Self {
+    Self {
+      text: text.to_string(),
+      lines: wrap_text(text, width),
+      width,
+      height,
+      scroll_offset: 0,
+    }
+  }
+
+  /// Replaces the text of the paragraph, keeping the scroll offset within the new text.
+  pub fn replace_text(&mut self, text: &str) {
+    self.text = text.to_string();
+    self.lines = wrap_text(text, self.width);
+    self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+  }
+
+  /// Returns the lines of the paragraph after being wrapped.
+  pub fn get_lines(&self) -> &[String] {
+    &self.lines
+  }
+
+  /// Scrolls the paragraph down by the given amount of lines, stopping once the last line is at the bottom.
+  pub fn scroll_down(&mut self, lines: usize) {
+    self.scroll_offset = (self.scroll_offset + lines).min(self.max_scroll_offset());
+  }
+
+  /// Scrolls the paragraph up by the given amount of lines, stopping at the first line.
+  pub fn scroll_up(&mut self, lines: usize) {
+    self.scroll_offset = self.scroll_offset.saturating_sub(lines);
+  }
+
+  /// Returns the index of the line at the top of the paragraph.
+  pub fn get_scroll_offset(&self) -> usize {
+    self.scroll_offset
+  }
+
+  /// Renders the lines in view into a grid of the paragraph's width and height.
+  pub fn render(&self) -> String {
+    (self.scroll_offset..self.scroll_offset + self.height)
+      .map(|index| fit_row_to_width(self.lines.get(index).map_or("", String::as_str), self.width))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Returns the largest scroll offset that still fills the paragraph with lines.
+  fn max_scroll_offset(&self) -> usize {
+    self.lines.len().saturating_sub(self.height)
+  }
+}
+
+impl Reflow for Paragraph {
+  /// Wraps the text again to the new width, keeping the scroll offset within the text.
+  fn reflow(&mut self, (width, height): (usize, usize)) {
+    if width != self.width {
+      self.width = width;
+      self.lines = wrap_text(&self.text, width);
+    }
+
+    self.height = height;
+    self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+  }
+
+  fn dimensions(&self) -> (usize, usize) {
+    (self.width, self.height)
+  }
+
+  fn render(&self) -> String {
+    Paragraph::render(self)
+  }
+}
+
+/// Wraps the text into lines no wider than the given width.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+  if width == 0 {
+    return Vec::new();
+  }
+
+  let mut lines = Vec::new();
+
+  for text_line in text.lines() {
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in text_line.split_whitespace() {
+      let word_width = measure_row_width(word);
+
+      if line_width > 0 && line_width + 1 + word_width <= width {
+        line.push(' ');
+        line.push_str(word);
+        line_width += 1 + word_width;
+
+        continue;
+      }
+
+      if line_width > 0 {
+        lines.push(std::mem::take(&mut line));
+        line_width = 0;
+      }
+
+      if word_width <= width {
+        line.push_str(word);
+        line_width = word_width;
+
+        continue;
+      }
+
+      // Words too long for a line are split wherever the line runs out.
+      for character in word.chars() {
+        let character_width = character.width().unwrap_or(0);
+
+        if line_width + character_width > width {
+          lines.push(std::mem::take(&mut line));
+          line_width = 0;
+        }
+
+        line.push(character);
+        line_width += character_width;
+      }
+    }
+
+    lines.push(line);
+  }
+
+  lines
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod wrap_text_tests {
+  use super::*;
+
+  #[test]
+  fn long_words_are_split() {
+    assert_eq!(wrap_text("a abcdefgh b", 4), vec!["a", "abcd", "efgh", "b"]);
+  }
+
+  #[test]
+  fn newlines_are_kept() {
+    assert_eq!(wrap_text("ab\n\ncd ef", 5), vec!["ab", "", "cd ef"]);
+  }
+
+  #[test]
+  fn wide_characters_are_measured() {
+    assert_eq!(wrap_text("日本語 ab", 5), vec!["日本", "語 ab"]);
+  }
+}
+
+#[cfg(test)]
+mod scroll_tests {
+  use super::*;
+
+  #[test]
+  fn scrolling_stops_at_ends() {
+    let mut paragraph = Paragraph::new("a\nb\nc\nd", 1, 2);
+
+    paragraph.scroll_down(10);
+    assert_eq!(paragraph.get_scroll_offset(), 2);
+    assert_eq!(paragraph.render(), "c\nd");
+
+    paragraph.scroll_up(10);
+    assert_eq!(paragraph.get_scroll_offset(), 0);
+  }
+
+  #[test]
+  fn replacing_text_keeps_scroll_in_text() {
+    let mut paragraph = Paragraph::new("a\nb\nc\nd", 2, 2);
+    paragraph.scroll_down(2);
+
+    paragraph.replace_text("a\nb\nc");
+
+    assert_eq!(paragraph.get_scroll_offset(), 1);
+    assert_eq!(paragraph.render(), "b \nc ");
+  }
+}
+
+#[cfg(test)]
+mod reflow_tests {
+  use super::*;
+
+  #[test]
+  fn reflowing_rewraps_the_text() {
+    let mut paragraph = Paragraph::new("ab cd ef", 5, 2);
+
+    paragraph.reflow((2, 3));
+
+    assert_eq!(paragraph.dimensions(), (2, 3));
+    assert_eq!(paragraph.render(), "ab\ncd\nef");
+  }
+
+  #[test]
+  fn reflowing_keeps_the_scroll_offset_within_the_text() {
+    let mut paragraph = Paragraph::new("ab cd ef gh", 2, 1);
+    paragraph.scroll_down(3);
+
+    paragraph.reflow((5, 1));
+
+    assert_eq!(paragraph.get_scroll_offset(), 1);
+    assert_eq!(paragraph.render(), "ef gh");
+  }
+
+  #[test]
+  fn resized_terminals_reflow_printed_paragraphs() {
+    use crate::printer::Printer;
+
+    let mut printer = Printer::on_virtual_terminal((5, 2));
+    let mut paragraph = Paragraph::new("ab cd", 5, 1);
+
+    printer.print_reflowed(&mut paragraph).unwrap();
+
+    assert_eq!(paragraph.dimensions(), (5, 2));
+
+    printer.replace_terminal_dimensions(Some((2, 2)));
+    printer.print_reflowed(&mut paragraph).unwrap();
+
+    assert_eq!(crate::snapshot::trimmed_screen(&printer), "ab\ncd");
+  }
+}
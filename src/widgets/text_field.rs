@@ -0,0 +1,203 @@
+mod tests;
+
+use crate::ansi::strip_ansi;
+use crate::widgets::Reflow;
+use unicode_width::UnicodeWidthChar;
+
+/// A single row of editable text, rendering the part of its content around the cursor.
+///
+/// Input is left to the caller, who supplies the content and where the cursor is within it.
+/// The field scrolls horizontally just enough to keep the cursor in view whenever either changes,
+/// leaving a column after the content for the cursor to sit at the end.
+///
+/// The cursor itself isn't drawn. Its [`column`](TextField::cursor_column) can be given to a
+/// [`Caret`](crate::caret::Caret) to show where input goes.
+///
+/// Any styling in the content is removed.
+///
+/// # Example
+/// ```
+/// use screen_printer::widgets::*;
+///
+/// let mut text_field = TextField::new(4);
+/// text_field.replace_content("hunter2");
+///
+/// assert_eq!(text_field.get_cursor_offset(), 7);
+/// assert_eq!(text_field.render(), "er2 ");
+/// assert_eq!(text_field.cursor_column(), Some(3));
+///
+/// let mut password_field = TextField::new(4).with_mask('*');
+/// password_field.replace_content("hunter2");
+/// password_field.replace_cursor_offset(0);
+///
+/// assert_eq!(password_field.render(), "****");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct TextField {
+  content: String,
+  width: usize,
+  mask: Option<char>,
+  cursor_offset: usize,
+  scroll_offset: usize,
+}
+
+impl TextField {
+  /// Creates an empty text field of the given width.
+  pub fn new(width: usize) -> Self {
+    Self {
+      width,
+      ..Default::default()
+    }
+  }
+
+  /// Replaces every character of the content with the given character when rendered, such as for passwords.
+  pub fn with_mask(mut self, mask: char) -> Self {
+    self.mask = Some(mask);
+    self.scroll_to_cursor();
+
+    self
+  }
+
+  /// Replaces the content of the field, placing the cursor at the end of it.
+  pub fn replace_content<S: Into<String>>(&mut self, content: S) {
+    self.content = strip_ansi(&content.into());
+    self.cursor_offset = self.content.chars().count();
+    self.scroll_to_cursor();
+  }
+
+  /// Returns the content of the field.
+  pub fn get_content(&self) -> &str {
+    &self.content
+  }
+
+  /// Moves the cursor to before the character at the given index in the content,
+  /// or the end of the content if the index is past it.
+  ///
+  /// The field is scrolled to keep the cursor in view.
+  pub fn replace_cursor_offset(&mut self, cursor_offset: usize) {
+    self.cursor_offset = cursor_offset.min(self.content.chars().count());
+    self.scroll_to_cursor();
+  }
+
+  /// Returns the index of the character in the content the cursor is before.
+  pub fn get_cursor_offset(&self) -> usize {
+    self.cursor_offset
+  }
+
+  /// Scrolls the field so the content starts the given amount of columns to the left of the field.
+  ///
+  /// The scroll offset is kept within the content, but can move the cursor out of view.
+  pub fn replace_scroll_offset(&mut self, scroll_offset: usize) {
+    self.scroll_offset = scroll_offset.min(self.max_scroll_offset());
+  }
+
+  /// Returns how many columns of the content are scrolled past the left of the field.
+  pub fn get_scroll_offset(&self) -> usize {
+    self.scroll_offset
+  }
+
+  /// Returns the column of the field the cursor is in, or None if it's scrolled out of view.
+  pub fn cursor_column(&self) -> Option<usize> {
+    self
+      .column_of(self.cursor_offset)
+      .checked_sub(self.scroll_offset)
+      .filter(|column| *column < self.width)
+  }
+
+  /// Renders the content in view into a single row of the field's width.
+  ///
+  /// Wide characters cut off by either edge are replaced with whitespace.
+  pub fn render(&self) -> String {
+    let visible_columns = self.scroll_offset..self.scroll_offset + self.width;
+    let mut rendered_row = String::with_capacity(self.width);
+    let mut column = 0;
+
+    for (character, character_width) in self.displayed_characters() {
+      let character_columns = column..column + character_width;
+      column += character_width;
+
+      if character_columns.end <= visible_columns.start || character_width == 0 {
+        continue;
+      }
+
+      if character_columns.start >= visible_columns.end {
+        break;
+      }
+
+      if character_columns.start < visible_columns.start
+        || character_columns.end > visible_columns.end
+      {
+        let cut_off_width = character_columns.end.min(visible_columns.end)
+          - character_columns.start.max(visible_columns.start);
+        rendered_row.push_str(&" ".repeat(cut_off_width));
+      } else {
+        rendered_row.push(character);
+      }
+    }
+
+    let rendered_width =
+      column.clamp(visible_columns.start, visible_columns.end) - visible_columns.start;
+    rendered_row.push_str(&" ".repeat(self.width - rendered_width));
+
+    rendered_row
+  }
+
+  /// Returns every character as it's displayed, along with its width.
+  fn displayed_characters(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+    self.content.chars().map(|character| {
+      let character = match self.mask {
+        Some(mask) if character.width().unwrap_or(0) > 0 => mask,
+        _ => character,
+      };
+
+      (character, character.width().unwrap_or(0))
+    })
+  }
+
+  /// Returns the column of the content the character at the given index starts at.
+  fn column_of(&self, character_index: usize) -> usize {
+    self
+      .displayed_characters()
+      .take(character_index)
+      .map(|(_, character_width)| character_width)
+      .sum()
+  }
+
+  /// Returns the largest scroll offset, where the column after the content is at the right of the field.
+  fn max_scroll_offset(&self) -> usize {
+    (self.column_of(usize::MAX) + 1).saturating_sub(self.width)
+  }
+
+  /// Scrolls just enough to keep the cursor in view, and the scroll offset within the content.
+  fn scroll_to_cursor(&mut self) {
+    let cursor_column = self.column_of(self.cursor_offset);
+    let cursor_width = self
+      .displayed_characters()
+      .nth(self.cursor_offset)
+      .map_or(1, |(_, character_width)| character_width.max(1));
+
+    if cursor_column < self.scroll_offset {
+      self.scroll_offset = cursor_column;
+    } else if cursor_column + cursor_width > self.scroll_offset + self.width {
+      self.scroll_offset = (cursor_column + cursor_width).saturating_sub(self.width);
+    }
+
+    self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+  }
+}
+
+impl Reflow for TextField {
+  /// Changes the width of the field, which is always a single row tall, scrolling to keep the cursor in view.
+  fn reflow(&mut self, (width, _): (usize, usize)) {
+    self.width = width;
+    self.scroll_to_cursor();
+  }
+
+  fn dimensions(&self) -> (usize, usize) {
+    (self.width, 1)
+  }
+
+  fn render(&self) -> String {
+    TextField::render(self)
+  }
+}
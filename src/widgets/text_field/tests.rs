@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use super::*;
+
+#[cfg(test)]
+mod render_tests {
+  use super::*;
+
+  #[test]
+  fn short_content_is_padded() {
+    let mut text_field = TextField::new(5);
+    text_field.replace_content("ab");
+
+    assert_eq!(text_field.render(), "ab   ");
+    assert_eq!(text_field.cursor_column(), Some(2));
+  }
+
+  #[test]
+  fn cut_off_wide_characters_are_whitespace() {
+    let mut text_field = TextField::new(3);
+    text_field.replace_content("a日本");
+    text_field.replace_scroll_offset(0);
+
+    assert_eq!(text_field.render(), "a日");
+
+    text_field.replace_scroll_offset(2);
+
+    assert_eq!(text_field.render(), " 本");
+  }
+
+  #[test]
+  fn mask_keeps_widths_of_mask() {
+    let mut text_field = TextField::new(4).with_mask('•');
+    text_field.replace_content("日本");
+
+    assert_eq!(text_field.render(), "••  ");
+  }
+}
+
+#[cfg(test)]
+mod cursor_tests {
+  use super::*;
+
+  #[test]
+  fn field_scrolls_to_keep_cursor_in_view() {
+    let mut text_field = TextField::new(3);
+    text_field.replace_content("abcdef");
+
+    assert_eq!(text_field.get_scroll_offset(), 4);
+    assert_eq!(text_field.render(), "ef ");
+
+    text_field.replace_cursor_offset(1);
+
+    assert_eq!(text_field.get_scroll_offset(), 1);
+    assert_eq!(text_field.render(), "bcd");
+    assert_eq!(text_field.cursor_column(), Some(0));
+  }
+
+  #[test]
+  fn cursor_offset_is_kept_within_content() {
+    let mut text_field = TextField::new(3);
+    text_field.replace_content("ab");
+    text_field.replace_cursor_offset(10);
+
+    assert_eq!(text_field.get_cursor_offset(), 2);
+  }
+
+  #[test]
+  fn scrolled_out_cursor_has_no_column() {
+    let mut text_field = TextField::new(2);
+    text_field.replace_content("abcd");
+    text_field.replace_scroll_offset(0);
+
+    assert_eq!(text_field.cursor_column(), None);
+  }
+}
@@ -0,0 +1,182 @@
+mod tests;
+
+use crate::cells::fit_row_to_width;
+
+/// The marker placed before the label of an expanded item with children.
+const EXPANDED_MARKER: &str = "▾ ";
+/// The marker placed before the label of a collapsed item with children.
+const COLLAPSED_MARKER: &str = "▸ ";
+
+/// A single item of a tree, with any items nested under it.
+///
+/// Whether an item is expanded is up to the caller, such as toggling it when the user selects it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TreeItem {
+  /// The text displayed for this item, which can contain style escape sequences.
+  pub label: String,
+  /// Whether the children of this item are displayed.
+  pub expanded: bool,
+  /// The items nested under this one.
+  pub children: Vec<TreeItem>,
+}
+
+impl TreeItem {
+  /// Creates a new expanded item with no children.
+  pub fn new<S: Into<String>>(label: S) -> Self {
+    Self {
+      label: label.into(),
+      expanded: true,
+      children: Vec::new(),
+    }
+  }
+
+  /// Nests the given items under this one.
+  pub fn with_children(mut self, children: Vec<TreeItem>) -> Self {
+    self.children = children;
+
+    self
+  }
+
+  /// Sets whether the children of this item are displayed.
+  pub fn with_expanded(mut self, expanded: bool) -> Self {
+    self.expanded = expanded;
+
+    self
+  }
+}
+
+/// Renders a tree of items into a grid, connecting each item to its parent with box-drawing characters.
+///
+/// Only the rows within the viewport are rendered, which starts at the scroll offset and is as tall as the grid.
+/// Rows wider than the grid are cut off.
+///
+/// # Example
+/// ```
+/// use screen_printer::widgets::*;
+///
+/// let tree = vec![TreeItem::new("src").with_children(vec![
+///   TreeItem::new("widgets").with_children(vec![TreeItem::new("tree_view.rs")]).with_expanded(false),
+///   TreeItem::new("lib.rs"),
+/// ])];
+///
+/// let grid = TreeView::new(16, 3).render(&tree);
+///
+/// assert_eq!(
+///   grid,
+///   [
+///     "▾ src           ",
+///     "├── ▸ widgets   ",
+///     "└── lib.rs      ",
+///   ]
+///   .join("\n")
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct TreeView {
+  width: usize,
+  height: usize,
+  scroll_offset: usize,
+}
+
+impl TreeView {
+  /// Creates a tree view that renders into a grid of the given width and height.
+  pub fn new(width: usize, height: usize) -> Self {
+    Self {
+      width,
+      height,
+      scroll_offset: 0,
+    }
+  }
+
+  /// Starts the viewport at the given row of the tree.
+  pub fn with_scroll_offset(mut self, scroll_offset: usize) -> Self {
+    self.scroll_offset = scroll_offset;
+
+    self
+  }
+
+  /// Replaces the row of the tree the viewport starts at.
+  pub fn replace_scroll_offset(&mut self, scroll_offset: usize) {
+    self.scroll_offset = scroll_offset;
+  }
+
+  /// Returns the row of the tree the viewport starts at.
+  pub fn get_scroll_offset(&self) -> usize {
+    self.scroll_offset
+  }
+
+  /// Returns how many rows the tree takes up when every expanded item's children are displayed.
+  ///
+  /// This is for keeping the scroll offset within the tree.
+  pub fn row_count(items: &[TreeItem]) -> usize {
+    items
+      .iter()
+      .map(|item| {
+        let children_rows = if item.expanded {
+          Self::row_count(&item.children)
+        } else {
+          0
+        };
+
+        1 + children_rows
+      })
+      .sum()
+  }
+
+  /// Renders the rows of the tree within the viewport.
+  ///
+  /// Items at the top level aren't connected to anything, while every nested item is connected to its parent.
+  pub fn render(&self, items: &[TreeItem]) -> String {
+    let mut rows = Vec::new();
+    let last_row = self.scroll_offset + self.height;
+
+    for item in items {
+      collect_rows(item, "", None, last_row, &mut rows);
+    }
+
+    (self.scroll_offset..last_row)
+      .map(|index| fit_row_to_width(rows.get(index).map_or("", String::as_str), self.width))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+}
+
+/// Adds the rows for the item and its displayed children, stopping once the given amount of rows have been collected.
+///
+/// The prefix is what's placed before the item's connector.
+/// Items with a parent are given whether they're the last child of that parent, which decides how they're connected to it.
+fn collect_rows(
+  item: &TreeItem,
+  prefix: &str,
+  is_last_child: Option<bool>,
+  row_limit: usize,
+  rows: &mut Vec<String>,
+) {
+  if rows.len() >= row_limit {
+    return;
+  }
+
+  let marker = match (item.children.is_empty(), item.expanded) {
+    (true, _) => "",
+    (false, true) => EXPANDED_MARKER,
+    (false, false) => COLLAPSED_MARKER,
+  };
+
+  let (connector, child_prefix) = match is_last_child {
+    Some(true) => ("└── ", format!("{prefix}    ")),
+    Some(false) => ("├── ", format!("{prefix}│   ")),
+    None => ("", prefix.to_string()),
+  };
+
+  rows.push(format!("{prefix}{connector}{marker}{}", item.label));
+
+  if !item.expanded {
+    return;
+  }
+
+  for (index, child) in item.children.iter().enumerate() {
+    let is_last_child = index + 1 == item.children.len();
+
+    collect_rows(child, &child_prefix, Some(is_last_child), row_limit, rows);
+  }
+}
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use super::*;
+
+/// Returns a tree with a few levels of nesting.
+fn get_nested_tree() -> Vec<TreeItem> {
+  vec![
+    TreeItem::new("a").with_children(vec![
+      TreeItem::new("b").with_children(vec![TreeItem::new("c"), TreeItem::new("d")]),
+      TreeItem::new("e").with_children(vec![TreeItem::new("f")]),
+    ]),
+    TreeItem::new("g"),
+  ]
+}
+
+#[cfg(test)]
+mod render_tests {
+  use super::*;
+
+  #[test]
+  fn nested_items_are_connected() {
+    let grid = TreeView::new(14, 7).render(&get_nested_tree());
+
+    let expected_grid = [
+      "▾ a           ",
+      "├── ▾ b       ",
+      "│   ├── c     ",
+      "│   └── d     ",
+      "└── ▾ e       ",
+      "    └── f     ",
+      "g             ",
+    ]
+    .join("\n");
+
+    assert_eq!(grid, expected_grid);
+  }
+
+  #[test]
+  fn viewport_shows_scrolled_rows() {
+    let grid = TreeView::new(8, 3)
+      .with_scroll_offset(5)
+      .render(&get_nested_tree());
+
+    assert_eq!(grid, "    └── \ng       \n        ");
+  }
+
+  #[test]
+  fn collapsed_items_hide_children() {
+    let mut tree = get_nested_tree();
+    tree[0].children[0].expanded = false;
+
+    assert_eq!(TreeView::row_count(&tree), 5);
+  }
+}